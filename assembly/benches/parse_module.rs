@@ -0,0 +1,32 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use miden_assembly::{parse_module, Assembler};
+use std::time::Duration;
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+fn parse_large_module(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_large_module");
+    group.measurement_time(Duration::from_secs(10));
+
+    let source = common::build_synthetic_module(200, 100);
+    group.throughput(Throughput::Bytes(source.len() as u64));
+
+    group.bench_function("parse_module", |bench| {
+        bench.iter(|| parse_module(&source).expect("failed to parse synthetic module"));
+    });
+
+    let program = format!("{source}begin\n    exec.proc_199\nend\n");
+    group.bench_function("compile", |bench| {
+        bench.iter(|| {
+            Assembler::new()
+                .compile(&program)
+                .expect("failed to compile synthetic program")
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(parse_group, parse_large_module);
+criterion_main!(parse_group);
@@ -0,0 +1,120 @@
+use super::{parse_module, ModuleAst, ModuleProvider, NamedModuleAst, ProcedureId, String, Vec};
+
+// SYNTHETIC SOURCES
+// ================================================================================================
+
+/// Source code of the kernel against which generated programs are compiled.
+pub const KERNEL_SOURCE: &str = "\
+export.kernel_add
+    add
+end
+
+export.kernel_caller
+    caller
+end";
+
+/// Paths and source code of modules which can be imported by generated programs.
+const MODULES: [(&str, &str); 2] = [
+    (
+        "synthetic::math",
+        "\
+proc.square
+    dup mul
+end
+
+export.sum_of_squares
+    exec.square swap exec.square add
+end
+
+export.add3
+    add add
+end
+
+export.scale.1
+    loc_store.0 push.3 loc_load.0 mul
+end",
+    ),
+    (
+        "synthetic::stack",
+        "\
+export.rotate_words
+    swapw movupw.2
+end
+
+export.checked_sum
+    u32checked_add u32checked_add
+end
+
+export.hash
+    rphash
+end",
+    ),
+];
+
+// SYNTHETIC LIBRARY
+// ================================================================================================
+
+/// A module provider which resolves modules imported by generated programs.
+///
+/// The library consists of a couple of small modules rooted at `synthetic` namespace. The modules
+/// contain both exported and internal procedures.
+pub struct SyntheticLibrary {
+    modules: Vec<(&'static str, ModuleAst)>,
+}
+
+impl Default for SyntheticLibrary {
+    fn default() -> Self {
+        let modules = MODULES
+            .iter()
+            .map(|&(path, source)| {
+                let module = parse_module(source).expect("invalid synthetic module");
+                (path, module)
+            })
+            .collect();
+        Self { modules }
+    }
+}
+
+impl SyntheticLibrary {
+    /// Returns an iterator over paths of modules in this library together with names of the
+    /// procedures exported from each module.
+    pub fn exports(&self) -> impl Iterator<Item = (&'static str, Vec<&str>)> + '_ {
+        self.modules.iter().map(|(path, module)| {
+            let names = module
+                .local_procs
+                .iter()
+                .filter(|proc| proc.is_export)
+                .map(|proc| proc.name.as_str())
+                .collect();
+            (*path, names)
+        })
+    }
+}
+
+impl ModuleProvider for SyntheticLibrary {
+    fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.modules
+            .iter()
+            .find(|(path, module)| {
+                module
+                    .local_procs
+                    .iter()
+                    .any(|proc| &ProcedureId::from_name(&proc.name, path) == id)
+            })
+            .map(|(path, module)| module.named_ref(*path))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns names of the procedures exported from the synthetic kernel.
+pub fn kernel_exports() -> Vec<String> {
+    parse_module(KERNEL_SOURCE)
+        .expect("invalid synthetic kernel")
+        .local_procs
+        .into_iter()
+        .filter(|proc| proc.is_export)
+        .map(|proc| proc.name)
+        .collect()
+}
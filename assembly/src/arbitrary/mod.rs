@@ -0,0 +1,44 @@
+//! Generators of Miden assembly programs for fuzzing and property-based testing.
+//!
+//! This module provides two generators:
+//! - [GeneratedProgram] - a program which is valid by construction. Generated programs can be
+//!   compiled against [SyntheticLibrary] and the synthetic kernel, and their execution always
+//!   terminates.
+//! - [NearMissSource] - source code of a program which is likely to be slightly malformed. Such
+//!   sources are useful for exercising error handling of the parser.
+//!
+//! Both generators implement [Arbitrary] trait, and thus, can be used directly in `cargo fuzz`
+//! targets. To use them with property-testing frameworks, such as proptest, generate a vector of
+//! bytes and build a value from it via [from_bytes()].
+use super::{
+    parse_module, Assembler, AssemblyError, BTreeMap, Felt, ModuleAst, ModuleProvider,
+    NamedModuleAst, ProcedureAst, ProcedureId, Program, ProgramAst, String, ToString, Vec,
+    MODULE_PATH_DELIM, ONE, ZERO,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+mod library;
+pub use library::{SyntheticLibrary, KERNEL_SOURCE};
+
+mod program;
+pub use program::GeneratedProgram;
+
+mod source;
+pub use source::NearMissSource;
+
+#[cfg(test)]
+mod tests;
+
+// PUBLIC FUNCTIONS
+// ================================================================================================
+
+/// Builds a value of the specified type from the provided bytes.
+///
+/// Generators in this module never run out of bytes: when all bytes have been consumed, the
+/// generated program is completed using the smallest possible blocks.
+///
+/// # Panics
+/// Panics if the value cannot be built from the provided bytes.
+pub fn from_bytes<'a, T: Arbitrary<'a>>(bytes: &'a [u8]) -> T {
+    T::arbitrary_take_rest(Unstructured::new(bytes)).expect("failed to build arbitrary value")
+}
@@ -0,0 +1,598 @@
+use super::{
+    library::kernel_exports, Arbitrary, Assembler, AssemblyError, BTreeMap, Felt, ProcedureAst,
+    ProcedureId, Program, ProgramAst, Result, String, SyntheticLibrary, Unstructured, Vec,
+    KERNEL_SOURCE, MODULE_PATH_DELIM, ONE, ZERO,
+};
+use crate::{
+    parsers::{Instruction, Node},
+    ADVICE_READ_LIMIT, MAX_EXP_BITS, MAX_PUSH_INPUTS, MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE,
+};
+use core::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of local procedures in a generated program.
+const MAX_PROCS: usize = 4;
+
+/// The maximum number of memory locals of a generated procedure.
+const MAX_PROC_LOCALS: u16 = 4;
+
+/// The maximum nesting depth of control flow blocks in a generated procedure or program body.
+const MAX_NESTING_DEPTH: usize = 3;
+
+/// The maximum number of nodes in a single block of a generated procedure or program body.
+const MAX_BLOCK_LEN: usize = 8;
+
+/// The maximum number of iterations of a generated `repeat` block.
+const MAX_REPEAT_COUNT: usize = 4;
+
+/// The maximum length of the random suffix of a generated procedure name.
+const MAX_PROC_NAME_SUFFIX_LEN: usize = 8;
+
+/// The maximum number of words copied by a generated `adv.mem` instruction.
+const MAX_ADV_MEM_WORDS: u32 = 4;
+
+/// The approximate maximum number of instructions executed by a generated procedure or program
+/// body. Procedure invocations and loops are accounted for, and thus, the budget also bounds the
+/// size of the compiled program.
+const MAX_EXECUTED_INSTRUCTIONS: usize = 1 << 12;
+
+/// Instructions which do not have parameters and which are valid outside of the kernel.
+const NULLARY_INSTRUCTIONS: &[Instruction] = {
+    use Instruction::*;
+    &[
+        // ----- field operations -----------------------------------------------------------------
+        Assert,
+        AssertEq,
+        Assertz,
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Neg,
+        Inv,
+        Pow2,
+        Exp,
+        Not,
+        And,
+        Or,
+        Xor,
+        Eq,
+        Neq,
+        Eqw,
+        Lt,
+        Lte,
+        Gt,
+        Gte,
+        // ----- u32 operations -------------------------------------------------------------------
+        U32Test,
+        U32TestW,
+        U32Assert,
+        U32Assert2,
+        U32AssertW,
+        U32Split,
+        U32Cast,
+        U32CheckedAdd,
+        U32WrappingAdd,
+        U32OverflowingAdd,
+        U32OverflowingAdd3,
+        U32WrappingAdd3,
+        U32CheckedSub,
+        U32WrappingSub,
+        U32OverflowingSub,
+        U32CheckedMul,
+        U32WrappingMul,
+        U32OverflowingMul,
+        U32OverflowingMadd,
+        U32WrappingMadd,
+        U32CheckedDiv,
+        U32UncheckedDiv,
+        U32CheckedMod,
+        U32UncheckedMod,
+        U32CheckedDivMod,
+        U32UncheckedDivMod,
+        U32CheckedAnd,
+        U32CheckedOr,
+        U32CheckedXor,
+        U32CheckedNot,
+        U32CheckedShr,
+        U32UncheckedShr,
+        U32CheckedShl,
+        U32UncheckedShl,
+        U32CheckedRotr,
+        U32UncheckedRotr,
+        U32CheckedRotl,
+        U32UncheckedRotl,
+        U32CheckedEq,
+        U32CheckedNeq,
+        U32CheckedLt,
+        U32UncheckedLt,
+        U32CheckedLte,
+        U32UncheckedLte,
+        U32CheckedGt,
+        U32UncheckedGt,
+        U32CheckedGte,
+        U32UncheckedGte,
+        U32CheckedMin,
+        U32UncheckedMin,
+        U32CheckedMax,
+        U32UncheckedMax,
+        // ----- stack manipulation ---------------------------------------------------------------
+        Drop,
+        DropW,
+        PadW,
+        Dup0,
+        Dup1,
+        Dup2,
+        Dup3,
+        Dup4,
+        Dup5,
+        Dup6,
+        Dup7,
+        Dup8,
+        Dup9,
+        Dup10,
+        Dup11,
+        Dup12,
+        Dup13,
+        Dup14,
+        Dup15,
+        DupW0,
+        DupW1,
+        DupW2,
+        DupW3,
+        Swap1,
+        Swap2,
+        Swap3,
+        Swap4,
+        Swap5,
+        Swap6,
+        Swap7,
+        Swap8,
+        Swap9,
+        Swap10,
+        Swap11,
+        Swap12,
+        Swap13,
+        Swap14,
+        Swap15,
+        SwapW1,
+        SwapW2,
+        SwapW3,
+        SwapDw,
+        MovUp2,
+        MovUp3,
+        MovUp4,
+        MovUp5,
+        MovUp6,
+        MovUp7,
+        MovUp8,
+        MovUp9,
+        MovUp10,
+        MovUp11,
+        MovUp12,
+        MovUp13,
+        MovUp14,
+        MovUp15,
+        MovUpW2,
+        MovUpW3,
+        MovDn2,
+        MovDn3,
+        MovDn4,
+        MovDn5,
+        MovDn6,
+        MovDn7,
+        MovDn8,
+        MovDn9,
+        MovDn10,
+        MovDn11,
+        MovDn12,
+        MovDn13,
+        MovDn14,
+        MovDn15,
+        MovDnW2,
+        MovDnW3,
+        CSwap,
+        CSwapW,
+        CDrop,
+        CDropW,
+        // ----- input / output operations --------------------------------------------------------
+        Sdepth,
+        PushMastRoot,
+        MemLoad,
+        MemLoadW,
+        MemStore,
+        MemStoreW,
+        MemStream,
+        AdvPipe,
+        AdvLoadW,
+        AdvU64Div,
+        AdvU256Div,
+        AdvKeyval,
+        // ----- cryptographic operations ---------------------------------------------------------
+        RpHash,
+        RpPerm,
+        MTreeGet,
+        MTreeSet,
+        MTreeCwm,
+    ]
+};
+
+// GENERATED PROGRAM
+// ================================================================================================
+
+/// A randomly generated Miden assembly program which is valid by construction.
+///
+/// A generated program contains instructions of all families supported by the assembler (except
+/// for kernel-only instructions), control flow blocks of bounded nesting depth, local procedures
+/// with and without memory locals, and invocations of procedures imported from
+/// [SyntheticLibrary] modules and exported from the synthetic kernel.
+///
+/// The number of instructions executed by a program is bounded, and thus, executing a generated
+/// program always terminates. However, execution may still fail (e.g., on a failed assertion).
+#[derive(Debug)]
+pub struct GeneratedProgram {
+    imports: Vec<&'static str>,
+    labels: BTreeMap<ProcedureId, String>,
+    ast: ProgramAst,
+}
+
+impl GeneratedProgram {
+    /// Returns the AST of this program.
+    pub fn ast(&self) -> &ProgramAst {
+        &self.ast
+    }
+
+    /// Compiles this program against [SyntheticLibrary] and the synthetic kernel.
+    ///
+    /// # Errors
+    /// Returns an error if the program could not be compiled; this is never expected to happen
+    /// for a generated program.
+    pub fn compile(&self) -> core::result::Result<Program, AssemblyError> {
+        Assembler::default()
+            .with_kernel(KERNEL_SOURCE)?
+            .with_module_provider(SyntheticLibrary::default())
+            .compile(self.to_string())
+    }
+}
+
+impl fmt::Display for GeneratedProgram {
+    /// Writes source code of this program. Imported procedures are referenced via aliases of
+    /// their modules, and kernel procedures are referenced by their names.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.ast.write_source(f, &self.imports, &self.labels)
+    }
+}
+
+impl<'a> Arbitrary<'a> for GeneratedProgram {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut imports = Vec::new();
+        let mut labels = BTreeMap::new();
+        let mut generator = BodyGenerator::default();
+
+        // import an arbitrary subset of the synthetic modules
+        for (path, exports) in SyntheticLibrary::default().exports() {
+            if !u.arbitrary::<bool>()? {
+                continue;
+            }
+            imports.push(path);
+            let alias = path
+                .rsplit_once(MODULE_PATH_DELIM)
+                .map_or(path, |(_, alias)| alias);
+            for name in exports {
+                let proc_id = ProcedureId::from_name(name, path);
+                labels.insert(proc_id, format!("{alias}{MODULE_PATH_DELIM}{name}"));
+                generator.imported_procs.push(proc_id);
+            }
+        }
+
+        for name in kernel_exports() {
+            let proc_id = ProcedureId::from_kernel_name(&name);
+            labels.insert(proc_id, name);
+            generator.kernel_procs.push(proc_id);
+        }
+
+        // generate local procedures; a procedure can invoke only the procedures generated before
+        // it, and thus, there are no recursive invocations
+        let num_procs = u.int_in_range(0..=MAX_PROCS)?;
+        let mut local_procs = Vec::with_capacity(num_procs);
+        for index in 0..num_procs {
+            generator.num_locals = u.int_in_range(0..=MAX_PROC_LOCALS)?;
+            let (body, cost) = generator.block(u, 0, MAX_EXECUTED_INSTRUCTIONS)?;
+            local_procs.push(ProcedureAst {
+                name: proc_name(u, index)?,
+                docs: None,
+                num_locals: generator.num_locals,
+                body: body.into(),
+                is_export: false,
+            });
+            generator.proc_costs.push(cost);
+        }
+
+        // generate program body
+        generator.num_locals = 0;
+        let (body, _) = generator.block(u, 0, MAX_EXECUTED_INSTRUCTIONS)?;
+
+        Ok(Self {
+            imports,
+            labels,
+            ast: ProgramAst::new(local_procs, body.into()),
+        })
+    }
+}
+
+// BODY GENERATOR
+// ================================================================================================
+
+/// Generates bodies of procedures and programs.
+///
+/// Every generated node is accompanied by its cost - an estimate of the number of instructions
+/// executed by the node. Costs are used to make sure that execution of generated programs takes
+/// a reasonable number of cycles.
+#[derive(Default)]
+struct BodyGenerator {
+    /// IDs of procedures exported from imported modules.
+    imported_procs: Vec<ProcedureId>,
+    /// IDs of procedures exported from the kernel.
+    kernel_procs: Vec<ProcedureId>,
+    /// Costs of the local procedures generated so far.
+    proc_costs: Vec<usize>,
+    /// Number of memory locals of the procedure being generated.
+    num_locals: u16,
+}
+
+impl BodyGenerator {
+    /// Returns a non-empty block of nodes and its cost. Nodes are added to the block until either
+    /// the block has reached its arbitrary length or its cost has exceeded the budget.
+    fn block(
+        &self,
+        u: &mut Unstructured,
+        depth: usize,
+        budget: usize,
+    ) -> Result<(Vec<Node>, usize)> {
+        let len = u.int_in_range(1..=MAX_BLOCK_LEN)?;
+        let mut nodes = Vec::with_capacity(len);
+        let mut cost = 0;
+        while nodes.is_empty() || (nodes.len() < len && cost < budget) {
+            let (node, node_cost) = self.node(u, depth, budget.saturating_sub(cost))?;
+            nodes.push(node);
+            cost += node_cost;
+        }
+        Ok((nodes, cost))
+    }
+
+    /// Returns a single node and its cost. Most nodes are instructions, the rest are control
+    /// flow blocks.
+    fn node(&self, u: &mut Unstructured, depth: usize, budget: usize) -> Result<(Node, usize)> {
+        if depth == MAX_NESTING_DEPTH || u.ratio(3, 4)? {
+            let (instruction, cost) = self.instruction(u, budget)?;
+            return Ok((Node::Instruction(instruction), cost));
+        }
+
+        match u.int_in_range(0..=2)? {
+            0 => {
+                let (t_branch, cost) = self.block(u, depth + 1, budget)?;
+                let f_branch = if u.arbitrary()? {
+                    // the assembler requires both branches to have the same stack effect when it
+                    // can be computed; the else branch executes the nodes of the true branch in
+                    // an arbitrary order, and thus, the branches are always balanced
+                    shuffle(u, t_branch.clone())?
+                } else {
+                    Vec::new()
+                };
+                Ok((Node::IfElse(t_branch.into(), f_branch.into()), cost + 1))
+            }
+            1 => {
+                let count = u.int_in_range(1..=MAX_REPEAT_COUNT)?;
+                let (body, cost) = self.block(u, depth + 1, budget / count)?;
+                Ok((Node::Repeat(count, body.into()), cost * count))
+            }
+            _ => {
+                // the loop body always leaves ZERO at the top of the stack, and thus, the body is
+                // executed at most once
+                let (mut body, cost) = self.block(u, depth + 1, budget)?;
+                body.push(Node::Instruction(Instruction::PushConstants(vec![ZERO])));
+                Ok((Node::While(body.into()), cost + 2))
+            }
+        }
+    }
+
+    /// Returns a single instruction and its cost.
+    fn instruction(&self, u: &mut Unstructured, budget: usize) -> Result<(Instruction, usize)> {
+        use Instruction::*;
+
+        let instruction = match u.int_in_range(0..=15)? {
+            0 => field_instruction(u)?,
+            1 | 2 => u32_instruction(u)?,
+            3 => io_instruction(u)?,
+            4 if self.num_locals > 0 => self.local_instruction(u)?,
+            5 => {
+                // only procedures which fit into the budget can be invoked
+                let candidates = self
+                    .proc_costs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &cost)| cost <= budget)
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>();
+                match choose(u, &candidates)? {
+                    Some(&index) => {
+                        let cost = self.proc_costs[index] + 1;
+                        let index = index as u16;
+                        let instruction = if u.arbitrary()? {
+                            ExecLocal(index)
+                        } else {
+                            CallLocal(index)
+                        };
+                        return Ok((instruction, cost));
+                    }
+                    None => nullary_instruction(u)?,
+                }
+            }
+            6 => match choose(u, &self.imported_procs)? {
+                Some(&proc_id) if u.arbitrary()? => ExecImported(proc_id),
+                Some(&proc_id) => CallImported(proc_id),
+                None => nullary_instruction(u)?,
+            },
+            7 => match choose(u, &self.kernel_procs)? {
+                Some(&proc_id) => SysCall(proc_id),
+                None => nullary_instruction(u)?,
+            },
+            _ => nullary_instruction(u)?,
+        };
+
+        Ok((instruction, 1))
+    }
+
+    /// Returns an instruction which accesses memory locals of the procedure being generated.
+    fn local_instruction(&self, u: &mut Unstructured) -> Result<Instruction> {
+        use Instruction::*;
+
+        let index = u.int_in_range(0..=self.num_locals - 1)?;
+        Ok(match u.int_in_range(0..=4)? {
+            0 => Locaddr(index),
+            1 => LocLoad(index),
+            2 => LocLoadW(index),
+            3 => LocStore(index),
+            _ => LocStoreW(index),
+        })
+    }
+}
+
+// INSTRUCTION GENERATORS
+// ================================================================================================
+
+fn nullary_instruction(u: &mut Unstructured) -> Result<Instruction> {
+    u.choose(NULLARY_INSTRUCTIONS).cloned()
+}
+
+/// Returns a field operation with an immediate value or an assertion with an error code.
+fn field_instruction(u: &mut Unstructured) -> Result<Instruction> {
+    use Instruction::*;
+
+    let value = felt(u)?;
+    Ok(match u.int_in_range(0..=14)? {
+        0 => AddImm(value),
+        1 => SubImm(value),
+        2 => MulImm(value),
+        3 => DivImm(if value == ZERO { ONE } else { value }),
+        4 => ExpImm(value),
+        5 => ExpBitLength(u.int_in_range(0..=MAX_EXP_BITS)?),
+        6 => EqImm(value),
+        7 => NeqImm(value),
+        8 => LtImm(value),
+        9 => LteImm(value),
+        10 => GtImm(value),
+        11 => GteImm(value),
+        12 => AssertWithError(u.arbitrary()?),
+        13 => AssertEqWithError(u.arbitrary()?),
+        _ => AssertzWithError(u.arbitrary()?),
+    })
+}
+
+/// Returns a u32 operation with an immediate value.
+fn u32_instruction(u: &mut Unstructured) -> Result<Instruction> {
+    use Instruction::*;
+
+    let value = u.arbitrary::<u32>()?;
+    let divisor = value.max(1);
+    let shift = u.int_in_range(0..=MAX_U32_SHIFT_VALUE)?;
+    let rotation = u.int_in_range(0..=MAX_U32_ROTATE_VALUE)?;
+    Ok(match u.int_in_range(0..=28)? {
+        0 => U32CheckedAddImm(value),
+        1 => U32WrappingAddImm(value),
+        2 => U32OverflowingAddImm(value),
+        3 => U32CheckedSubImm(value),
+        4 => U32WrappingSubImm(value),
+        5 => U32OverflowingSubImm(value),
+        6 => U32CheckedMulImm(value),
+        7 => U32WrappingMulImm(value),
+        8 => U32OverflowingMulImm(value),
+        9 => U32CheckedDivImm(divisor),
+        10 => U32UncheckedDivImm(divisor),
+        11 => U32CheckedModImm(divisor),
+        12 => U32UncheckedModImm(divisor),
+        13 => U32CheckedDivModImm(divisor),
+        14 => U32UncheckedDivModImm(divisor),
+        15 => U32CheckedShrImm(shift),
+        16 => U32UncheckedShrImm(shift),
+        17 => U32CheckedShlImm(shift),
+        18 => U32UncheckedShlImm(shift),
+        19 => U32CheckedRotrImm(rotation),
+        20 => U32UncheckedRotrImm(rotation),
+        21 => U32CheckedRotlImm(rotation),
+        22 => U32UncheckedRotlImm(rotation),
+        23 => U32CheckedEqImm(value),
+        24 => U32CheckedNeqImm(value),
+        25 => U32CheckedLtImm(value),
+        26 => U32CheckedLteImm(value),
+        27 => U32CheckedGtImm(value),
+        _ => U32CheckedGteImm(value),
+    })
+}
+
+/// Returns an input / output operation with parameters.
+fn io_instruction(u: &mut Unstructured) -> Result<Instruction> {
+    use Instruction::*;
+
+    Ok(match u.int_in_range(0..=7)? {
+        0 | 1 => {
+            let num_values = u.int_in_range(1..=MAX_PUSH_INPUTS)?;
+            let values = (0..num_values)
+                .map(|_| felt(u))
+                .collect::<Result<Vec<_>>>()?;
+            PushConstants(values)
+        }
+        2 => MemLoadImm(u.arbitrary()?),
+        3 => MemLoadWImm(u.arbitrary()?),
+        4 => MemStoreImm(u.arbitrary()?),
+        5 => MemStoreWImm(u.arbitrary()?),
+        6 => AdvPush(u.int_in_range(1..=ADVICE_READ_LIMIT)?),
+        _ => {
+            let start_addr = u.int_in_range(0..=u32::MAX - MAX_ADV_MEM_WORDS)?;
+            AdvMem(start_addr, u.int_in_range(1..=MAX_ADV_MEM_WORDS)?)
+        }
+    })
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a unique name for the local procedure at the specified index.
+fn proc_name(u: &mut Unstructured, index: usize) -> Result<String> {
+    const SUFFIX_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_";
+
+    let mut name = format!("proc{index}");
+    let suffix_len = u.int_in_range(0..=MAX_PROC_NAME_SUFFIX_LEN)?;
+    if suffix_len > 0 {
+        // separate the suffix from the index to keep names unique
+        name.push('_');
+        for _ in 0..suffix_len {
+            name.push(*u.choose(SUFFIX_CHARS)? as char);
+        }
+    }
+    Ok(name)
+}
+
+fn felt(u: &mut Unstructured) -> Result<Felt> {
+    Ok(Felt::new(u.arbitrary()?))
+}
+
+/// Returns the provided items in an arbitrary order.
+fn shuffle<T>(u: &mut Unstructured, mut items: Vec<T>) -> Result<Vec<T>> {
+    for i in (1..items.len()).rev() {
+        let j = u.int_in_range(0..=i)?;
+        items.swap(i, j);
+    }
+    Ok(items)
+}
+
+/// Returns an arbitrary item of the provided slice, or None if the slice is empty.
+fn choose<'b, T>(u: &mut Unstructured, items: &'b [T]) -> Result<Option<&'b T>> {
+    if items.is_empty() {
+        Ok(None)
+    } else {
+        u.choose(items).map(Some)
+    }
+}
@@ -0,0 +1,216 @@
+use super::{Arbitrary, GeneratedProgram, Result, String, ToString, Unstructured, Vec};
+use core::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of mutations applied to the source of a generated program.
+const MAX_MUTATIONS: usize = 4;
+
+/// Tokens which can be inserted into a source; these include valid and slightly malformed
+/// control tokens, as well as instructions with missing or malformed parameters.
+const TOKENS: &[&str] = &[
+    "use",
+    "use.synthetic",
+    "use.synthetic::math",
+    "use.synthetic::",
+    "proc",
+    "proc.foo",
+    "proc.foo.1",
+    "proc.foo.65536",
+    "proc.0foo",
+    "export.foo",
+    "begin",
+    "begin.1",
+    "end",
+    "end.1",
+    "if",
+    "if.true",
+    "if.false",
+    "else",
+    "else.1",
+    "while",
+    "while.true",
+    "repeat",
+    "repeat.0",
+    "repeat.4294967296",
+    "exec",
+    "exec.foo",
+    "exec.math::add3",
+    "exec.foo::bar",
+    "exec.::",
+    "call",
+    "call.foo",
+    "call.0x",
+    "syscall",
+    "syscall.kernel_add",
+    "adv",
+    "adv.mem",
+    "adv.mem.1",
+    "push",
+    "push.0x",
+    "exp.u",
+    "u32assert",
+    "dup.16",
+    "movup.1",
+    "swapw.4",
+    "loc_load",
+    "locaddr",
+    "caller",
+    "#",
+    "#!",
+];
+
+/// Values which can replace instruction parameters; these include boundary values of various
+/// integer types and of the field modulus, as well as malformed hexadecimal values.
+const PARAMS: &[&str] = &[
+    "",
+    "0",
+    "1",
+    "2",
+    "15",
+    "16",
+    "17",
+    "31",
+    "32",
+    "63",
+    "64",
+    "65",
+    "255",
+    "256",
+    "65535",
+    "65536",
+    "4294967295",
+    "4294967296",
+    "18446744069414584320",
+    "18446744069414584321",
+    "18446744073709551615",
+    "18446744073709551616",
+    "-1",
+    "0x",
+    "0x0",
+    "0x00",
+    "0xg0",
+    "0x0102",
+    "0xffffffffffffffff",
+    "0x00000000000000010000000000000002",
+    "0x\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}\u{e9}",
+    "u",
+    "u0",
+    "u64",
+    "u65",
+    "true",
+    "false",
+];
+
+/// Strings which can be inserted inside a token.
+const JUNK: &[&str] = &[".", "..", ":", "::", "#", "0x", "\u{e9}", "\u{0}"];
+
+/// Strings which can separate tokens.
+const SEPARATORS: &[&str] = &[" ", "\t", "\n", "\n\n", "\n#! doc\n", "\n# comment\n"];
+
+// NEAR-MISS SOURCE
+// ================================================================================================
+
+/// Source code of a Miden assembly program which is likely to be slightly malformed.
+///
+/// The source is built by applying a few random mutations (e.g., removing, duplicating, or
+/// swapping tokens, or replacing instruction parameters with boundary values) to the source of a
+/// [GeneratedProgram]. Unlike sources built from random bytes, such sources usually get deep into
+/// the parser before an error is encountered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearMissSource(String);
+
+impl NearMissSource {
+    /// Returns this source as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NearMissSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'a> Arbitrary<'a> for NearMissSource {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let program = GeneratedProgram::arbitrary(u)?.to_string();
+        let mut tokens = program
+            .split_whitespace()
+            .map(|token| token.to_string())
+            .collect::<Vec<_>>();
+
+        let num_mutations = u.int_in_range(1..=MAX_MUTATIONS)?;
+        for _ in 0..num_mutations {
+            mutate(u, &mut tokens)?;
+        }
+
+        let mut source = String::new();
+        for token in tokens {
+            source.push_str(&token);
+            source.push_str(u.choose(SEPARATORS)?);
+        }
+        Ok(Self(source))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Applies a single random mutation to the provided list of tokens.
+fn mutate(u: &mut Unstructured, tokens: &mut Vec<String>) -> Result<()> {
+    let position = u.int_in_range(0..=tokens.len())?;
+    let mutation = u.int_in_range(0..=6)?;
+
+    // insertion is the only mutation which can be applied to an empty list
+    if mutation == 0 || tokens.is_empty() {
+        tokens.insert(position, u.choose(TOKENS)?.to_string());
+        return Ok(());
+    }
+
+    let index = position.min(tokens.len() - 1);
+    match mutation {
+        1 => {
+            tokens.remove(index);
+        }
+        2 => {
+            let token = tokens[index].clone();
+            tokens.insert(index, token);
+        }
+        3 => {
+            let other = u.int_in_range(0..=tokens.len() - 1)?;
+            tokens.swap(index, other);
+        }
+        4 => {
+            // replace the last parameter of the token, or append a new one
+            let param = u.choose(PARAMS)?;
+            let token = &mut tokens[index];
+            match token.rsplit_once('.') {
+                Some((head, _)) if u.arbitrary()? => *token = format!("{head}.{param}"),
+                _ => *token = format!("{token}.{param}"),
+            }
+        }
+        5 => {
+            // remove the last part of the token
+            let token = &mut tokens[index];
+            if let Some((head, _)) = token.rsplit_once('.') {
+                *token = head.to_string();
+            }
+        }
+        _ => {
+            // insert junk at an arbitrary character boundary of the token
+            let token = &mut tokens[index];
+            let boundaries = token
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([token.len()])
+                .collect::<Vec<_>>();
+            let offset = *u.choose(&boundaries)?;
+            token.insert_str(offset, u.choose(JUNK)?);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use super::{from_bytes, GeneratedProgram, NearMissSource};
+use crate::{parse_module, parse_program};
+use proptest::{collection::vec, prelude::*};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum number of bytes from which a single generated value is built.
+const MAX_ENTROPY: usize = 4096;
+
+// PROPERTY TESTS
+// ================================================================================================
+
+proptest! {
+    #[test]
+    fn generated_program_parse_display_parse(bytes in vec(any::<u8>(), 0..MAX_ENTROPY)) {
+        let program: GeneratedProgram = from_bytes(&bytes);
+
+        // source of a generated program is parsed into the generated AST
+        let ast = parse_program(&program.to_string()).unwrap();
+        prop_assert_eq!(program.ast(), &ast);
+
+        // displaying a parsed AST and parsing it again results in the same AST
+        let source = ast.to_string();
+        let reparsed = parse_program(&source).unwrap();
+        prop_assert_eq!(&ast, &reparsed);
+        prop_assert_eq!(source, reparsed.to_string());
+    }
+
+    #[test]
+    fn generated_program_compiles(bytes in vec(any::<u8>(), 0..MAX_ENTROPY)) {
+        let program: GeneratedProgram = from_bytes(&bytes);
+        let result = program.compile();
+        prop_assert!(result.is_ok(), "{}\n\n{}", result.unwrap_err(), program);
+    }
+
+    #[test]
+    fn near_miss_source_does_not_panic(bytes in vec(any::<u8>(), 0..MAX_ENTROPY)) {
+        let source: NearMissSource = from_bytes(&bytes);
+        let _ = parse_program(source.as_str());
+        let _ = parse_module(source.as_str());
+    }
+}
+
+// UNIT TESTS
+// ================================================================================================
+
+#[test]
+fn generated_program_without_entropy() {
+    // when there are no bytes to consume, the smallest possible program is generated
+    let program: GeneratedProgram = from_bytes(&[]);
+    assert!(program.ast().local_procs.is_empty());
+    assert_eq!(1, program.ast().body.len());
+    assert!(program.compile().is_ok());
+}
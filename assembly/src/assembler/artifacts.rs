@@ -0,0 +1,73 @@
+use super::{Digest, Program, String, Vec};
+use vm_core::utils::{DisplayHex, Serializable};
+
+// COMPILED ARTIFACTS
+// ================================================================================================
+
+/// A program compiled via [Assembler::compile_full()](super::Assembler::compile_full) together
+/// with its commonly used representations.
+///
+/// All representations are computed once, when the program is compiled:
+/// - the MAST of the program formatted via [Display](core::fmt::Display);
+/// - the binary form of the program (i.e., the opcodes of its MAST and its metadata) as written
+///   by the [Serializable] implementation of [Program];
+/// - the hash of the program as a hexadecimal string (without `0x` prefix).
+#[derive(Debug, Clone)]
+pub struct CompiledArtifacts {
+    program: Program,
+    mast: String,
+    bytes: Vec<u8>,
+    hash_hex: String,
+}
+
+impl CompiledArtifacts {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns new [CompiledArtifacts] computed from the specified program.
+    pub(super) fn new(program: Program) -> Self {
+        let mast = format!("{program}");
+        let bytes = program.to_bytes();
+        let hash_bytes: [u8; 32] = program.hash().into();
+        let hash_hex = format!("{}", DisplayHex(&hash_bytes));
+        Self {
+            program,
+            mast,
+            bytes,
+            hash_hex,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the compiled program.
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Returns the MAST of the program formatted via [Display](core::fmt::Display).
+    pub fn mast(&self) -> &str {
+        &self.mast
+    }
+
+    /// Returns the binary form of the program, which can be read back via
+    /// [Program::read_from_bytes()].
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the hash of the program as a hexadecimal string.
+    pub fn hash_hex(&self) -> &str {
+        &self.hash_hex
+    }
+
+    /// Returns the hash of the program.
+    pub fn hash(&self) -> Digest {
+        self.program.hash()
+    }
+
+    /// Consumes these artifacts and returns the compiled program.
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, Procedure, ProcedureCache,
-    ProcedureId, String, ToString, Vec,
+    AssemblyError, CallSet, CodeBlock, CodeBlockTable, InlineSite, InliningTrace, Kernel,
+    ModuleProvider, Procedure, ProcedureCache, ProcedureId, ProcedureMetadata, ResolveContext,
+    String, ToString, Vec,
 };
 use crate::MODULE_PATH_DELIM;
 
@@ -18,13 +19,14 @@ const MAIN_PROC_NAME: &str = "#main";
 /// stack of [ProcedureContext]'s. Thus, at any point in time, we are in a context of compiling a
 /// procedure within a module, and we have access to the info about the current module/procedure
 /// tuple bing compiled.
-pub struct AssemblyContext {
+pub struct AssemblyContext<'a> {
     module_stack: Vec<ModuleContext>,
     is_kernel: bool,
     kernel: Option<Kernel>,
+    module_provider: Option<&'a dyn ModuleProvider>,
 }
 
-impl AssemblyContext {
+impl<'a> AssemblyContext<'a> {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
     /// Returns a new [AssemblyContext]. When is_kernel is set to true, the context is instantiated
@@ -43,9 +45,17 @@ impl AssemblyContext {
             module_stack: modules,
             is_kernel,
             kernel: None,
+            module_provider: None,
         }
     }
 
+    /// Sets a [ModuleProvider] for this context. This provider is used to look up imported
+    /// modules which cannot be found via the module provider of the assembler.
+    pub fn with_module_provider(mut self, provider: &'a dyn ModuleProvider) -> Self {
+        self.module_provider = Some(provider);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -54,6 +64,30 @@ impl AssemblyContext {
         self.is_kernel
     }
 
+    /// Returns the module provider set for this context, if any.
+    pub fn module_provider(&self) -> Option<&'a dyn ModuleProvider> {
+        self.module_provider
+    }
+
+    /// Returns a [ResolveContext] describing the procedure currently being compiled.
+    ///
+    /// # Panics
+    /// Panics if module or procedure stacks are empty.
+    pub fn resolve_context(&self) -> ResolveContext<'_> {
+        let module = self.module_stack.last().expect("no modules");
+        let proc = module.proc_stack.last().expect("no procedures");
+        let module_path = (!module.is_executable()).then_some(module.path.as_str());
+        ResolveContext::new(module_path, &proc.name)
+    }
+
+    /// Returns true if the procedure currently being compiled is a part of the program (i.e., it
+    /// does not belong to an imported module or a kernel).
+    pub fn is_in_program(&self) -> bool {
+        self.module_stack
+            .last()
+            .map_or(false, |module| module.is_executable())
+    }
+
     /// Returns the number of memory locals allocated for the procedure currently being compiled.
     pub fn num_proc_locals(&self) -> u16 {
         self.current_proc_context()
@@ -61,6 +95,27 @@ impl AssemblyContext {
             .num_locals
     }
 
+    /// Returns the peak local frame of the procedure currently being compiled, considering only
+    /// the instructions compiled so far (see [Procedure::max_local_frame()]).
+    ///
+    /// At the end of compiling a program, this is the peak local frame of the program.
+    pub fn max_local_frame(&self) -> u64 {
+        self.current_proc_context()
+            .expect("no procedures")
+            .max_local_frame()
+    }
+
+    /// Returns the list of procedures inlined into the procedure currently being compiled.
+    ///
+    /// At the end of compiling a program, this is the list of procedures inlined into the body of
+    /// the program.
+    pub fn inlining_trace(&self) -> &InliningTrace {
+        &self
+            .current_proc_context()
+            .expect("no procedures")
+            .inlining_trace
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -70,7 +125,9 @@ impl AssemblyContext {
     /// dependencies.
     ///
     /// # Errors
-    /// Returns an error if a module with the same path already exists in the module stack.
+    /// Returns an error if a module with the same path already exists in the module stack; the
+    /// error contains the chain of modules from the first occurrence of this module in the stack
+    /// to the module itself (e.g., `a -> b -> a`).
     pub fn begin_module(&mut self, module_path: &str) -> Result<(), AssemblyError> {
         if self.is_kernel && self.module_stack.is_empty() {
             // a kernel context must be initialized with a kernel module path
@@ -82,11 +139,12 @@ impl AssemblyContext {
         }
 
         // make sure this module is not in the chain of modules which are currently being compiled
-        if self.module_stack.iter().any(|m| m.path == module_path) {
-            let dep_chain = self
-                .module_stack
+        if let Some(idx) = self.module_stack.iter().position(|m| m.path == module_path) {
+            let dep_chain = self.module_stack[idx..]
                 .iter()
-                .map(|m| m.path.to_string())
+                .map(|m| m.path.as_str())
+                .chain([module_path])
+                .map(String::from)
                 .collect::<Vec<_>>();
             return Err(AssemblyError::circular_module_dependency(&dep_chain));
         }
@@ -152,6 +210,16 @@ impl AssemblyContext {
             .complete_proc(code_root);
     }
 
+    /// Marks compilation of an instruction in the body of the procedure currently being compiled
+    /// as completed; this is used to track positions of procedure inline sites.
+    pub fn complete_instruction(&mut self) {
+        self.module_stack
+            .last_mut()
+            .and_then(|m| m.proc_stack.last_mut())
+            .expect("no procedures")
+            .num_instructions += 1;
+    }
+
     // CALL PROCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -236,8 +304,12 @@ impl AssemblyContext {
     /// - There is not exactly one module left on the module stack.
     /// - If this module is not an executable module.
     /// - If any of the procedures in the module's callset cannot be found in the specified
-    ///   procedure cache or the local procedure set of the module.
-    pub fn into_cb_table(mut self, proc_cache: &ProcedureCache) -> CodeBlockTable {
+    ///   procedure caches or the local procedure set of the module.
+    pub fn into_cb_table(
+        mut self,
+        proc_cache: &ProcedureCache,
+        library_callees: &ProcedureCache,
+    ) -> CodeBlockTable {
         // get the last module off the module stack
         let mut main_module_context = self.module_stack.pop().expect("no modules");
         assert!(self.module_stack.is_empty(), "executable not last module");
@@ -248,11 +320,13 @@ impl AssemblyContext {
 
         // build the code block table based on the callset of the executable module; called
         // procedures can be either in the specified procedure cache (for procedures imported from
-        // other modules) or in the module's procedures (for procedures defined locally).
+        // other modules), among the procedures called by loaded libraries, or in the module's
+        // procedures (for procedures defined locally).
         let mut cb_table = CodeBlockTable::default();
         for proc_id in main_module_context.callset.iter() {
             let proc = proc_cache
                 .get(proc_id)
+                .or_else(|| library_callees.get(proc_id))
                 .or_else(|| main_module_context.find_local_proc(proc_id))
                 .expect("callset procedure not found");
 
@@ -418,8 +492,12 @@ impl ModuleContext {
         context.callset.append(called_proc.callset());
 
         // if the called procedure was not inlined, we include it in the current callset as well
+        // and record the frame it uses in its own context; otherwise, we record the inline site
         if !inlined {
             context.callset.insert(*called_proc.id());
+            context.register_call_site(called_proc);
+        } else {
+            context.register_inline_site(called_proc);
         }
         Ok(called_proc)
     }
@@ -439,8 +517,12 @@ impl ModuleContext {
         context.callset.append(called_proc.callset());
 
         // if the called procedure was not inlined, we include it in the current callset as well
+        // and record the frame it uses in its own context; otherwise, we record the inline site
         if !inlined {
             context.callset.insert(*called_proc.id());
+            context.register_call_site(called_proc);
+        } else {
+            context.register_inline_site(called_proc);
         }
     }
 
@@ -473,7 +555,11 @@ struct ProcedureContext {
     name: String,
     is_export: bool,
     num_locals: u16,
+    max_inlined_frame: u64,
+    max_called_frame: u64,
     callset: CallSet,
+    inlining_trace: InliningTrace,
+    num_instructions: usize,
 }
 
 impl ProcedureContext {
@@ -482,7 +568,11 @@ impl ProcedureContext {
             name: name.to_string(),
             is_export,
             num_locals,
+            max_inlined_frame: 0,
+            max_called_frame: 0,
             callset: CallSet::default(),
+            inlining_trace: InliningTrace::default(),
+            num_instructions: 0,
         }
     }
 
@@ -490,14 +580,51 @@ impl ProcedureContext {
         self.name == MAIN_PROC_NAME
     }
 
+    /// Returns the peak local frame of this procedure based on the calls registered so far.
+    ///
+    /// Frames of inlined procedures are stacked on top of the locals of this procedure, while
+    /// procedures invoked via `call` or `syscall` use frames in their own memory contexts.
+    pub fn max_local_frame(&self) -> u64 {
+        let inlined_frame = self.num_locals as u64 + self.max_inlined_frame;
+        inlined_frame.max(self.max_called_frame)
+    }
+
+    /// Records that the specified procedure was inlined at the current instruction; this also
+    /// appends the inline sites of the inlined procedure to the inlining trace of this procedure.
+    pub fn register_inline_site(&mut self, inlined_proc: &Procedure) {
+        let site = InlineSite::new(&self.name, inlined_proc.label(), self.num_instructions);
+        self.inlining_trace.push(site);
+        self.inlining_trace.append(inlined_proc.inlining_trace());
+        self.max_inlined_frame = self.max_inlined_frame.max(inlined_proc.max_local_frame());
+    }
+
+    /// Records that the specified procedure was invoked via `call` or `syscall` instruction.
+    pub fn register_call_site(&mut self, called_proc: &Procedure) {
+        self.max_called_frame = self.max_called_frame.max(called_proc.max_local_frame());
+    }
+
     pub fn into_procedure(self, id: ProcedureId, code_root: CodeBlock) -> Procedure {
+        let max_local_frame = self.max_local_frame();
         let Self {
             name,
             is_export,
             num_locals,
             callset,
+            inlining_trace,
+            ..
         } = self;
 
-        Procedure::new(id, name, is_export, num_locals as u32, code_root, callset)
+        Procedure::new(
+            code_root,
+            ProcedureMetadata {
+                id,
+                label: name,
+                is_export,
+                num_locals: num_locals as u32,
+                max_local_frame,
+                callset,
+                inlining_trace,
+            },
+        )
     }
 }
@@ -1,4 +1,4 @@
-use super::{validate_param, AdviceInjector, AssemblyError, Decorator, SpanBuilder};
+use super::{validate_param, AdviceInjector, Arc, AssemblyError, Decorator, SpanBuilder};
 use crate::ADVICE_READ_LIMIT;
 use vm_core::{code_blocks::CodeBlock, Operation::*};
 
@@ -12,7 +12,7 @@ use vm_core::{code_blocks::CodeBlock, Operation::*};
 /// # Errors
 /// Returns an error if the specified number of values to pushed is smaller than 1 or greater
 /// than 16.
-pub fn adv_push(span: &mut SpanBuilder, n: u8) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn adv_push(span: &mut SpanBuilder, n: u8) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     validate_param(n, 1, ADVICE_READ_LIMIT)?;
     span.push_op_many(Read, n as usize);
     Ok(None)
@@ -30,7 +30,7 @@ pub fn adv_mem(
     span: &mut SpanBuilder,
     start_addr: u32,
     num_words: u32,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     validate_param(num_words, 0, u32::MAX - start_addr)?;
     span.add_decorator(Decorator::Advice(AdviceInjector::Memory(
         start_addr, num_words,
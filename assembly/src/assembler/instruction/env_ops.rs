@@ -1,6 +1,6 @@
 use super::{
-    mem_ops::local_to_absolute_addr, push_felt, AssemblyContext, AssemblyError, CodeBlock, Felt,
-    Operation::*, SpanBuilder,
+    mem_ops::local_to_absolute_addr, push_felt, AdviceInjector, Arc, AssemblyContext,
+    AssemblyError, CodeBlock, Decorator, Felt, Operation::*, SpanBuilder,
 };
 
 // CONSTANT INPUTS
@@ -25,7 +25,10 @@ use super::{
 /// It will return an error if no immediate value is provided or if any of parameter formats are
 /// invalid. It will also return an error if the op token is malformed or doesn't match the expected
 /// instruction.
-pub fn push(imms: &[Felt], span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn push(
+    imms: &[Felt],
+    span: &mut SpanBuilder,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     imms.iter().copied().for_each(|imm| push_felt(span, imm));
     Ok(None)
 }
@@ -37,12 +40,12 @@ pub fn push(imms: &[Felt], span: &mut SpanBuilder) -> Result<Option<CodeBlock>,
 /// consists of putting i onto the stack and then executing LOCADDR operation.
 ///
 /// # Errors
-/// Returns an error if index is greater than the number of procedure locals.
+/// Returns an error if index is not smaller than the number of procedure locals.
 pub fn locaddr(
     span: &mut SpanBuilder,
     index: u16,
     context: &AssemblyContext,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     local_to_absolute_addr(span, index, context.num_proc_locals())?;
     Ok(None)
 }
@@ -55,9 +58,27 @@ pub fn locaddr(
 pub fn caller(
     span: &mut SpanBuilder,
     context: &AssemblyContext,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if !context.is_kernel() {
         return Err(AssemblyError::caller_out_of_kernel());
     }
     span.add_op(Caller)
 }
+
+/// Appends a sequence of operations to the span which pushes the hash of the program being
+/// executed (i.e., the root of its MAST) onto the stack. The hash is put onto the stack in the same
+/// order as by `caller` instruction (i.e., with the last element of the hash at the top).
+///
+/// The hash of a program depends on all of its instructions, and thus, it cannot be embedded into
+/// the program as a constant. Instead, the hash is resolved at execution time: the processor
+/// injects the hash of the program it executes into the advice tape, and 4 READ operations move
+/// it onto the stack. As with other advice inputs, the pushed values are not constrained by the
+/// AIR; thus, a proof of execution does not guarantee that the pushed word is the program hash.
+///
+/// The instruction pushes the hash of the whole program even when it is used inside a procedure
+/// (including procedures invoked via `call` and `syscall` instructions).
+pub fn push_mast_root(span: &mut SpanBuilder) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    span.push_decorator(Decorator::Advice(AdviceInjector::ProgramHash));
+    span.push_op_many(Read, 4);
+    Ok(None)
+}
@@ -1,9 +1,42 @@
 use super::{
-    validate_param, AssemblyError, CodeBlock, Felt, FieldElement, Operation::*, SpanBuilder,
-    StarkField, ONE, ZERO,
+    push_felt, validate_param, Arc, AssemblyError, CodeBlock, Decorator, Felt, FieldElement,
+    Operation::*, SpanBuilder, StarkField, ONE, ZERO,
 };
 use crate::MAX_EXP_BITS;
 
+// ASSERTIONS
+// ================================================================================================
+
+/// Appends ASSERT operation preceded by a decorator with the specified error code; the error code
+/// is reported by the VM if the assertion fails.
+pub fn assert(
+    span: &mut SpanBuilder,
+    err_code: u32,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    span.push_decorator(Decorator::ErrorCode(err_code));
+    span.add_op(Assert)
+}
+
+/// Appends EQ ASSERT operations, with the specified error code attached to ASSERT (see
+/// [assert()]).
+pub fn assert_eq(
+    span: &mut SpanBuilder,
+    err_code: u32,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    span.push_op(Eq);
+    assert(span, err_code)
+}
+
+/// Appends EQZ ASSERT operations, with the specified error code attached to ASSERT (see
+/// [assert()]).
+pub fn assertz(
+    span: &mut SpanBuilder,
+    err_code: u32,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    span.push_op(Eqz);
+    assert(span, err_code)
+}
+
 // BASIC ARITHMETIC OPERATIONS
 // ================================================================================================
 
@@ -14,7 +47,7 @@ use crate::MAX_EXP_BITS;
 ///
 /// We do not optimize away adding 0 because it may result in a empty SPAN block and cause failures
 /// later on.
-pub fn add_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn add_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if imm == ONE {
         span.add_op(Incr)
     } else {
@@ -30,7 +63,7 @@ pub fn add_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, A
 ///
 /// We do not optimize away multiplication by 1 because it may result in a empty SPAN block and
 /// cause failures later on.
-pub fn mul_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn mul_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if imm == ZERO {
         span.add_ops([Drop, Pad])
     } else {
@@ -47,7 +80,7 @@ pub fn mul_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, A
 ///
 /// # Errors
 /// Returns an error if the immediate value is ZERO.
-pub fn div_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn div_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     // TODO: warning if imm is ONE?
     if imm == ZERO {
         return Err(AssemblyError::division_by_zero());
@@ -62,7 +95,7 @@ pub fn div_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, A
 /// top of the stack.
 ///
 /// VM cycles: 16 cycles
-pub fn pow2(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn pow2(span: &mut SpanBuilder) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     append_pow2_op(span);
     Ok(None)
 }
@@ -98,7 +131,10 @@ pub fn append_pow2_op(span: &mut SpanBuilder) {
 ///
 /// # Errors
 /// Returns an error if num_pow_bits is greater than 64.
-pub fn exp(span: &mut SpanBuilder, num_pow_bits: u8) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn exp(
+    span: &mut SpanBuilder,
+    num_pow_bits: u8,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     validate_param(num_pow_bits, 0, MAX_EXP_BITS)?;
 
     // arranging the stack to prepare it for expacc instruction.
@@ -127,7 +163,7 @@ pub fn exp(span: &mut SpanBuilder, num_pow_bits: u8) -> Result<Option<CodeBlock>
 /// - pow = 6: 10 cycles
 /// - pow = 7: 12 cycles
 /// - pow > 7: 9 + Ceil(log2(pow))
-pub fn exp_imm(span: &mut SpanBuilder, pow: Felt) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn exp_imm(span: &mut SpanBuilder, pow: Felt) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if pow.as_int() <= 7 {
         perform_exp_for_small_power(span, pow.as_int());
         Ok(None)
@@ -202,7 +238,7 @@ fn perform_exp_for_small_power(span: &mut SpanBuilder, pow: u64) {
 /// and the provided immediate value. Specifically, the sequences are:
 /// - if imm = 0: EQZ
 /// - otherwise: PUSH(imm) EQ
-pub fn eq_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn eq_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if imm == ZERO {
         span.add_op(Eqz)
     } else {
@@ -214,7 +250,7 @@ pub fn eq_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, As
 /// and the provided immediate value. Specifically, the sequences are:
 /// - if imm = 0: EQZ NOT
 /// - otherwise: PUSH(imm) EQ NOT
-pub fn neq_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn neq_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if imm == ZERO {
         span.add_ops([Eqz, Not])
     } else {
@@ -225,7 +261,7 @@ pub fn neq_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, A
 /// Appends a sequence of operations to check equality between two words at the top of the stack.
 ///
 /// This operation takes 15 VM cycles.
-pub fn eqw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn eqw(span: &mut SpanBuilder) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     span.add_ops([
         // duplicate first pair of for comparison(4th elements of each word) in reverse order
         // to avoid using dup.8 after stack shifting(dup.X where X > 7, takes more VM cycles )
@@ -239,8 +275,17 @@ pub fn eqw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
 /// than" comparison. The stack is expected to be arranged as [b, a, ...] (from the top). A value
 /// of 1 is pushed onto the stack if a < b. Otherwise, 0 is pushed.
 ///
-/// This operation takes 17 VM cycles.
-pub fn lt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+/// If an immediate value is provided, it is pushed onto the stack first and used as b.
+///
+/// This operation takes 17 VM cycles, plus 1 - 2 cycles to push the immediate value.
+pub fn lt(
+    span: &mut SpanBuilder,
+    imm: Option<Felt>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_felt(span, imm);
+    }
+
     // Split both elements into high and low bits
     // 3 cycles
     split_elements(span);
@@ -265,8 +310,17 @@ pub fn lt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
 /// than or equal" comparison. The stack is expected to be arranged as [b, a, ...] (from the top).
 /// A value of 1 is pushed onto the stack if a <= b. Otherwise, 0 is pushed.
 ///
-/// This operation takes 18 VM cycles.
-pub fn lte(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+/// If an immediate value is provided, it is pushed onto the stack first and used as b.
+///
+/// This operation takes 18 VM cycles, plus 1 - 2 cycles to push the immediate value.
+pub fn lte(
+    span: &mut SpanBuilder,
+    imm: Option<Felt>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_felt(span, imm);
+    }
+
     // Split both elements into high and low bits
     // 3 cycles
     split_elements(span);
@@ -291,8 +345,17 @@ pub fn lte(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
 /// than" comparison. The stack is expected to be arranged as [b, a, ...] (from the top). A value
 /// of 1 is pushed onto the stack if a > b. Otherwise, 0 is pushed.
 ///
-/// This operation takes 18 VM cycles.
-pub fn gt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+/// If an immediate value is provided, it is pushed onto the stack first and used as b.
+///
+/// This operation takes 18 VM cycles, plus 1 - 2 cycles to push the immediate value.
+pub fn gt(
+    span: &mut SpanBuilder,
+    imm: Option<Felt>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_felt(span, imm);
+    }
+
     // Split both elements into high and low bits
     // 3 cycles
     split_elements(span);
@@ -317,8 +380,17 @@ pub fn gt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
 /// than or equal" comparison. The stack is expected to be arranged as [b, a, ...] (from the top).
 /// A value of 1 is pushed onto the stack if a >= b. Otherwise, 0 is pushed.
 ///
-/// This operation takes 19 VM cycles.
-pub fn gte(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+/// If an immediate value is provided, it is pushed onto the stack first and used as b.
+///
+/// This operation takes 19 VM cycles, plus 1 - 2 cycles to push the immediate value.
+pub fn gte(
+    span: &mut SpanBuilder,
+    imm: Option<Felt>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_felt(span, imm);
+    }
+
     // Split both elements into high and low bits
     // 3 cycles
     split_elements(span);
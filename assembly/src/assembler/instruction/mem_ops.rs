@@ -1,6 +1,6 @@
 use super::{
-    push_felt, push_u32_value, validate_param, AssemblyContext, AssemblyError, CodeBlock, Felt,
-    Operation::*, SpanBuilder,
+    push_felt, push_u32_value, Arc, AssemblyContext, AssemblyError, CodeBlock, Felt, Operation::*,
+    SpanBuilder,
 };
 
 // INSTRUCTION PARSERS
@@ -18,7 +18,7 @@ use super::{
 ///    - 3 cycles if b != 1
 ///
 /// # Errors
-/// Returns an error if we are reading from local memory and local memory index is greater than
+/// Returns an error if we are reading from local memory and local memory index is not smaller than
 /// the number of procedure locals.
 pub fn mem_read(
     span: &mut SpanBuilder,
@@ -26,7 +26,7 @@ pub fn mem_read(
     addr: Option<u32>,
     is_local: bool,
     is_single: bool,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     // if the address was provided as an immediate value, put it onto the stack
     if let Some(addr) = addr {
         if is_local {
@@ -69,7 +69,7 @@ pub fn mem_read(
 ///   - 3 cycles if b != 1
 ///
 /// # Errors
-/// Returns an error if we are writing to local memory and local memory index is greater than
+/// Returns an error if we are writing to local memory and local memory index is not smaller than
 /// the number of procedure locals.
 pub fn mem_write(
     span: &mut SpanBuilder,
@@ -77,7 +77,7 @@ pub fn mem_write(
     addr: Option<u32>,
     is_local: bool,
     is_single: bool,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     // if the address was provided as an immediate value, put it onto the stack
     if let Some(addr) = addr {
         if is_local {
@@ -111,14 +111,20 @@ pub fn mem_write(
 /// - 2 VM cycles if index != 1
 ///
 /// # Errors
-/// Returns an error if index is greater than the number of procedure locals.
+/// Returns an error if index is not smaller than the number of locals declared by the procedure
+/// (e.g., if the procedure declares no locals).
 pub fn local_to_absolute_addr(
     span: &mut SpanBuilder,
     index: u16,
     num_proc_locals: u16,
 ) -> Result<(), AssemblyError> {
+    if index >= num_proc_locals {
+        return Err(AssemblyError::local_index_out_of_bounds(
+            index,
+            num_proc_locals,
+        ));
+    }
     let max = num_proc_locals - 1;
-    validate_param(index, 0, max)?;
 
     push_felt(span, -Felt::from(max - index));
     span.push_op(FmpAdd);
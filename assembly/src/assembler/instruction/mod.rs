@@ -1,6 +1,6 @@
 use super::{
-    Assembler, AssemblyContext, AssemblyError, CodeBlock, Decorator, Felt, Instruction, Operation,
-    ProcedureId, SpanBuilder, ONE, ZERO,
+    Arc, Assembler, AssemblyContext, AssemblyError, CodeBlock, Decorator, Felt, Instruction,
+    Operation, ProcedureId, SpanBuilder, ONE, ZERO,
 };
 use vm_core::{AdviceInjector, FieldElement, StarkField};
 
@@ -23,7 +23,7 @@ impl Assembler {
         instruction: &Instruction,
         span: &mut SpanBuilder,
         ctx: &mut AssemblyContext,
-    ) -> Result<Option<CodeBlock>, AssemblyError> {
+    ) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         use AdviceInjector::*;
         use Operation::*;
 
@@ -36,8 +36,11 @@ impl Assembler {
 
         let result = match instruction {
             Instruction::Assert => span.add_op(Assert),
+            Instruction::AssertWithError(err_code) => field_ops::assert(span, *err_code),
             Instruction::AssertEq => span.add_ops([Eq, Assert]),
+            Instruction::AssertEqWithError(err_code) => field_ops::assert_eq(span, *err_code),
             Instruction::Assertz => span.add_ops([Eqz, Assert]),
+            Instruction::AssertzWithError(err_code) => field_ops::assertz(span, *err_code),
 
             Instruction::Add => span.add_op(Add),
             Instruction::AddImm(imm) => field_ops::add_imm(span, *imm),
@@ -65,10 +68,14 @@ impl Assembler {
             Instruction::Eqw => field_ops::eqw(span),
             Instruction::Neq => span.add_ops([Eq, Not]),
             Instruction::NeqImm(imm) => field_ops::neq_imm(span, *imm),
-            Instruction::Lt => field_ops::lt(span),
-            Instruction::Lte => field_ops::lte(span),
-            Instruction::Gt => field_ops::gt(span),
-            Instruction::Gte => field_ops::gte(span),
+            Instruction::Lt => field_ops::lt(span, None),
+            Instruction::LtImm(imm) => field_ops::lt(span, Some(*imm)),
+            Instruction::Lte => field_ops::lte(span, None),
+            Instruction::LteImm(imm) => field_ops::lte(span, Some(*imm)),
+            Instruction::Gt => field_ops::gt(span, None),
+            Instruction::GtImm(imm) => field_ops::gt(span, Some(*imm)),
+            Instruction::Gte => field_ops::gte(span, None),
+            Instruction::GteImm(imm) => field_ops::gte(span, Some(*imm)),
 
             Instruction::U32Test => span.add_ops([Dup0, U32split, Swap, Drop, Eqz]),
             Instruction::U32TestW => u32_ops::u32testw(span),
@@ -141,14 +148,18 @@ impl Assembler {
             Instruction::U32CheckedEqImm(v) => u32_ops::u32eq(span, Some(*v)),
             Instruction::U32CheckedNeq => u32_ops::u32neq(span, None),
             Instruction::U32CheckedNeqImm(v) => u32_ops::u32neq(span, Some(*v)),
-            Instruction::U32CheckedLt => u32_ops::u32lt(span, Checked),
-            Instruction::U32UncheckedLt => u32_ops::u32lt(span, Unchecked),
-            Instruction::U32CheckedLte => u32_ops::u32lte(span, Checked),
-            Instruction::U32UncheckedLte => u32_ops::u32lte(span, Unchecked),
-            Instruction::U32CheckedGt => u32_ops::u32gt(span, Checked),
-            Instruction::U32UncheckedGt => u32_ops::u32gt(span, Unchecked),
-            Instruction::U32CheckedGte => u32_ops::u32gte(span, Checked),
-            Instruction::U32UncheckedGte => u32_ops::u32gte(span, Unchecked),
+            Instruction::U32CheckedLt => u32_ops::u32lt(span, Checked, None),
+            Instruction::U32CheckedLtImm(v) => u32_ops::u32lt(span, Checked, Some(*v)),
+            Instruction::U32UncheckedLt => u32_ops::u32lt(span, Unchecked, None),
+            Instruction::U32CheckedLte => u32_ops::u32lte(span, Checked, None),
+            Instruction::U32CheckedLteImm(v) => u32_ops::u32lte(span, Checked, Some(*v)),
+            Instruction::U32UncheckedLte => u32_ops::u32lte(span, Unchecked, None),
+            Instruction::U32CheckedGt => u32_ops::u32gt(span, Checked, None),
+            Instruction::U32CheckedGtImm(v) => u32_ops::u32gt(span, Checked, Some(*v)),
+            Instruction::U32UncheckedGt => u32_ops::u32gt(span, Unchecked, None),
+            Instruction::U32CheckedGte => u32_ops::u32gte(span, Checked, None),
+            Instruction::U32CheckedGteImm(v) => u32_ops::u32gte(span, Checked, Some(*v)),
+            Instruction::U32UncheckedGte => u32_ops::u32gte(span, Unchecked, None),
             Instruction::U32CheckedMin => u32_ops::u32min(span, Checked),
             Instruction::U32UncheckedMin => u32_ops::u32min(span, Unchecked),
             Instruction::U32CheckedMax => u32_ops::u32max(span, Checked),
@@ -237,6 +248,7 @@ impl Assembler {
             Instruction::PushConstants(imms) => env_ops::push(imms, span),
             Instruction::Sdepth => span.add_op(SDepth),
             Instruction::Caller => env_ops::caller(span, ctx),
+            Instruction::PushMastRoot => env_ops::push_mast_root(span),
             Instruction::AdvPipe => span.add_ops([Pipe, RpPerm]),
             Instruction::AdvPush(n) => adv_ops::adv_push(span, *n),
             Instruction::AdvLoadW => span.add_op(ReadW),
@@ -260,6 +272,7 @@ impl Assembler {
             }
 
             Instruction::AdvU64Div => span.add_decorator(Decorator::Advice(DivResultU64)),
+            Instruction::AdvU256Div => span.add_decorator(Decorator::Advice(DivResultU256)),
             Instruction::AdvKeyval => span.add_decorator(Decorator::Advice(MapValue)),
             Instruction::AdvMem(a, n) => adv_ops::adv_mem(span, *a, *n),
 
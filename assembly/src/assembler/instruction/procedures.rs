@@ -1,4 +1,4 @@
-use super::{Assembler, AssemblyContext, AssemblyError, CodeBlock, ProcedureId};
+use super::{Arc, Assembler, AssemblyContext, AssemblyError, CodeBlock, ProcedureId};
 
 // PROCEDURE INVOCATIONS
 // ================================================================================================
@@ -8,7 +8,7 @@ impl Assembler {
         &self,
         proc_idx: u16,
         context: &mut AssemblyContext,
-    ) -> Result<Option<CodeBlock>, AssemblyError> {
+    ) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         // register an "inlined" call to the procedure at the specified index in the module
         // currently being complied; this updates the callset of the procedure currently being
         // compiled
@@ -18,34 +18,34 @@ impl Assembler {
         // operations from that SPAN block to the span builder instead of returning a code block
 
         // return the code block of the procedure
-        Ok(Some(proc.code_root().clone()))
+        Ok(Some(proc.shared_code_root().clone()))
     }
 
     pub(super) fn exec_imported(
         &self,
         proc_id: &ProcedureId,
         context: &mut AssemblyContext,
-    ) -> Result<Option<CodeBlock>, AssemblyError> {
+    ) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         // get the procedure from the assembler
         let proc = self.get_imported_proc(proc_id, context)?;
         debug_assert!(proc.is_export(), "not imported procedure");
 
         // register and "inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
-        context.register_external_call(proc, true)?;
+        context.register_external_call(&proc, true)?;
 
         // TODO: if the procedure consists of a single SPAN block, we could just append all
         // operations from that SPAN block to the span builder instead of returning a code block
 
         // return the code block of the procedure
-        Ok(Some(proc.code_root().clone()))
+        Ok(Some(proc.shared_code_root().clone()))
     }
 
     pub(super) fn call_local(
         &self,
         index: u16,
         context: &mut AssemblyContext,
-    ) -> Result<Option<CodeBlock>, AssemblyError> {
+    ) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         // register an "non-inlined" call to the procedure at the specified index in the module
         // currently being complied; this updates the callset of the procedure currently being
         // compiled
@@ -53,40 +53,49 @@ impl Assembler {
 
         // create a new CALL block for the procedure call and return
         let digest = proc.code_root().hash();
-        Ok(Some(CodeBlock::new_call(digest)))
+        Ok(Some(Arc::new(CodeBlock::new_call(digest))))
     }
 
     pub(super) fn call_imported(
         &self,
         proc_id: &ProcedureId,
         context: &mut AssemblyContext,
-    ) -> Result<Option<CodeBlock>, AssemblyError> {
+    ) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         // get the procedure from the assembler
         let proc = self.get_imported_proc(proc_id, context)?;
         debug_assert!(proc.is_export(), "not imported procedure");
 
         // register and "non-inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
-        context.register_external_call(proc, false)?;
+        context.register_external_call(&proc, false)?;
 
         // create a new CALL block for the procedure call and return
         let digest = proc.code_root().hash();
-        Ok(Some(CodeBlock::new_call(digest)))
+        Ok(Some(Arc::new(CodeBlock::new_call(digest))))
     }
 
     pub(super) fn syscall(
         &self,
         proc_id: &ProcedureId,
         context: &mut AssemblyContext,
-    ) -> Result<Option<CodeBlock>, AssemblyError> {
+    ) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         // fetch from proc cache and check if its a kernel procedure
         // note: the assembler is expected to have all kernel procedures properly inserted in the
         // proc cache upon initialization, with their correct procedure ids
         let proc = self
             .proc_cache
+            .borrow()
             .get(proc_id)
+            .cloned()
             .ok_or_else(|| AssemblyError::kernel_proc_not_found(proc_id))?;
 
+        // the procedure must also belong to the kernel of the assembler; this may not be the case
+        // if the procedure was cached while compiling a kernel which was not set for the assembler
+        let digest = proc.code_root().hash();
+        if !self.kernel.contains_proc(digest) {
+            return Err(AssemblyError::kernel_proc_not_found(proc_id));
+        }
+
         // since call and syscall instructions cannot be executed inside a kernel, a callset for
         // a kernel procedure must be empty.
         debug_assert!(
@@ -96,10 +105,9 @@ impl Assembler {
 
         // register and "non-inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
-        context.register_external_call(proc, false)?;
+        context.register_external_call(&proc, false)?;
 
         // create a new SYSCALL block for the procedure call and return
-        let digest = proc.code_root().hash();
-        Ok(Some(CodeBlock::new_syscall(digest)))
+        Ok(Some(Arc::new(CodeBlock::new_syscall(digest))))
     }
 }
@@ -1,6 +1,6 @@
 use super::{
     field_ops::append_pow2_op,
-    push_u32_value, validate_param, AssemblyError, CodeBlock, Felt,
+    push_u32_value, validate_param, Arc, AssemblyError, CodeBlock, Felt,
     Operation::{self, *},
     SpanBuilder,
 };
@@ -25,7 +25,7 @@ pub enum U32OpMode {
 ///
 /// Implemented by executing DUP U32SPLIT SWAP DROP EQZ on each element in the word
 /// and combining the results using AND operation (total of 23 VM cycles)
-pub fn u32testw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn u32testw(span: &mut SpanBuilder) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     #[rustfmt::skip]
     let ops = [
          // Test the fourth element
@@ -47,7 +47,7 @@ pub fn u32testw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyErr
 ///
 /// Implemented by executing `U32ASSERT2` on each pair of elements in the word.
 /// Total of 6 VM cycles.
-pub fn u32assertw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn u32assertw(span: &mut SpanBuilder) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     #[rustfmt::skip]
     let ops = [
         // Test the first and the second elements
@@ -86,7 +86,7 @@ pub fn u32add(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     handle_arithmetic_operation(span, U32add, op_mode, imm)
 }
 
@@ -108,7 +108,7 @@ pub fn u32sub(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     handle_arithmetic_operation(span, U32sub, op_mode, imm)
 }
 
@@ -130,7 +130,7 @@ pub fn u32mul(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     handle_arithmetic_operation(span, U32mul, op_mode, imm)
 }
 
@@ -149,7 +149,7 @@ pub fn u32div(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     handle_division(span, op_mode, imm)?;
     span.add_op(Drop)
 }
@@ -169,7 +169,7 @@ pub fn u32mod(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     handle_division(span, op_mode, imm)?;
     span.add_ops([Swap, Drop])
 }
@@ -189,7 +189,7 @@ pub fn u32divmod(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     handle_division(span, op_mode, imm)
 }
 
@@ -202,7 +202,7 @@ pub fn u32divmod(
 /// subtracting the element, flips the bits of the original value to perform a bitwise NOT.
 ///
 /// This takes 5 VM cycles.
-pub fn u32not(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn u32not(span: &mut SpanBuilder) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     #[rustfmt::skip]
     let ops = [
         // Perform the operation
@@ -232,7 +232,7 @@ pub fn u32shl(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u8>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode)?;
     span.add_ops([U32mul, Drop])
 }
@@ -252,7 +252,7 @@ pub fn u32shr(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u8>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode)?;
     span.add_ops([U32div, Drop])
 }
@@ -273,7 +273,7 @@ pub fn u32rotl(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u8>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     prepare_bitwise::<MAX_U32_ROTATE_VALUE>(span, imm, op_mode)?;
     span.add_ops([U32mul, Add])
 }
@@ -294,7 +294,7 @@ pub fn u32rotr(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u8>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     match (imm, op_mode) {
         (Some(imm), U32OpMode::Checked) => {
             // Assert the top of the stack is a u32 value.
@@ -347,7 +347,7 @@ fn handle_arithmetic_operation(
     op: Operation,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     let mut drop_high_bits = false;
     let mut assert_u32_res = false;
 
@@ -384,7 +384,7 @@ fn handle_division(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if let Some(imm) = imm {
         if imm == 0 {
             return Err(AssemblyError::division_by_zero());
@@ -451,7 +451,10 @@ fn prepare_bitwise<const MAX_VALUE: u8>(
 /// VM cycles per mode:
 /// - u32checked_eq: 2 cycles
 /// - u32checked_eq.b: 3 cycles
-pub fn u32eq(span: &mut SpanBuilder, imm: Option<u32>) -> Result<Option<CodeBlock>, AssemblyError> {
+pub fn u32eq(
+    span: &mut SpanBuilder,
+    imm: Option<u32>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if let Some(imm) = imm {
         push_u32_value(span, imm);
     }
@@ -470,7 +473,7 @@ pub fn u32eq(span: &mut SpanBuilder, imm: Option<u32>) -> Result<Option<CodeBloc
 pub fn u32neq(
     span: &mut SpanBuilder,
     imm: Option<u32>,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     if let Some(imm) = imm {
         push_u32_value(span, imm);
     }
@@ -485,11 +488,16 @@ pub fn u32neq(
 ///
 /// VM cycles per mode:
 /// - u32checked_lt: 6 cycles
+/// - u32checked_lt.b: 6 cycles, plus 1 - 2 cycles to push b
 /// - u32unchecked_lt 5 cycles
 pub fn u32lt(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+    imm: Option<u32>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_u32_value(span, imm);
+    }
     handle_u32_and_unchecked_mode(span, op_mode);
     compute_lt(span);
 
@@ -503,11 +511,16 @@ pub fn u32lt(
 ///
 /// VM cycles per mode:
 /// - u32checked_lte: 8 cycles
+/// - u32checked_lte.b: 8 cycles, plus 1 - 2 cycles to push b
 /// - u32unchecked_lte: 7 cycles
 pub fn u32lte(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+    imm: Option<u32>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_u32_value(span, imm);
+    }
     handle_u32_and_unchecked_mode(span, op_mode);
 
     // Compute the lt with reversed number to get a gt check
@@ -525,11 +538,16 @@ pub fn u32lte(
 ///
 /// VM cycles per mode:
 /// - u32checked_gt: 7 cycles
+/// - u32checked_gt.b: 7 cycles, plus 1 - 2 cycles to push b
 /// - u32unchecked_gt: 6 cycles
 pub fn u32gt(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+    imm: Option<u32>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_u32_value(span, imm);
+    }
     handle_u32_and_unchecked_mode(span, op_mode);
 
     // Reverse the numbers so we can get a gt check.
@@ -547,11 +565,16 @@ pub fn u32gt(
 ///
 /// VM cycles per mode:
 /// - u32checked_gte: 7 cycles
+/// - u32checked_gte.b: 7 cycles, plus 1 - 2 cycles to push b
 /// - u32unchecked_gte: 6 cycles
 pub fn u32gte(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+    imm: Option<u32>,
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
+    if let Some(imm) = imm {
+        push_u32_value(span, imm);
+    }
     handle_u32_and_unchecked_mode(span, op_mode);
 
     compute_lt(span);
@@ -573,7 +596,7 @@ pub fn u32gte(
 pub fn u32min(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     compute_max_and_min(span, op_mode);
 
     // Drop the max and keep the min
@@ -593,7 +616,7 @@ pub fn u32min(
 pub fn u32max(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
-) -> Result<Option<CodeBlock>, AssemblyError> {
+) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
     compute_max_and_min(span, op_mode);
 
     // Drop the min and keep the max
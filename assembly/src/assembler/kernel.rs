@@ -0,0 +1,62 @@
+use super::{Digest, Kernel, Procedure, Vec};
+
+// COMPILED KERNEL
+// ================================================================================================
+
+/// A kernel compiled via [Assembler::compile_kernel()](super::Assembler::compile_kernel).
+///
+/// A compiled kernel contains MAST of all procedures exported from the kernel module, and thus, it
+/// can be linked into any number of assemblers via
+/// [Assembler::with_compiled_kernel()](super::Assembler::with_compiled_kernel) without compiling
+/// the kernel source again.
+#[derive(Debug, Clone)]
+pub struct CompiledKernel {
+    kernel: Kernel,
+    procedures: Vec<Procedure>,
+}
+
+impl CompiledKernel {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [CompiledKernel] instantiated from the specified kernel and procedures
+    /// exported from the kernel module.
+    pub(super) fn new(kernel: Kernel, procedures: Vec<Procedure>) -> Self {
+        debug_assert!(procedures
+            .iter()
+            .all(|proc| kernel.contains_proc(proc.code_root().hash())));
+        Self { kernel, procedures }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [Kernel] defined by the procedures of this compiled kernel.
+    pub fn kernel(&self) -> &Kernel {
+        &self.kernel
+    }
+
+    /// Returns an iterator over names and MAST roots of the procedures exported from this kernel.
+    ///
+    /// Procedures are returned in the order in which they are declared in the kernel module.
+    pub fn exports(&self) -> impl Iterator<Item = (&str, Digest)> {
+        self.procedures
+            .iter()
+            .map(|proc| (proc.label(), proc.code_root().hash()))
+    }
+
+    /// Returns the MAST root of the procedure with the specified name exported from this kernel,
+    /// or None if the kernel does not export a procedure with such name.
+    pub fn export_digest(&self, name: &str) -> Option<Digest> {
+        self.exports()
+            .find(|&(label, _)| label == name)
+            .map(|(_, digest)| digest)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the compiled procedures exported from this kernel.
+    pub(super) fn procedures(&self) -> &[Procedure] {
+        &self.procedures
+    }
+}
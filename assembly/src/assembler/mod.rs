@@ -1,10 +1,19 @@
 use super::{
-    parsers::{self, Instruction, Node, ProcedureAst, ProgramAst},
-    AssemblyError, BTreeMap, Box, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, ModuleAst,
-    ModuleProvider, Operation, Procedure, ProcedureId, Program, String, ToString, Vec, ONE, ZERO,
+    parsers::{self, CodeBody, Instruction, Node, ProcedureAst, ProgramAst},
+    Arc, AssemblyError, AsyncModuleProvider, BTreeMap, BTreeSet, Box, CallSet, CodeBlock,
+    CodeBlockTable, Felt, InlineSite, InliningTrace, Kernel, LibraryError, LibraryModuleProvider,
+    MaslLibrary, ModuleAst, ModuleProvider, NamedModuleAst, Operation, ParsingError, Procedure,
+    ProcedureId, ProcedureMetadata, Program, ResolveContext, SerializableLibrary, String, ToString,
+    Vec, DEFAULT_MAX_REPEAT_COUNT, ONE, ZERO,
 };
-use core::{borrow::Borrow, pin::Pin};
-use vm_core::{utils::group_vector_elements, Decorator, DecoratorList};
+use core::{borrow::Borrow, cell::RefCell};
+use vm_core::{
+    chiplets::hasher::Digest, code_blocks::Join, utils::group_vector_elements, Decorator,
+    DecoratorList,
+};
+
+#[cfg(feature = "tracing")]
+use vm_core::utils::DisplayHex;
 
 mod instruction;
 
@@ -14,6 +23,12 @@ use span_builder::SpanBuilder;
 mod context;
 use context::AssemblyContext;
 
+mod kernel;
+pub use kernel::CompiledKernel;
+
+mod artifacts;
+pub use artifacts::CompiledArtifacts;
+
 #[cfg(test)]
 mod tests;
 
@@ -22,22 +37,39 @@ mod tests;
 
 type ProcedureCache = BTreeMap<ProcedureId, Procedure>;
 
+type ResolveCallback = dyn Fn(ResolveContext, &ProcedureId);
+
 // ASSEMBLER
 // ================================================================================================
 /// Miden Assembler which can be used to convert Miden assembly source code into program MAST (
 /// represented by the [Program] struct). The assembler can be instantiated in several ways using
 /// a "builder" patter. Specifically:
-/// - If `with_kernel()` or `with_kernel_module()` methods are not used, the assembler will be
-///   instantiated with a default empty kernel. Programs compiled using such assembler
+/// - If `with_kernel()`, `with_kernel_module()`, or `with_compiled_kernel()` methods are not used,
+///   the assembler will be instantiated with a default empty kernel. Programs compiled using such assembler
 ///   cannot make calls to kernel procedures via `syscall` instruction.
 /// - If `with_module_provider()` method is not used, the assembler will be instantiated without
 ///   access to external libraries. Programs compiled with such assembler must be self-contained
 ///   (i.e., they cannot invoke procedures from external libraries).
+/// - If `with_libraries()` or `with_library_module_provider()` methods are not used, programs can
+///   import only modules available via the module providers of the assembler.
+/// - If `with_async_module_provider()` method is not used, programs compiled via
+///   `compile_async()` can import only modules available via the sync module provider.
+/// - If `with_program_name()` method is not used, compiled programs will have no name.
+/// - If `with_prelude()` method is not used, programs can invoke only their own procedures and
+///   the procedures of the modules they import.
+/// - If `on_resolve()` method is not used, resolution of imported procedures is not reported.
 pub struct Assembler {
     kernel: Kernel,
     module_provider: Box<dyn ModuleProvider>,
-    proc_cache: Pin<Box<ProcedureCache>>,
+    async_module_provider: Box<dyn AsyncModuleProvider>,
+    libraries: Vec<MaslLibrary>,
+    proc_cache: RefCell<ProcedureCache>,
+    library_callees: ProcedureCache,
     in_debug_mode: bool,
+    max_repeat_count: usize,
+    program_name: Option<String>,
+    prelude: BTreeSet<String>,
+    on_resolve: Option<Box<ResolveCallback>>,
 }
 
 impl Assembler {
@@ -48,8 +80,15 @@ impl Assembler {
         Self {
             kernel: Kernel::default(),
             module_provider: Box::new(()),
-            proc_cache: Box::pin(BTreeMap::default()),
+            async_module_provider: Box::new(()),
+            libraries: Vec::new(),
+            proc_cache: RefCell::new(BTreeMap::default()),
+            library_callees: BTreeMap::default(),
             in_debug_mode: false,
+            max_repeat_count: DEFAULT_MAX_REPEAT_COUNT,
+            program_name: None,
+            prelude: BTreeSet::new(),
+            on_resolve: None,
         }
     }
 
@@ -59,7 +98,28 @@ impl Assembler {
         self
     }
 
+    /// Sets the maximum number of times a body of a `repeat` block can be unrolled.
+    ///
+    /// A body of nested `repeat` blocks is unrolled as many times as the product of the counts of
+    /// all these blocks. Programs containing `repeat` blocks with a larger count (or a larger
+    /// product of nested counts) fail to compile. By default, the maximum is set to 65536.
+    pub fn with_max_repeat_count(mut self, max_repeat_count: usize) -> Self {
+        self.max_repeat_count = max_repeat_count;
+        self
+    }
+
+    /// Sets the name with which programs compiled by this assembler are tagged.
+    ///
+    /// The name is metadata only and does not affect hashes of compiled programs.
+    pub fn with_program_name(mut self, name: &str) -> Self {
+        self.program_name = Some(name.to_string());
+        self
+    }
+
     /// Adds the specified [ModuleProvider] to the assembler.
+    ///
+    /// The provider replaces the previously added provider; to resolve modules via several
+    /// providers, they can be combined into a [ModuleProviderChain](crate::ModuleProviderChain).
     pub fn with_module_provider<P>(mut self, provider: P) -> Self
     where
         P: ModuleProvider + 'static,
@@ -68,6 +128,132 @@ impl Assembler {
         self
     }
 
+    /// Registers the specified libraries with the assembler.
+    ///
+    /// Modules of the registered libraries can be imported by compiled programs in addition to the
+    /// modules available via the module provider of the assembler. This method can be invoked
+    /// multiple times; libraries registered in all invocations are retained.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A library with the same namespace as one of the specified libraries has already been
+    ///   registered.
+    /// - A dependency of any of the registered libraries is not satisfied by the registered
+    ///   libraries. Thus, interdependent libraries must be registered together, or dependencies
+    ///   must be registered first.
+    pub fn with_libraries<I>(mut self, libraries: I) -> Result<Self, LibraryError>
+    where
+        I: IntoIterator<Item = MaslLibrary>,
+    {
+        for library in libraries {
+            if self
+                .libraries
+                .iter()
+                .any(|registered| registered.namespace() == library.namespace())
+            {
+                return Err(LibraryError::DuplicateNamespace(
+                    library.namespace().to_string(),
+                ));
+            }
+            self.libraries.push(library);
+        }
+
+        for library in self.libraries.iter() {
+            library.check_dependencies(&self.libraries)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Sets a callback which is invoked each time the assembler resolves a procedure imported by
+    /// the code being compiled.
+    ///
+    /// The callback receives the context of the invoking procedure and the ID of the resolved
+    /// procedure. It is invoked for every `exec` and `call` instruction referencing an imported
+    /// procedure, including procedures which have already been compiled and cached, and thus, it
+    /// can be used to build a graph of dependencies between modules and procedures.
+    pub fn on_resolve<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(ResolveContext, &ProcedureId) + 'static,
+    {
+        self.on_resolve = Some(Box::new(callback));
+        self
+    }
+
+    /// Adds procedures of the libraries loaded into the specified [LibraryModuleProvider] to the
+    /// assembler.
+    ///
+    /// Procedures exported from the module of a library are added to the procedure cache of the
+    /// assembler as is; thus, programs can import modules of the loaded libraries without these
+    /// modules being parsed or compiled. All other procedures of a library (i.e., internal
+    /// procedures of the module, and procedures of other modules it calls) cannot be imported by
+    /// programs; their MAST is used only to build call block tables of the programs which invoke
+    /// the library. This method can be invoked multiple times; procedures of all providers are
+    /// retained.
+    ///
+    /// # Errors
+    /// Returns an error if an exported procedure of a library has already been loaded into the
+    /// assembler (e.g., by another library), or if it was compiled by the assembler.
+    pub fn with_library_module_provider(
+        mut self,
+        provider: LibraryModuleProvider,
+    ) -> Result<Self, AssemblyError> {
+        for library in provider.libraries() {
+            for proc in library.procedures() {
+                // IDs are read from untrusted bytes; only a procedure whose ID is derived from its
+                // label and the path of the library module can be imported by programs
+                let is_module_export = proc.is_export()
+                    && proc.id() == &ProcedureId::from_name(proc.label(), library.path());
+                if !is_module_export {
+                    self.library_callees
+                        .entry(*proc.id())
+                        .or_insert_with(|| proc.clone());
+                } else if self.proc_cache.get_mut().contains_key(proc.id()) {
+                    return Err(AssemblyError::conflicting_library_proc(
+                        proc.id(),
+                        library.path(),
+                    ));
+                } else {
+                    self.proc_cache.get_mut().insert(*proc.id(), proc.clone());
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Sets the prelude for the assembler to the module defined by the provided source.
+    ///
+    /// Procedures exported from the prelude can be invoked via `exec` and `call` instructions in
+    /// any program compiled by the assembler by their names alone, as if they were local
+    /// procedures of the program; the programs do not need to import the prelude. Programs which
+    /// declare local procedures with the same names as the prelude procedures fail to compile.
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the prelude module fails.
+    pub fn with_prelude(mut self, module_source: &str) -> Result<Self, AssemblyError> {
+        let module = parsers::parse_module(module_source)?;
+        let mut context = AssemblyContext::new(false);
+        self.compile_module_ast(&module, ProcedureId::PRELUDE_PATH, &mut context)?;
+        self.prelude = module
+            .local_procs
+            .iter()
+            .filter(|proc_ast| proc_ast.is_export)
+            .map(|proc_ast| proc_ast.name.clone())
+            .collect();
+        Ok(self)
+    }
+
+    /// Adds the specified [AsyncModuleProvider] to the assembler.
+    ///
+    /// This provider is used only by `compile_async()`.
+    pub fn with_async_module_provider<P>(mut self, provider: P) -> Self
+    where
+        P: AsyncModuleProvider + 'static,
+    {
+        self.async_module_provider = Box::new(provider);
+        self
+    }
+
     /// Sets the kernel for the assembler to the kernel defined by the provided source.
     ///
     /// # Errors
@@ -84,16 +270,22 @@ impl Assembler {
     ///
     /// # Errors
     /// Returns an error if compiling kernel source results in an error.
-    pub fn with_kernel_module(mut self, module: &ModuleAst) -> Result<Self, AssemblyError> {
-        // compile the kernel; this adds all exported kernel procedures to the procedure cache
-        let mut context = AssemblyContext::new(true);
-        self.compile_module(module, ProcedureId::KERNEL_PATH, &mut context)?;
-
-        // convert the context into Kernel; this builds the kernel from hashes of procedures
-        // exported form the kernel module
-        self.kernel = context.into_kernel();
+    pub fn with_kernel_module(self, module: &ModuleAst) -> Result<Self, AssemblyError> {
+        let kernel = self.compile_kernel_module(module)?;
+        Ok(self.with_compiled_kernel(kernel))
+    }
 
-        Ok(self)
+    /// Sets the kernel for the assembler to the provided compiled kernel.
+    ///
+    /// Procedures of the compiled kernel are added to the procedure cache of the assembler as is.
+    /// Thus, the same compiled kernel can be used by many assemblers without compiling the kernel
+    /// source for each of them.
+    pub fn with_compiled_kernel(mut self, kernel: CompiledKernel) -> Self {
+        for proc in kernel.procedures() {
+            self.proc_cache.get_mut().insert(*proc.id(), proc.clone());
+        }
+        self.kernel = kernel.kernel().clone();
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -104,6 +296,12 @@ impl Assembler {
         self.in_debug_mode
     }
 
+    /// Returns the maximum number of times a body of a `repeat` block can be unrolled by this
+    /// assembler.
+    pub fn max_repeat_count(&self) -> usize {
+        self.max_repeat_count
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -111,6 +309,156 @@ impl Assembler {
         &self.kernel
     }
 
+    // KERNEL COMPILER
+    // --------------------------------------------------------------------------------------------
+    /// Compiles the provided kernel source into a [CompiledKernel].
+    ///
+    /// The compiled kernel can be linked into any number of assemblers via
+    /// `with_compiled_kernel()`. Kernel procedures may invoke procedures from the modules
+    /// available to this assembler; these are inlined into the kernel procedures.
+    ///
+    /// Note that the kernel is not set as the kernel of this assembler.
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the specified kernel fails.
+    pub fn compile_kernel(&self, kernel_source: &str) -> Result<CompiledKernel, AssemblyError> {
+        let kernel_ast = parsers::parse_module(kernel_source)?;
+        self.compile_kernel_module(&kernel_ast)
+    }
+
+    /// Compiles the provided kernel module into a [CompiledKernel].
+    ///
+    /// # Errors
+    /// Returns an error if compilation of the specified kernel module fails.
+    pub fn compile_kernel_module(
+        &self,
+        module: &ModuleAst,
+    ) -> Result<CompiledKernel, AssemblyError> {
+        // compile the kernel; this adds all exported kernel procedures to the procedure cache
+        let mut context = AssemblyContext::new(true);
+        self.compile_module_ast(module, ProcedureId::KERNEL_PATH, &mut context)?;
+
+        // convert the context into Kernel; this builds the kernel from hashes of procedures
+        // exported form the kernel module
+        let kernel = context.into_kernel();
+
+        // get the compiled exported procedures from the procedure cache
+        let proc_cache = self.proc_cache.borrow();
+        let procedures = module
+            .local_procs
+            .iter()
+            .filter(|proc_ast| proc_ast.is_export)
+            .map(|proc_ast| {
+                let proc_id = ProcedureId::from_kernel_name(&proc_ast.name);
+                proc_cache
+                    .get(&proc_id)
+                    .cloned()
+                    .expect("kernel procedure not in cache")
+            })
+            .collect();
+
+        Ok(CompiledKernel::new(kernel, procedures))
+    }
+
+    // LIBRARY MODULE COMPILER
+    // --------------------------------------------------------------------------------------------
+    /// Compiles all procedures of the library module defined by the provided source, and returns
+    /// MAST roots of the procedures exported from the module in the order of their declaration.
+    ///
+    /// Unlike programs, library modules consist only of procedure declarations. Thus, this method
+    /// can be used to make sure that a module is valid before it is used by any program: all
+    /// procedures of the module are compiled, including exported procedures which are not invoked
+    /// from anywhere. The compiled procedures are cached by the assembler under the specified
+    /// module path, and thus, programs compiled by the assembler can import them from this path.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Parsing of the module fails, or compilation of any of the module's procedures fails.
+    /// - The path is the path of the kernel or of the prelude.
+    /// - A module with the same path has already been compiled or loaded by the assembler, or it
+    ///   can be resolved via the module provider or the libraries of the assembler.
+    pub fn compile_module(&self, path: &str, source: &str) -> Result<Vec<Digest>, AssemblyError> {
+        let module = parsers::parse_module(source)?;
+        self.validate_module_path(path, &module)?;
+        let mut context = AssemblyContext::new(false);
+        self.compile_module_ast(&module, path, &mut context)?;
+
+        let proc_cache = self.proc_cache.borrow();
+        let exports = module
+            .local_procs
+            .iter()
+            .filter(|proc_ast| proc_ast.is_export)
+            .map(|proc_ast| {
+                let proc_id = ProcedureId::from_name(&proc_ast.name, path);
+                proc_cache
+                    .get(&proc_id)
+                    .expect("exported procedure not in cache")
+                    .code_root()
+                    .hash()
+            })
+            .collect();
+
+        Ok(exports)
+    }
+
+    /// Compiles the library module defined by the provided source into a [SerializableLibrary].
+    ///
+    /// The module is compiled in the same way as via `compile_module()` method, but the compiled
+    /// procedures of the module are returned in the library instead of being cached by the
+    /// assembler; to import the module, programs need to be compiled by an assembler into which
+    /// the library is loaded. The returned library contains MAST of the procedures exported from
+    /// the module together with all procedures which these may invoke via `call` instructions; it
+    /// can be serialized and loaded into any number of assemblers via [LibraryModuleProvider].
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the module fails, or if the module cannot be
+    /// compiled under the specified path (see `compile_module()`).
+    pub fn compile_library(
+        &self,
+        path: &str,
+        source: &str,
+    ) -> Result<SerializableLibrary, AssemblyError> {
+        let module = parsers::parse_module(source)?;
+        self.validate_module_path(path, &module)?;
+        let mut context = AssemblyContext::new(false);
+        let module_procs = self
+            .compile_module_procs(&module, path, &mut context)?
+            .into_iter()
+            .map(|proc| (*proc.id(), proc))
+            .collect::<BTreeMap<_, _>>();
+
+        // collect the exported procedures followed by the procedures reachable from them via
+        // callsets; procedures of other modules have been cached (or loaded from libraries) by
+        // the assembler
+        let mut proc_ids = module
+            .local_procs
+            .iter()
+            .filter(|proc_ast| proc_ast.is_export)
+            .map(|proc_ast| ProcedureId::from_name(&proc_ast.name, path))
+            .collect::<Vec<_>>();
+        let proc_cache = self.proc_cache.borrow();
+        let mut visited = BTreeSet::new();
+        let mut procedures = Vec::new();
+        let mut idx = 0;
+        while idx < proc_ids.len() {
+            let proc_id = proc_ids[idx];
+            idx += 1;
+            if !visited.insert(proc_id) {
+                continue;
+            }
+            let proc = module_procs
+                .get(&proc_id)
+                .or_else(|| proc_cache.get(&proc_id))
+                .or_else(|| self.library_callees.get(&proc_id));
+            if let Some(proc) = proc {
+                proc_ids.extend(proc.callset().iter().copied());
+                procedures.push(proc.clone());
+            }
+        }
+
+        Ok(SerializableLibrary::new(path, procedures))
+    }
+
     // PROGRAM COMPILER
     // --------------------------------------------------------------------------------------------
     /// Compiles the provided source code into a [Program]. The resulting program can be executed
@@ -119,16 +467,141 @@ impl Assembler {
     /// # Errors
     /// Returns an error if parsing or compilation of the specified program fails.
     pub fn compile<S>(&self, source: S) -> Result<Program, AssemblyError>
+    where
+        S: AsRef<str>,
+    {
+        self.compile_checked(source)
+            .map_err(|mut errors| errors.remove(0))
+    }
+
+    /// Compiles the provided source code into a [Program] in the same way as `compile()` method,
+    /// but instead of stopping at the first parsing error, reports all parsing errors found in the
+    /// source.
+    ///
+    /// On an invalid instruction (e.g., a malformed instruction or an invocation of an undefined
+    /// procedure), parsing continues with the next instruction; on an error in the structure of a
+    /// procedure (e.g., an unmatched block), the rest of the procedure is skipped. Each error
+    /// carries its own location in the source.
+    ///
+    /// # Errors
+    /// Returns a non-empty list of errors ordered by their position in the source. The program is
+    /// compiled only if it was parsed without errors; compilation stops at the first error (e.g.,
+    /// a missing imported module), and thus, at most one compilation error is reported.
+    pub fn compile_checked<S>(&self, source: S) -> Result<Program, Vec<AssemblyError>>
+    where
+        S: AsRef<str>,
+    {
+        // parse the program into an AST
+        let source = source.as_ref();
+        let program =
+            parsers::parse_program_with_recovery(source, &self.prelude).map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(AssemblyError::from)
+                    .collect::<Vec<_>>()
+            })?;
+
+        // compile the program
+        let (program, _) = self
+            .compile_program(&program, AssemblyContext::new(false))
+            .map_err(|err| vec![err])?;
+        Ok(program)
+    }
+
+    /// Compiles the provided source code into a [Program] and returns it together with its
+    /// MAST, binary, and hash representations (see [CompiledArtifacts]).
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the specified program fails.
+    pub fn compile_full<S>(&self, source: S) -> Result<CompiledArtifacts, AssemblyError>
+    where
+        S: AsRef<str>,
+    {
+        self.compile(source).map(CompiledArtifacts::new)
+    }
+
+    /// Compiles the provided source code into a [Program] and also returns an [InliningTrace]
+    /// which lists all procedures inlined into the program MAST via `exec` instructions, in the
+    /// order in which they were inlined.
+    ///
+    /// The trace does not depend on whether the inlined procedures were compiled as a part of
+    /// this compilation or were cached by the assembler during a previous compilation.
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the specified program fails.
+    pub fn compile_with_inlining_trace<S>(
+        &self,
+        source: S,
+    ) -> Result<(Program, InliningTrace), AssemblyError>
+    where
+        S: AsRef<str>,
+    {
+        let source = source.as_ref();
+        let program = self.parse_program(source)?;
+        self.compile_program(&program, AssemblyContext::new(false))
+    }
+
+    /// Checks that the provided source code is a syntactically valid program without compiling it.
+    ///
+    /// The source is parsed in the same way as by `compile()` method (i.e., imports are parsed and
+    /// references to imported procedures are checked against them), but the imported modules
+    /// are not resolved. Thus, errors which can be detected only during import resolution (e.g.,
+    /// a missing module or procedure) are not reported.
+    ///
+    /// # Errors
+    /// Returns a list of all parsing errors found in the source if the source is not a
+    /// syntactically valid program; see `compile_checked()` method for details on how parsing
+    /// recovers from errors.
+    pub fn check_syntax<S>(source: S) -> Result<(), Vec<ParsingError>>
+    where
+        S: AsRef<str>,
+    {
+        parsers::parse_program_with_recovery(source.as_ref(), &BTreeSet::new()).map(|_| ())
+    }
+
+    /// Compiles the provided source code into a [Program], fetching imported modules which are
+    /// not available via the sync module provider from the async module provider of the
+    /// assembler.
+    ///
+    /// All imported modules (including the ones imported by other imported modules) are fetched
+    /// before compilation starts; after that, the program is compiled in the same way as via
+    /// `compile()` method.
+    ///
+    /// # Errors
+    /// Returns an error if parsing or compilation of the specified program fails.
+    pub async fn compile_async<S>(&self, source: S) -> Result<Program, AssemblyError>
     where
         S: AsRef<str>,
     {
         // parse the program into an AST
         let source = source.as_ref();
-        let ProgramAst { local_procs, body } = parsers::parse_program(source)?;
+        let program = self.parse_program(source)?;
+
+        // fetch all imported modules which are not available locally
+        let fetched_modules = self.fetch_imported_modules(&program).await;
+
+        // compile the program; fetched modules are provided to the compiler via the context
+        let context = AssemblyContext::new(false).with_module_provider(&fetched_modules);
+        let (program, _) = self.compile_program(&program, context)?;
+        Ok(program)
+    }
+
+    /// Parses the provided source into a program AST; procedures of the prelude of this assembler
+    /// can be referenced by the program without being imported.
+    fn parse_program(&self, source: &str) -> Result<ProgramAst, ParsingError> {
+        parsers::parse_program_with_prelude(source, &self.prelude)
+    }
 
+    /// Compiles the provided program AST into a [Program] using the specified context; the
+    /// inlining trace of the program is returned together with the program.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "compile", skip_all))]
+    fn compile_program(
+        &self,
+        program: &ProgramAst,
+        mut context: AssemblyContext,
+    ) -> Result<(Program, InliningTrace), AssemblyError> {
         // compile all local procedures; this will add the procedures to the specified context
-        let mut context = AssemblyContext::new(false);
-        for proc_ast in local_procs.iter() {
+        for proc_ast in program.local_procs.iter() {
             if proc_ast.is_export {
                 return Err(AssemblyError::exported_proc_in_program(&proc_ast.name));
             }
@@ -136,30 +609,124 @@ impl Assembler {
         }
 
         // compile the program body
-        let program_root = self.compile_body(body.iter(), &mut context, None)?;
+        let program_root = self.compile_body(&program.body, &mut context, None)?;
 
-        // convert the context into a call block table for the program
-        let cb_table = context.into_cb_table(&self.proc_cache);
+        // convert the context into a call block table for the program; the inlining trace and
+        // the peak local frame need to be extracted before that as the context is consumed in
+        // the process
+        let inlining_trace = context.inlining_trace().clone();
+        let max_local_frame = context.max_local_frame();
+        let cb_table = context.into_cb_table(&self.proc_cache.borrow(), &self.library_callees);
 
         // build and return the program
-        Ok(Program::with_kernel(
-            program_root,
-            self.kernel.clone(),
-            cb_table,
-        ))
+        let input_spec = program.input_spec();
+        let program = Program::with_kernel(program_root, self.kernel.clone(), cb_table)
+            .with_max_local_frame(max_local_frame);
+        let program = match self.program_name.as_ref() {
+            Some(name) => program.with_name(name),
+            None => program,
+        };
+        let program = match input_spec {
+            Some(input_spec) => program.with_input_spec(input_spec),
+            None => program,
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            program_hash = %DisplayHex(&<[u8; 32]>::from(program.hash())),
+            "compiled program"
+        );
+
+        Ok((program, inlining_trace))
+    }
+
+    // MODULE FETCHER
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches all modules imported by the specified program (directly or via other imported
+    /// modules) which are available neither in the procedure cache nor via the sync module
+    /// provider.
+    ///
+    /// Modules which the async module provider fails to find are skipped; an error for these is
+    /// reported later, during compilation of the program.
+    async fn fetch_imported_modules(&self, program: &ProgramAst) -> FetchedModules {
+        let mut fetched_modules = FetchedModules::default();
+        let mut requested_procs = BTreeSet::new();
+        let mut scanned_modules = BTreeSet::new();
+
+        let mut pending_procs = Vec::new();
+        for proc_ast in program.local_procs.iter() {
+            collect_imported_procs(&proc_ast.body, &mut pending_procs);
+        }
+        collect_imported_procs(&program.body, &mut pending_procs);
+
+        while let Some(proc_id) = pending_procs.pop() {
+            if !requested_procs.insert(proc_id)
+                || self.proc_cache.borrow().contains_key(&proc_id)
+                || fetched_modules.get_module(&proc_id).is_some()
+            {
+                continue;
+            }
+
+            // modules available via the sync provider or the registered libraries are not
+            // fetched, but they may import modules which need to be fetched
+            // modules which cannot be resolved unambiguously are not fetched either; the error
+            // is reported when the program is compiled
+            match self.find_module(&proc_id) {
+                Ok(Some(module)) => {
+                    if scanned_modules.insert(module.path().to_string()) {
+                        collect_module_imports(&module, &mut pending_procs);
+                    }
+                    continue;
+                }
+                Err(_) => continue,
+                Ok(None) => (),
+            }
+
+            if let Some((path, module)) = self.async_module_provider.get_module(&proc_id).await {
+                collect_module_imports(&module, &mut pending_procs);
+                fetched_modules.insert(path, module);
+            }
+        }
+
+        fetched_modules
     }
 
     // MODULE COMPILER
     // --------------------------------------------------------------------------------------------
 
     /// Compiles all procedures in the specified module and adds them to the procedure cache.
-    #[allow(clippy::cast_ref_to_mut)]
-    fn compile_module(
+    fn compile_module_ast(
         &self,
         module: &ModuleAst,
         module_path: &str,
         context: &mut AssemblyContext,
     ) -> Result<(), AssemblyError> {
+        let module_procs = self.compile_module_procs(module, module_path, context)?;
+
+        // add the compiled procedures to the assembler's cache
+        let mut proc_cache = self.proc_cache.borrow_mut();
+        for proc in module_procs {
+            proc_cache.insert(*proc.id(), proc);
+        }
+
+        Ok(())
+    }
+
+    /// Compiles all procedures of the provided module and returns the compiled procedures which
+    /// can be referenced from outside of the module body. These are:
+    /// - procedures exported from the module, and
+    /// - procedures present in the combined callset of the module - i.e., internal procedures
+    ///   which have been invoked via a local call instruction.
+    ///
+    /// The returned procedures are not added to the assembler's cache; procedures of the modules
+    /// imported by this module are compiled and cached as usual.
+    fn compile_module_procs(
+        &self,
+        module: &ModuleAst,
+        module_path: &str,
+        context: &mut AssemblyContext,
+    ) -> Result<Vec<Procedure>, AssemblyError> {
         // compile all procedures in the module; once the compilation is complete, we get all
         // compiled procedures (and their combined callset) from the context
         context.begin_module(module_path)?;
@@ -168,22 +735,10 @@ impl Assembler {
         }
         let (module_procs, module_callset) = context.complete_module();
 
-        // add the compiled procedures to the assembler's cache. the procedures are added to the
-        // cache only if:
-        // - a procedure is exported from the module, or
-        // - a procedure is present in the combined callset - i.e., it is an internal procedure
-        //   which has been invoked via a local call instruction.
-        for proc in module_procs {
-            if proc.is_export() || module_callset.contains(proc.id()) {
-                // TODO: figure out how to do this using interior mutability
-                unsafe {
-                    let mutable_self = &mut *(self as *const _ as *mut Assembler);
-                    mutable_self.proc_cache.insert(*proc.id(), proc);
-                }
-            }
-        }
-
-        Ok(())
+        Ok(module_procs
+            .into_iter()
+            .filter(|proc| proc.is_export() || module_callset.contains(proc.id()))
+            .collect())
     }
 
     // PROCEDURE COMPILER
@@ -207,9 +762,9 @@ impl Assembler {
                 prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
             };
-            self.compile_body(proc.body.iter(), context, Some(wrapper))?
+            self.compile_body(&proc.body, context, Some(wrapper))?
         } else {
-            self.compile_body(proc.body.iter(), context, None)?
+            self.compile_body(&proc.body, context, None)?
         };
 
         context.complete_proc(code_root);
@@ -221,72 +776,119 @@ impl Assembler {
     // --------------------------------------------------------------------------------------------
 
     /// TODO: add comments
-    fn compile_body<A, N>(
+    fn compile_body(
         &self,
-        body: A,
+        body: &CodeBody,
         context: &mut AssemblyContext,
         wrapper: Option<BodyWrapper>,
-    ) -> Result<CodeBlock, AssemblyError>
-    where
-        A: Iterator<Item = N>,
-        N: Borrow<Node>,
-    {
-        let mut blocks: Vec<CodeBlock> = Vec::new();
+    ) -> Result<CodeBlock, AssemblyError> {
+        let mut blocks: Vec<Arc<CodeBlock>> = Vec::new();
         let mut span = SpanBuilder::new(wrapper);
 
-        for node in body {
-            match node.borrow() {
-                Node::Instruction(instruction) => {
-                    if let Some(block) =
-                        self.compile_instruction(instruction, &mut span, context)?
-                    {
-                        span.extract_span_into(&mut blocks);
-                        blocks.push(block);
-                    }
-                }
+        // most instructions compile to at least one operation; thus, the number of nodes in the
+        // body is a good lower bound for the number of operations in its spans
+        span.reserve_ops(body.len());
 
-                Node::IfElse(t, f) => {
-                    span.extract_span_into(&mut blocks);
-
-                    let t = self.compile_body(t.iter(), context, None)?;
+        // node locations refer to the source of the program, and thus, errors are located only
+        // in the bodies of the program and its local procedures; errors which were already
+        // located in a nested node keep their location
+        let in_program = context.is_in_program();
+        for (node, location) in body.located_nodes() {
+            self.compile_node(node, &mut span, &mut blocks, context)
+                .map_err(|err| match location {
+                    Some(location) if in_program => err.with_location(location),
+                    _ => err,
+                })?;
+        }
 
-                    // else is an exception because it is optional; hence, will have to be replaced
-                    // by noop span
-                    let f = if !f.is_empty() {
-                        self.compile_body(f.iter(), context, None)?
-                    } else {
-                        CodeBlock::new_span(vec![Operation::Noop])
-                    };
+        span.extract_final_span_into(&mut blocks);
 
-                    let block = CodeBlock::new_split(t, f);
+        Ok(combine_blocks(blocks))
+    }
 
+    /// Compiles the specified node, appending the resulting code blocks to the specified blocks or
+    /// the resulting operations to the specified span.
+    fn compile_node(
+        &self,
+        node: &Node,
+        span: &mut SpanBuilder,
+        blocks: &mut Vec<Arc<CodeBlock>>,
+        context: &mut AssemblyContext,
+    ) -> Result<(), AssemblyError> {
+        match node {
+            Node::Instruction(instruction) => {
+                if let Some(block) = self.compile_instruction(instruction, span, context)? {
+                    span.extract_span_into(blocks);
                     blocks.push(block);
                 }
+                context.complete_instruction();
+            }
 
-                Node::Repeat(n, nodes) => {
-                    span.extract_span_into(&mut blocks);
+            Node::IfElse(t, f) => {
+                span.extract_span_into(blocks);
 
-                    let block = self.compile_body(nodes.iter(), context, None)?;
+                let t = self.compile_body(t, context, None)?;
 
-                    for _ in 0..*n {
-                        blocks.push(block.clone());
+                // else is an exception because it is optional; hence, will have to be replaced
+                // by noop span
+                let f = if !f.is_empty() {
+                    let f = self.compile_body(f, context, None)?;
+
+                    // both branches must leave the stack at the same depth; this can be
+                    // checked only when both branches are straight-line code. an omitted else
+                    // branch is not checked, as conditionally modifying the stack is the
+                    // common purpose of an if without else
+                    if let (Some(t_effect), Some(f_effect)) =
+                        (t.net_stack_effect(), f.net_stack_effect())
+                    {
+                        if t_effect != f_effect {
+                            return Err(AssemblyError::mismatched_branch_stack_effects(
+                                t_effect, f_effect,
+                            ));
+                        }
                     }
+                    f
+                } else {
+                    CodeBlock::new_span(vec![Operation::Noop])
+                };
+
+                let block = CodeBlock::new_split(t, f);
+
+                blocks.push(Arc::new(block));
+            }
+
+            Node::Repeat(n, nodes) => {
+                // make sure the body is not unrolled more times than allowed; bodies of nested
+                // repeat blocks are unrolled as many times as the product of all their counts
+                let unroll_count = n.saturating_mul(max_unroll_count(nodes));
+                if unroll_count > self.max_repeat_count {
+                    return Err(AssemblyError::repeat_count_too_large(
+                        unroll_count,
+                        self.max_repeat_count,
+                    ));
                 }
 
-                Node::While(nodes) => {
-                    span.extract_span_into(&mut blocks);
+                span.extract_span_into(blocks);
 
-                    let block = self.compile_body(nodes.iter(), context, None)?;
-                    let block = CodeBlock::new_loop(block);
+                // the unrolled copies of the body share the same code block
+                let block = Arc::new(self.compile_body(nodes, context, None)?);
 
-                    blocks.push(block);
+                for _ in 0..*n {
+                    blocks.push(block.clone());
                 }
             }
-        }
 
-        span.extract_final_span_into(&mut blocks);
+            Node::While(nodes) => {
+                span.extract_span_into(blocks);
 
-        Ok(combine_blocks(blocks))
+                let block = self.compile_body(nodes, context, None)?;
+                let block = CodeBlock::new_loop(block);
+
+                blocks.push(Arc::new(block));
+            }
+        }
+
+        Ok(())
     }
 
     // PROCEDURE GETTER
@@ -296,31 +898,119 @@ impl Assembler {
     /// This will first check if procedure is in the assembler's cache, and if not, will attempt
     /// to find the module in which the procedure is located, compile the module, and return the
     /// compiled procedure MAST.
+    ///
+    /// The procedure is returned by value since the cache can be updated while the returned
+    /// procedure is in use; this is cheap as the MAST of the procedure is shared by its clones.
     fn get_imported_proc(
         &self,
         proc_id: &ProcedureId,
         context: &mut AssemblyContext,
-    ) -> Result<&Procedure, AssemblyError> {
+    ) -> Result<Procedure, AssemblyError> {
+        if let Some(callback) = &self.on_resolve {
+            callback(context.resolve_context(), proc_id);
+        }
+
         // if the procedure is already in the procedure cache, return it
-        if let Some(p) = self.proc_cache.get(proc_id) {
-            return Ok(p);
+        if let Some(p) = self.proc_cache.borrow().get(proc_id) {
+            return Ok(p.clone());
         }
 
-        // otherwise, get the module to which the procedure belongs and compile the entire module;
-        // this will add all procedures exported from the module to the procedure cache
-        let module = self
-            .module_provider
-            .get_module(proc_id)
-            .ok_or_else(|| AssemblyError::imported_proc_module_not_found(proc_id))?;
-        self.compile_module(&module, module.path(), context)?;
+        // get the module to which the procedure belongs; if the procedure is re-exported from
+        // this module, follow the chain of re-exports to the module in which it is defined
+        let mut proc_id = *proc_id;
+        let mut module = self.find_imported_module(&proc_id, context.module_provider())?;
+        let mut reexport_chain = Vec::new();
+        while let Some(reexport) = module.get_reexport(&proc_id) {
+            reexport_chain.push((proc_id, module.path().to_string()));
+            proc_id = reexport.proc_id;
+
+            if let Some(idx) = reexport_chain.iter().position(|(id, _)| id == &proc_id) {
+                let mut module_chain: Vec<String> =
+                    reexport_chain.into_iter().map(|(_, path)| path).collect();
+                module_chain.push(module_chain[idx].clone());
+                return Err(AssemblyError::circular_reexport(&module_chain));
+            }
+            if let Some(p) = self.proc_cache.borrow().get(&proc_id) {
+                return Ok(p.clone());
+            }
+            module = self.find_imported_module(&proc_id, context.module_provider())?;
+        }
+
+        // compile the entire module; this will add all procedures exported from the module to
+        // the procedure cache
+        self.compile_module_ast(&module, module.path(), context)?;
 
         // then, get the procedure out of the procedure cache and return; if the procedure
         // cannot be found in the cache, it is possible that the procedure was not in the
         // module returned from the module provider
-        let proc = self.proc_cache.get(proc_id).ok_or_else(|| {
-            AssemblyError::imported_proc_not_found_in_module(proc_id, module.path())
-        })?;
-        Ok(proc)
+        self.proc_cache
+            .borrow()
+            .get(&proc_id)
+            .cloned()
+            .ok_or_else(|| {
+                AssemblyError::imported_proc_not_found_in_module(&proc_id, module.path())
+            })
+    }
+
+    /// Returns the module containing a procedure with the specified ID from the module provider
+    /// of the assembler, one of the registered libraries, or the specified module provider of the
+    /// current context.
+    ///
+    /// # Errors
+    /// Returns an error if the module cannot be found, or if the module provider of the assembler
+    /// cannot resolve the module unambiguously.
+    fn find_imported_module<'b>(
+        &'b self,
+        proc_id: &ProcedureId,
+        context_provider: Option<&'b dyn ModuleProvider>,
+    ) -> Result<NamedModuleAst<'b>, AssemblyError> {
+        match self.find_module(proc_id)? {
+            Some(module) => Some(module),
+            None => context_provider.and_then(|provider| provider.get_module(proc_id)),
+        }
+        .ok_or_else(|| AssemblyError::imported_proc_module_not_found(proc_id))
+    }
+
+    /// Returns the module containing a procedure with the specified ID from the module provider
+    /// of the assembler or from one of the registered libraries, or None if the module cannot be
+    /// found.
+    ///
+    /// # Errors
+    /// Returns an error if the module provider of the assembler cannot resolve the module
+    /// unambiguously.
+    fn find_module(
+        &self,
+        proc_id: &ProcedureId,
+    ) -> Result<Option<NamedModuleAst<'_>>, AssemblyError> {
+        let module = self.module_provider.try_get_module(proc_id)?.or_else(|| {
+            self.libraries
+                .iter()
+                .find_map(|library| ModuleProvider::get_module(library, proc_id))
+        });
+        Ok(module)
+    }
+
+    /// Returns an error if the specified module cannot be compiled under the specified path.
+    ///
+    /// The paths of the kernel and of the prelude are reserved. A module also cannot be compiled
+    /// under the path of a module which the assembler has already compiled or loaded, or which it
+    /// can resolve via its module provider or libraries, as this would shadow the procedures of
+    /// that module.
+    fn validate_module_path(&self, path: &str, module: &ModuleAst) -> Result<(), AssemblyError> {
+        if path == ProcedureId::KERNEL_PATH || path == ProcedureId::PRELUDE_PATH {
+            return Err(AssemblyError::reserved_module_path(path));
+        }
+
+        for proc_name in module.proc_names() {
+            let proc_id = ProcedureId::from_name(proc_name, path);
+            if self.proc_cache.borrow().contains_key(&proc_id)
+                || self.find_module(&proc_id)?.is_some()
+            {
+                return Err(AssemblyError::duplicate_module_path(path));
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -340,29 +1030,106 @@ struct BodyWrapper {
     epilogue: Vec<Operation>,
 }
 
+// FETCHED MODULES
+// ================================================================================================
+
+/// A set of modules fetched via an [AsyncModuleProvider], indexed by IDs of their procedures.
+#[derive(Default)]
+struct FetchedModules {
+    modules: Vec<(String, ModuleAst)>,
+    proc_index: BTreeMap<ProcedureId, usize>,
+}
+
+impl FetchedModules {
+    /// Adds the specified module to this set.
+    fn insert(&mut self, path: String, module: ModuleAst) {
+        let module_idx = self.modules.len();
+        for proc_name in module.proc_names() {
+            let proc_id = ProcedureId::from_name(proc_name, &path);
+            self.proc_index.insert(proc_id, module_idx);
+        }
+        self.modules.push((path, module));
+    }
+}
+
+impl ModuleProvider for FetchedModules {
+    fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.proc_index.get(id).map(|&module_idx| {
+            let (path, module) = &self.modules[module_idx];
+            NamedModuleAst::new(path.as_str(), module)
+        })
+    }
+}
+
 // UTILITY FUNCTIONS
 // ================================================================================================
 
-pub fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
+/// Appends IDs of all procedures invoked via `exec` or `call` instructions from the specified
+/// code body to the provided list.
+fn collect_imported_procs(body: &[Node], result: &mut Vec<ProcedureId>) {
+    for node in body {
+        match node {
+            Node::Instruction(Instruction::ExecImported(proc_id))
+            | Node::Instruction(Instruction::CallImported(proc_id)) => result.push(*proc_id),
+            Node::Instruction(_) => (),
+            Node::IfElse(t, f) => {
+                collect_imported_procs(t, result);
+                collect_imported_procs(f, result);
+            }
+            Node::Repeat(_, nodes) | Node::While(nodes) => collect_imported_procs(nodes, result),
+        }
+    }
+}
+
+/// Returns the largest number of times any part of the specified code body is unrolled due to
+/// `repeat` blocks nested in the body; this is 1 for bodies without `repeat` blocks.
+fn max_unroll_count(body: &[Node]) -> usize {
+    body.iter()
+        .map(|node| match node {
+            Node::Instruction(_) => 1,
+            Node::IfElse(t, f) => max_unroll_count(t).max(max_unroll_count(f)),
+            Node::Repeat(n, nodes) => n.saturating_mul(max_unroll_count(nodes)),
+            Node::While(nodes) => max_unroll_count(nodes),
+        })
+        .max()
+        .unwrap_or(1)
+}
+
+/// Appends IDs of all procedures invoked from the procedures of the specified module, as well as
+/// IDs of all procedures re-exported from the module, to the provided list.
+fn collect_module_imports(module: &ModuleAst, result: &mut Vec<ProcedureId>) {
+    for proc_ast in module.local_procs.iter() {
+        collect_imported_procs(&proc_ast.body, result);
+    }
+    result.extend(module.reexported_procs.iter().map(|proc| proc.proc_id));
+}
+
+/// Combines the specified code blocks into a single code block.
+///
+/// Consecutive span blocks are merged into a single span block, and the remaining blocks are
+/// joined into a balanced binary tree of join blocks. The combined blocks are not copied, and
+/// thus, a block shared by several call sites (e.g., the body of a procedure inlined more than
+/// once) remains shared in the resulting tree.
+pub fn combine_blocks(mut blocks: Vec<Arc<CodeBlock>>) -> CodeBlock {
     debug_assert!(!blocks.is_empty(), "cannot combine empty block list");
     // merge consecutive Span blocks.
-    let mut merged_blocks: Vec<CodeBlock> = Vec::with_capacity(blocks.len());
+    let mut merged_blocks: Vec<Arc<CodeBlock>> = Vec::with_capacity(blocks.len());
     // Keep track of all the consecutive Span blocks and are merged together when
     // there is a discontinuity.
-    let mut contiguous_spans: Vec<CodeBlock> = Vec::new();
+    let mut contiguous_spans: Vec<Arc<CodeBlock>> = Vec::new();
 
     blocks.drain(0..).for_each(|block| {
         if block.is_span() {
             contiguous_spans.push(block);
         } else {
             if !contiguous_spans.is_empty() {
-                merged_blocks.push(combine_spans(&mut contiguous_spans));
+                merged_blocks.push(Arc::new(combine_spans(&mut contiguous_spans)));
             }
             merged_blocks.push(block);
         }
     });
     if !contiguous_spans.is_empty() {
-        merged_blocks.push(combine_spans(&mut contiguous_spans));
+        merged_blocks.push(Arc::new(combine_spans(&mut contiguous_spans)));
     }
 
     // build a binary tree of blocks joining them using Join blocks
@@ -376,9 +1143,9 @@ pub fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
 
         let mut grouped_blocks = Vec::new();
         core::mem::swap(&mut blocks, &mut grouped_blocks);
-        let mut grouped_blocks = group_vector_elements::<CodeBlock, 2>(grouped_blocks);
+        let mut grouped_blocks = group_vector_elements::<Arc<CodeBlock>, 2>(grouped_blocks);
         grouped_blocks.drain(0..).for_each(|pair| {
-            blocks.push(CodeBlock::new_join(pair));
+            blocks.push(Arc::new(CodeBlock::Join(Join::new_shared(pair))));
         });
 
         if let Some(block) = last_block {
@@ -387,19 +1154,19 @@ pub fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
     }
 
     debug_assert!(!blocks.is_empty(), "no blocks");
-    blocks.remove(0)
+    unwrap_block(blocks.remove(0))
 }
 
 /// Returns a CodeBlock [Span] from sequence of Span blocks provided as input.
-pub fn combine_spans(spans: &mut Vec<CodeBlock>) -> CodeBlock {
+pub fn combine_spans(spans: &mut Vec<Arc<CodeBlock>>) -> CodeBlock {
     if spans.len() == 1 {
-        return spans.remove(0);
+        return unwrap_block(spans.remove(0));
     }
 
     let mut ops = Vec::<Operation>::new();
     let mut decorators = DecoratorList::new();
     spans.drain(0..).for_each(|block| {
-        if let CodeBlock::Span(span) = block {
+        if let CodeBlock::Span(span) = block.as_ref() {
             for decorator in span.decorators() {
                 decorators.push((decorator.0 + ops.len(), decorator.1.clone()));
             }
@@ -412,3 +1179,8 @@ pub fn combine_spans(spans: &mut Vec<CodeBlock>) -> CodeBlock {
     });
     CodeBlock::new_span_with_decorators(ops, decorators)
 }
+
+/// Returns the specified code block, copying it only if it is shared.
+fn unwrap_block(block: Arc<CodeBlock>) -> CodeBlock {
+    Arc::try_unwrap(block).unwrap_or_else(|block| block.as_ref().clone())
+}
@@ -1,5 +1,5 @@
 use super::{
-    AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList, Instruction,
+    Arc, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList, Instruction,
     Operation, ToString, Vec,
 };
 use vm_core::AssemblyOp;
@@ -46,13 +46,13 @@ impl SpanBuilder {
     // --------------------------------------------------------------------------------------------
 
     /// Adds the specified operation to the list of span operations and returns Ok(None).
-    pub fn add_op(&mut self, op: Operation) -> Result<Option<CodeBlock>, AssemblyError> {
+    pub fn add_op(&mut self, op: Operation) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         self.ops.push(op);
         Ok(None)
     }
 
     /// Adds the specified sequence operations to the list of span operations and returns Ok(None).
-    pub fn add_ops<I, O>(&mut self, ops: I) -> Result<Option<CodeBlock>, AssemblyError>
+    pub fn add_ops<I, O>(&mut self, ops: I) -> Result<Option<Arc<CodeBlock>>, AssemblyError>
     where
         I: IntoIterator<Item = O>,
         O: Borrow<Operation>,
@@ -75,6 +75,11 @@ impl SpanBuilder {
         self.ops.extend(ops.into_iter().map(|o| *o.borrow()));
     }
 
+    /// Reserves capacity for at least `additional` more operations in the list of span operations.
+    pub fn reserve_ops(&mut self, additional: usize) {
+        self.ops.reserve(additional);
+    }
+
     /// Adds the specified operation n times to the list of span operations.
     pub fn push_op_many(&mut self, op: Operation, n: usize) {
         let new_len = self.ops.len() + n;
@@ -93,7 +98,7 @@ impl SpanBuilder {
     pub fn add_decorator(
         &mut self,
         decorator: Decorator,
-    ) -> Result<Option<CodeBlock>, AssemblyError> {
+    ) -> Result<Option<Arc<CodeBlock>>, AssemblyError> {
         self.push_decorator(decorator);
         Ok(None)
     }
@@ -141,15 +146,21 @@ impl SpanBuilder {
     ///
     /// This consumes all operations and decorators in the builder, but does not touch the
     /// operations in the epilogue of the builder.
-    pub fn extract_span_into(&mut self, target: &mut Vec<CodeBlock>) {
+    ///
+    /// If the builder contains decorators but no operations (e.g., an advice injector is the last
+    /// instruction after a control flow block), a NOOP is added so that the decorators are
+    /// attached to a SPAN block.
+    pub fn extract_span_into(&mut self, target: &mut Vec<Arc<CodeBlock>>) {
+        if self.ops.is_empty() && !self.decorators.is_empty() {
+            self.ops.push(Operation::Noop);
+        }
+
         if !self.ops.is_empty() {
             let ops = self.ops.drain(..).collect();
             let decorators = self.decorators.drain(..).collect();
-            target.push(CodeBlock::new_span_with_decorators(ops, decorators));
-        } else if !self.decorators.is_empty() {
-            // this is a bug in the assembler. we shouldn't have decorators added without their
-            // associated operations
-            unreachable!()
+            target.push(Arc::new(CodeBlock::new_span_with_decorators(
+                ops, decorators,
+            )));
         }
     }
 
@@ -160,7 +171,7 @@ impl SpanBuilder {
     /// - Operations contained in the epilogue of the span builder are appended to the list of
     ///   ops which go into the new SPAN block.
     /// - The span builder is consumed in the process.
-    pub fn extract_final_span_into(mut self, target: &mut Vec<CodeBlock>) {
+    pub fn extract_final_span_into(mut self, target: &mut Vec<Arc<CodeBlock>>) {
         self.ops.append(&mut self.epilogue);
         self.extract_span_into(target);
     }
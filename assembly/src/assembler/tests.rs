@@ -1,6 +1,4 @@
-use super::{
-    combine_blocks, parsers, Assembler, CodeBlock, ModuleProvider, Operation, ProcedureId,
-};
+use super::{parsers, Arc, Assembler, CodeBlock, ModuleProvider, Operation, ProcedureId};
 
 // TESTS
 // ================================================================================================
@@ -17,11 +15,12 @@ fn nested_blocks() {
     let assembler = Assembler::new().with_kernel(&kernel).unwrap();
 
     // the assembler should have a single kernel proc in its cache
-    assert_eq!(assembler.proc_cache.len(), 1);
+    assert_eq!(assembler.proc_cache.borrow().len(), 1);
 
     // fetch the kernel digest and store into a syscall block
     let syscall = assembler
         .proc_cache
+        .borrow()
         .values()
         .next()
         .map(|p| CodeBlock::new_syscall(p.code_root().hash()))
@@ -112,3 +111,162 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+#[test]
+fn compiled_kernel_linked_into_many_programs() {
+    let kernel_source = "
+        export.foo
+            add
+        end
+
+        export.bar
+            mul
+        end";
+
+    // compile the kernel once
+    let kernel = Assembler::new().compile_kernel(kernel_source).unwrap();
+    let exports = kernel.exports().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(vec!["foo", "bar"], exports);
+    assert!(kernel.export_digest("baz").is_none());
+    let foo = CodeBlock::new_syscall(kernel.export_digest("foo").unwrap());
+    let bar = CodeBlock::new_syscall(kernel.export_digest("bar").unwrap());
+
+    // link the compiled kernel into two assemblers, and use each of them to compile a program
+    let source1 = "begin syscall.foo syscall.bar end";
+    let program1 = Assembler::new()
+        .with_compiled_kernel(kernel.clone())
+        .compile(source1)
+        .unwrap();
+
+    let source2 = "begin push.1 syscall.foo end";
+    let program2 = Assembler::new()
+        .with_compiled_kernel(kernel.clone())
+        .compile(source2)
+        .unwrap();
+
+    // syscalls in both programs resolve to the procedures of the compiled kernel
+    assert_eq!(kernel.kernel(), program1.kernel());
+    assert_eq!(kernel.kernel(), program2.kernel());
+    let expected1 = combine_blocks(vec![foo.clone(), bar]);
+    assert_eq!(expected1.hash(), program1.hash());
+    let push = CodeBlock::new_span(vec![Operation::Pad, Operation::Incr]);
+    let expected2 = combine_blocks(vec![push, foo]);
+    assert_eq!(expected2.hash(), program2.hash());
+
+    // the programs are the same as the ones compiled with the kernel compiled from source
+    let assembler = Assembler::new().with_kernel(kernel_source).unwrap();
+    assert_eq!(assembler.compile(source1).unwrap().hash(), program1.hash());
+    assert_eq!(assembler.compile(source2).unwrap().hash(), program2.hash());
+
+    // compiling a kernel does not set it as the kernel of the assembler
+    let assembler = Assembler::new();
+    assembler.compile_kernel(kernel_source).unwrap();
+    assert!(assembler.kernel().is_empty());
+    assert!(assembler.compile(source1).is_err());
+}
+
+#[test]
+fn syscalls_to_undefined_kernel_procedures() {
+    use crate::AssemblyError;
+
+    let kernel_source = "export.foo add end";
+    let foo_id = ProcedureId::from_kernel_name("foo");
+    let bar_id = ProcedureId::from_kernel_name("bar");
+
+    // without a kernel, no procedure can be invoked via a syscall
+    let assembler = Assembler::new();
+    let error = assembler.compile("begin syscall.foo end").unwrap_err();
+    assert_eq!(
+        &AssemblyError::kernel_proc_not_found(&foo_id),
+        error.unlocated()
+    );
+
+    // with a kernel, only the procedures exported from the kernel can be invoked via a syscall
+    let assembler = Assembler::new().with_kernel(kernel_source).unwrap();
+    let error = assembler
+        .compile("begin syscall.foo syscall.bar end")
+        .unwrap_err();
+    assert_eq!(
+        &AssemblyError::kernel_proc_not_found(&bar_id),
+        error.unlocated()
+    );
+
+    // the compiled program carries the roots of the kernel procedures; a program compiled
+    // without a kernel carries an empty kernel
+    let program = assembler.compile("begin syscall.foo end").unwrap();
+    assert_eq!(assembler.kernel(), program.kernel());
+    assert_eq!(1, program.kernel().proc_hashes().len());
+    let program = Assembler::new().compile("begin push.1 end").unwrap();
+    assert!(program.kernel().is_empty());
+}
+
+#[test]
+fn compile_full_artifacts_are_consistent() {
+    use vm_core::{
+        chiplets::hasher::Digest,
+        utils::{Deserializable, SliceReader},
+        Program,
+    };
+
+    let source = "\
+        proc.double add.0 dup add end
+        begin push.3 exec.double if.true push.1 else push.2 end end";
+    let assembler = Assembler::default();
+    let artifacts = assembler.compile_full(source).unwrap();
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(program.hash(), artifacts.hash());
+
+    // MAST representation matches the one of the program
+    assert_eq!(format!("{program}"), artifacts.mast());
+    assert_eq!(format!("{}", artifacts.program()), artifacts.mast());
+
+    // binary representation is read back into the same program
+    let read = Program::read_from_bytes(artifacts.bytes()).unwrap();
+    assert_eq!(artifacts.program().root(), read.root());
+    assert_eq!(artifacts.hash(), read.hash());
+
+    // hash hex is parsed back into the hash of the program
+    let hash_hex = artifacts.hash_hex();
+    assert_eq!(64, hash_hex.len());
+    let hash_bytes = (0..hash_hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hash_hex[i..i + 2], 16).unwrap())
+        .collect::<Vec<_>>();
+    let hash = Digest::read_from(&mut SliceReader::new(&hash_bytes)).unwrap();
+    assert_eq!(artifacts.hash(), hash);
+}
+
+#[test]
+fn program_with_trailing_decorator_round_trip() {
+    use vm_core::{utils::Serializable, Program};
+
+    // advice injectors placed after the last operation of a span are attached to the index
+    // immediately past the end of the span
+    let source = "begin push.1 adv.u64div end";
+    let program = Assembler::default().compile(source).unwrap();
+    match program.root() {
+        CodeBlock::Span(span) => {
+            let num_ops = span
+                .op_batches()
+                .iter()
+                .map(|batch| batch.ops().len())
+                .sum::<usize>();
+            assert_eq!(Some(num_ops), span.decorators().last().map(|(idx, _)| *idx));
+        }
+        _ => panic!("expected a span block"),
+    }
+
+    let bytes = program.to_bytes();
+    let read = Program::read_verified(&bytes, program.hash()).unwrap();
+    assert_eq!(program.hash(), read.hash());
+    assert_eq!(bytes, read.to_bytes());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Combines the specified code blocks in the same way as the assembler combines the blocks of a
+/// body.
+fn combine_blocks(blocks: Vec<CodeBlock>) -> CodeBlock {
+    super::combine_blocks(blocks.into_iter().map(Arc::new).collect())
+}
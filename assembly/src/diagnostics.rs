@@ -0,0 +1,403 @@
+use super::{AssemblyError, ParsingError, String, ToString};
+use core::fmt::Write;
+
+// SOURCE LOCATION
+// ================================================================================================
+
+/// Location of a token in the source code.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Line of the token, starting from 1.
+    pub line: usize,
+    /// Column of the first character of the token, starting from 1.
+    pub column: usize,
+    /// Number of characters in the token.
+    pub len: usize,
+}
+
+// DIAGNOSTIC
+// ================================================================================================
+
+/// An error reported by the assembler in a form suitable for presenting it to a user.
+///
+/// A diagnostic retains the error itself (without its location), and thus, front-ends can inspect
+/// the kind of the error instead of matching its message. When the location of the error in the
+/// source code is known, a diagnostic also contains the location and the line of the source code
+/// at which the error occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    error: AssemblyError,
+    message: String,
+    location: Option<SourceLocation>,
+    source_line: Option<String>,
+}
+
+impl Diagnostic {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [Diagnostic] for the specified parsing error, which occurred while parsing
+    /// the specified source code.
+    pub fn from_parsing_error(error: &ParsingError, source: &str) -> Self {
+        Self::from_assembly_error(&error.clone().into(), source)
+    }
+
+    /// Returns a new [Diagnostic] for the specified error, which occurred while compiling the
+    /// specified program source code.
+    ///
+    /// The diagnostic has a location only if the error carries one (i.e., if the error was caused
+    /// by a specific token of the program source); errors which are not tied to a location have
+    /// no location in the diagnostic either.
+    pub fn from_assembly_error(error: &AssemblyError, source: &str) -> Self {
+        let location = error.location();
+        let source_line = location
+            .and_then(|location| source.lines().nth(location.line - 1))
+            .map(|line| line.to_string());
+        let error = error.unlocated().clone();
+        Self {
+            message: error.to_string(),
+            error,
+            location,
+            source_line,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the error of this diagnostic without its location.
+    pub fn error(&self) -> &AssemblyError {
+        &self.error
+    }
+
+    /// Returns the name of the kind of the error of this diagnostic (see
+    /// [AssemblyError::kind()]).
+    pub fn kind(&self) -> &'static str {
+        self.error.kind()
+    }
+
+    /// Returns the error message of this diagnostic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the location of the error in the source code, if it is known.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.location
+    }
+
+    /// Returns the line of the source code at which the error occurred, if it is known.
+    pub fn source_line(&self) -> Option<&str> {
+        self.source_line.as_deref()
+    }
+}
+
+// DIAGNOSTIC RENDERERS
+// ================================================================================================
+
+/// Formats diagnostics for presentation to a user.
+///
+/// [PlainRenderer] is the default renderer; [ColoredRenderer] and [JsonRenderer] are provided for
+/// terminals and for tools respectively. Front-ends which need other presentations can implement
+/// this trait themselves.
+pub trait DiagnosticRenderer {
+    /// Returns the specified diagnostic formatted by this renderer.
+    fn render(&self, diagnostic: &Diagnostic) -> String;
+}
+
+/// Renders diagnostics as plain text; when the location of an error is known, the line of the
+/// source code at which the error occurred is printed with the erroneous token underlined.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlainRenderer;
+
+impl DiagnosticRenderer for PlainRenderer {
+    fn render(&self, diagnostic: &Diagnostic) -> String {
+        render_text(diagnostic, &TextStyle::PLAIN)
+    }
+}
+
+/// Renders diagnostics in the same way as [PlainRenderer], but highlights parts of the output
+/// using ANSI terminal colors.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ColoredRenderer;
+
+impl DiagnosticRenderer for ColoredRenderer {
+    fn render(&self, diagnostic: &Diagnostic) -> String {
+        render_text(diagnostic, &TextStyle::COLORED)
+    }
+}
+
+/// Renders diagnostics as single-line JSON objects of the following form:
+///
+/// `{"kind": "...", "message": "...", "location": {"line": 1, "column": 1, "len": 1},
+/// "source_line": "..."}`
+///
+/// `kind` is the name of the kind of the error (see [AssemblyError::kind()]).
+///
+/// `location` and `source_line` are set to `null` when the location of an error is not known.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonRenderer;
+
+impl DiagnosticRenderer for JsonRenderer {
+    fn render(&self, diagnostic: &Diagnostic) -> String {
+        let mut result = String::from("{\"kind\": ");
+        write_json_string(&mut result, diagnostic.kind());
+
+        result.push_str(", \"message\": ");
+        write_json_string(&mut result, &diagnostic.message);
+
+        result.push_str(", \"location\": ");
+        match diagnostic.location {
+            Some(location) => {
+                let SourceLocation { line, column, len } = location;
+                write!(
+                    result,
+                    "{{\"line\": {line}, \"column\": {column}, \"len\": {len}}}"
+                )
+                .expect("writing to a string should not fail");
+            }
+            None => result.push_str("null"),
+        }
+
+        result.push_str(", \"source_line\": ");
+        match &diagnostic.source_line {
+            Some(line) => write_json_string(&mut result, line),
+            None => result.push_str("null"),
+        }
+        result.push('}');
+        result
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Prefixes which switch the style of the text rendered by text renderers.
+struct TextStyle {
+    error: &'static str,
+    gutter: &'static str,
+    marker: &'static str,
+    reset: &'static str,
+}
+
+impl TextStyle {
+    const PLAIN: Self = Self {
+        error: "",
+        gutter: "",
+        marker: "",
+        reset: "",
+    };
+
+    const COLORED: Self = Self {
+        error: "\x1b[1;31m",
+        gutter: "\x1b[1;34m",
+        marker: "\x1b[1;31m",
+        reset: "\x1b[0m",
+    };
+}
+
+/// Renders the diagnostic as follows:
+///
+/// ```text
+/// error: instruction 'pushh.2' is invalid; did you mean 'push'?
+///  --> line 2, column 12
+///   |
+/// 2 |     push.1 pushh.2
+///   |            ^^^^^^^
+/// ```
+fn render_text(diagnostic: &Diagnostic, style: &TextStyle) -> String {
+    let TextStyle {
+        error,
+        gutter,
+        marker,
+        reset,
+    } = style;
+
+    let mut result = String::new();
+    write!(result, "{error}error{reset}: {}", diagnostic.message)
+        .expect("writing to a string should not fail");
+
+    if let (Some(location), Some(line)) = (diagnostic.location, &diagnostic.source_line) {
+        let SourceLocation {
+            line: line_num,
+            column,
+            len,
+        } = location;
+        let width = line_num.to_string().len();
+
+        // tabs in front of the token are preserved so that the marker is aligned with the token
+        let padding = line
+            .chars()
+            .take(column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let underline = "^".repeat(len.max(1));
+
+        write!(
+            result,
+            "\n{:width$}{gutter}-->{reset} line {line_num}, column {column}\
+            \n{:width$} {gutter}|{reset}\
+            \n{gutter}{line_num} |{reset} {line}\
+            \n{:width$} {gutter}|{reset} {padding}{marker}{underline}{reset}",
+            "", "", ""
+        )
+        .expect("writing to a string should not fail");
+    }
+    result
+}
+
+/// Writes the specified value into the target as a JSON string literal.
+fn write_json_string(target: &mut String, value: &str) {
+    target.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => target.push_str("\\\""),
+            '\\' => target.push_str("\\\\"),
+            '\n' => target.push_str("\\n"),
+            '\r' => target.push_str("\\r"),
+            '\t' => target.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(target, "\\u{:04x}", c as u32).expect("writing to a string should not fail")
+            }
+            c => target.push(c),
+        }
+    }
+    target.push('"');
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AssemblyError, ColoredRenderer, Diagnostic, DiagnosticRenderer, JsonRenderer,
+        PlainRenderer, SourceLocation,
+    };
+    use crate::Assembler;
+
+    const SOURCE: &str = "\
+#! Doc comments do not produce tokens.
+begin
+    push.1 pushh.2 # a \"quoted\" comment
+end";
+
+    fn compile_error(source: &str) -> AssemblyError {
+        Assembler::default().compile(source).unwrap_err()
+    }
+
+    #[test]
+    fn diagnostic_location() {
+        let diagnostic = Diagnostic::from_assembly_error(&compile_error(SOURCE), SOURCE);
+        assert_eq!("parsing_error", diagnostic.kind());
+        assert_eq!(
+            "instruction 'pushh.2' is invalid; did you mean 'push'?",
+            diagnostic.message()
+        );
+        assert_eq!(
+            Some(SourceLocation {
+                line: 3,
+                column: 12,
+                len: 7
+            }),
+            diagnostic.location()
+        );
+        assert_eq!(
+            Some("    push.1 pushh.2 # a \"quoted\" comment"),
+            diagnostic.source_line()
+        );
+
+        // errors in resolution of imported procedures are located at the invocation
+        let source = "use.acme::math begin exec.math::double end";
+        let diagnostic = Diagnostic::from_assembly_error(&compile_error(source), source);
+        assert_eq!("imported_proc_module_not_found", diagnostic.kind());
+        assert!(matches!(
+            diagnostic.error(),
+            AssemblyError::ImportedProcModuleNotFound(_)
+        ));
+        assert_eq!(
+            Some(SourceLocation {
+                line: 1,
+                column: 22,
+                len: 17
+            }),
+            diagnostic.location()
+        );
+        assert_eq!(Some(source), diagnostic.source_line());
+
+        // errors detected during compilation are located at the node which caused them
+        let source = "begin repeat.100000 push.1 end end";
+        let diagnostic = Diagnostic::from_assembly_error(&compile_error(source), source);
+        assert_eq!(
+            &AssemblyError::repeat_count_too_large(100000, 65536),
+            diagnostic.error()
+        );
+        assert_eq!(
+            "repeat count 100000 exceeds maximum 65536",
+            diagnostic.message()
+        );
+        assert_eq!(
+            Some(SourceLocation {
+                line: 1,
+                column: 7,
+                len: 13
+            }),
+            diagnostic.location()
+        );
+
+        // errors which are not tied to a token have no location
+        let diagnostic =
+            Diagnostic::from_assembly_error(&AssemblyError::division_by_zero(), source);
+        assert_eq!(None, diagnostic.location());
+        assert_eq!(None, diagnostic.source_line());
+    }
+
+    #[test]
+    fn text_renderers() {
+        let diagnostic = Diagnostic::from_assembly_error(&compile_error(SOURCE), SOURCE);
+        let expected = "\
+error: instruction 'pushh.2' is invalid; did you mean 'push'?
+ --> line 3, column 12
+  |
+3 |     push.1 pushh.2 # a \"quoted\" comment
+  |            ^^^^^^^";
+        assert_eq!(expected, PlainRenderer.render(&diagnostic));
+
+        // the colored renderer produces the same text once the color codes are removed
+        let colored = ColoredRenderer.render(&diagnostic);
+        assert_ne!(expected, colored);
+        let stripped = ["\x1b[1;31m", "\x1b[1;34m", "\x1b[0m"]
+            .iter()
+            .fold(colored, |text, code| text.replace(code, ""));
+        assert_eq!(expected, stripped);
+    }
+
+    #[test]
+    fn json_renderer() {
+        let diagnostic = Diagnostic::from_assembly_error(&compile_error(SOURCE), SOURCE);
+        let json: serde_json::Value =
+            serde_json::from_str(&JsonRenderer.render(&diagnostic)).unwrap();
+        assert_eq!("parsing_error", json["kind"]);
+        assert_eq!(
+            "instruction 'pushh.2' is invalid; did you mean 'push'?",
+            json["message"]
+        );
+        assert_eq!(3, json["location"]["line"]);
+        assert_eq!(12, json["location"]["column"]);
+        assert_eq!(7, json["location"]["len"]);
+        assert_eq!(
+            "    push.1 pushh.2 # a \"quoted\" comment",
+            json["source_line"]
+        );
+
+        // without a location, the corresponding fields are null
+        let diagnostic =
+            Diagnostic::from_assembly_error(&AssemblyError::division_by_zero(), SOURCE);
+        let json: serde_json::Value =
+            serde_json::from_str(&JsonRenderer.render(&diagnostic)).unwrap();
+        assert_eq!("division_by_zero", json["kind"]);
+        assert_eq!(diagnostic.message(), json["message"]);
+        assert!(json["location"].is_null());
+        assert!(json["source_line"].is_null());
+    }
+}
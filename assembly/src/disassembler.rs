@@ -0,0 +1,346 @@
+use super::{
+    parsers::{Instruction, Node},
+    BTreeMap, DisassemblyError, ProcedureAst, ProgramAst, Vec, ONE, ZERO,
+};
+use vm_core::{
+    chiplets::hasher::Digest,
+    code_blocks::{CodeBlock, Span},
+    AdviceInjector, Decorator, Operation, Program, StarkField,
+};
+
+// DISASSEMBLER
+// ================================================================================================
+
+/// Returns the AST of a program whose source compiles into the MAST of the specified program.
+///
+/// The source of the program can be obtained by writing the returned AST via its [Display]
+/// implementation. Control flow blocks are written as `if.true` and `while.true` blocks, and
+/// procedures invoked via `call` instructions are written as local procedures of the program
+/// (named `proc_0`, `proc_1`, etc.). Procedures invoked via `exec` instructions are inlined into
+/// the MAST, and thus, their bodies are written in place of the invocations. Operations are
+/// written as the assembly instructions which compile into them; debug info is not preserved.
+///
+/// Compiling the disassembled source results in a program with the same MAST root as long as the
+/// program does not inline via `exec` instructions procedures which consist of more than a single
+/// span block: the operations of such procedures are merged with the surrounding operations.
+///
+/// # Errors
+/// Returns an error if the program contains:
+/// - An operation which cannot be produced by any assembly instruction on its own (e.g., the
+///   operations which manage procedure locals).
+/// - An advice injector which is not produced by any assembly instruction on its own.
+/// - A `syscall` block, a proxy block, or a block without any operations.
+/// - A `call` block whose target is not present in the code block table of the program.
+///
+/// [Display]: core::fmt::Display
+pub fn disassemble(program: &Program) -> Result<ProgramAst, DisassemblyError> {
+    let mut disassembler = Disassembler::new(program);
+    let body = disassembler.block_nodes(program.root())?;
+    let ast = ProgramAst::new(disassembler.procs, body.into());
+    Ok(match program.input_spec() {
+        Some(input_spec) => ast.with_input_spec(*input_spec),
+        None => ast,
+    })
+}
+
+/// Keeps track of the procedures invoked via `call` blocks while the program is disassembled.
+struct Disassembler<'a> {
+    program: &'a Program,
+    procs: Vec<ProcedureAst>,
+    proc_indexes: BTreeMap<[u8; 32], u16>,
+}
+
+impl<'a> Disassembler<'a> {
+    fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            procs: Vec::new(),
+            proc_indexes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the nodes for the specified block; the nodes must not be empty as the assembly
+    /// syntax does not allow empty blocks.
+    ///
+    /// Join blocks are flattened into the list of nodes as the assembler joins consecutive blocks
+    /// of a body implicitly. Nested blocks are processed using an explicit stack of tasks rather
+    /// than via recursion, and thus, disassembling deeply nested blocks does not overflow the
+    /// call stack.
+    fn block_nodes(&mut self, block: &CodeBlock) -> Result<Vec<Node>, DisassemblyError> {
+        // node lists of the bodies which are being disassembled; nodes are appended to the last one
+        let mut bodies = vec![Vec::new()];
+        let mut tasks = vec![Task::Visit(block)];
+        while let Some(task) = tasks.pop() {
+            match task {
+                Task::Visit(block) => self.visit_block(block, &mut bodies, &mut tasks)?,
+                Task::Open => bodies.push(Vec::new()),
+                Task::CloseIf => {
+                    let f_branch = bodies.pop().expect("no false branch");
+                    let t_branch = non_empty(bodies.pop().expect("no true branch"))?;
+                    current_body(&mut bodies).push(Node::IfElse(t_branch.into(), f_branch.into()));
+                }
+                Task::CloseWhile => {
+                    let body = non_empty(bodies.pop().expect("no loop body"))?;
+                    current_body(&mut bodies).push(Node::While(body.into()));
+                }
+            }
+        }
+        non_empty(bodies.pop().expect("no body"))
+    }
+
+    /// Appends the nodes for the specified block to the current body, or schedules the tasks
+    /// which disassemble the children of the block.
+    fn visit_block<'b>(
+        &mut self,
+        block: &'b CodeBlock,
+        bodies: &mut [Vec<Node>],
+        tasks: &mut Vec<Task<'b>>,
+    ) -> Result<(), DisassemblyError> {
+        // tasks are pushed in reverse order so that they are executed in the execution order
+        match block {
+            CodeBlock::Span(span) => append_span_nodes(span, current_body(bodies))?,
+            CodeBlock::Join(join) => {
+                tasks.push(Task::Visit(join.second()));
+                tasks.push(Task::Visit(join.first()));
+            }
+            CodeBlock::Split(split) => {
+                // an `if.true` block without an `else` branch is compiled into a split block with
+                // a NOOP span as the false branch, and thus, the false branch may be empty
+                tasks.push(Task::CloseIf);
+                tasks.push(Task::Visit(split.on_false()));
+                tasks.push(Task::Open);
+                tasks.push(Task::Visit(split.on_true()));
+                tasks.push(Task::Open);
+            }
+            CodeBlock::Loop(block) => {
+                tasks.push(Task::CloseWhile);
+                tasks.push(Task::Visit(block.body()));
+                tasks.push(Task::Open);
+            }
+            CodeBlock::Call(block) if block.is_syscall() => {
+                return Err(DisassemblyError::UnsupportedBlock("syscall"));
+            }
+            CodeBlock::Call(block) => {
+                let proc_idx = self.proc_index(block.fn_hash())?;
+                current_body(bodies).push(Node::Instruction(Instruction::CallLocal(proc_idx)));
+            }
+            CodeBlock::Proxy(_) => return Err(DisassemblyError::UnsupportedBlock("proxy")),
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the local procedure for the code block with the specified hash,
+    /// disassembling the code block into a new procedure if this has not been done yet.
+    fn proc_index(&mut self, hash: Digest) -> Result<u16, DisassemblyError> {
+        let key: [u8; 32] = hash.into();
+        if let Some(&proc_idx) = self.proc_indexes.get(&key) {
+            return Ok(proc_idx);
+        }
+
+        let program = self.program;
+        let block = program
+            .cb_table()
+            .get(hash)
+            .ok_or(DisassemblyError::CallTargetNotFound(hash))?;
+
+        // procedures called from the body are added before the procedure itself as procedures
+        // must be declared before they are invoked
+        let body = self.block_nodes(block)?;
+        let proc_idx = self.procs.len() as u16;
+        self.procs.push(ProcedureAst {
+            name: format!("proc_{proc_idx}"),
+            docs: None,
+            num_locals: 0,
+            body: body.into(),
+            is_export: false,
+        });
+        self.proc_indexes.insert(key, proc_idx);
+        Ok(proc_idx)
+    }
+}
+
+/// A step of disassembling a code block (see [Disassembler::block_nodes()]).
+enum Task<'a> {
+    /// Appends the nodes for a block to the current body.
+    Visit(&'a CodeBlock),
+    /// Starts a new body, which becomes the current body.
+    Open,
+    /// Completes an `if.true` block from the last two bodies.
+    CloseIf,
+    /// Completes a `while.true` block from the last body.
+    CloseWhile,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the body which nodes are currently appended to.
+fn current_body(bodies: &mut [Vec<Node>]) -> &mut Vec<Node> {
+    bodies.last_mut().expect("no current body")
+}
+
+/// Returns the specified nodes, or an error if there are none as the assembly syntax does not
+/// allow empty blocks.
+fn non_empty(nodes: Vec<Node>) -> Result<Vec<Node>, DisassemblyError> {
+    if nodes.is_empty() {
+        return Err(DisassemblyError::UnsupportedBlock("empty"));
+    }
+    Ok(nodes)
+}
+
+/// Appends the instructions for the operations and advice injectors of the specified span to the
+/// provided list.
+fn append_span_nodes(span: &Span, nodes: &mut Vec<Node>) -> Result<(), DisassemblyError> {
+    let ops = span
+        .op_batches()
+        .iter()
+        .flat_map(|batch| batch.ops())
+        .copied()
+        .collect::<Vec<_>>();
+
+    // a span consisting of a single NOOP is emitted by the assembler for an empty `else` branch
+    let ops: &[Operation] = if ops == [Operation::Noop] {
+        &[]
+    } else {
+        &ops[..]
+    };
+
+    let mut decorators = span.decorators().iter().peekable();
+    let mut op_idx = 0;
+    let mut err_code = None;
+    loop {
+        // advice injectors are written before the operation at which they are executed; error
+        // codes are attached to the next assertion in the same way as the VM does
+        while let Some((_, decorator)) = decorators.next_if(|(idx, _)| *idx <= op_idx) {
+            if let Decorator::ErrorCode(code) = decorator {
+                err_code = Some(*code);
+            } else if let Some(instruction) = decorator_instruction(decorator)? {
+                nodes.push(Node::Instruction(instruction));
+            }
+        }
+        if op_idx == ops.len() {
+            break;
+        }
+
+        let (instruction, num_ops) = op_instruction(&ops[op_idx..])?;
+        let instruction = match (instruction, err_code) {
+            (Instruction::Assert, Some(code)) => {
+                err_code = None;
+                Instruction::AssertWithError(code)
+            }
+            (instruction, _) => instruction,
+        };
+        nodes.push(Node::Instruction(instruction));
+        op_idx += num_ops;
+    }
+
+    Ok(())
+}
+
+/// Returns the instruction which compiles into the operations at the start of the specified
+/// list, together with the number of these operations.
+fn op_instruction(ops: &[Operation]) -> Result<(Instruction, usize), DisassemblyError> {
+    use Operation::*;
+
+    // instructions which compile into a pair of operations
+    let pair = match ops {
+        [Pad, Incr, ..] => Some(Instruction::PushConstants(vec![ONE])),
+        [MStore, Drop, ..] => Some(Instruction::MemStore),
+        [MStream, RpPerm, ..] => Some(Instruction::MemStream),
+        [Pipe, RpPerm, ..] => Some(Instruction::AdvPipe),
+        _ => None,
+    };
+    if let Some(instruction) = pair {
+        return Ok((instruction, 2));
+    }
+
+    let instruction = match ops[0] {
+        Assert => Instruction::Assert,
+        SDepth => Instruction::Sdepth,
+        Add => Instruction::Add,
+        Neg => Instruction::Neg,
+        Mul => Instruction::Mul,
+        Inv => Instruction::Inv,
+        Incr => Instruction::AddImm(ONE),
+        And => Instruction::And,
+        Or => Instruction::Or,
+        Not => Instruction::Not,
+        Eq => Instruction::Eq,
+        Eqz => Instruction::EqImm(ZERO),
+        U32split => Instruction::U32Split,
+        U32add => Instruction::U32OverflowingAdd,
+        U32assert2 => Instruction::U32Assert2,
+        U32add3 => Instruction::U32OverflowingAdd3,
+        U32sub => Instruction::U32OverflowingSub,
+        U32mul => Instruction::U32OverflowingMul,
+        U32madd => Instruction::U32OverflowingMadd,
+        U32div => Instruction::U32UncheckedDivMod,
+        U32and => Instruction::U32CheckedAnd,
+        U32xor => Instruction::U32CheckedXor,
+        Pad => Instruction::PushConstants(vec![ZERO]),
+        Drop => Instruction::Drop,
+        Dup0 => Instruction::Dup0,
+        Dup1 => Instruction::Dup1,
+        Dup2 => Instruction::Dup2,
+        Dup3 => Instruction::Dup3,
+        Dup4 => Instruction::Dup4,
+        Dup5 => Instruction::Dup5,
+        Dup6 => Instruction::Dup6,
+        Dup7 => Instruction::Dup7,
+        Dup9 => Instruction::Dup9,
+        Dup11 => Instruction::Dup11,
+        Dup13 => Instruction::Dup13,
+        Dup15 => Instruction::Dup15,
+        Swap => Instruction::Swap1,
+        SwapW => Instruction::SwapW1,
+        SwapW2 => Instruction::SwapW2,
+        SwapW3 => Instruction::SwapW3,
+        SwapDW => Instruction::SwapDw,
+        MovUp2 => Instruction::MovUp2,
+        MovUp3 => Instruction::MovUp3,
+        MovUp4 => Instruction::MovUp4,
+        MovUp5 => Instruction::MovUp5,
+        MovUp6 => Instruction::MovUp6,
+        MovUp7 => Instruction::MovUp7,
+        MovUp8 => Instruction::MovUp8,
+        MovDn2 => Instruction::MovDn2,
+        MovDn3 => Instruction::MovDn3,
+        MovDn4 => Instruction::MovDn4,
+        MovDn5 => Instruction::MovDn5,
+        MovDn6 => Instruction::MovDn6,
+        MovDn7 => Instruction::MovDn7,
+        MovDn8 => Instruction::MovDn8,
+        CSwap => Instruction::CSwap,
+        CSwapW => Instruction::CSwapW,
+        // the assembler replaces pushes of 0 and 1 with PAD and PAD INCR respectively
+        Push(value) if value.as_int() > 1 => Instruction::PushConstants(vec![value]),
+        Read => Instruction::AdvPush(1),
+        ReadW => Instruction::AdvLoadW,
+        MLoadW => Instruction::MemLoadW,
+        MStoreW => Instruction::MemStoreW,
+        MLoad => Instruction::MemLoad,
+        RpPerm => Instruction::RpPerm,
+        op => return Err(DisassemblyError::UnsupportedOperation(op)),
+    };
+    Ok((instruction, 1))
+}
+
+/// Returns the instruction which compiles into the specified decorator, or None if the decorator
+/// carries only debug info or is attached to an instruction of an operation (i.e., error codes).
+fn decorator_instruction(decorator: &Decorator) -> Result<Option<Instruction>, DisassemblyError> {
+    let instruction = match decorator {
+        Decorator::AsmOp(_) | Decorator::ErrorCode(_) => return Ok(None),
+        Decorator::Advice(AdviceInjector::DivResultU64) => Instruction::AdvU64Div,
+        Decorator::Advice(AdviceInjector::DivResultU256) => Instruction::AdvU256Div,
+        Decorator::Advice(AdviceInjector::MapValue) => Instruction::AdvKeyval,
+        Decorator::Advice(AdviceInjector::Memory(start_addr, num_words)) => {
+            Instruction::AdvMem(*start_addr, *num_words)
+        }
+        Decorator::Advice(injector) => {
+            return Err(DisassemblyError::UnsupportedDecorator(format!(
+                "{injector}"
+            )))
+        }
+    };
+    Ok(Some(instruction))
+}
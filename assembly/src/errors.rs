@@ -1,5 +1,9 @@
-use super::{ProcedureId, String, ToString, Token, Vec};
+use super::{
+    Box, Operation, ProcedureId, SourceLocation, String, ToString, Token, Vec, MAX_PUSH_INPUTS,
+    MAX_REEXPORTED_PROCS,
+};
 use core::fmt;
+use vm_core::{chiplets::hasher::Digest, felt_fmt::format_digest};
 
 // ASSEMBLY ERROR
 // ================================================================================================
@@ -9,15 +13,25 @@ pub enum AssemblyError {
     CallInKernel(String),
     CallerOutOKernel,
     CircularModuleDependency(Vec<String>),
+    CircularReExport(Vec<String>),
+    ConflictingLibraryProc(ProcedureId, String),
+    ConflictingModuleProviders(ProcedureId, String),
     DivisionByZero,
+    DuplicateModulePath(String),
     DuplicateProcName(String, String),
     ExportedProcInProgram(String),
     ImportedProcModuleNotFound(ProcedureId),
     ImportedProcNotFoundInModule(ProcedureId, String),
+    InvalidModule(String, String),
     KernelProcNotFound(ProcedureId),
+    LocalIndexOutOfBounds(u16, u16),
     LocalProcNotFound(u16, String),
+    Located(SourceLocation, Box<AssemblyError>),
+    MismatchedBranchStackEffects(isize, isize),
     ParsingError(String),
     ParamOutOfBounds(u64, u64, u64),
+    RepeatCountTooLarge(usize, usize),
+    ReservedModulePath(String),
     SysCallInKernel(String),
 }
 
@@ -37,10 +51,18 @@ impl AssemblyError {
         Self::CircularModuleDependency(dep_chain.to_vec())
     }
 
+    pub fn circular_reexport(module_chain: &[String]) -> Self {
+        Self::CircularReExport(module_chain.to_vec())
+    }
+
     pub fn division_by_zero() -> Self {
         Self::DivisionByZero
     }
 
+    pub fn duplicate_module_path(module_path: &str) -> Self {
+        Self::DuplicateModulePath(module_path.to_string())
+    }
+
     pub fn duplicate_proc_name(proc_name: &str, module_path: &str) -> Self {
         Self::DuplicateProcName(proc_name.to_string(), module_path.to_string())
     }
@@ -49,6 +71,14 @@ impl AssemblyError {
         Self::ExportedProcInProgram(proc_name.to_string())
     }
 
+    pub fn conflicting_library_proc(proc_id: &ProcedureId, library_path: &str) -> Self {
+        Self::ConflictingLibraryProc(*proc_id, library_path.to_string())
+    }
+
+    pub fn conflicting_module_providers(proc_id: &ProcedureId, module_path: &str) -> Self {
+        Self::ConflictingModuleProviders(*proc_id, module_path.to_string())
+    }
+
     pub fn imported_proc_module_not_found(proc_id: &ProcedureId) -> Self {
         Self::ImportedProcModuleNotFound(*proc_id)
     }
@@ -57,26 +87,109 @@ impl AssemblyError {
         Self::ImportedProcNotFoundInModule(*proc_id, module_path.to_string())
     }
 
+    pub fn invalid_module(module_path: &str, reason: &str) -> Self {
+        Self::InvalidModule(module_path.to_string(), reason.to_string())
+    }
+
     pub fn kernel_proc_not_found(kernel_proc_id: &ProcedureId) -> Self {
         Self::KernelProcNotFound(*kernel_proc_id)
     }
 
+    pub fn local_index_out_of_bounds(index: u16, num_locals: u16) -> Self {
+        Self::LocalIndexOutOfBounds(index, num_locals)
+    }
+
     pub fn local_proc_not_found(proc_idx: u16, module_path: &str) -> Self {
         Self::LocalProcNotFound(proc_idx, module_path.to_string())
     }
 
+    pub fn mismatched_branch_stack_effects(t_effect: isize, f_effect: isize) -> Self {
+        Self::MismatchedBranchStackEffects(t_effect, f_effect)
+    }
+
     pub fn param_out_of_bounds(value: u64, min: u64, max: u64) -> Self {
         Self::ParamOutOfBounds(value, min, max)
     }
 
+    pub fn repeat_count_too_large(count: usize, max_count: usize) -> Self {
+        Self::RepeatCountTooLarge(count, max_count)
+    }
+
+    pub fn reserved_module_path(module_path: &str) -> Self {
+        Self::ReservedModulePath(module_path.to_string())
+    }
+
     pub fn syscall_in_kernel(kernel_proc_name: &str) -> Self {
         Self::SysCallInKernel(kernel_proc_name.to_string())
     }
+
+    /// Returns this error attached to the specified location in the source code. If the error
+    /// already has a location, the error is returned unchanged.
+    pub fn with_location(self, location: SourceLocation) -> Self {
+        match self {
+            Self::Located(..) => self,
+            err => Self::Located(location, Box::new(err)),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the location in the source code at which this error occurred, if it is known.
+    pub fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::Located(location, _) => Some(*location),
+            _ => None,
+        }
+    }
+
+    /// Returns this error without its location.
+    pub fn unlocated(&self) -> &Self {
+        match self {
+            Self::Located(_, err) => err,
+            err => err,
+        }
+    }
+
+    /// Returns the name of the kind of this error (e.g., `repeat_count_too_large`); the name
+    /// identifies the variant of the error regardless of its location and parameters, and thus,
+    /// tools can rely on it instead of the error message.
+    pub fn kind(&self) -> &'static str {
+        use AssemblyError::*;
+        match self.unlocated() {
+            CallInKernel(..) => "call_in_kernel",
+            CallerOutOKernel => "caller_out_of_kernel",
+            CircularModuleDependency(..) => "circular_module_dependency",
+            CircularReExport(..) => "circular_reexport",
+            ConflictingLibraryProc(..) => "conflicting_library_proc",
+            ConflictingModuleProviders(..) => "conflicting_module_providers",
+            DivisionByZero => "division_by_zero",
+            DuplicateModulePath(..) => "duplicate_module_path",
+            DuplicateProcName(..) => "duplicate_proc_name",
+            ExportedProcInProgram(..) => "exported_proc_in_program",
+            ImportedProcModuleNotFound(..) => "imported_proc_module_not_found",
+            ImportedProcNotFoundInModule(..) => "imported_proc_not_found_in_module",
+            InvalidModule(..) => "invalid_module",
+            KernelProcNotFound(..) => "kernel_proc_not_found",
+            LocalIndexOutOfBounds(..) => "local_index_out_of_bounds",
+            LocalProcNotFound(..) => "local_proc_not_found",
+            Located(_, err) => err.kind(),
+            MismatchedBranchStackEffects(..) => "mismatched_branch_stack_effects",
+            ParsingError(..) => "parsing_error",
+            ParamOutOfBounds(..) => "param_out_of_bounds",
+            RepeatCountTooLarge(..) => "repeat_count_too_large",
+            ReservedModulePath(..) => "reserved_module_path",
+            SysCallInKernel(..) => "syscall_in_kernel",
+        }
+    }
 }
 
 impl From<ParsingError> for AssemblyError {
     fn from(err: ParsingError) -> Self {
-        Self::ParsingError(err.message)
+        match err.location {
+            Some(location) => Self::ParsingError(err.message).with_location(location),
+            None => Self::ParsingError(err.message),
+        }
     }
 }
 
@@ -86,16 +199,26 @@ impl fmt::Display for AssemblyError {
         match self {
             CallInKernel(proc_name) => write!(f, "call instruction used kernel procedure '{proc_name}'"),
             CallerOutOKernel => write!(f, "caller instruction used outside of kernel"),
-            CircularModuleDependency(dep_chain) => write!(f, "circular module dependency in the following chain: {dep_chain:?}"),
+            CircularModuleDependency(dep_chain) => write!(f, "circular module dependency: {}", dep_chain.join(" -> ")),
+            CircularReExport(module_chain) => write!(f, "circular re-export: {}", module_chain.join(" -> ")),
+            ConflictingLibraryProc(proc_id, library_path) => write!(f, "procedure {proc_id} of library {library_path} has already been loaded into the assembler"),
+            ConflictingModuleProviders(proc_id, module_path) => write!(f, "procedure {proc_id} of module {module_path} is provided by more than one module provider"),
             DivisionByZero => write!(f, "division by zero"),
+            DuplicateModulePath(module_path) => write!(f, "module {module_path} has already been loaded"),
             DuplicateProcName(proc_name, module_path) => write!(f, "duplicate proc name '{proc_name}' in module {module_path}"),
             ExportedProcInProgram(proc_name) => write!(f, "exported procedure '{proc_name}' in executable program"),
             ImportedProcModuleNotFound(proc_id) => write!(f, "module for imported procedure {proc_id} not found"),
             ImportedProcNotFoundInModule(proc_id, module_path) => write!(f, "imported procedure {proc_id} not found in module {module_path}"),
+            InvalidModule(module_path, reason) => write!(f, "module {module_path} is invalid: {reason}"),
             KernelProcNotFound(proc_id) => write!(f, "procedure {proc_id} not found in kernel"),
+            LocalIndexOutOfBounds(index, num_locals) => write!(f, "local index {index} is out of bounds: the procedure declares {num_locals} locals"),
             LocalProcNotFound(proc_idx, module_path) => write!(f, "procedure at index {proc_idx} not found in module {module_path}"),
+            Located(location, err) => write!(f, "{err} at line {}, column {}", location.line, location.column),
+            MismatchedBranchStackEffects(t_effect, f_effect) => write!(f, "if/else branches have mismatched stack effects ({} vs {})", SignedEffect(*t_effect), SignedEffect(*f_effect)),
             ParsingError(err) => write!(f, "{err}"),
             ParamOutOfBounds(value, min, max) => write!(f, "parameter value must be greater than or equal to {min} and less than or equal to {max}, but was {value}"),
+            RepeatCountTooLarge(count, max_count) => write!(f, "repeat count {count} exceeds maximum {max_count}"),
+            ReservedModulePath(module_path) => write!(f, "module path {module_path} is reserved"),
             SysCallInKernel(proc_name) => write!(f, "syscall instruction used in kernel procedure '{proc_name}'"),
         }
     }
@@ -104,6 +227,19 @@ impl fmt::Display for AssemblyError {
 #[cfg(feature = "std")]
 impl std::error::Error for AssemblyError {}
 
+/// Formats a change in stack depth with an explicit sign (e.g., "+1", "0", "-2").
+struct SignedEffect(isize);
+
+impl fmt::Display for SignedEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 > 0 {
+            write!(f, "+{}", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
 // PARSING ERROR
 // ================================================================================================
 
@@ -112,6 +248,7 @@ pub struct ParsingError {
     message: String,
     step: usize,
     op: String,
+    location: Option<SourceLocation>,
 }
 
 impl ParsingError {
@@ -119,243 +256,242 @@ impl ParsingError {
     // --------------------------------------------------------------------------------------------
 
     pub fn empty_source() -> Self {
-        ParsingError {
-            message: "source code cannot be an empty string".to_string(),
-            step: 0,
-            op: "".to_string(),
-        }
+        Self::at_step("source code cannot be an empty string".to_string(), 0)
     }
 
     pub fn unexpected_eof(step: usize) -> Self {
-        ParsingError {
-            message: "unexpected EOF".to_string(),
-            step,
-            op: "".to_string(),
-        }
+        Self::at_step("unexpected EOF".to_string(), step)
     }
 
     pub fn unexpected_token(token: &Token, expected: &str) -> Self {
-        ParsingError {
-            message: format!("unexpected token: expected '{expected}' but was '{token}'"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            format!("unexpected token: expected '{expected}' but was '{token}'"),
+            token,
+        )
     }
 
     pub fn unexpected_body_end(token: &Token) -> Self {
-        ParsingError {
-            message: format!("unexpected body termination: invalid token '{token}'"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            format!("unexpected body termination: invalid token '{token}'"),
+            token,
+        )
     }
 
     pub fn empty_block(token: &Token) -> Self {
-        ParsingError {
-            message: "a code block must contain at least one instruction".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            "a code block must contain at least one instruction".to_string(),
+            token,
+        )
     }
 
     pub fn invalid_op(token: &Token) -> Self {
-        ParsingError {
-            message: format!("instruction '{token}' is invalid"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(format!("instruction '{token}' is invalid"), token)
+    }
+
+    /// Returns an error for an instruction with an unknown name; if a suggestion is provided, the
+    /// error message proposes the suggested instruction name.
+    pub fn unknown_op(token: &Token, suggestion: Option<&str>) -> Self {
+        let message = match suggestion {
+            Some(name) => format!("instruction '{token}' is invalid; did you mean '{name}'?"),
+            None => format!("instruction '{token}' is invalid"),
+        };
+        Self::at_token(message, token)
     }
 
     /// TODO: currently unused
     pub fn invalid_op_with_reason(token: &Token, reason: &str) -> Self {
-        ParsingError {
-            message: format!("instruction '{token}' is invalid: {reason}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(format!("instruction '{token}' is invalid: {reason}"), token)
     }
 
     pub fn missing_param(token: &Token) -> Self {
-        ParsingError {
-            message: format!("malformed instruction '{token}': missing required parameter"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            format!("malformed instruction '{token}': missing required parameter"),
+            token,
+        )
     }
 
     pub fn extra_param(token: &Token) -> Self {
-        ParsingError {
-            message: format!("malformed instruction '{token}': too many parameters provided"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            format!("malformed instruction '{token}': too many parameters provided"),
+            token,
+        )
+    }
+
+    pub fn too_many_push_values(token: &Token, num_values: usize) -> Self {
+        Self::at_token(
+            format!(
+                "malformed instruction '{token}': too many parameters provided; push accepts at \
+                most {MAX_PUSH_INPUTS} values, but {num_values} were provided"
+            ),
+            token,
+        )
     }
 
     pub fn invalid_param(token: &Token, part_idx: usize) -> Self {
-        ParsingError {
-            message: format!(
+        Self::at_token(
+            format!(
                 "malformed instruction `{token}`: parameter '{}' is invalid",
                 token.parts()[part_idx]
             ),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+            token,
+        )
     }
 
     pub fn invalid_param_with_reason(token: &Token, part_idx: usize, reason: &str) -> Self {
-        ParsingError {
-            message: format!(
+        Self::at_token(
+            format!(
                 "malformed instruction '{token}', parameter {} is invalid: {reason}",
                 token.parts()[part_idx],
             ),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+            token,
+        )
+    }
+
+    pub fn invalid_hex_immediate(token: &Token, part_idx: usize) -> Self {
+        Self::at_token(
+            format!(
+                "malformed instruction `{token}`: invalid hex immediate '{}'",
+                token.parts()[part_idx]
+            ),
+            token,
+        )
     }
 
     pub fn dangling_else(token: &Token) -> Self {
-        ParsingError {
-            message: "else without matching if".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("else without matching if".to_string(), token)
     }
 
     pub fn unmatched_if(token: &Token) -> Self {
-        ParsingError {
-            message: "if without matching else/end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("if without matching else/end".to_string(), token)
     }
 
     pub fn unmatched_while(token: &Token) -> Self {
-        ParsingError {
-            message: "while without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("while without matching end".to_string(), token)
     }
 
     pub fn unmatched_repeat(token: &Token) -> Self {
-        ParsingError {
-            message: "repeat without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("repeat without matching end".to_string(), token)
     }
 
     pub fn unmatched_else(token: &Token) -> Self {
-        ParsingError {
-            message: "else without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("else without matching end".to_string(), token)
     }
 
-    pub fn malformed_doc_comment(step: usize) -> Self {
+    /// Returns an error for a block comment which is not closed until the end of the source; the
+    /// error is located at the start of the comment.
+    pub fn unterminated_block_comment(step: usize, location: SourceLocation) -> Self {
         ParsingError {
-            message: "doc comments separated by line break".to_string(),
+            message: "unterminated block comment".to_string(),
             step,
             op: "".to_string(),
+            location: Some(location),
         }
     }
 
+    pub fn malformed_doc_comment(step: usize) -> Self {
+        Self::at_step("doc comments separated by line break".to_string(), step)
+    }
+
     // PROGRAM
     // --------------------------------------------------------------------------------------------
 
     pub fn unmatched_begin(token: &Token) -> Self {
-        ParsingError {
-            message: "begin without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("begin without matching end".to_string(), token)
     }
 
     pub fn dangling_ops_after_program(token: &Token) -> Self {
-        ParsingError {
-            message: "dangling instructions after program end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("dangling instructions after program end".to_string(), token)
     }
 
     // PROCEDURES
     // --------------------------------------------------------------------------------------------
 
+    pub fn too_many_reexports(token: &Token) -> Self {
+        Self::at_token(
+            format!("number of re-exported procedures exceeds maximum of {MAX_REEXPORTED_PROCS}"),
+            token,
+        )
+    }
+
     pub fn duplicate_proc_label(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("duplicate procedure label: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(format!("duplicate procedure label: {label}"), token)
+    }
+
+    pub fn invalid_inputs_annotation(token: &Token, annotation: &str, reason: &str) -> Self {
+        Self::at_token(
+            format!("invalid inputs annotation '{annotation}': {reason}"),
+            token,
+        )
+    }
+
+    pub fn prelude_proc_label(token: &Token, label: &str) -> Self {
+        Self::at_token(
+            format!("procedure label {label} collides with a prelude procedure"),
+            token,
+        )
     }
 
     pub fn invalid_proc_label(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("invalid procedure label: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(format!("invalid procedure label: {label}"), token)
     }
 
     pub fn invalid_proc_locals(token: &Token, locals: &str) -> Self {
-        ParsingError {
-            message: format!("invalid procedure locals: {locals}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(format!("invalid procedure locals: {locals}"), token)
+    }
+
+    pub fn too_many_proc_locals(token: &Token, label: &str, locals: &str) -> Self {
+        Self::at_token(format!(
+                "number of procedure locals ({locals}) exceeds maximum of {} in procedure '{label}'",
+                u16::MAX
+            ), token)
     }
 
     pub fn unmatched_proc(token: &Token) -> Self {
-        ParsingError {
-            message: "proc without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("proc without matching end".to_string(), token)
     }
 
     pub fn undefined_proc(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("undefined procedure: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(format!("undefined procedure: {label}"), token)
     }
 
     pub fn proc_export_not_allowed(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("exported procedures not allowed in this context: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            format!("exported procedures not allowed in this context: {label}"),
+            token,
+        )
     }
 
     /// TODO: currently unused
     pub fn syscall_in_kernel(token: &Token) -> Self {
-        ParsingError {
-            message: "syscall inside kernel".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("syscall inside kernel".to_string(), token)
     }
 
     /// TODO: currently unused
     pub fn call_in_kernel(token: &Token) -> Self {
-        ParsingError {
-            message: "call inside kernel".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token("call inside kernel".to_string(), token)
     }
 
     /// TODO: currently unused
     pub fn caller_out_of_kernel(token: &Token) -> Self {
-        ParsingError {
-            message: "caller instruction executed outside of kernel context".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            "caller instruction executed outside of kernel context".to_string(),
+            token,
+        )
+    }
+
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    pub fn duplicate_const_name(token: &Token, name: &str) -> Self {
+        Self::at_token(format!("duplicate constant name: {name}"), token)
+    }
+
+    pub fn invalid_const_name(token: &Token, name: &str) -> Self {
+        Self::at_token(format!("invalid constant name: {name}"), token)
+    }
+
+    pub fn undefined_const(token: &Token, name: &str) -> Self {
+        Self::at_token(format!("undefined constant: {name}"), token)
     }
 
     // IMPORTS AND MODULES
@@ -363,26 +499,62 @@ impl ParsingError {
 
     /// TODO: currently unused
     pub fn dangling_ops_after_module(token: &Token, module_path: &str) -> Self {
-        ParsingError {
-            message: format!("dangling instructions after module end at {module_path}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::at_token(
+            format!("dangling instructions after module end at {module_path}"),
+            token,
+        )
+    }
+
+    pub fn duplicate_import_alias(token: &Token, alias: &str) -> Self {
+        Self::at_token(
+            format!("duplicate module alias: {alias} is already used by another import"),
+            token,
+        )
     }
 
     pub fn duplicate_module_import(token: &Token, module: &str) -> Self {
+        Self::at_token(format!("duplicate module import found: {module}"), token)
+    }
+
+    pub fn invalid_module_alias(token: &Token, alias: &str) -> Self {
+        Self::at_token(format!("invalid module alias: {alias}"), token)
+    }
+
+    pub fn invalid_module_path(token: &Token, module_path: &str) -> Self {
+        Self::at_token(format!("invalid module import path: {module_path}"), token)
+    }
+
+    pub fn module_imported_under_alias(token: &Token, module: &str, alias: &str) -> Self {
+        Self::at_token(
+            format!("module {module} is imported under alias {alias}; use the alias instead"),
+            token,
+        )
+    }
+
+    pub fn module_not_imported(token: &Token, module: &str) -> Self {
+        Self::at_token(format!("module not imported: {module}"), token)
+    }
+
+    /// Returns an error with the specified message which occurred at the specified token; the
+    /// error is located at the location of the token in the source code.
+    fn at_token(message: String, token: &Token) -> Self {
         ParsingError {
-            message: format!("duplicate module import found: {module}"),
+            message,
             step: token.pos(),
             op: token.to_string(),
+            location: Some(token.location()),
         }
     }
 
-    pub fn invalid_module_path(token: &Token, module_path: &str) -> Self {
+    /// Returns an error with the specified message which occurred at the specified position of
+    /// the token stream but not at a specific token (e.g., at the end of the source); such errors
+    /// have no location in the source code.
+    fn at_step(message: String, step: usize) -> Self {
         ParsingError {
-            message: format!("invalid module import path: {module_path}"),
-            step: token.pos(),
-            op: token.to_string(),
+            message,
+            step,
+            op: "".to_string(),
+            location: None,
         }
     }
 
@@ -392,6 +564,12 @@ impl ParsingError {
         &self.message
     }
 
+    /// Returns the location in the source code of the token at which this error occurred, or None
+    /// if the error did not occur at a token (e.g., at the end of the source).
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.location
+    }
+
     pub fn operation(&self) -> &String {
         &self.op
     }
@@ -426,6 +604,8 @@ pub enum SerializationError {
     EndOfReader,
     InvalidOpCode,
     InvalidFieldElement,
+    InvalidInputSpec,
+    UnsupportedVersion(u8),
 }
 
 // LIBRARY ERROR
@@ -434,6 +614,34 @@ pub enum SerializationError {
 #[derive(Clone, Debug)]
 pub enum LibraryError {
     ModuleNotFound(String),
+    InvalidNamespace(String),
+    InvalidModulePath(String),
+    NamespaceViolation {
+        namespace: String,
+        path: String,
+    },
+    DuplicateModulePath(String),
+    DuplicateDependency(String),
+    DuplicateNamespace(String),
+    InvalidVersion(String),
+    InvalidVersionReq(String),
+    DependencyNotFound {
+        library: String,
+        dependency: String,
+    },
+    DependencyVersionMismatch {
+        library: String,
+        dependency: String,
+        version: String,
+    },
+    ModuleDependencyNotFound {
+        module: String,
+        dependency: String,
+    },
+    DeserializationError(String),
+    SerializationError(String),
+    FileError(String, String),
+    ModuleParsingError(String, String),
 }
 
 impl fmt::Display for LibraryError {
@@ -441,9 +649,85 @@ impl fmt::Display for LibraryError {
         use LibraryError::*;
         match self {
             ModuleNotFound(path) => write!(f, "module '{path}' not found"),
+            InvalidNamespace(namespace) => write!(f, "invalid library namespace '{namespace}'"),
+            InvalidModulePath(path) => write!(f, "invalid module path '{path}'"),
+            NamespaceViolation { namespace, path } => {
+                write!(f, "module '{path}' is not located under namespace '{namespace}'")
+            }
+            DuplicateModulePath(path) => write!(f, "duplicate module path '{path}'"),
+            DuplicateDependency(namespace) => {
+                write!(f, "duplicate dependency on library '{namespace}'")
+            }
+            DuplicateNamespace(namespace) => {
+                write!(f, "library '{namespace}' has already been registered")
+            }
+            InvalidVersion(version) => write!(f, "invalid library version '{version}'"),
+            InvalidVersionReq(req) => write!(f, "invalid version requirement '{req}'"),
+            DependencyNotFound {
+                library,
+                dependency,
+            } => write!(
+                f,
+                "library '{library}' depends on '{dependency}' which has not been registered"
+            ),
+            DependencyVersionMismatch {
+                library,
+                dependency,
+                version,
+            } => write!(
+                f,
+                "library '{library}' depends on '{dependency}' but version {version} has been registered"
+            ),
+            ModuleDependencyNotFound { module, dependency } => write!(
+                f,
+                "module '{module}' depends on module '{dependency}' which is not a part of the library"
+            ),
+            DeserializationError(err) => write!(f, "failed to deserialize library: {err}"),
+            SerializationError(err) => write!(f, "failed to serialize library: {err}"),
+            FileError(path, err) => write!(f, "failed to read library file '{path}': {err}"),
+            ModuleParsingError(path, err) => write!(f, "failed to parse module '{path}': {err}"),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for LibraryError {}
+
+// DISASSEMBLY ERROR
+// ================================================================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DisassemblyError {
+    CallTargetNotFound(Digest),
+    UnsupportedBlock(&'static str),
+    UnsupportedDecorator(String),
+    UnsupportedOperation(Operation),
+}
+
+impl fmt::Display for DisassemblyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use DisassemblyError::*;
+        match self {
+            CallTargetNotFound(hash) => {
+                write!(
+                    f,
+                    "code block 0x{} of a call target not found",
+                    format_digest(hash)
+                )
+            }
+            UnsupportedBlock(kind) => write!(f, "{kind} block cannot be disassembled"),
+            UnsupportedDecorator(decorator) => {
+                write!(
+                    f,
+                    "decorator '{decorator}' has no equivalent assembly instruction"
+                )
+            }
+            UnsupportedOperation(op) => {
+                write!(f, "operation '{op}' has no equivalent assembly instruction")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DisassemblyError {}
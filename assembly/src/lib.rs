@@ -4,33 +4,54 @@
 #[macro_use]
 extern crate alloc;
 
+use core::{future::Future, pin::Pin};
 use vm_core::{
     code_blocks::CodeBlock,
     utils::{
         collections::{BTreeMap, BTreeSet, Vec},
         string::{String, ToString},
-        Box,
+        Arc, Box,
     },
-    CodeBlockTable, Felt, Kernel, Operation, Program, StarkField, ONE, ZERO,
+    CodeBlockTable, Felt, InputSpec, Kernel, Operation, Program, StarkField, ONE, ZERO,
 };
 
 mod procedures;
-pub use procedures::ProcedureId;
-use procedures::{CallSet, Procedure};
+use procedures::{CallSet, Procedure, ProcedureMetadata};
+pub use procedures::{InlineSite, InliningTrace, ProcedureId};
 
 mod parsers;
 pub use parsers::{
-    parse_module, parse_program, ModuleAst, NamedModuleAst, ProcedureAst, ProgramAst,
+    parse_module, parse_program, ModuleAst, NamedModuleAst, ProcReExport, ProcedureAst,
+    ProcedureInfo, ProgramAst,
 };
 
 mod tokens;
-use tokens::{Token, TokenStream};
+use tokens::{is_const_name, Token, TokenStream};
 
 mod errors;
-pub use errors::{AssemblyError, LibraryError, ParsingError};
+pub use errors::{AssemblyError, DisassemblyError, LibraryError, ParsingError};
+
+mod diagnostics;
+pub use diagnostics::{
+    ColoredRenderer, Diagnostic, DiagnosticRenderer, JsonRenderer, PlainRenderer, SourceLocation,
+};
 
 mod assembler;
-pub use assembler::Assembler;
+pub use assembler::{Assembler, CompiledArtifacts, CompiledKernel};
+
+mod disassembler;
+pub use disassembler::disassemble;
+
+mod library;
+pub use library::{
+    LibraryDependency, LibraryModuleProvider, MaslLibrary, SerializableLibrary, Version,
+    VersionReq, COMPILED_LIBRARY_FILE_EXTENSION, LIBRARY_FILE_EXTENSION,
+};
+#[cfg(feature = "std")]
+pub use library::{SourceModuleProvider, MODULE_FILE_EXTENSION};
+
+#[cfg(any(test, feature = "arbitrary"))]
+pub mod arbitrary;
 
 #[cfg(test)]
 mod tests;
@@ -59,6 +80,12 @@ const MAX_EXP_BITS: u8 = 64;
 /// The maximum length of a procedure's name.
 const MAX_PROC_NAME_LEN: u8 = 100;
 
+/// The maximum number of procedures a module can re-export.
+const MAX_REEXPORTED_PROCS: usize = u16::MAX as usize;
+
+/// The default maximum number of times a body of a `repeat` block can be unrolled.
+const DEFAULT_MAX_REPEAT_COUNT: usize = 65536;
+
 // MODULE PROVIDER
 // ================================================================================================
 
@@ -67,6 +94,16 @@ const MAX_PROC_NAME_LEN: u8 = 100;
 pub trait ModuleProvider {
     /// Fetch a module AST from its ID
     fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>>;
+
+    /// Fetch a module AST from its ID, failing if the module cannot be resolved unambiguously.
+    ///
+    /// This is the method used by the assembler; by default, it never fails.
+    fn try_get_module(
+        &self,
+        id: &ProcedureId,
+    ) -> Result<Option<NamedModuleAst<'_>>, AssemblyError> {
+        Ok(self.get_module(id))
+    }
 }
 
 // A default provider that won't resolve modules
@@ -76,10 +113,153 @@ impl ModuleProvider for () {
     }
 }
 
+// A reference to a provider resolves modules via the referenced provider; this allows sharing a
+// single provider (e.g., a static instance of the standard library) between assemblers.
+impl<P: ModuleProvider + ?Sized> ModuleProvider for &P {
+    fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        (**self).get_module(id)
+    }
+
+    fn try_get_module(
+        &self,
+        id: &ProcedureId,
+    ) -> Result<Option<NamedModuleAst<'_>>, AssemblyError> {
+        (**self).try_get_module(id)
+    }
+}
+
+/// A [ModuleProvider] which combines several module providers.
+///
+/// Providers are queried in the order in which they were added to the chain, and a module is
+/// resolved by the first provider which contains it. Thus, a module of a provider added earlier
+/// shadows the module with the same path of a provider added later (e.g., a project module at
+/// `std::sys` shadows the corresponding module of the standard library).
+///
+/// In strict mode, all providers of the chain are queried instead, and resolution of a procedure
+/// which is provided by more than one provider fails with
+/// [AssemblyError::ConflictingModuleProviders].
+#[derive(Default)]
+pub struct ModuleProviderChain {
+    providers: Vec<Box<dyn ModuleProvider>>,
+    is_strict: bool,
+}
+
+impl ModuleProviderChain {
+    /// Returns a new empty [ModuleProviderChain].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the specified [ModuleProvider] to the end of this chain.
+    pub fn with_provider<P>(mut self, provider: P) -> Self
+    where
+        P: ModuleProvider + 'static,
+    {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// Puts this chain into the strict mode, in which procedures provided by more than one
+    /// provider of the chain cannot be resolved.
+    pub fn with_strict_mode(mut self, is_strict: bool) -> Self {
+        self.is_strict = is_strict;
+        self
+    }
+}
+
+impl ModuleProvider for ModuleProviderChain {
+    fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.providers
+            .iter()
+            .find_map(|provider| provider.get_module(id))
+    }
+
+    fn try_get_module(
+        &self,
+        id: &ProcedureId,
+    ) -> Result<Option<NamedModuleAst<'_>>, AssemblyError> {
+        // providers are queried via try_get_module() so that strict chains nested in this chain
+        // remain strict
+        let mut result: Option<NamedModuleAst> = None;
+        for provider in self.providers.iter() {
+            if let Some(module) = provider.try_get_module(id)? {
+                if !self.is_strict {
+                    return Ok(Some(module));
+                }
+                match result {
+                    Some(first) => {
+                        return Err(AssemblyError::conflicting_module_providers(
+                            id,
+                            first.path(),
+                        ))
+                    }
+                    None => result = Some(module),
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// A future returned by [AsyncModuleProvider]; resolves into the full path and the AST of the
+/// fetched module, or into `None` if the module could not be found.
+///
+/// The future must be [Send] so that compilation can be driven by multi-threaded executors.
+pub type ModuleFuture<'a> = Pin<Box<dyn Future<Output = Option<(String, ModuleAst)>> + Send + 'a>>;
+
+/// An asynchronous counterpart of [ModuleProvider].
+///
+/// This provider is intended for modules which are not available locally (e.g., modules which
+/// need to be fetched from a remote procedure index). It is used only by
+/// [Assembler::compile_async()], and only for modules which cannot be resolved via the sync
+/// [ModuleProvider] of the assembler.
+///
+/// Futures returned by the provider may borrow it, and thus, the provider must be [Sync].
+pub trait AsyncModuleProvider: Send + Sync {
+    /// Fetch a module containing the procedure with the specified ID.
+    fn get_module(&self, id: &ProcedureId) -> ModuleFuture<'_>;
+}
+
+// A default async provider that won't resolve modules
+impl AsyncModuleProvider for () {
+    fn get_module(&self, _id: &ProcedureId) -> ModuleFuture<'_> {
+        Box::pin(core::future::ready(None))
+    }
+}
+
+/// Describes the procedure which invokes an imported procedure resolved by the assembler; passed
+/// to the callback set via [Assembler::on_resolve()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolveContext<'a> {
+    module_path: Option<&'a str>,
+    proc_name: &'a str,
+}
+
+impl<'a> ResolveContext<'a> {
+    /// Returns a new [ResolveContext] for the specified procedure of the specified module.
+    pub(crate) fn new(module_path: Option<&'a str>, proc_name: &'a str) -> Self {
+        Self {
+            module_path,
+            proc_name,
+        }
+    }
+
+    /// Returns the full path of the module containing the invoking procedure, or None if the
+    /// procedure belongs to the program being compiled.
+    pub fn module_path(&self) -> Option<&'a str> {
+        self.module_path
+    }
+
+    /// Returns the name of the invoking procedure; for the body of a program, the name is `#main`.
+    pub fn proc_name(&self) -> &'a str {
+        self.proc_name
+    }
+}
+
 // LIBRARY
 // ================================================================================================
 
-/// TODO: add docs
+/// A collection of modules which can be listed and retrieved by their paths.
 pub trait Library {
     type Module;
 
@@ -89,9 +269,49 @@ pub trait Library {
     /// Returns the version number of this library.
     fn version(&self) -> &str;
 
+    /// Returns paths of all modules contained in this library.
+    ///
+    /// The default implementation returns an empty list; libraries which can enumerate their
+    /// modules should override it.
+    fn modules(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
     /// Returns the module located at the specified path.
     ///
     /// # Errors
     /// Returns an error if the modules for the specified path does not exist in this library.
     fn get_module(&self, module_path: &str) -> Result<&Self::Module, LibraryError>;
+
+    /// Returns the module located at the specified path, or None if the module does not exist in
+    /// this library.
+    fn get_module_ast(&self, module_path: &str) -> Option<&Self::Module> {
+        self.get_module(module_path).ok()
+    }
+
+    /// Returns the source code of the module located at the specified path, or None if the module
+    /// does not exist in this library or its source code is not available.
+    ///
+    /// The default implementation always returns None.
+    fn get_module_source(&self, _module_path: &str) -> Option<&str> {
+        None
+    }
+
+    /// Returns procedures exported from the module located at the specified path in the order in
+    /// which they are defined in the module, or None if the module does not exist in this library.
+    ///
+    /// Each procedure AST contains the name, the number of locals, and the doc comment of the
+    /// procedure. This is available only for libraries of parsed modules (i.e., [ModuleAst]).
+    fn procedures(&self, module_path: &str) -> Option<Vec<&ProcedureAst>>
+    where
+        Self: Library<Module = ModuleAst>,
+    {
+        let module = self.get_module(module_path).ok()?;
+        let procs = module
+            .local_procs
+            .iter()
+            .filter(|proc| proc.is_export)
+            .collect();
+        Some(procs)
+    }
 }
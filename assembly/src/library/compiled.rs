@@ -0,0 +1,328 @@
+use super::{
+    read_string, write_string, CallSet, InlineSite, InliningTrace, LibraryError, Procedure,
+    ProcedureId, ProcedureMetadata, String, ToString, Vec,
+};
+use vm_core::{
+    artifact::{read_and_check_header, write_header, ArtifactKind},
+    chiplets::hasher::Digest,
+    code_blocks::CodeBlock,
+    utils::{
+        collections::BTreeSet, ByteReader, ByteWriter, Deserializable, DeserializationError,
+        Serializable, SliceReader,
+    },
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// File extension of serialized compiled libraries.
+pub const COMPILED_LIBRARY_FILE_EXTENSION: &str = "masc";
+
+// SERIALIZABLE LIBRARY
+// ================================================================================================
+
+/// A module compiled via [Assembler::compile_library()](crate::Assembler::compile_library) into
+/// MAST of its procedures.
+///
+/// Each procedure is stored as a standalone [CodeBlock] keyed by its [ProcedureId]. Besides the
+/// procedures exported from the module, the library contains all procedures which they may invoke
+/// via `call` instructions (procedures invoked via `exec` instructions are already inlined into
+/// the MAST). Thus, the library is self-contained, and it can be loaded by any assembler via a
+/// [LibraryModuleProvider] without parsing or compiling the source of the module.
+#[derive(Debug, Clone)]
+pub struct SerializableLibrary {
+    path: String,
+    procedures: Vec<Procedure>,
+}
+
+impl SerializableLibrary {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [SerializableLibrary] for the module located at the specified path.
+    pub(crate) fn new(path: &str, procedures: Vec<Procedure>) -> Self {
+        Self {
+            path: path.to_string(),
+            procedures,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the path of the module compiled into this library.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns an iterator over names and MAST roots of the procedures exported from the module
+    /// compiled into this library.
+    ///
+    /// Procedures are returned in the order in which they are declared in the module.
+    pub fn exports(&self) -> impl Iterator<Item = (&str, Digest)> {
+        self.procedures
+            .iter()
+            .filter(|proc| {
+                proc.is_export() && proc.id() == &ProcedureId::from_name(proc.label(), &self.path)
+            })
+            .map(|proc| (proc.label(), proc.code_root().hash()))
+    }
+
+    /// Returns an iterator over IDs, names, and MAST of all procedures compiled into this library.
+    ///
+    /// Besides the procedures exported from the module, these include all procedures which the
+    /// exported procedures may invoke via `call` instructions. Procedures invoked via `exec`
+    /// instructions are inlined into the MAST of their callers in the same way as when a program
+    /// is compiled.
+    pub fn code_blocks(&self) -> impl Iterator<Item = (&ProcedureId, &str, &CodeBlock)> {
+        self.procedures
+            .iter()
+            .map(|proc| (proc.id(), proc.label(), proc.code_root()))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns all compiled procedures of this library.
+    pub(crate) fn procedures(&self) -> &[Procedure] {
+        &self.procedures
+    }
+
+    // FILE I/O
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes this library into the specified file.
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be serialized (see
+    /// [to_bytes()](SerializableLibrary::to_bytes)), or if the file cannot be written.
+    #[cfg(feature = "std")]
+    pub fn write_to_file<P>(&self, path: P) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let bytes = self
+            .to_bytes()
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string()))?;
+        std::fs::write(path, bytes)
+    }
+
+    /// Reads a library from the specified file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, or its contents are not a valid compiled
+    /// library.
+    #[cfg(feature = "std")]
+    pub fn read_from_file<P>(path: P) -> Result<Self, LibraryError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|err| LibraryError::FileError(path.display().to_string(), err.to_string()))?;
+
+        Self::read_from_bytes(&bytes)
+            .map_err(|err| LibraryError::DeserializationError(err.to_string()))
+    }
+}
+
+// LIBRARY MODULE PROVIDER
+// ================================================================================================
+
+/// Provides procedures of compiled libraries to an assembler.
+///
+/// Unlike a [ModuleProvider](crate::ModuleProvider), which provides ASTs of modules to be compiled
+/// by the assembler, this provider supplies already compiled procedures. When the provider is
+/// added to an assembler via
+/// [Assembler::with_library_module_provider()](crate::Assembler::with_library_module_provider),
+/// programs compiled by the assembler can import the modules of the loaded libraries in the same
+/// way as any other modules, but the procedures of these modules are never parsed or compiled.
+#[derive(Debug, Default, Clone)]
+pub struct LibraryModuleProvider {
+    libraries: Vec<SerializableLibrary>,
+}
+
+impl LibraryModuleProvider {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [LibraryModuleProvider] without any libraries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the specified library to this provider.
+    pub fn with_library(mut self, library: SerializableLibrary) -> Self {
+        self.libraries.push(library);
+        self
+    }
+
+    /// Deserializes a library from the specified bytes and adds it to this provider.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized compiled library.
+    pub fn load(self, bytes: &[u8]) -> Result<Self, LibraryError> {
+        let library = SerializableLibrary::read_from_bytes(bytes)
+            .map_err(|err| LibraryError::DeserializationError(err.to_string()))?;
+        Ok(self.with_library(library))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the libraries loaded into this provider.
+    pub fn libraries(&self) -> &[SerializableLibrary] {
+        &self.libraries
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+/// A serialized compiled library consists of the following:
+/// - artifact header of the [ArtifactKind::CompiledLibrary] kind (see [vm_core::artifact]).
+/// - path of the compiled module.
+/// - number of procedures (u16) followed by each procedure.
+///
+/// A procedure is serialized as its ID (24 bytes), label, export flag (u8), number of locals
+/// (u32), peak local frame (u64), and MAST root, followed by the number of procedures in its
+/// callset (u16) with the ID of each procedure, and the number of sites in its inlining trace
+/// (u32) with the caller, the callee, and the position (u32) of each site.
+///
+/// All strings are encoded as UTF-8 bytes prefixed with their length (u16).
+impl SerializableLibrary {
+    /// Serializes this library into a vector of bytes.
+    ///
+    /// # Errors
+    /// Returns an error if any of the lengths encoded in the library (e.g., the number of
+    /// procedures, or the length of a procedure label) does not fit into its field.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, LibraryError> {
+        let mut target = Vec::new();
+        write_header(&mut target, ArtifactKind::CompiledLibrary);
+        write_checked_string(&mut target, &self.path, "module path")?;
+
+        target.write_u16(checked_len(self.procedures.len(), "number of procedures")?);
+        for proc in self.procedures.iter() {
+            target.write_u8_slice(&proc.id().0);
+            write_checked_string(&mut target, proc.label(), "procedure label")?;
+            target.write_u8(proc.is_export() as u8);
+            target.write_u32(proc.num_locals());
+            target.write_u64(proc.max_local_frame());
+            proc.code_root().write_into(&mut target);
+
+            target.write_u16(checked_len(proc.callset().len(), "callset size")?);
+            for proc_id in proc.callset().iter() {
+                target.write_u8_slice(&proc_id.0);
+            }
+
+            target.write_u32(checked_len(
+                proc.inlining_trace().len(),
+                "inlining trace length",
+            )?);
+            for site in proc.inlining_trace().iter() {
+                write_checked_string(&mut target, site.caller(), "procedure label")?;
+                write_checked_string(&mut target, site.callee(), "procedure label")?;
+                target.write_u32(checked_len(site.position(), "inline site position")?);
+            }
+        }
+
+        Ok(target)
+    }
+}
+
+impl Deserializable for SerializableLibrary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_and_check_header(source, ArtifactKind::CompiledLibrary)?;
+        let path = read_string(source)?;
+
+        let num_procs = source.read_u16()? as usize;
+        let mut procedures = Vec::with_capacity(num_procs);
+        let mut proc_ids = BTreeSet::new();
+        for _ in 0..num_procs {
+            let id = ProcedureId(source.read_u8_array()?);
+            if !proc_ids.insert(id) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "duplicate procedure {id}"
+                )));
+            }
+            let label = read_string(source)?;
+            let is_export = match source.read_u8()? {
+                0 => false,
+                1 => true,
+                flag => {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "invalid export flag {flag}"
+                    )))
+                }
+            };
+            let num_locals = source.read_u32()?;
+            let max_local_frame = source.read_u64()?;
+            let code_root = CodeBlock::read_from(source)?;
+
+            let mut callset = CallSet::default();
+            for _ in 0..source.read_u16()? {
+                callset.insert(ProcedureId(source.read_u8_array()?));
+            }
+
+            let mut inlining_trace = InliningTrace::default();
+            for _ in 0..source.read_u32()? {
+                let caller = read_string(source)?;
+                let callee = read_string(source)?;
+                let position = source.read_u32()? as usize;
+                inlining_trace.push(InlineSite::new(&caller, &callee, position));
+            }
+
+            procedures.push(Procedure::new(
+                code_root,
+                ProcedureMetadata {
+                    id,
+                    label,
+                    is_export,
+                    num_locals,
+                    max_local_frame,
+                    callset,
+                    inlining_trace,
+                },
+            ));
+        }
+
+        Ok(Self { path, procedures })
+    }
+}
+
+impl SerializableLibrary {
+    /// Returns a library deserialized from the specified bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized compiled library, or if there are
+    /// unread bytes left after the library has been deserialized.
+    pub fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let library = Self::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(library)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the specified length converted into the type of the field it is serialized into.
+///
+/// # Errors
+/// Returns an error if the length does not fit into the field.
+fn checked_len<T: TryFrom<usize>>(len: usize, field: &str) -> Result<T, LibraryError> {
+    T::try_from(len)
+        .map_err(|_| LibraryError::SerializationError(format!("{field} {len} is too large")))
+}
+
+/// Writes the specified string into the target, failing if its length does not fit into u16.
+fn write_checked_string<W: ByteWriter>(
+    target: &mut W,
+    value: &str,
+    field: &str,
+) -> Result<(), LibraryError> {
+    checked_len::<u16>(value.len(), &format!("{field} length"))?;
+    write_string(target, value);
+    Ok(())
+}
@@ -0,0 +1,385 @@
+use super::{
+    BTreeMap, CallSet, InlineSite, InliningTrace, Library, LibraryError, ModuleAst, ModuleProvider,
+    NamedModuleAst, Procedure, ProcedureId, ProcedureMetadata, String, ToString, Vec,
+    MODULE_PATH_DELIM,
+};
+use vm_core::{
+    artifact::{read_and_check_header, write_header, ArtifactKind},
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+    },
+};
+
+mod version;
+pub use version::{Version, VersionReq};
+
+mod compiled;
+pub use compiled::{LibraryModuleProvider, SerializableLibrary, COMPILED_LIBRARY_FILE_EXTENSION};
+
+#[cfg(feature = "std")]
+mod source;
+#[cfg(feature = "std")]
+pub use source::{SourceModuleProvider, MODULE_FILE_EXTENSION};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// File extension of serialized libraries.
+pub const LIBRARY_FILE_EXTENSION: &str = "masl";
+
+// MASL LIBRARY
+// ================================================================================================
+
+/// A library of Miden assembly modules which can be distributed as a single `.masl` file.
+///
+/// A library is identified by its namespace (e.g., `acme`) and has a version. All modules of a
+/// library must be located under its namespace (e.g., `acme::math::u64`). A library can also
+/// declare dependencies on other libraries; when a library is registered with an [Assembler]
+/// via [Assembler::with_libraries()], all of its dependencies must be registered as well.
+///
+/// Modules are stored as parsed ASTs, which include doc comments of their procedures, but not
+/// the source code of the modules.
+///
+/// [Assembler]: crate::Assembler
+/// [Assembler::with_libraries()]: crate::Assembler::with_libraries
+#[derive(Debug, PartialEq, Eq)]
+pub struct MaslLibrary {
+    namespace: String,
+    version: Version,
+    version_label: String,
+    dependencies: Vec<LibraryDependency>,
+    modules: Vec<(String, ModuleAst)>,
+    proc_index: BTreeMap<ProcedureId, usize>,
+}
+
+impl MaslLibrary {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [MaslLibrary] instantiated from the specified parameters.
+    ///
+    /// Modules are specified as tuples of module paths and module ASTs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The namespace is not a valid module path component.
+    /// - Any of the module paths is not a valid module path, or is not located under the
+    ///   namespace of the library.
+    /// - Two modules have the same path, or two dependencies have the same namespace.
+    pub fn new(
+        namespace: &str,
+        version: Version,
+        dependencies: Vec<LibraryDependency>,
+        modules: Vec<(String, ModuleAst)>,
+    ) -> Result<Self, LibraryError> {
+        if !is_valid_path_component(namespace) {
+            return Err(LibraryError::InvalidNamespace(namespace.to_string()));
+        }
+
+        for (i, dependency) in dependencies.iter().enumerate() {
+            if !is_valid_path_component(&dependency.namespace) {
+                return Err(LibraryError::InvalidNamespace(dependency.namespace.clone()));
+            }
+            if dependencies[..i]
+                .iter()
+                .any(|other| other.namespace == dependency.namespace)
+            {
+                return Err(LibraryError::DuplicateDependency(
+                    dependency.namespace.clone(),
+                ));
+            }
+        }
+
+        let mut proc_index = BTreeMap::new();
+        for (i, (path, module)) in modules.iter().enumerate() {
+            validate_module_path(namespace, path)?;
+            if modules[..i].iter().any(|(other, _)| other == path) {
+                return Err(LibraryError::DuplicateModulePath(path.clone()));
+            }
+
+            for proc_name in module.proc_names() {
+                proc_index.insert(ProcedureId::from_name(proc_name, path), i);
+            }
+        }
+
+        Ok(Self {
+            namespace: namespace.to_string(),
+            version,
+            version_label: version.to_string(),
+            dependencies,
+            modules,
+            proc_index,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the namespace of this library.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Returns the version of this library.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Returns the libraries on which this library depends.
+    pub fn dependencies(&self) -> &[LibraryDependency] {
+        &self.dependencies
+    }
+
+    // DEPENDENCY VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that all dependencies of this library are satisfied by the provided libraries.
+    ///
+    /// # Errors
+    /// Returns an error if, for any of the dependencies, there is no library with the required
+    /// namespace among the provided libraries, or the version of such library does not satisfy
+    /// the version requirement of the dependency.
+    pub fn check_dependencies(&self, libraries: &[MaslLibrary]) -> Result<(), LibraryError> {
+        for dependency in self.dependencies.iter() {
+            let library = libraries
+                .iter()
+                .find(|library| library.namespace == dependency.namespace)
+                .ok_or_else(|| LibraryError::DependencyNotFound {
+                    library: self.namespace.clone(),
+                    dependency: dependency.to_string(),
+                })?;
+
+            if !dependency.version_req.matches(&library.version) {
+                return Err(LibraryError::DependencyVersionMismatch {
+                    library: self.namespace.clone(),
+                    dependency: dependency.to_string(),
+                    version: library.version_label.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    // FILE I/O
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes this library into the specified file.
+    #[cfg(feature = "std")]
+    pub fn write_to_file<P>(&self, path: P) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        std::fs::write(path, self.to_bytes())
+    }
+
+    /// Reads a library from the specified file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, or its contents are not a valid library.
+    #[cfg(feature = "std")]
+    pub fn read_from_file<P>(path: P) -> Result<Self, LibraryError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|err| LibraryError::FileError(path.display().to_string(), err.to_string()))?;
+
+        Self::read_from_bytes(&bytes)
+            .map_err(|err| LibraryError::DeserializationError(err.to_string()))
+    }
+}
+
+impl ModuleProvider for MaslLibrary {
+    fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.proc_index.get(id).map(|&module_idx| {
+            let (path, module) = &self.modules[module_idx];
+            NamedModuleAst::new(path.as_str(), module)
+        })
+    }
+}
+
+impl Library for MaslLibrary {
+    type Module = ModuleAst;
+
+    fn root_ns(&self) -> &str {
+        &self.namespace
+    }
+
+    fn version(&self) -> &str {
+        &self.version_label
+    }
+
+    /// Returns paths of all modules contained in this library, in the order in which they were
+    /// added to the library.
+    fn modules(&self) -> Vec<&str> {
+        self.modules.iter().map(|(path, _)| path.as_str()).collect()
+    }
+
+    fn get_module(&self, module_path: &str) -> Result<&ModuleAst, LibraryError> {
+        self.modules
+            .iter()
+            .find(|(path, _)| path == module_path)
+            .map(|(_, module)| module)
+            .ok_or_else(|| LibraryError::ModuleNotFound(module_path.to_string()))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+/// A serialized library consists of the following:
+/// - artifact header of the [ArtifactKind::Library] kind (see [vm_core::artifact]).
+/// - namespace of the library.
+/// - version of the library as major, minor, and patch components (u32 each).
+/// - number of dependencies (u16) followed by the namespace and the version requirement of each
+///   dependency.
+/// - number of modules (u16) followed by the path of each module and its serialized AST, prefixed
+///   with the length of the AST (u32). ASTs of libraries written in format version 1 do not
+///   contain re-exported procedures.
+///
+/// All strings are encoded as UTF-8 bytes prefixed with their length (u16).
+impl Serializable for MaslLibrary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_header(target, ArtifactKind::Library);
+
+        write_string(target, &self.namespace);
+        target.write_u32(self.version.major);
+        target.write_u32(self.version.minor);
+        target.write_u32(self.version.patch);
+
+        target.write_u16(self.dependencies.len() as u16);
+        for dependency in self.dependencies.iter() {
+            write_string(target, &dependency.namespace);
+            write_string(target, &dependency.version_req.to_string());
+        }
+
+        target.write_u16(self.modules.len() as u16);
+        for (path, module) in self.modules.iter() {
+            write_string(target, path);
+            let module_bytes = module.to_bytes();
+            target.write_u32(module_bytes.len() as u32);
+            target.write_u8_slice(&module_bytes);
+        }
+    }
+}
+
+impl Deserializable for MaslLibrary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = read_and_check_header(source, ArtifactKind::Library)?;
+        let has_reexports = header.format_version >= 2;
+
+        let namespace = read_string(source)?;
+        let version = Version::new(source.read_u32()?, source.read_u32()?, source.read_u32()?);
+
+        let num_dependencies = source.read_u16()? as usize;
+        let mut dependencies = Vec::with_capacity(num_dependencies);
+        for _ in 0..num_dependencies {
+            let namespace = read_string(source)?;
+            let version_req = read_string(source)?
+                .parse()
+                .map_err(|err: LibraryError| DeserializationError::InvalidValue(err.to_string()))?;
+            dependencies.push(LibraryDependency::new(&namespace, version_req));
+        }
+
+        let num_modules = source.read_u16()? as usize;
+        let mut modules = Vec::with_capacity(num_modules);
+        for _ in 0..num_modules {
+            let path = read_string(source)?;
+            let module_len = source.read_u32()? as usize;
+            let module_bytes = source.read_u8_vec(module_len)?;
+            let module = ModuleAst::from_bytes_with_reexports(&module_bytes, has_reexports)
+                .map_err(|err| {
+                    DeserializationError::InvalidValue(format!("invalid module {path}: {err:?}"))
+                })?;
+            modules.push((path, module));
+        }
+
+        Self::new(&namespace, version, dependencies, modules)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+impl MaslLibrary {
+    /// Returns a library deserialized from the specified bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized library, or if there are unread
+    /// bytes left after the library has been deserialized.
+    pub fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let library = Self::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(library)
+    }
+}
+
+// LIBRARY DEPENDENCY
+// ================================================================================================
+
+/// A dependency of a library on another library, identified by its namespace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibraryDependency {
+    pub namespace: String,
+    pub version_req: VersionReq,
+}
+
+impl LibraryDependency {
+    /// Returns a new [LibraryDependency] on a library with the specified namespace.
+    pub fn new(namespace: &str, version_req: VersionReq) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            version_req,
+        }
+    }
+}
+
+impl core::fmt::Display for LibraryDependency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}@{}", self.namespace, self.version_req)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the specified string can be used as a component of a module path: it must
+/// start with an ascii letter, and can contain only ascii letters, numbers, and underscores.
+fn is_valid_path_component(component: &str) -> bool {
+    let mut chars = component.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Makes sure that the specified module path is valid and is located under the namespace.
+fn validate_module_path(namespace: &str, path: &str) -> Result<(), LibraryError> {
+    if !path.split(MODULE_PATH_DELIM).all(is_valid_path_component) {
+        return Err(LibraryError::InvalidModulePath(path.to_string()));
+    }
+
+    match path.split_once(MODULE_PATH_DELIM) {
+        Some((root, _)) if root == namespace => Ok(()),
+        _ => Err(LibraryError::NamespaceViolation {
+            namespace: namespace.to_string(),
+            path: path.to_string(),
+        }),
+    }
+}
+
+/// Writes the specified string into the target prefixed with its length.
+fn write_string<W: ByteWriter>(target: &mut W, value: &str) {
+    target.write_u16(value.len() as u16);
+    target.write_u8_slice(value.as_bytes());
+}
+
+/// Reads a string written by [write_string()] from the source.
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_u16()? as usize;
+    String::from_utf8(source.read_u8_vec(len)?)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
@@ -0,0 +1,258 @@
+use super::{
+    is_valid_path_component, validate_module_path, LibraryError, ModuleAst, ModuleProvider,
+    NamedModuleAst, ProcedureId, String, ToString, Vec, MODULE_PATH_DELIM,
+};
+use crate::{parse_module, AssemblyError, BTreeSet};
+use once_cell::sync::OnceCell;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// File extension of Miden assembly source files.
+pub const MODULE_FILE_EXTENSION: &str = "masm";
+
+// SOURCE MODULE PROVIDER
+// ================================================================================================
+
+/// A [ModuleProvider] which resolves modules from Miden assembly source files located in a
+/// directory.
+///
+/// The provider is instantiated with a root directory and a namespace; a module at path
+/// `myns::foo::bar` is read from file `<root>/foo/bar.masm`. Thus, a program can import modules
+/// of a multi-file project via `use` statements (e.g., `use.myns::foo::bar`) once the provider is
+/// registered with the assembler via [Assembler::with_module_provider()].
+///
+/// The `.masm` files under the root directory are listed when the provider is instantiated, but
+/// a file is read and parsed only when the assembler looks up a procedure which may be located
+/// in it; the resulting AST is kept in memory for subsequent lookups. Since the assembler looks
+/// up modules by IDs of their procedures (which cannot be mapped back to module paths), modules
+/// are parsed in the order of their file paths until the requested procedure is found.
+///
+/// A malformed module is reported only when a procedure declared in it is requested, and a
+/// module which does not exist is simply not resolved by the provider (and the assembler reports
+/// the invoked procedure as undefined).
+///
+/// [Assembler::with_module_provider()]: crate::Assembler::with_module_provider
+#[derive(Debug)]
+pub struct SourceModuleProvider {
+    namespace: String,
+    modules: Vec<SourceModule>,
+}
+
+impl SourceModuleProvider {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [SourceModuleProvider] for modules located in the specified root directory
+    /// under the specified namespace.
+    ///
+    /// Symbolic links under the root directory are not followed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The namespace is not a valid module path component.
+    /// - The root directory or any of its subdirectories cannot be read.
+    /// - A path of any of the `.masm` files does not map to a valid module path.
+    pub fn new<P: AsRef<Path>>(root: P, namespace: &str) -> Result<Self, LibraryError> {
+        if !is_valid_path_component(namespace) {
+            return Err(LibraryError::InvalidNamespace(namespace.to_string()));
+        }
+
+        let root = root.as_ref();
+        let mut files = Vec::new();
+        collect_module_files(root, &mut files)?;
+        files.sort();
+
+        let mut modules = Vec::with_capacity(files.len());
+        for file in files {
+            let path = module_path(root, &file, namespace);
+            validate_module_path(namespace, &path)?;
+            modules.push(SourceModule {
+                path,
+                file,
+                parsed: OnceCell::new(),
+            });
+        }
+
+        Ok(Self {
+            namespace: namespace.to_string(),
+            modules,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the namespace under which the modules of this provider are located.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Returns paths of all modules available via this provider in lexicographic order of their
+    /// file paths.
+    pub fn modules(&self) -> Vec<&str> {
+        self.modules
+            .iter()
+            .map(|module| module.path.as_str())
+            .collect()
+    }
+}
+
+impl ModuleProvider for SourceModuleProvider {
+    fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.try_get_module(id).ok().flatten()
+    }
+
+    /// Returns an error if the requested procedure is declared in a malformed module, or if the
+    /// procedure is not found in any of the valid modules while some of the modules could not be
+    /// read.
+    fn try_get_module(
+        &self,
+        id: &ProcedureId,
+    ) -> Result<Option<NamedModuleAst<'_>>, AssemblyError> {
+        let mut unreadable_module = None;
+        for module in self.modules.iter() {
+            match module.parse() {
+                Ok(parsed) if parsed.proc_ids.contains(id) => {
+                    return Ok(Some(NamedModuleAst::new(module.path.as_str(), &parsed.ast)))
+                }
+                Ok(_) => (),
+                Err(err) => match &err.proc_ids {
+                    Some(proc_ids) if proc_ids.contains(id) => {
+                        return Err(AssemblyError::invalid_module(&module.path, &err.reason))
+                    }
+                    Some(_) => (),
+                    None => {
+                        unreadable_module.get_or_insert((module, err));
+                    }
+                },
+            }
+        }
+
+        // the procedure may be located in a module which could not be read
+        match unreadable_module {
+            Some((module, err)) => Err(AssemblyError::invalid_module(&module.path, &err.reason)),
+            None => Ok(None),
+        }
+    }
+}
+
+// SOURCE MODULE
+// ================================================================================================
+
+/// A module located in a source file, which is parsed when it is first requested.
+#[derive(Debug)]
+struct SourceModule {
+    path: String,
+    file: PathBuf,
+    parsed: OnceCell<Result<ParsedModule, InvalidModule>>,
+}
+
+/// AST of a module together with IDs of the procedures declared in it.
+#[derive(Debug)]
+struct ParsedModule {
+    ast: ModuleAst,
+    proc_ids: BTreeSet<ProcedureId>,
+}
+
+/// Reason for which a module could not be parsed together with IDs of the procedures which
+/// appear to be declared in it; the IDs are not known if the module could not be read.
+#[derive(Debug)]
+struct InvalidModule {
+    reason: String,
+    proc_ids: Option<BTreeSet<ProcedureId>>,
+}
+
+impl SourceModule {
+    /// Returns the parsed module, reading and parsing its file on the first call.
+    fn parse(&self) -> &Result<ParsedModule, InvalidModule> {
+        self.parsed.get_or_init(|| {
+            let source = fs::read_to_string(&self.file).map_err(|err| InvalidModule {
+                reason: file_error(&self.file, err).to_string(),
+                proc_ids: None,
+            })?;
+
+            match parse_module(&source) {
+                Ok(ast) => {
+                    let proc_ids = self.proc_ids(ast.proc_names());
+                    Ok(ParsedModule { ast, proc_ids })
+                }
+                Err(err) => Err(InvalidModule {
+                    reason: AssemblyError::from(err).to_string(),
+                    proc_ids: Some(self.proc_ids(declared_proc_names(&source))),
+                }),
+            }
+        })
+    }
+
+    /// Returns IDs of the procedures with the specified names in this module.
+    fn proc_ids<'a>(&self, names: impl Iterator<Item = &'a str>) -> BTreeSet<ProcedureId> {
+        names
+            .map(|name| ProcedureId::from_name(name, &self.path))
+            .collect()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Recursively collects paths of all `.masm` files located in the specified directory; symbolic
+/// links are skipped, and thus, a link to one of the parent directories does not cause an endless
+/// recursion.
+fn collect_module_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), LibraryError> {
+    let entries = fs::read_dir(dir).map_err(|err| file_error(dir, err))?;
+    for entry in entries {
+        let entry = entry.map_err(|err| file_error(dir, err))?;
+        let path = entry.path();
+        let file_type = entry.file_type().map_err(|err| file_error(&path, err))?;
+        if file_type.is_dir() {
+            collect_module_files(&path, files)?;
+        } else if file_type.is_file()
+            && path
+                .extension()
+                .map_or(false, |ext| ext == MODULE_FILE_EXTENSION)
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Returns names of the procedures which appear to be declared in the specified module source.
+///
+/// This is used to find out which procedures are affected by a module which cannot be parsed;
+/// thus, the source is not validated, and procedure declarations are recognized by the `proc.`
+/// and `export.` prefixes of whitespace-separated words.
+fn declared_proc_names(source: &str) -> impl Iterator<Item = &str> {
+    source.split_whitespace().filter_map(|word| {
+        let label = word
+            .strip_prefix("proc.")
+            .or_else(|| word.strip_prefix("export."))?;
+        let label = label.split('.').next().unwrap_or(label);
+        // a re-exported procedure is declared under its alias, or under its own name
+        match label.split_once("->") {
+            Some((_, alias)) => Some(alias),
+            None => Some(label.rsplit(MODULE_PATH_DELIM).next().unwrap_or(label)),
+        }
+    })
+}
+
+/// Returns the module path for the specified file: the namespace followed by the directories of
+/// the file relative to the root and the name of the file without the extension.
+fn module_path(root: &Path, file: &Path, namespace: &str) -> String {
+    let relative = file.strip_prefix(root).unwrap_or(file).with_extension("");
+    let mut components = vec![namespace.to_string()];
+    components.extend(
+        relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned()),
+    );
+    components.join(MODULE_PATH_DELIM)
+}
+
+fn file_error(path: &Path, err: std::io::Error) -> LibraryError {
+    LibraryError::FileError(path.display().to_string(), err.to_string())
+}
@@ -0,0 +1,470 @@
+use super::{
+    Library, LibraryDependency, LibraryError, MaslLibrary, ModuleAst, Serializable,
+    SourceModuleProvider, ToString, Vec, Version, VersionReq,
+};
+use crate::{parse_module, Assembler, AssemblyError};
+use vm_core::{
+    artifact::{write_header, ArtifactKind, HEADER_LEN},
+    utils::DeserializationError,
+};
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const ACME_MATH: &str = "\
+#! Doubles the value at the top of the stack.
+export.double
+    dup add
+end";
+
+const BETA_UTIL: &str = "\
+use.acme::math
+
+#! Quadruples the value at the top of the stack.
+export.quad
+    exec.math::double
+    exec.math::double
+end";
+
+fn acme_library() -> MaslLibrary {
+    build_library("acme", "1.2.0", &[], &[("acme::math", ACME_MATH)]).unwrap()
+}
+
+fn beta_library(acme_req: &str) -> MaslLibrary {
+    build_library(
+        "beta",
+        "0.1.0",
+        &[("acme", acme_req)],
+        &[("beta::util", BETA_UTIL)],
+    )
+    .unwrap()
+}
+
+fn build_library(
+    namespace: &str,
+    version: &str,
+    dependencies: &[(&str, &str)],
+    modules: &[(&str, &str)],
+) -> Result<MaslLibrary, LibraryError> {
+    let dependencies = dependencies
+        .iter()
+        .map(|(namespace, req)| LibraryDependency::new(namespace, req.parse().unwrap()))
+        .collect();
+    let modules = modules
+        .iter()
+        .map(|(path, source)| (path.to_string(), parse_module(source).unwrap()))
+        .collect::<Vec<_>>();
+    MaslLibrary::new(namespace, version.parse()?, dependencies, modules)
+}
+
+fn temp_file_path(name: &str) -> std::path::PathBuf {
+    let file_name = format!("{name}-{}.masl", std::process::id());
+    std::env::temp_dir().join(file_name)
+}
+
+fn temp_dir_path(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn write_source_file(root: &std::path::Path, path: &str, source: &str) {
+    let path = root.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, source).unwrap();
+}
+
+// SERIALIZATION TESTS
+// ================================================================================================
+
+#[test]
+fn masl_round_trip() {
+    let library = beta_library("^1.0.0");
+    let bytes = library.to_bytes();
+    let deserialized = MaslLibrary::read_from_bytes(&bytes).unwrap();
+    assert_eq!(library, deserialized);
+
+    // doc comments of procedures are preserved
+    let procs = deserialized.procedures("beta::util").unwrap();
+    assert_eq!(1, procs.len());
+    assert_eq!(
+        Some("Quadruples the value at the top of the stack."),
+        procs[0].docs.as_deref()
+    );
+
+    // library metadata is preserved
+    assert_eq!("beta", deserialized.root_ns());
+    assert_eq!(Version::new(0, 1, 0), deserialized.version());
+    assert_eq!("0.1.0", Library::version(&deserialized));
+    assert_eq!(vec!["beta::util"], deserialized.modules());
+    assert_eq!(
+        &[LibraryDependency::new(
+            "acme",
+            VersionReq::Compatible(Version::new(1, 0, 0))
+        )],
+        deserialized.dependencies()
+    );
+
+    // the same library is read back from a file
+    let path = temp_file_path("masl_round_trip");
+    library.write_to_file(&path).unwrap();
+    let from_file = MaslLibrary::read_from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(library, from_file);
+}
+
+#[test]
+fn masl_malformed_bytes() {
+    let bytes = acme_library().to_bytes();
+
+    // truncated library
+    assert!(MaslLibrary::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // trailing bytes
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert!(MaslLibrary::read_from_bytes(&extended).is_err());
+
+    // wrong magic bytes
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert!(MaslLibrary::read_from_bytes(&corrupted).is_err());
+
+    // unsupported format version
+    let mut corrupted = bytes;
+    corrupted[4] += 1;
+    assert!(MaslLibrary::read_from_bytes(&corrupted).is_err());
+
+    // missing file
+    let path = temp_file_path("masl_missing_file");
+    assert!(matches!(
+        MaslLibrary::read_from_file(path),
+        Err(LibraryError::FileError(..))
+    ));
+}
+
+#[test]
+fn masl_read_format_version_1() {
+    // modules of libraries written in format version 1 end before the re-exported procedures
+    let library = acme_library();
+    let module_len = parse_module(ACME_MATH).unwrap().to_bytes().len();
+    let mut bytes = library.to_bytes();
+    let len_offset = bytes.len() - module_len - 4;
+    bytes.truncate(bytes.len() - 2);
+    bytes[len_offset..len_offset + 4].copy_from_slice(&(module_len as u32 - 2).to_le_bytes());
+    assert!(MaslLibrary::read_from_bytes(&bytes).is_err());
+
+    bytes[4] = 1;
+    assert_eq!(library, MaslLibrary::read_from_bytes(&bytes).unwrap());
+}
+
+#[test]
+fn masl_wrong_artifact_kind() {
+    let library_bytes = acme_library().to_bytes();
+
+    // a library body preceded by a header of another kind is rejected, and the error names the
+    // kind of the artifact which was found
+    for kind in [
+        ArtifactKind::Program,
+        ArtifactKind::Proof,
+        ArtifactKind::Bundle,
+    ] {
+        let mut bytes = Vec::new();
+        write_header(&mut bytes, kind);
+        bytes.extend_from_slice(&library_bytes[HEADER_LEN..]);
+
+        let expected = format!("expected a library (.masl) but found a {kind}");
+        assert!(matches!(
+            MaslLibrary::read_from_bytes(&bytes),
+            Err(DeserializationError::InvalidValue(message)) if message == expected
+        ));
+    }
+    assert!(matches!(
+        MaslLibrary::read_from_bytes(b"not a library"),
+        Err(DeserializationError::InvalidValue(message))
+            if message == "expected a library (.masl) but found an unrecognized file"
+    ));
+}
+
+// VALIDATION TESTS
+// ================================================================================================
+
+#[test]
+fn masl_namespace_validation() {
+    // modules must be located under the namespace of the library
+    let result = build_library("acme", "1.0.0", &[], &[("other::math", ACME_MATH)]);
+    assert!(matches!(
+        result,
+        Err(LibraryError::NamespaceViolation { namespace, path })
+            if namespace == "acme" && path == "other::math"
+    ));
+
+    // a module cannot be located at the root of the namespace
+    let result = build_library("acme", "1.0.0", &[], &[("acme", ACME_MATH)]);
+    assert!(matches!(
+        result,
+        Err(LibraryError::NamespaceViolation { .. })
+    ));
+
+    // a namespace which is a prefix of the path component is not enough
+    let result = build_library("acme", "1.0.0", &[], &[("acmex::math", ACME_MATH)]);
+    assert!(matches!(
+        result,
+        Err(LibraryError::NamespaceViolation { .. })
+    ));
+
+    // invalid namespaces and module paths
+    for namespace in ["", "1acme", "acme::math", "ac-me"] {
+        let result = build_library(namespace, "1.0.0", &[], &[]);
+        assert!(matches!(result, Err(LibraryError::InvalidNamespace(_))));
+    }
+    for path in ["acme::", "acme::::math", "acme::1math"] {
+        let result = build_library("acme", "1.0.0", &[], &[(path, ACME_MATH)]);
+        assert!(matches!(result, Err(LibraryError::InvalidModulePath(_))));
+    }
+
+    // duplicate modules and dependencies
+    let modules = [("acme::math", ACME_MATH), ("acme::math", ACME_MATH)];
+    let result = build_library("acme", "1.0.0", &[], &modules);
+    assert!(matches!(result, Err(LibraryError::DuplicateModulePath(_))));
+
+    let dependencies = [("beta", "1.0.0"), ("beta", "2.0.0")];
+    let result = build_library("acme", "1.0.0", &dependencies, &[]);
+    assert!(matches!(result, Err(LibraryError::DuplicateDependency(_))));
+}
+
+#[test]
+fn masl_missing_dependency() {
+    // beta depends on acme which is not registered
+    let result = Assembler::default().with_libraries([beta_library("^1.0.0")]);
+    assert!(matches!(
+        result,
+        Err(LibraryError::DependencyNotFound { library, dependency })
+            if library == "beta" && dependency == "acme@^1.0.0"
+    ));
+
+    // acme is registered, but its version does not match the requirement
+    let result = Assembler::default().with_libraries([beta_library("^2.0.0"), acme_library()]);
+    assert!(matches!(
+        result,
+        Err(LibraryError::DependencyVersionMismatch { version, .. }) if version == "1.2.0"
+    ));
+
+    // a library cannot be registered twice
+    let result = Assembler::default()
+        .with_libraries([acme_library()])
+        .unwrap()
+        .with_libraries([acme_library()]);
+    assert!(matches!(result, Err(LibraryError::DuplicateNamespace(_))));
+
+    // dependencies can be registered before the libraries which depend on them
+    let result = Assembler::default()
+        .with_libraries([acme_library()])
+        .unwrap()
+        .with_libraries([beta_library("^1.0.0")]);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn version_requirements() {
+    let version = |value: &str| value.parse::<Version>().unwrap();
+    let matches =
+        |req: &str, value: &str| req.parse::<VersionReq>().unwrap().matches(&version(value));
+
+    assert!(matches("*", "0.0.1"));
+    assert!(matches("=1.2.3", "1.2.3"));
+    assert!(!matches("=1.2.3", "1.2.4"));
+    assert!(matches(">=1.2.3", "3.0.0"));
+    assert!(!matches(">=1.2.3", "1.2.2"));
+    assert!(matches("^1.2.3", "1.9.0"));
+    assert!(!matches("^1.2.3", "2.0.0"));
+    assert!(matches("1.2.3", "1.2.3"));
+    assert!(!matches("1.2.3", "1.2.2"));
+    assert!(matches("^0.2.3", "0.2.9"));
+    assert!(!matches("^0.2.3", "0.3.0"));
+    assert!(matches("^0.0.3", "0.0.3"));
+    assert!(!matches("^0.0.3", "0.0.4"));
+
+    for req in ["*", "=1.2.3", ">=1.2.3", "^1.2.3"] {
+        assert_eq!(req, req.parse::<VersionReq>().unwrap().to_string());
+    }
+
+    for invalid in ["", "1", "1.2", "1.2.3.4", "a.b.c", "-1.2.3"] {
+        assert!(invalid.parse::<Version>().is_err());
+    }
+    for invalid in ["", "~1.2.3", "<1.2.3", "^1.2"] {
+        assert!(invalid.parse::<VersionReq>().is_err());
+    }
+}
+
+// COMPILATION TESTS
+// ================================================================================================
+
+#[test]
+fn compile_against_interdependent_libraries() {
+    // write both libraries into files and read them back
+    let acme_path = temp_file_path("acme");
+    let beta_path = temp_file_path("beta");
+    acme_library().write_to_file(&acme_path).unwrap();
+    beta_library("^1.0.0").write_to_file(&beta_path).unwrap();
+    let libraries = [&beta_path, &acme_path].map(|path| {
+        let library = MaslLibrary::read_from_file(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        library
+    });
+
+    let assembler = Assembler::default().with_libraries(libraries).unwrap();
+    let source = "\
+        use.beta::util
+        use.acme::math
+        begin
+            exec.util::quad
+            exec.math::double
+        end";
+    let program = assembler.compile(source).unwrap();
+
+    // all procedures are inlined into the program
+    let expected = Assembler::default()
+        .compile("begin dup add dup add dup add end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // without the libraries, the imported modules cannot be found
+    assert!(Assembler::default().compile(source).is_err());
+}
+
+#[test]
+fn library_module_not_found() {
+    let library = acme_library();
+    assert!(matches!(
+        Library::get_module(&library, "acme::other"),
+        Err(LibraryError::ModuleNotFound(path)) if path == "acme::other"
+    ));
+    assert!(library.get_module_source("acme::math").is_none());
+}
+
+#[test]
+fn library_default_methods() {
+    // a library which implements only the required methods gets the default implementations of
+    // the listing methods
+    struct SingleModule(ModuleAst);
+
+    impl Library for SingleModule {
+        type Module = ModuleAst;
+
+        fn root_ns(&self) -> &str {
+            "acme"
+        }
+
+        fn version(&self) -> &str {
+            "0.1.0"
+        }
+
+        fn get_module(&self, module_path: &str) -> Result<&ModuleAst, LibraryError> {
+            match module_path {
+                "acme::math" => Ok(&self.0),
+                _ => Err(LibraryError::ModuleNotFound(module_path.to_string())),
+            }
+        }
+    }
+
+    let library = SingleModule(parse_module(ACME_MATH).unwrap());
+    assert!(library.modules().is_empty());
+    assert!(library.get_module_source("acme::math").is_none());
+    let procs = library.procedures("acme::math").unwrap();
+    assert_eq!(
+        vec!["double"],
+        procs
+            .iter()
+            .map(|proc| proc.name.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert!(library.procedures("acme::other").is_none());
+}
+
+// SOURCE MODULE PROVIDER TESTS
+// ================================================================================================
+
+#[test]
+fn source_module_provider() {
+    let root = temp_dir_path("source_module_provider");
+    write_source_file(&root, "foo/bar.masm", "export.x push.1 add end");
+    write_source_file(
+        &root,
+        "util.masm",
+        "use.myns::foo::bar\nexport.y exec.bar::x dup end",
+    );
+    write_source_file(&root, "notes.txt", "not a module");
+
+    let provider = SourceModuleProvider::new(&root, "myns").unwrap();
+    assert_eq!("myns", provider.namespace());
+    assert_eq!(vec!["myns::foo::bar", "myns::util"], provider.modules());
+
+    let assembler = Assembler::default().with_module_provider(provider);
+    let program = assembler
+        .compile("use.myns::foo::bar use.myns::util begin exec.bar::x exec.util::y end")
+        .unwrap();
+    let expected = Assembler::default()
+        .compile("begin push.1 add push.1 add dup end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // a module which does not exist is not resolved by the provider
+    assert!(assembler
+        .compile("use.myns::foo::baz begin exec.baz::x end")
+        .is_err());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn source_module_provider_errors() {
+    let root = temp_dir_path("source_module_provider_errors");
+    write_source_file(&root, "foo/bar.masm", "export.x push.1 add");
+    write_source_file(&root, "foo/baz.masm", "export.y push.2 add end");
+
+    // malformed modules are parsed only when their procedures are requested
+    let provider = SourceModuleProvider::new(&root, "myns").unwrap();
+    let assembler = Assembler::default().with_module_provider(provider);
+    assert!(assembler
+        .compile("use.myns::foo::baz begin exec.baz::y end")
+        .is_ok());
+    let error = assembler
+        .compile("use.myns::foo::bar begin exec.bar::x end")
+        .unwrap_err();
+    assert!(matches!(
+        error.unlocated(),
+        AssemblyError::InvalidModule(path, _) if path == "myns::foo::bar"
+    ));
+
+    // file names must be valid module path components
+    std::fs::remove_file(root.join("foo/bar.masm")).unwrap();
+    write_source_file(&root, "foo/1bar.masm", "export.x push.1 add end");
+    assert!(matches!(
+        SourceModuleProvider::new(&root, "myns"),
+        Err(LibraryError::InvalidModulePath(path)) if path == "myns::foo::1bar"
+    ));
+
+    assert!(matches!(
+        SourceModuleProvider::new(&root, "my-ns"),
+        Err(LibraryError::InvalidNamespace(_))
+    ));
+
+    std::fs::remove_dir_all(&root).unwrap();
+
+    // symbolic links are not followed
+    #[cfg(unix)]
+    {
+        write_source_file(&root, "foo/bar.masm", "export.x push.1 add end");
+        std::os::unix::fs::symlink(&root, root.join("foo/loop")).unwrap();
+        let provider = SourceModuleProvider::new(&root, "myns").unwrap();
+        assert_eq!(vec!["myns::foo::bar"], provider.modules());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    // missing root directory
+    assert!(matches!(
+        SourceModuleProvider::new(&root, "myns"),
+        Err(LibraryError::FileError(..))
+    ));
+}
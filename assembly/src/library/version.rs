@@ -0,0 +1,125 @@
+use super::{LibraryError, ToString};
+use core::{fmt, str::FromStr};
+
+// VERSION
+// ================================================================================================
+
+/// A semantic version of a library in the `major.minor.patch` format.
+///
+/// Versions are ordered by their major, minor, and patch components, in that order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    /// Returns a new [Version] instantiated from the specified components.
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for Version {
+    type Err = LibraryError;
+
+    /// Parses a version from a string in the `major.minor.patch` format; all three components
+    /// must be present.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split('.').map(|part| part.parse::<u32>().ok());
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => {
+                Ok(Self::new(major, minor, patch))
+            }
+            _ => Err(LibraryError::InvalidVersion(value.to_string())),
+        }
+    }
+}
+
+// VERSION REQUIREMENT
+// ================================================================================================
+
+/// A requirement which the version of a library dependency must satisfy.
+///
+/// Requirements follow the semantics of Cargo version requirements, and can be written as:
+/// - `*` - any version.
+/// - `=1.2.3` - exactly the specified version.
+/// - `>=1.2.3` - the specified version or any later version.
+/// - `^1.2.3` or `1.2.3` - any version compatible with the specified version: the version must
+///   not be lower than the specified one, and must have the same left-most non-zero component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionReq {
+    Any,
+    Exact(Version),
+    AtLeast(Version),
+    Compatible(Version),
+}
+
+impl VersionReq {
+    /// Returns true if the specified version satisfies this requirement.
+    pub fn matches(&self, version: &Version) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Exact(required) => version == required,
+            Self::AtLeast(required) => version >= required,
+            Self::Compatible(required) => {
+                if version < required {
+                    false
+                } else if required.major > 0 {
+                    version.major == required.major
+                } else if required.minor > 0 {
+                    version.major == 0 && version.minor == required.minor
+                } else {
+                    version == required
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "*"),
+            Self::Exact(version) => write!(f, "={version}"),
+            Self::AtLeast(version) => write!(f, ">={version}"),
+            Self::Compatible(version) => write!(f, "^{version}"),
+        }
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = LibraryError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let parse = |version: &str| {
+            version
+                .parse::<Version>()
+                .map_err(|_| LibraryError::InvalidVersionReq(value.to_string()))
+        };
+
+        if value == "*" {
+            Ok(Self::Any)
+        } else if let Some(version) = value.strip_prefix(">=") {
+            parse(version).map(Self::AtLeast)
+        } else if let Some(version) = value.strip_prefix('=') {
+            parse(version).map(Self::Exact)
+        } else if let Some(version) = value.strip_prefix('^') {
+            parse(version).map(Self::Compatible)
+        } else {
+            parse(value).map(Self::Compatible)
+        }
+    }
+}
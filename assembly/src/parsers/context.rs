@@ -1,9 +1,11 @@
 use super::{
-    field_ops, io_ops, stack_ops, u32_ops, Instruction, LocalProcMap, Node, ParsingError,
-    ProcedureAst, ProcedureId, Token, TokenStream, MODULE_PATH_DELIM,
+    field_ops, io_ops, is_const_name, stack_ops, u32_ops, CodeBody, Instruction, LocalProcMap,
+    Node, ParsingError, ProcReExport, ProcedureAst, ProcedureId, Token, TokenStream,
+    MAX_REEXPORTED_PROCS, MODULE_PATH_DELIM,
 };
+use core::fmt;
 use vm_core::utils::{
-    collections::{BTreeMap, Vec},
+    collections::{BTreeMap, BTreeSet, Vec},
     string::{String, ToString},
 };
 
@@ -14,7 +16,14 @@ use vm_core::utils::{
 #[derive(Default)]
 pub struct ParserContext {
     pub imports: BTreeMap<String, String>,
+    pub local_constants: BTreeMap<String, u64>,
     pub local_procs: LocalProcMap,
+    pub reexported_procs: Vec<ProcReExport>,
+    pub prelude: BTreeSet<String>,
+    /// When set, errors which the parser can recover from are recorded in `errors` instead of
+    /// being returned.
+    pub recovery: bool,
+    pub errors: Vec<ParsingError>,
 }
 
 impl ParserContext {
@@ -22,12 +31,12 @@ impl ParserContext {
     // --------------------------------------------------------------------------------------------
 
     // Parses an if-else statement from the provided token stream.
-    fn parse_if(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_if(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
         // record start of the if-else block and consume the 'if' token
         let if_start = tokens.pos();
         tokens.advance();
 
-        let mut t_branch = Vec::<Node>::new();
+        let mut t_branch = CodeBody::default();
         // read the `if` clause
         self.parse_body(tokens, &mut t_branch, true)?;
 
@@ -42,7 +51,7 @@ impl ParserContext {
                     let else_start = tokens.pos();
                     tokens.advance();
 
-                    let mut f_branch = Vec::<Node>::new();
+                    let mut f_branch = CodeBody::default();
                     // parse the `false` branch
                     self.parse_body(tokens, &mut f_branch, false)?;
 
@@ -68,7 +77,7 @@ impl ParserContext {
                     // consume the `end` token
                     token.validate_end()?;
                     tokens.advance();
-                    Vec::new()
+                    CodeBody::default()
                 }
                 _ => {
                     return Err(ParsingError::unmatched_if(
@@ -87,12 +96,12 @@ impl ParserContext {
     }
 
     /// Parse while token into AST nodes.
-    fn parse_while(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_while(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
         // record start of the while block and consume the 'while' token
         let while_start = tokens.pos();
         tokens.advance();
 
-        let mut loop_body = Vec::<Node>::new();
+        let mut loop_body = CodeBody::default();
         // read the loop body
         self.parse_body(tokens, &mut loop_body, false)?;
 
@@ -115,11 +124,19 @@ impl ParserContext {
     }
 
     /// Parse repeat token into AST nodes.
-    fn parse_repeat(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_repeat(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
         // record start of the repeat block and consume the 'repeat' token
         let repeat_start = tokens.pos();
         let count = match tokens.read() {
-            Some(token) => token.parse_repeat()? as usize,
+            Some(token) => {
+                let count = match self.resolve_constants(token)? {
+                    Some(resolved) => {
+                        Token::new(&resolved, token.pos(), token.location()).parse_repeat()?
+                    }
+                    None => token.parse_repeat()?,
+                };
+                count as usize
+            }
             None => {
                 return Err(ParsingError::missing_param(
                     tokens.read_at(repeat_start).expect("no repeat token"),
@@ -128,7 +145,7 @@ impl ParserContext {
         };
         tokens.advance();
 
-        let mut loop_body = Vec::<Node>::new();
+        let mut loop_body = CodeBody::default();
         // read the loop body
         self.parse_body(tokens, &mut loop_body, false)?;
 
@@ -154,46 +171,59 @@ impl ParserContext {
     // --------------------------------------------------------------------------------------------
 
     /// Parse exec token into AST nodes.
-    fn parse_exec(&self, label: String, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
-        tokens.advance();
-
-        if label.contains(MODULE_PATH_DELIM) {
-            let full_proc_name = self.get_full_imported_proc_name(label);
+    fn parse_exec(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+        let token = tokens.read().expect("no exec token");
+        let label = token.parse_exec()?;
+        let node = if let Some(proc_id) = ProcedureId::from_hex(label) {
+            Node::Instruction(Instruction::ExecImported(proc_id))
+        } else if label.contains(MODULE_PATH_DELIM) {
+            let full_proc_name = self.get_full_imported_proc_name(label, token)?;
             let proc_id = ProcedureId::new(full_proc_name);
-            Ok(Node::Instruction(Instruction::ExecImported(proc_id)))
+            Node::Instruction(Instruction::ExecImported(proc_id))
         } else {
-            let index = self
-                .local_procs
-                .get(&label)
-                .ok_or_else(|| ParsingError::undefined_proc(tokens.read().unwrap(), &label))?
-                .0;
-
-            Ok(Node::Instruction(Instruction::ExecLocal(index)))
-        }
+            match self.local_procs.get(label) {
+                Some((index, _)) => Node::Instruction(Instruction::ExecLocal(*index)),
+                None if self.prelude.contains(label) => {
+                    let proc_id = ProcedureId::from_prelude_name(label);
+                    Node::Instruction(Instruction::ExecImported(proc_id))
+                }
+                None => return Err(ParsingError::undefined_proc(token, label)),
+            }
+        };
+        tokens.advance();
+        Ok(node)
     }
 
     /// Parse call token into AST nodes.
-    fn parse_call(&self, label: String, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
-        tokens.advance();
-        if label.contains(MODULE_PATH_DELIM) {
-            let full_proc_name = self.get_full_imported_proc_name(label);
+    fn parse_call(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+        let token = tokens.read().expect("no call token");
+        let label = token.parse_call()?;
+        let node = if let Some(proc_id) = ProcedureId::from_hex(label) {
+            Node::Instruction(Instruction::CallImported(proc_id))
+        } else if label.contains(MODULE_PATH_DELIM) {
+            let full_proc_name = self.get_full_imported_proc_name(label, token)?;
             let proc_id = ProcedureId::new(full_proc_name);
-            Ok(Node::Instruction(Instruction::CallImported(proc_id)))
+            Node::Instruction(Instruction::CallImported(proc_id))
         } else {
-            let index = self
-                .local_procs
-                .get(&label)
-                .ok_or_else(|| ParsingError::undefined_proc(tokens.read().unwrap(), &label))?
-                .0;
-
-            Ok(Node::Instruction(Instruction::CallLocal(index)))
-        }
+            match self.local_procs.get(label) {
+                Some((index, _)) => Node::Instruction(Instruction::CallLocal(*index)),
+                None if self.prelude.contains(label) => {
+                    let proc_id = ProcedureId::from_prelude_name(label);
+                    Node::Instruction(Instruction::CallImported(proc_id))
+                }
+                None => return Err(ParsingError::undefined_proc(token, label)),
+            }
+        };
+        tokens.advance();
+        Ok(node)
     }
 
     /// Parse syscall token into AST nodes.
-    fn parse_syscall(&self, label: String, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_syscall(&mut self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+        let label = tokens.read().expect("no syscall token").parse_syscall()?;
         tokens.advance();
-        let proc_id = ProcedureId::from_kernel_name(label.as_str());
+        let proc_id =
+            ProcedureId::from_hex(label).unwrap_or_else(|| ProcedureId::from_kernel_name(label));
         Ok(Node::Instruction(Instruction::SysCall(proc_id)))
     }
 
@@ -209,28 +239,119 @@ impl ParserContext {
         while let Some(token) = tokens.read() {
             match token.parts()[0] {
                 Token::EXPORT | Token::PROC => {
-                    let (label, _, is_export) = token.parse_proc()?;
-                    if !allow_export && is_export {
-                        return Err(ParsingError::proc_export_not_allowed(token, &label));
+                    let proc_start = tokens.pos();
+                    let result = if token.is_reexport() {
+                        self.parse_reexport(tokens, allow_export)
+                    } else {
+                        self.parse_procedure_declaration(tokens, allow_export)
+                    };
+                    if let Err(err) = result {
+                        // in recovery mode, skip the rest of the procedure; the error could have
+                        // been detected at the header of the next procedure, which is kept
+                        self.record_error(err)?;
+                        if tokens.pos() == proc_start {
+                            tokens.advance();
+                        }
+                        while let Some(token) = tokens.read() {
+                            if matches!(
+                                token.parts()[0],
+                                Token::EXPORT | Token::PROC | Token::BEGIN
+                            ) {
+                                break;
+                            }
+                            tokens.advance();
+                        }
                     }
+                }
+                _ => break,
+            }
+        }
 
-                    if self.local_procs.contains_key(&label) {
-                        return Err(ParsingError::duplicate_proc_label(token, &label));
-                    }
+        Ok(())
+    }
 
-                    let proc = self.parse_procedure(tokens)?;
-                    self.local_procs
-                        .insert(label.to_string(), (self.local_procs.len() as u16, proc));
+    /// Parse a procedure declaration from token stream and add the procedure to the procedure map
+    /// in context.
+    fn parse_procedure_declaration(
+        &mut self,
+        tokens: &mut TokenStream,
+        allow_export: bool,
+    ) -> Result<(), ParsingError> {
+        let token = tokens.read().expect("missing procedure header");
+        let (label, num_locals, is_export) = token.parse_proc()?;
+        if !allow_export && is_export {
+            return Err(ParsingError::proc_export_not_allowed(token, &label));
+        }
+
+        self.validate_proc_label(token, &label)?;
+
+        let index = self.local_procs.len() as u16;
+        match self.parse_procedure(tokens) {
+            Ok(proc) => {
+                self.local_procs.insert(label, (index, proc));
+                Ok(())
+            }
+            Err(err) => {
+                // in recovery mode, keep an empty procedure so that its invocations are not
+                // reported as undefined
+                if self.recovery {
+                    let proc = ProcedureAst {
+                        name: label.clone(),
+                        docs: None,
+                        num_locals,
+                        is_export,
+                        body: CodeBody::default(),
+                    };
+                    self.local_procs.insert(label, (index, proc));
                 }
-                _ => break,
+                Err(err)
             }
         }
+    }
+
+    /// Parse a re-export of an imported procedure from token stream and add it to the list of
+    /// re-exported procedures in context.
+    fn parse_reexport(
+        &mut self,
+        tokens: &mut TokenStream,
+        allow_export: bool,
+    ) -> Result<(), ParsingError> {
+        let token = tokens.read().expect("missing re-export");
+        let (short_name, label) = token.parse_reexport()?;
+        if !allow_export {
+            return Err(ParsingError::proc_export_not_allowed(token, &label));
+        }
+        self.validate_proc_label(token, &label)?;
+        if self.reexported_procs.len() == MAX_REEXPORTED_PROCS {
+            return Err(ParsingError::too_many_reexports(token));
+        }
 
+        let full_proc_name = self.get_full_imported_proc_name(short_name, token)?;
+        self.reexported_procs.push(ProcReExport {
+            name: label,
+            proc_id: ProcedureId::new(full_proc_name),
+        });
+
+        tokens.advance();
+        Ok(())
+    }
+
+    /// Returns an error if a procedure with the specified label cannot be declared in the current
+    /// module because the label is already taken by another procedure.
+    fn validate_proc_label(&self, token: &Token, label: &str) -> Result<(), ParsingError> {
+        if self.local_procs.contains_key(label)
+            || self.reexported_procs.iter().any(|proc| proc.name == label)
+        {
+            return Err(ParsingError::duplicate_proc_label(token, label));
+        }
+        if self.prelude.contains(label) {
+            return Err(ParsingError::prelude_proc_label(token, label));
+        }
         Ok(())
     }
 
     /// Parse procedure from token stream and add it to the procedure map in context.
-    fn parse_procedure(&self, tokens: &mut TokenStream) -> Result<ProcedureAst, ParsingError> {
+    fn parse_procedure(&mut self, tokens: &mut TokenStream) -> Result<ProcedureAst, ParsingError> {
         let proc_start = tokens.pos();
 
         // read procedure name and consume the procedure header token
@@ -244,7 +365,7 @@ impl ParserContext {
 
         tokens.advance();
 
-        let mut body = Vec::<Node>::new();
+        let mut body = CodeBody::default();
         // parse procedure body
         self.parse_body(tokens, &mut body, false)?;
 
@@ -278,9 +399,9 @@ impl ParserContext {
     // --------------------------------------------------------------------------------------------
     /// Parses a token from the token stream in a body, which generates a series of AST nodes.
     pub fn parse_body(
-        &self,
+        &mut self,
         tokens: &mut TokenStream,
-        nodes: &mut Vec<Node>,
+        nodes: &mut CodeBody,
         break_on_else: bool,
     ) -> Result<(), ParsingError> {
         while let Some(token) = tokens.read() {
@@ -294,30 +415,26 @@ impl ParserContext {
                 }
                 Token::IF => {
                     token.validate_if()?;
-                    nodes.push(self.parse_if(tokens)?);
+                    let location = token.location();
+                    nodes.push(self.parse_if(tokens)?, location);
                 }
                 Token::WHILE => {
                     token.validate_while()?;
-                    nodes.push(self.parse_while(tokens)?);
+                    let location = token.location();
+                    nodes.push(self.parse_while(tokens)?, location);
                 }
-                Token::REPEAT => nodes.push(self.parse_repeat(tokens)?),
-                Token::EXEC => {
-                    let label = token.parse_exec()?;
-                    nodes.push(self.parse_exec(label, tokens)?);
-                }
-                Token::CALL => {
-                    let label = token.parse_call()?;
-                    nodes.push(self.parse_call(label, tokens)?);
-                }
-                Token::SYSCALL => {
-                    let label = token.parse_syscall()?;
-                    nodes.push(self.parse_syscall(label, tokens)?);
+                Token::REPEAT => {
+                    let location = token.location();
+                    nodes.push(self.parse_repeat(tokens)?, location);
                 }
+                Token::EXEC => self.parse_instruction(tokens, nodes, Self::parse_exec)?,
+                Token::CALL => self.parse_instruction(tokens, nodes, Self::parse_call)?,
+                Token::SYSCALL => self.parse_instruction(tokens, nodes, Self::parse_syscall)?,
                 Token::END => {
                     token.validate_end()?;
                     break;
                 }
-                Token::USE | Token::EXPORT | Token::PROC | Token::BEGIN => {
+                Token::USE | Token::CONST | Token::EXPORT | Token::PROC | Token::BEGIN => {
                     // TODO improve the error with the originating block
                     // https://github.com/0xPolygonMiden/miden-vm/issues/514
                     return Err(ParsingError::unexpected_body_end(token));
@@ -328,7 +445,10 @@ impl ParserContext {
                         if op.is_control_token() {
                             break;
                         }
-                        nodes.push(parse_op_token(op)?);
+                        match self.parse_op(op) {
+                            Ok(node) => nodes.push(node, op.location()),
+                            Err(err) => self.record_error(err)?,
+                        }
                         tokens.advance();
                     }
                 }
@@ -338,181 +458,295 @@ impl ParserContext {
         Ok(())
     }
 
+    /// Parses a single instruction token via the specified parser and appends the resulting node
+    /// to the nodes; in recovery mode, an invalid instruction is recorded and skipped.
+    fn parse_instruction(
+        &mut self,
+        tokens: &mut TokenStream,
+        nodes: &mut CodeBody,
+        parser: fn(&mut Self, &mut TokenStream) -> Result<Node, ParsingError>,
+    ) -> Result<(), ParsingError> {
+        let location = tokens.location().expect("no instruction token");
+        match parser(self, tokens) {
+            Ok(node) => nodes.push(node, location),
+            Err(err) => {
+                self.record_error(err)?;
+                tokens.advance();
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a non-control token into an AST node, replacing references to constants with their
+    /// values.
+    fn parse_op(&self, op: &Token) -> Result<Node, ParsingError> {
+        match self.resolve_constants(op)? {
+            Some(resolved) => parse_op_token(&Token::new(&resolved, op.pos(), op.location())),
+            None => parse_op_token(op),
+        }
+    }
+
+    /// Records the specified error if recovery is enabled; otherwise, returns the error.
+    pub fn record_error(&mut self, err: ParsingError) -> Result<(), ParsingError> {
+        if self.recovery {
+            self.errors.push(err);
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
     // HELPER FUNCTIONS
     // ================================================================================================
 
-    fn get_full_imported_proc_name(&self, short_name: String) -> String {
-        let (module_name, proc_name) = short_name.rsplit_once(MODULE_PATH_DELIM).unwrap();
-        let full_module_name = self.imports.get(module_name).unwrap();
-        ProcedureId::path(proc_name, full_module_name)
-    }
-}
+    /// Returns the source of the specified token with all references to constants replaced by
+    /// the values of these constants, or None if the token does not reference any constants.
+    ///
+    /// Targets of procedure invocations (i.e., `exec`, `call`, and `syscall` instructions) are
+    /// procedure names which may look like constant names, and thus, they are never resolved.
+    ///
+    /// # Errors
+    /// Returns an error if the token references a constant which is not declared in the source
+    /// being parsed.
+    fn resolve_constants(&self, token: &Token) -> Result<Option<String>, ParsingError> {
+        if matches!(token.parts()[0], Token::EXEC | Token::CALL | Token::SYSCALL) {
+            return Ok(None);
+        }
 
-/// Parses a Token into a node instruction.
-fn parse_op_token(op: &Token) -> Result<Node, ParsingError> {
-    use Instruction::*;
+        let params = &token.parts()[1..];
+        if !params.iter().any(|param| is_const_name(param)) {
+            return Ok(None);
+        }
 
-    // based on the instruction, invoke the correct parser for the operation
-    match op.parts()[0] {
-        // ----- field operations -----------------------------------------------------------------
-        "assert" => simple_instruction(op, Assert),
-        "assertz" => simple_instruction(op, Assertz),
-        "assert_eq" => simple_instruction(op, AssertEq),
+        let mut parts = Vec::with_capacity(token.num_parts());
+        parts.push(token.parts()[0].to_string());
+        for &param in params {
+            if is_const_name(param) {
+                let value = self
+                    .local_constants
+                    .get(param)
+                    .ok_or_else(|| ParsingError::undefined_const(token, param))?;
+                parts.push(value.to_string());
+            } else {
+                parts.push(param.to_string());
+            }
+        }
+        Ok(Some(parts.join(".")))
+    }
 
-        "add" => field_ops::parse_add(op),
-        "sub" => field_ops::parse_sub(op),
-        "mul" => field_ops::parse_mul(op),
-        "div" => field_ops::parse_div(op),
-        "neg" => simple_instruction(op, Neg),
-        "inv" => simple_instruction(op, Inv),
-
-        "pow2" => simple_instruction(op, Pow2),
-        "exp" => field_ops::parse_exp(op),
-
-        "not" => simple_instruction(op, Not),
-        "and" => simple_instruction(op, And),
-        "or" => simple_instruction(op, Or),
-        "xor" => simple_instruction(op, Xor),
-
-        "eq" => field_ops::parse_eq(op),
-        "neq" => field_ops::parse_neq(op),
-        "lt" => simple_instruction(op, Lt),
-        "lte" => simple_instruction(op, Lte),
-        "gt" => simple_instruction(op, Gt),
-        "gte" => simple_instruction(op, Gte),
-        "eqw" => simple_instruction(op, Eqw),
-
-        // ----- u32 operations -------------------------------------------------------------------
-        "u32test" => simple_instruction(op, U32Test),
-        "u32testw" => simple_instruction(op, U32TestW),
-        "u32assert" => u32_ops::parse_u32assert(op),
-        "u32assertw" => simple_instruction(op, U32AssertW),
-        "u32cast" => simple_instruction(op, U32Cast),
-        "u32split" => simple_instruction(op, U32Split),
+    /// Returns the fully-qualified name of an imported procedure referenced by the specified short
+    /// name (e.g., "u64::checked_add").
+    ///
+    /// # Errors
+    /// Returns an error if the module of the procedure has not been imported.
+    fn get_full_imported_proc_name(
+        &self,
+        short_name: &str,
+        token: &Token,
+    ) -> Result<String, ParsingError> {
+        let (module_name, proc_name) = short_name
+            .rsplit_once(MODULE_PATH_DELIM)
+            .expect("no module path delimiter");
+        let full_module_name = match self.imports.get(module_name) {
+            Some(full_module_name) => full_module_name,
+            None => {
+                // a module imported under an alias cannot be referenced by its own name
+                let alias = self.imports.iter().find_map(|(alias, path)| {
+                    path.rsplit_once(MODULE_PATH_DELIM)
+                        .filter(|(_, name)| *name == module_name)
+                        .map(|_| alias)
+                });
+                return Err(match alias {
+                    Some(alias) => {
+                        ParsingError::module_imported_under_alias(token, module_name, alias)
+                    }
+                    None => ParsingError::module_not_imported(token, module_name),
+                });
+            }
+        };
+        Ok(ProcedureId::path(proc_name, full_module_name))
+    }
+}
 
-        "u32checked_add" => u32_ops::parse_u32checked_add(op),
-        "u32wrapping_add" => u32_ops::parse_u32wrapping_add(op),
-        "u32overflowing_add" => u32_ops::parse_u32overflowing_add(op),
+/// Defines `parse_op_token()`, which parses a token into a node instruction by dispatching on the
+/// name of the instruction, together with [INSTRUCTION_NAMES], which lists the names handled by
+/// the dispatch. Thus, instructions added to the parser are always known to the suggestions for
+/// unknown instruction names.
+macro_rules! instruction_parsers {
+    ($op:ident => { $($name:literal => $parser:expr,)* }) => {
+        /// Names of all instructions parsed by `parse_op_token()`.
+        const INSTRUCTION_NAMES: &[&str] = &[$($name),*];
+
+        /// Parses a Token into a node instruction.
+        fn parse_op_token($op: &Token) -> Result<Node, ParsingError> {
+            use Instruction::*;
+
+            // based on the instruction, invoke the correct parser for the operation
+            match $op.parts()[0] {
+                $($name => $parser,)*
+                name => Err(ParsingError::unknown_op($op, suggest_instruction(name))),
+            }
+        }
+    };
+}
 
-        "u32overflowing_add3" => simple_instruction(op, U32OverflowingAdd3),
-        "u32wrapping_add3" => simple_instruction(op, U32WrappingAdd3),
+instruction_parsers!(op => {
+    // ----- field operations ----------------------------------------------------------------------
+    "assert" => field_ops::parse_assert(op),
+    "assertz" => field_ops::parse_assertz(op),
+    "assert_eq" => field_ops::parse_assert_eq(op),
 
-        "u32checked_sub" => u32_ops::parse_u32checked_sub(op),
-        "u32wrapping_sub" => u32_ops::parse_u32wrapping_sub(op),
-        "u32overflowing_sub" => u32_ops::parse_u32overflowing_sub(op),
+    "add" => field_ops::parse_add(op),
+    "sub" => field_ops::parse_sub(op),
+    "mul" => field_ops::parse_mul(op),
+    "div" => field_ops::parse_div(op),
+    "neg" => simple_instruction(op, Neg),
+    "inv" => simple_instruction(op, Inv),
+
+    "pow2" => simple_instruction(op, Pow2),
+    "exp" => field_ops::parse_exp(op),
+
+    "not" => simple_instruction(op, Not),
+    "and" => simple_instruction(op, And),
+    "or" => simple_instruction(op, Or),
+    "xor" => simple_instruction(op, Xor),
+
+    "eq" => field_ops::parse_eq(op),
+    "neq" => field_ops::parse_neq(op),
+    "lt" => field_ops::parse_lt(op),
+    "lte" => field_ops::parse_lte(op),
+    "gt" => field_ops::parse_gt(op),
+    "gte" => field_ops::parse_gte(op),
+    "eqw" => simple_instruction(op, Eqw),
+
+    // ----- u32 operations ------------------------------------------------------------------------
+    "u32test" => simple_instruction(op, U32Test),
+    "u32testw" => simple_instruction(op, U32TestW),
+    "u32assert" => u32_ops::parse_u32assert(op),
+    "u32assertw" => simple_instruction(op, U32AssertW),
+    "u32cast" => simple_instruction(op, U32Cast),
+    "u32split" => simple_instruction(op, U32Split),
+
+    "u32checked_add" => u32_ops::parse_u32checked_add(op),
+    "u32wrapping_add" => u32_ops::parse_u32wrapping_add(op),
+    "u32overflowing_add" => u32_ops::parse_u32overflowing_add(op),
 
-        "u32checked_mul" => u32_ops::parse_u32checked_mul(op),
-        "u32wrapping_mul" => u32_ops::parse_u32wrapping_mul(op),
-        "u32overflowing_mul" => u32_ops::parse_u32overflowing_mul(op),
+    "u32overflowing_add3" => simple_instruction(op, U32OverflowingAdd3),
+    "u32wrapping_add3" => simple_instruction(op, U32WrappingAdd3),
 
-        "u32overflowing_madd" => simple_instruction(op, U32OverflowingMadd),
-        "u32wrapping_madd" => simple_instruction(op, U32WrappingMadd),
+    "u32checked_sub" => u32_ops::parse_u32checked_sub(op),
+    "u32wrapping_sub" => u32_ops::parse_u32wrapping_sub(op),
+    "u32overflowing_sub" => u32_ops::parse_u32overflowing_sub(op),
 
-        "u32checked_div" => u32_ops::parse_u32_div(op, true),
-        "u32unchecked_div" => u32_ops::parse_u32_div(op, false),
+    "u32checked_mul" => u32_ops::parse_u32checked_mul(op),
+    "u32wrapping_mul" => u32_ops::parse_u32wrapping_mul(op),
+    "u32overflowing_mul" => u32_ops::parse_u32overflowing_mul(op),
 
-        "u32checked_mod" => u32_ops::parse_u32_mod(op, true),
-        "u32unchecked_mod" => u32_ops::parse_u32_mod(op, false),
+    "u32overflowing_madd" => simple_instruction(op, U32OverflowingMadd),
+    "u32wrapping_madd" => simple_instruction(op, U32WrappingMadd),
 
-        "u32checked_divmod" => u32_ops::parse_u32_divmod(op, true),
-        "u32unchecked_divmod" => u32_ops::parse_u32_divmod(op, false),
+    "u32checked_div" => u32_ops::parse_u32_div(op, true),
+    "u32unchecked_div" => u32_ops::parse_u32_div(op, false),
 
-        "u32checked_and" => simple_instruction(op, U32CheckedAnd),
-        "u32checked_or" => simple_instruction(op, U32CheckedOr),
-        "u32checked_xor" => simple_instruction(op, U32CheckedXor),
-        "u32checked_not" => simple_instruction(op, U32CheckedNot),
+    "u32checked_mod" => u32_ops::parse_u32_mod(op, true),
+    "u32unchecked_mod" => u32_ops::parse_u32_mod(op, false),
 
-        "u32checked_shr" => u32_ops::parse_u32_shr(op, true),
-        "u32unchecked_shr" => u32_ops::parse_u32_shr(op, false),
+    "u32checked_divmod" => u32_ops::parse_u32_divmod(op, true),
+    "u32unchecked_divmod" => u32_ops::parse_u32_divmod(op, false),
 
-        "u32checked_shl" => u32_ops::parse_u32_shl(op, true),
-        "u32unchecked_shl" => u32_ops::parse_u32_shl(op, false),
+    "u32checked_and" => simple_instruction(op, U32CheckedAnd),
+    "u32checked_or" => simple_instruction(op, U32CheckedOr),
+    "u32checked_xor" => simple_instruction(op, U32CheckedXor),
+    "u32checked_not" => simple_instruction(op, U32CheckedNot),
 
-        "u32checked_rotr" => u32_ops::parse_u32_rotr(op, true),
-        "u32unchecked_rotr" => u32_ops::parse_u32_rotr(op, false),
+    "u32checked_shr" => u32_ops::parse_u32_shr(op, true),
+    "u32unchecked_shr" => u32_ops::parse_u32_shr(op, false),
 
-        "u32checked_rotl" => u32_ops::parse_u32_rotl(op, true),
-        "u32unchecked_rotl" => u32_ops::parse_u32_rotl(op, false),
+    "u32checked_shl" => u32_ops::parse_u32_shl(op, true),
+    "u32unchecked_shl" => u32_ops::parse_u32_shl(op, false),
 
-        "u32checked_eq" => u32_ops::parse_u32checked_eq(op),
-        "u32checked_neq" => u32_ops::parse_u32checked_neq(op),
+    "u32checked_rotr" => u32_ops::parse_u32_rotr(op, true),
+    "u32unchecked_rotr" => u32_ops::parse_u32_rotr(op, false),
 
-        "u32checked_lt" => simple_instruction(op, U32CheckedLt),
-        "u32unchecked_lt" => simple_instruction(op, U32UncheckedLt),
+    "u32checked_rotl" => u32_ops::parse_u32_rotl(op, true),
+    "u32unchecked_rotl" => u32_ops::parse_u32_rotl(op, false),
 
-        "u32checked_lte" => simple_instruction(op, U32CheckedLte),
-        "u32unchecked_lte" => simple_instruction(op, U32UncheckedLte),
+    "u32checked_eq" => u32_ops::parse_u32checked_eq(op),
+    "u32checked_neq" => u32_ops::parse_u32checked_neq(op),
 
-        "u32checked_gt" => simple_instruction(op, U32CheckedGt),
-        "u32unchecked_gt" => simple_instruction(op, U32UncheckedGt),
+    "u32checked_lt" => u32_ops::parse_u32checked_lt(op),
+    "u32unchecked_lt" => simple_instruction(op, U32UncheckedLt),
 
-        "u32checked_gte" => simple_instruction(op, U32CheckedGte),
-        "u32unchecked_gte" => simple_instruction(op, U32UncheckedGte),
+    "u32checked_lte" => u32_ops::parse_u32checked_lte(op),
+    "u32unchecked_lte" => simple_instruction(op, U32UncheckedLte),
 
-        "u32checked_min" => simple_instruction(op, U32CheckedMin),
-        "u32unchecked_min" => simple_instruction(op, U32UncheckedMin),
+    "u32checked_gt" => u32_ops::parse_u32checked_gt(op),
+    "u32unchecked_gt" => simple_instruction(op, U32UncheckedGt),
 
-        "u32checked_max" => simple_instruction(op, U32CheckedMax),
-        "u32unchecked_max" => simple_instruction(op, U32UncheckedMax),
+    "u32checked_gte" => u32_ops::parse_u32checked_gte(op),
+    "u32unchecked_gte" => simple_instruction(op, U32UncheckedGte),
 
-        // ----- stack manipulation ---------------------------------------------------------------
-        "drop" => simple_instruction(op, Drop),
-        "dropw" => simple_instruction(op, DropW),
-        "padw" => simple_instruction(op, PadW),
-        "dup" => stack_ops::parse_dup(op),
-        "dupw" => stack_ops::parse_dupw(op),
-        "swap" => stack_ops::parse_swap(op),
-        "swapw" => stack_ops::parse_swapw(op),
-        "swapdw" => simple_instruction(op, SwapDw),
-        "movup" => stack_ops::parse_movup(op),
-        "movupw" => stack_ops::parse_movupw(op),
-        "movdn" => stack_ops::parse_movdn(op),
-        "movdnw" => stack_ops::parse_movdnw(op),
+    "u32checked_min" => simple_instruction(op, U32CheckedMin),
+    "u32unchecked_min" => simple_instruction(op, U32UncheckedMin),
 
-        "cswap" => simple_instruction(op, CSwap),
-        "cswapw" => simple_instruction(op, CSwapW),
-        "cdrop" => simple_instruction(op, CDrop),
-        "cdropw" => simple_instruction(op, CDropW),
+    "u32checked_max" => simple_instruction(op, U32CheckedMax),
+    "u32unchecked_max" => simple_instruction(op, U32UncheckedMax),
 
-        // ----- input / output operations --------------------------------------------------------
-        "push" => io_ops::parse_push(op),
+    // ----- stack manipulation --------------------------------------------------------------------
+    "drop" => simple_instruction(op, Drop),
+    "dropw" => simple_instruction(op, DropW),
+    "padw" => simple_instruction(op, PadW),
+    "dup" => stack_ops::parse_dup(op),
+    "dupw" => stack_ops::parse_dupw(op),
+    "swap" => stack_ops::parse_swap(op),
+    "swapw" => stack_ops::parse_swapw(op),
+    "swapdw" => simple_instruction(op, SwapDw),
+    "movup" => stack_ops::parse_movup(op),
+    "movupw" => stack_ops::parse_movupw(op),
+    "movdn" => stack_ops::parse_movdn(op),
+    "movdnw" => stack_ops::parse_movdnw(op),
 
-        "sdepth" => simple_instruction(op, Sdepth),
-        "locaddr" => io_ops::parse_locaddr(op),
-        "caller" => io_ops::parse_caller(op), // TODO: error if not in SYSCALL
+    "cswap" => simple_instruction(op, CSwap),
+    "cswapw" => simple_instruction(op, CSwapW),
+    "cdrop" => simple_instruction(op, CDrop),
+    "cdropw" => simple_instruction(op, CDropW),
 
-        "mem_load" => io_ops::parse_mem_load(op),
-        "loc_load" => io_ops::parse_loc_load(op),
+    // ----- input / output operations -------------------------------------------------------------
+    "push" => io_ops::parse_push(op),
 
-        "mem_loadw" => io_ops::parse_mem_loadw(op),
-        "loc_loadw" => io_ops::parse_loc_loadw(op),
+    "sdepth" => simple_instruction(op, Sdepth),
+    "locaddr" => io_ops::parse_locaddr(op),
+    "caller" => io_ops::parse_caller(op), // TODO: error if not in SYSCALL
+    "push_mast_root" => simple_instruction(op, PushMastRoot),
 
-        "mem_store" => io_ops::parse_mem_store(op),
-        "loc_store" => io_ops::parse_loc_store(op),
+    "mem_load" => io_ops::parse_mem_load(op),
+    "loc_load" => io_ops::parse_loc_load(op),
 
-        "mem_storew" => io_ops::parse_mem_storew(op),
-        "loc_storew" => io_ops::parse_loc_storew(op),
+    "mem_loadw" => io_ops::parse_mem_loadw(op),
+    "loc_loadw" => io_ops::parse_loc_loadw(op),
 
-        "mem_stream" => simple_instruction(op, MemStream),
-        "adv_pipe" => simple_instruction(op, AdvPipe),
+    "mem_store" => io_ops::parse_mem_store(op),
+    "loc_store" => io_ops::parse_loc_store(op),
 
-        "adv_push" => io_ops::parse_adv_push(op),
-        "adv_loadw" => simple_instruction(op, AdvLoadW),
+    "mem_storew" => io_ops::parse_mem_storew(op),
+    "loc_storew" => io_ops::parse_loc_storew(op),
 
-        "adv" => io_ops::parse_adv_inject(op),
+    "mem_stream" => simple_instruction(op, MemStream),
+    "adv_pipe" => simple_instruction(op, AdvPipe),
 
-        // ----- cryptographic operations ---------------------------------------------------------
-        "rphash" => simple_instruction(op, RpHash),
-        "rpperm" => simple_instruction(op, RpPerm),
+    "adv_push" => io_ops::parse_adv_push(op),
+    "adv_loadw" => simple_instruction(op, AdvLoadW),
 
-        "mtree_get" => simple_instruction(op, MTreeGet),
-        "mtree_set" => simple_instruction(op, MTreeSet),
-        "mtree_cwm" => simple_instruction(op, MTreeCwm),
+    "adv" => io_ops::parse_adv_inject(op),
 
-        // ----- catch all ------------------------------------------------------------------------
-        _ => Err(ParsingError::invalid_op(op)),
-    }
-}
+    // ----- cryptographic operations --------------------------------------------------------------
+    "rphash" => simple_instruction(op, RpHash),
+    "rpperm" => simple_instruction(op, RpPerm),
+
+    "mtree_get" => simple_instruction(op, MTreeGet),
+    "mtree_set" => simple_instruction(op, MTreeSet),
+    "mtree_cwm" => simple_instruction(op, MTreeCwm),
+});
 
 /// Validates that the provided token does not contain any immediate parameters and returns a node
 /// for the specified instruction.
@@ -520,10 +754,82 @@ fn parse_op_token(op: &Token) -> Result<Node, ParsingError> {
 /// # Errors
 /// Returns an error if the token is not a simple operation (i.e., contains immediate values).
 fn simple_instruction(op: &Token, instruction: Instruction) -> Result<Node, ParsingError> {
-    debug_assert_eq!(op.parts()[0], instruction.to_string());
+    debug_assert!(
+        is_instruction_name(&instruction, op.parts()[0]),
+        "token {} does not match instruction {instruction}",
+        op.parts()[0]
+    );
     match op.num_parts() {
         0 => unreachable!(),
         1 => Ok(Node::Instruction(instruction)),
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+/// Returns true if the textual representation of the specified instruction is equal to `name`.
+///
+/// The representation is compared piece by piece as it is being formatted, and thus, this does not
+/// allocate a string for every parsed instruction.
+fn is_instruction_name(instruction: &Instruction, name: &str) -> bool {
+    struct NameMatcher<'a>(&'a str);
+
+    impl fmt::Write for NameMatcher<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 = self.0.strip_prefix(s).ok_or(fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    let mut matcher = NameMatcher(name);
+    fmt::write(&mut matcher, format_args!("{instruction}")).is_ok() && matcher.0.is_empty()
+}
+
+// INSTRUCTION SUGGESTIONS
+// ================================================================================================
+
+/// Names of control flow instructions; these are parsed while the structure of a body is parsed
+/// rather than via `parse_op_token()`.
+const CONTROL_FLOW_NAMES: [&str; 8] = [
+    Token::IF,
+    Token::ELSE,
+    Token::WHILE,
+    Token::REPEAT,
+    Token::EXEC,
+    Token::CALL,
+    Token::SYSCALL,
+    Token::END,
+];
+
+/// Returns the name of the instruction closest to the specified unknown instruction name, or None
+/// if no instruction name is close enough to be a likely typo.
+///
+/// Names are compared via Levenshtein distance; a name is considered close if at most a third of
+/// its characters (but at least one) need to be edited. Among equally close names, the first one
+/// in [INSTRUCTION_NAMES] followed by [CONTROL_FLOW_NAMES] is returned.
+fn suggest_instruction(name: &str) -> Option<&'static str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    INSTRUCTION_NAMES
+        .iter()
+        .chain(CONTROL_FLOW_NAMES.iter())
+        .map(|&candidate| (levenshtein_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the minimum number of single-character insertions, deletions, and substitutions
+/// required to change `a` into `b`.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
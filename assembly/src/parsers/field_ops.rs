@@ -1,5 +1,5 @@
 use super::{
-    check_div_by_zero, parse_element_param,
+    check_div_by_zero, is_hex_digits, parse_element_param, parse_int,
     Instruction::*,
     Node::{self, Instruction},
     ParsingError, StarkField, Token,
@@ -8,6 +8,60 @@ use super::{
 // INSTRUCTION PARSERS
 // ================================================================================================
 
+/// Returns `Assert` instruction node if no error code is provided or `AssertWithError`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_assert(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "assert");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Assert)),
+        2 => {
+            let err_code = parse_err_code_param(op, 1)?;
+            Ok(Instruction(AssertWithError(err_code)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `Assertz` instruction node if no error code is provided or `AssertzWithError`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_assertz(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "assertz");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Assertz)),
+        2 => {
+            let err_code = parse_err_code_param(op, 1)?;
+            Ok(Instruction(AssertzWithError(err_code)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `AssertEq` instruction node if no error code is provided or `AssertEqWithError`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_assert_eq(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "assert_eq");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(AssertEq)),
+        2 => {
+            let err_code = parse_err_code_param(op, 1)?;
+            Ok(Instruction(AssertEqWithError(err_code)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `Add` instruction node if no immediate value is provided or `AddImm` instruction
 /// node otherwise.
 ///
@@ -154,6 +208,78 @@ pub fn parse_neq(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `Lt` instruction node if no immediate value is provided or `LtImm` instruction
+/// node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_lt(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "lt");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Lt)),
+        2 => {
+            let imm = parse_element_param(op, 1)?;
+            Ok(Instruction(LtImm(imm)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `Lte` instruction node if no immediate value is provided or `LteImm` instruction
+/// node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_lte(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "lte");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Lte)),
+        2 => {
+            let imm = parse_element_param(op, 1)?;
+            Ok(Instruction(LteImm(imm)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `Gt` instruction node if no immediate value is provided or `GtImm` instruction
+/// node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_gt(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "gt");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Gt)),
+        2 => {
+            let imm = parse_element_param(op, 1)?;
+            Ok(Instruction(GtImm(imm)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `Gte` instruction node if no immediate value is provided or `GteImm` instruction
+/// node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token has invalid param or more than one param.
+pub fn parse_gte(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "gte");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(Gte)),
+        2 => {
+            let imm = parse_element_param(op, 1)?;
+            Ok(Instruction(GteImm(imm)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -171,3 +297,25 @@ fn parse_bit_len_param(op: &Token, param_idx: usize) -> Result<u8, ParsingError>
         Err(ParsingError::invalid_param(op, param_idx))
     }
 }
+
+/// Parses the error code of an assertion specified as `err=<code>`, where the code is a u32 value
+/// written in decimal or in hexadecimal with a `0x` prefix.
+fn parse_err_code_param(op: &Token, param_idx: usize) -> Result<u32, ParsingError> {
+    let param_value = op.parts()[param_idx];
+
+    let err_code = param_value
+        .strip_prefix("err=")
+        .ok_or_else(|| ParsingError::invalid_param(op, param_idx))?;
+    match err_code.strip_prefix("0x") {
+        Some(digits) if !is_hex_digits(digits) => {
+            Err(ParsingError::invalid_hex_immediate(op, param_idx))
+        }
+        _ => parse_int(err_code).ok_or_else(|| {
+            ParsingError::invalid_param_with_reason(
+                op,
+                param_idx,
+                "error code must be a 32-bit unsigned integer",
+            )
+        }),
+    }
+}
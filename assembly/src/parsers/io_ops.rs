@@ -23,9 +23,17 @@ const HEX_CHUNK_SIZE: usize = 16;
 /// Returns an error if the instruction token has invalid values or inappropriate number of
 /// values.
 pub fn parse_push(op: &Token) -> Result<Node, ParsingError> {
-    validate_operation!(op, "push", 1..MAX_PUSH_INPUTS);
+    validate_operation!(op, "push");
+    if op.num_parts() == 1 {
+        return Err(ParsingError::missing_param(op));
+    }
 
+    // the number of values is checked after parsing as a single hex parameter may contain
+    // several values
     let constants = parse_constants(op)?;
+    if constants.len() > MAX_PUSH_INPUTS {
+        return Err(ParsingError::too_many_push_values(op, constants.len()));
+    }
     Ok(Instruction(PushConstants(constants)))
 }
 
@@ -78,7 +86,7 @@ pub fn parse_adv_push(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
-/// Returns `AdvU64Div`, `AdvKeyval`, or `AdvMem`  instruction node.
+/// Returns `AdvU64Div`, `AdvU256Div`, `AdvKeyval`, or `AdvMem`  instruction node.
 ///
 /// # Errors
 /// Returns an error if:
@@ -86,11 +94,19 @@ pub fn parse_adv_push(op: &Token) -> Result<Node, ParsingError> {
 /// - adv.mem.a.n has a + n > u32::MAX.
 pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
     debug_assert_eq!(op.parts()[0], "adv");
+    if op.num_parts() < 2 {
+        return Err(ParsingError::invalid_op(op));
+    }
+
     match op.parts()[1] {
         "u64div" => {
             validate_operation!(op, "adv.u64div", 0);
             Ok(Instruction(AdvU64Div))
         }
+        "u256div" => {
+            validate_operation!(op, "adv.u256div", 0);
+            Ok(Instruction(AdvU256Div))
+        }
         "keyval" => {
             validate_operation!(op, "adv.keyval", 0);
             Ok(Instruction(AdvKeyval))
@@ -257,12 +273,12 @@ pub fn parse_loc_storew(op: &Token) -> Result<Node, ParsingError> {
 // ================================================================================================
 
 fn parse_constants(op: &Token) -> Result<Vec<Felt>, ParsingError> {
-    let mut constants = Vec::new();
     let param_idx = 1;
     let param_count = op.num_parts() - param_idx;
 
     // for multiple input parameters, parse & push each one onto the stack in order, then return
     if param_count > 1 {
+        let mut constants = Vec::with_capacity(param_count);
         for param_idx in param_idx..=param_count {
             let value = parse_element_param(op, param_idx)?;
             constants.push(value);
@@ -275,18 +291,12 @@ fn parse_constants(op: &Token) -> Result<Vec<Felt>, ParsingError> {
     let param_str = op.parts()[param_idx];
     if let Some(param_str) = param_str.strip_prefix("0x") {
         // parse 1 or more hexadecimal values
-        let values = parse_hex_params(op, param_idx, param_str)?;
-        // push each value onto the stack in order
-        for &value in values.iter() {
-            constants.push(value);
-        }
+        parse_hex_params(op, param_idx, param_str)
     } else {
         // parse 1 decimal value and push it onto the stack
         let value = parse_decimal_param(op, param_idx, param_str)?;
-        constants.push(value);
+        Ok(vec![value])
     }
-
-    Ok(constants)
 }
 
 fn parse_hex_params(
@@ -294,17 +304,21 @@ fn parse_hex_params(
     param_idx: usize,
     param_str: &str,
 ) -> Result<Vec<Felt>, ParsingError> {
-    // handle error cases where the hex string is poorly formed
+    // handle error cases where the hex string is poorly formed; non-ASCII strings are rejected
+    // upfront as they cannot be split into chunks by byte offsets
+    if !param_str.is_ascii() {
+        return Err(ParsingError::invalid_hex_immediate(op, param_idx));
+    }
     let is_single_element = if param_str.len() <= HEX_CHUNK_SIZE {
         if param_str.len() % 2 != 0 {
             // parameter string is not a valid hex representation
-            return Err(ParsingError::invalid_param(op, param_idx));
+            return Err(ParsingError::invalid_hex_immediate(op, param_idx));
         }
         true
     } else {
         if param_str.len() % HEX_CHUNK_SIZE != 0 {
             // hex string doesn't contain a valid number of bytes
-            return Err(ParsingError::invalid_param(op, param_idx));
+            return Err(ParsingError::invalid_hex_immediate(op, param_idx));
         }
         false
     };
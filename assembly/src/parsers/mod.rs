@@ -1,12 +1,17 @@
 use super::{
-    errors::SerializationError, BTreeMap, Felt, ParsingError, ProcedureId, StarkField, String,
-    ToString, Token, TokenStream, Vec, MODULE_PATH_DELIM,
+    errors::SerializationError, is_const_name, BTreeMap, BTreeSet, Felt, InputSpec, ParsingError,
+    ProcedureId, SourceLocation, StarkField, String, ToString, Token, TokenStream, Vec,
+    MAX_REEXPORTED_PROCS, MODULE_PATH_DELIM,
+};
+use core::{
+    fmt::{self, Display},
+    ops::Deref,
 };
-use core::{fmt::Display, ops::Deref};
 use serde::{ByteReader, ByteWriter, Deserializable, Serializable};
 
 mod nodes;
-pub(crate) use nodes::{Instruction, Node};
+use nodes::SourceWriter;
+pub(crate) use nodes::{CodeBody, Instruction, Node};
 
 mod context;
 use context::ParserContext;
@@ -24,23 +29,71 @@ pub mod tests;
 // ================================================================================================
 type LocalProcMap = BTreeMap<String, (u16, ProcedureAst)>;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Prefix of the annotation which declares the inputs of a program.
+const INPUTS_ANNOTATION: &str = "@inputs";
+
+/// Version of the binary encoding of program ASTs; the encoding starts with this version, and
+/// encodings of other versions are rejected when read.
+const PROGRAM_AST_VERSION: u8 = 1;
+
 // ABSTRACT SYNTAX TREE STRUCTS
 // ================================================================================================
 
 /// An abstract syntax tree (AST) of a Miden program.
 ///
-/// A program AST consists of a list of internal procedure ASTs and a list of body nodes.
+/// A program AST consists of a list of internal procedure ASTs and a list of body nodes. It also
+/// contains the inputs declared via an `@inputs` annotation in the doc comment of the program
+/// body, if any.
 #[derive(Debug, Eq, PartialEq)]
 pub struct ProgramAst {
     pub local_procs: Vec<ProcedureAst>,
-    pub body: Vec<Node>,
+    pub body: CodeBody,
+    input_spec: Option<InputSpec>,
 }
 
 impl ProgramAst {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ProgramAst] with the specified local procedures and body which does not
+    /// declare its inputs.
+    pub(crate) fn new(local_procs: Vec<ProcedureAst>, body: CodeBody) -> Self {
+        Self {
+            local_procs,
+            body,
+            input_spec: None,
+        }
+    }
+
+    /// Sets the inputs which this program declares to expect.
+    pub fn with_input_spec(mut self, input_spec: InputSpec) -> Self {
+        self.input_spec = Some(input_spec);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the inputs declared via an `@inputs` annotation of this program, if any.
+    pub fn input_spec(&self) -> Option<InputSpec> {
+        self.input_spec
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
     /// Returns byte representation of the `ProgramAst`.
+    ///
+    /// The representation starts with the version of the encoding (u8), which is followed by the
+    /// local procedures, the body, and the declared inputs, if any.
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut byte_writer = ByteWriter::new();
 
+        byte_writer.write_u8(PROGRAM_AST_VERSION);
+
         // local procedures
         byte_writer.write_u16(self.local_procs.len() as u16);
 
@@ -51,13 +104,28 @@ impl ProgramAst {
         // body
         self.body.write_into(&mut byte_writer);
 
+        // declared inputs
+        byte_writer.write_bool(self.input_spec.is_some());
+        if let Some(input_spec) = self.input_spec {
+            byte_writer.write_input_spec(&input_spec);
+        }
+
         byte_writer.into_bytes()
     }
 
     /// Returns a `ProgramAst` struct by its byte representation.
+    ///
+    /// # Errors
+    /// Returns an error if the representation was written using an unsupported version of the
+    /// encoding or if it is malformed.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
         let mut byte_reader = ByteReader::new(bytes);
 
+        let version = byte_reader.read_u8()?;
+        if version != PROGRAM_AST_VERSION {
+            return Err(SerializationError::UnsupportedVersion(version));
+        }
+
         let num_local_procs = byte_reader.read_u16()?;
 
         let local_procs = (0..num_local_procs)
@@ -66,16 +134,75 @@ impl ProgramAst {
 
         let body = Deserializable::read_from(&mut byte_reader)?;
 
-        Ok(ProgramAst { local_procs, body })
+        let input_spec = if byte_reader.read_bool()? {
+            Some(byte_reader.read_input_spec()?)
+        } else {
+            None
+        };
+
+        Ok(ProgramAst {
+            local_procs,
+            body,
+            input_spec,
+        })
+    }
+
+    /// Writes source code of this program into the provided formatter.
+    ///
+    /// Modules listed in `imports` are written as `use` statements, and invocations of procedures
+    /// with IDs present in `labels` are written using these labels (e.g., `u64::checked_add`).
+    /// All other imported procedures are referenced by their IDs.
+    pub(crate) fn write_source(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        imports: &[&str],
+        labels: &BTreeMap<ProcedureId, String>,
+    ) -> fmt::Result {
+        for path in imports {
+            writeln!(f, "use.{path}")?;
+        }
+        if !imports.is_empty() {
+            writeln!(f)?;
+        }
+
+        let writer = SourceWriter::new(&self.local_procs, labels);
+        for proc in self.local_procs.iter() {
+            if proc.num_locals == 0 {
+                writeln!(f, "proc.{}", proc.name)?;
+            } else {
+                writeln!(f, "proc.{}.{}", proc.name, proc.num_locals)?;
+            }
+            writer.write_nodes(f, &proc.body, 1)?;
+            writeln!(f, "end\n")?;
+        }
+
+        if let Some(input_spec) = self.input_spec {
+            writeln!(f, "#! {input_spec}")?;
+        }
+        writeln!(f, "begin")?;
+        writer.write_nodes(f, &self.body, 1)?;
+        write!(f, "end")
+    }
+}
+
+impl fmt::Display for ProgramAst {
+    /// Writes source code of this program. The source can be parsed back into an identical AST.
+    ///
+    /// Imported procedures are referenced by their IDs as the AST does not retain the imports.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_source(f, &[], &BTreeMap::new())
     }
 }
 
 /// An abstract syntax tree (AST) of a Miden code module.
 ///
 /// A module AST consists of a list of procedure ASTs. These procedures could be local or exported.
-#[derive(Debug, Eq, PartialEq)]
+/// A module can also re-export procedures of the modules it imports (e.g.,
+/// `export.u256::iszero_unsafe`); these are listed separately as they have no bodies.
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ModuleAst {
     pub local_procs: Vec<ProcedureAst>,
+    pub reexported_procs: Vec<ProcReExport>,
 }
 
 impl ModuleAst {
@@ -90,11 +217,30 @@ impl ModuleAst {
             .iter()
             .for_each(|proc| proc.write_into(&mut byte_writer));
 
+        // re-exported procedures; the parser rejects modules with more re-exports than this
+        let num_reexports =
+            u16::try_from(self.reexported_procs.len()).expect("too many re-exported procedures");
+        byte_writer.write_u16(num_reexports);
+
+        self.reexported_procs
+            .iter()
+            .for_each(|proc| proc.write_into(&mut byte_writer));
+
         byte_writer.into_bytes()
     }
 
     /// Returns a `ModuleAst` struct by its byte representation.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+        Self::from_bytes_with_reexports(bytes, true)
+    }
+
+    /// Returns a `ModuleAst` struct by its byte representation, which contains the section of
+    /// re-exported procedures only if `has_reexports` is set; modules written before re-exports
+    /// were introduced (e.g., in version 1 of the library format) end after local procedures.
+    pub(crate) fn from_bytes_with_reexports(
+        bytes: &[u8],
+        has_reexports: bool,
+    ) -> Result<Self, SerializationError> {
         let mut byte_reader = ByteReader::new(bytes);
 
         let local_procs_len = byte_reader.read_u16()?;
@@ -103,7 +249,79 @@ impl ModuleAst {
             .map(|_| ProcedureAst::read_from(&mut byte_reader))
             .collect::<Result<_, _>>()?;
 
-        Ok(ModuleAst { local_procs })
+        let reexported_procs_len = if has_reexports {
+            byte_reader.read_u16()?
+        } else {
+            0
+        };
+
+        let reexported_procs = (0..reexported_procs_len)
+            .map(|_| ProcReExport::read_from(&mut byte_reader))
+            .collect::<Result<_, _>>()?;
+
+        Ok(ModuleAst {
+            local_procs,
+            reexported_procs,
+        })
+    }
+
+    /// Returns names of all procedures declared in this module: names of the local procedures
+    /// followed by the names under which procedures of other modules are re-exported.
+    ///
+    /// These are the names by which procedures of this module can be referenced from other
+    /// modules, and thus, module providers index modules by IDs computed from these names.
+    pub fn proc_names(&self) -> impl Iterator<Item = &str> {
+        self.local_procs
+            .iter()
+            .map(|proc| proc.name.as_str())
+            .chain(self.reexported_procs.iter().map(|proc| proc.name.as_str()))
+    }
+
+    /// Returns an iterator over information about the procedures defined in this module.
+    ///
+    /// Procedures are returned in the order in which they are declared in the module.
+    pub fn procedures(&self) -> impl Iterator<Item = ProcedureInfo<'_>> {
+        self.local_procs.iter().map(|proc| ProcedureInfo {
+            name: &proc.name,
+            is_export: proc.is_export,
+            num_locals: proc.num_locals,
+            docs: proc.docs.as_deref(),
+        })
+    }
+
+    /// Returns IDs of all procedures of other modules which this module depends on: procedures
+    /// invoked via `exec` or `call` from any of the local procedures, as well as re-exported
+    /// procedures.
+    ///
+    /// Procedures invoked via `syscall` are not included as they are provided by the kernel
+    /// rather than by other modules.
+    pub fn imported_procs(&self) -> BTreeSet<ProcedureId> {
+        fn collect(nodes: &[Node], result: &mut BTreeSet<ProcedureId>) {
+            for node in nodes {
+                match node {
+                    Node::Instruction(Instruction::ExecImported(proc_id))
+                    | Node::Instruction(Instruction::CallImported(proc_id)) => {
+                        result.insert(*proc_id);
+                    }
+                    Node::Instruction(_) => (),
+                    Node::IfElse(t_branch, f_branch) => {
+                        collect(t_branch, result);
+                        collect(f_branch, result);
+                    }
+                    Node::Repeat(_, body) | Node::While(body) => collect(body, result),
+                }
+            }
+        }
+
+        let mut result = self
+            .reexported_procs
+            .iter()
+            .map(|proc| proc.proc_id)
+            .collect();
+        for proc in self.local_procs.iter() {
+            collect(&proc.body, &mut result);
+        }
+        result
     }
 
     /// Return a named reference of the module, binding it to an arbitrary path
@@ -175,6 +393,15 @@ impl<'a> NamedModuleAst<'a> {
             .iter()
             .find(|proc| &self.procedure_id(&proc.name) == id)
     }
+
+    /// Returns the re-export of a procedure of another module under the specified ID, or None if
+    /// no procedure is re-exported from this module under this ID.
+    pub fn get_reexport(&self, id: &ProcedureId) -> Option<&ProcReExport> {
+        self.module
+            .reexported_procs
+            .iter()
+            .find(|proc| &self.procedure_id(&proc.name) == id)
+    }
 }
 
 /// An abstract syntax tree of a Miden procedure.
@@ -187,7 +414,7 @@ pub struct ProcedureAst {
     pub name: String,
     pub docs: Option<String>,
     pub num_locals: u16,
-    pub body: Vec<Node>,
+    pub body: CodeBody,
     pub is_export: bool,
 }
 
@@ -224,20 +451,121 @@ impl Deserializable for ProcedureAst {
     }
 }
 
+/// A procedure of an imported module re-exported from a module.
+///
+/// A re-export is declared as `export.<module>::<proc>` (e.g., `export.u256::iszero_unsafe`) and
+/// can rename the procedure via `->` (e.g., `export.u256::iszero_unsafe->iszero`). Invocations of
+/// the procedure under the re-exported name are resolved to the original procedure, and thus,
+/// re-exports do not add any code to the MAST.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProcReExport {
+    /// Name under which the procedure is exported from the re-exporting module.
+    pub name: String,
+    /// ID of the re-exported procedure.
+    pub proc_id: ProcedureId,
+}
+
+impl Serializable for ProcReExport {
+    /// Writes byte representation of the `ProcReExport` into the provided `ByteWriter` struct.
+    fn write_into(&self, target: &mut ByteWriter) {
+        target
+            .write_proc_name(&self.name)
+            .expect("String serialization failure");
+        target.write_procedure_id(&self.proc_id);
+    }
+}
+
+impl Deserializable for ProcReExport {
+    /// Returns a `ProcReExport` from its byte representation stored in provided `ByteReader`
+    /// struct.
+    fn read_from(bytes: &mut ByteReader) -> Result<Self, SerializationError> {
+        let name = bytes.read_proc_name()?;
+        let proc_id = bytes.read_procedure_id()?;
+        Ok(ProcReExport { name, proc_id })
+    }
+}
+
+/// Information about a procedure defined in a module (see [ModuleAst::procedures()]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcedureInfo<'a> {
+    /// Name of the procedure.
+    pub name: &'a str,
+    /// True if the procedure is exported from the module.
+    pub is_export: bool,
+    /// Number of memory locals declared by the procedure.
+    pub num_locals: u16,
+    /// Doc comment of the procedure, if any.
+    pub docs: Option<&'a str>,
+}
+
 // PARSERS
 // ================================================================================================
 
 /// Parses the provided source into a program AST. A program consist of a body and a set of
 /// internal (i.e., not exported) procedures.
 pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
-    let mut tokens = TokenStream::new(source)?;
-    let imports = parse_imports(&mut tokens)?;
+    parse_program_with_prelude(source, &BTreeSet::new())
+}
+
+/// Parses the provided source into a program AST in the same way as [parse_program()], but also
+/// resolves references to the specified prelude procedures.
+///
+/// Procedures of the prelude can be invoked by the program without importing them; their
+/// invocations are parsed into invocations of the procedures imported from the prelude module
+/// (see [ProcedureId::PRELUDE_PATH]).
+pub(crate) fn parse_program_with_prelude(
+    source: &str,
+    prelude: &BTreeSet<String>,
+) -> Result<ProgramAst, ParsingError> {
+    parse_program_source(source, prelude, false).map_err(|mut errors| errors.remove(0))
+}
+
+/// Parses the provided source into a program AST in the same way as
+/// [parse_program_with_prelude()], but instead of stopping at the first error, collects all
+/// errors found in the source.
+///
+/// After an invalid instruction, parsing continues with the next instruction. After an error in
+/// the structure of a procedure (e.g., an unmatched block), the rest of the procedure is skipped
+/// and parsing continues with the next procedure or the program body. Errors in the program body
+/// which are not related to a single instruction, as well as errors in imports and constants,
+/// end parsing.
+pub(crate) fn parse_program_with_recovery(
+    source: &str,
+    prelude: &BTreeSet<String>,
+) -> Result<ProgramAst, Vec<ParsingError>> {
+    parse_program_source(source, prelude, true)
+}
 
+/// Parses the provided source into a program AST, collecting recoverable errors if `recovery` is
+/// set; all returned errors have their locations set.
+fn parse_program_source(
+    source: &str,
+    prelude: &BTreeSet<String>,
+    recovery: bool,
+) -> Result<ProgramAst, Vec<ParsingError>> {
     let mut context = ParserContext {
-        imports,
+        prelude: prelude.clone(),
+        recovery,
         ..Default::default()
     };
 
+    match parse_program_tokens(source, &mut context) {
+        Ok(program) if context.errors.is_empty() => return Ok(program),
+        Ok(_) => (),
+        Err(err) => context.errors.push(err),
+    }
+    Err(context.errors)
+}
+
+/// Parses the provided source into a program AST using the specified context.
+fn parse_program_tokens(
+    source: &str,
+    context: &mut ParserContext,
+) -> Result<ProgramAst, ParsingError> {
+    let mut tokens = TokenStream::new(source)?;
+    context.imports = parse_imports(&mut tokens)?;
+    context.local_constants = parse_constants(&mut tokens)?;
+
     context.parse_procedures(&mut tokens, false)?;
 
     // make sure program body is present
@@ -249,9 +577,14 @@ pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
     }
 
     let program_start = tokens.pos();
+    let docs = tokens.take_doc_comment_at(program_start);
     // consume the 'begin' token
     let header = tokens.read().expect("missing program header");
     header.validate_begin()?;
+    let input_spec = match docs {
+        Some(docs) => parse_input_spec(&docs, header)?,
+        None => None,
+    };
     tokens.advance();
 
     // make sure there is something to be read
@@ -260,7 +593,7 @@ pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
         return Err(ParsingError::unexpected_eof(start_pos));
     }
 
-    let mut body = Vec::<Node>::new();
+    let mut body = CodeBody::default();
 
     // parse the sequence of nodes and add each node to the list
     let mut end_of_nodes = false;
@@ -271,8 +604,9 @@ pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
         end_of_nodes = body.len() == node_count;
     }
 
-    // make sure at least one block has been read
-    if body.len() == beginning_node_count {
+    // make sure at least one block has been read; a body consisting only of invalid instructions
+    // has already been reported
+    if body.len() == beginning_node_count && context.errors.is_empty() {
         let start_op = tokens.read_at(start_pos).expect("no start token");
         return Err(ParsingError::empty_block(start_op));
     }
@@ -297,11 +631,13 @@ pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
         return Err(ParsingError::dangling_ops_after_program(token));
     }
 
-    let local_procs = sort_procs_into_vec(context.local_procs);
-
-    let program = ProgramAst { body, local_procs };
+    let local_procs = sort_procs_into_vec(core::mem::take(&mut context.local_procs));
 
-    Ok(program)
+    let program = ProgramAst::new(local_procs, body);
+    Ok(match input_spec {
+        Some(input_spec) => program.with_input_spec(input_spec),
+        None => program,
+    })
 }
 
 /// Parses the provided source into a module ST. A module consists of internal and exported
@@ -310,8 +646,10 @@ pub fn parse_module(source: &str) -> Result<ModuleAst, ParsingError> {
     let mut tokens = TokenStream::new(source)?;
 
     let imports = parse_imports(&mut tokens)?;
+    let local_constants = parse_constants(&mut tokens)?;
     let mut context = ParserContext {
         imports,
+        local_constants,
         ..Default::default()
     };
     context.parse_procedures(&mut tokens, true)?;
@@ -323,6 +661,7 @@ pub fn parse_module(source: &str) -> Result<ModuleAst, ParsingError> {
 
     let module = ModuleAst {
         local_procs: sort_procs_into_vec(context.local_procs),
+        reexported_procs: context.reexported_procs,
     };
 
     Ok(module)
@@ -330,19 +669,30 @@ pub fn parse_module(source: &str) -> Result<ModuleAst, ParsingError> {
 
 /// Parses all `use` statements into a map of imports which maps a module name (e.g., "u64") to
 /// its fully-qualified path (e.g., "std::math::u64").
+///
+/// A module imported under an alias (e.g., `use.std::math::u256->big`) is mapped by its alias
+/// instead of its name.
 fn parse_imports(tokens: &mut TokenStream) -> Result<BTreeMap<String, String>, ParsingError> {
     let mut imports = BTreeMap::<String, String>::new();
     // read tokens from the token stream until all `use` tokens are consumed
     while let Some(token) = tokens.read() {
         match token.parts()[0] {
             Token::USE => {
-                let module_path = &token.parse_use()?;
-                let (_, short_name) = module_path.rsplit_once(MODULE_PATH_DELIM).unwrap();
+                let (module_path, alias) = token.parse_use()?;
+                let (_, short_name) = module_path
+                    .rsplit_once(MODULE_PATH_DELIM)
+                    .ok_or_else(|| ParsingError::invalid_module_path(token, &module_path))?;
+
+                // an aliased module is referenced only by its alias
+                let short_name = alias.unwrap_or(short_name);
                 if imports.contains_key(short_name) {
-                    return Err(ParsingError::duplicate_module_import(token, module_path));
+                    return Err(match alias {
+                        Some(alias) => ParsingError::duplicate_import_alias(token, alias),
+                        None => ParsingError::duplicate_module_import(token, &module_path),
+                    });
                 }
 
-                imports.insert(short_name.to_string(), module_path.to_string());
+                imports.insert(short_name.to_string(), module_path);
 
                 // consume the `use` token
                 tokens.advance();
@@ -354,6 +704,89 @@ fn parse_imports(tokens: &mut TokenStream) -> Result<BTreeMap<String, String>, P
     Ok(imports)
 }
 
+/// Parses all `const` declarations into a map of constant names to constant values.
+///
+/// A constant is declared as `const.NAME=VALUE`, where the value is a field element written in
+/// decimal or in `0x`-prefixed hexadecimal form. Constants are visible only within the source in
+/// which they are declared.
+fn parse_constants(tokens: &mut TokenStream) -> Result<BTreeMap<String, u64>, ParsingError> {
+    let mut constants = BTreeMap::<String, u64>::new();
+    // read tokens from the token stream until all `const` tokens are consumed
+    while let Some(token) = tokens.read() {
+        match token.parts()[0] {
+            Token::CONST => {
+                let (name, value) = token.parse_const()?;
+                if constants.contains_key(name) {
+                    return Err(ParsingError::duplicate_const_name(token, name));
+                }
+
+                let value = match value.strip_prefix("0x") {
+                    Some(value) => parse_hex_param(token, 1, value)?,
+                    None => parse_decimal_param(token, 1, value)?,
+                };
+                constants.insert(name.to_string(), value.as_int());
+
+                // consume the `const` token
+                tokens.advance();
+            }
+            _ => break,
+        }
+    }
+
+    Ok(constants)
+}
+
+/// Parses the `@inputs` annotation from the provided doc comment of the program body, if the doc
+/// comment contains one.
+///
+/// The annotation occupies a line of the doc comment and has the form `@inputs(stack=3, advice=6)`;
+/// either of the parameters can be omitted. Other lines of the doc comment are ignored.
+fn parse_input_spec(docs: &str, header: &Token) -> Result<Option<InputSpec>, ParsingError> {
+    let mut input_spec = None;
+    for line in docs.lines() {
+        let line = line.trim();
+        let params = match line.strip_prefix(INPUTS_ANNOTATION) {
+            Some(params) => params,
+            None => continue,
+        };
+        let invalid = |reason: &str| ParsingError::invalid_inputs_annotation(header, line, reason);
+        if input_spec.is_some() {
+            return Err(invalid("inputs are declared more than once"));
+        }
+
+        let params = params
+            .strip_prefix('(')
+            .and_then(|params| params.strip_suffix(')'))
+            .ok_or_else(|| invalid("parameters must be enclosed in parentheses"))?;
+
+        let (mut stack, mut advice) = (None, None);
+        for param in params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+        {
+            let (name, value) = param
+                .split_once('=')
+                .ok_or_else(|| invalid("parameters must have the form name=value"))?;
+            let count = match name.trim() {
+                "stack" => &mut stack,
+                "advice" => &mut advice,
+                _ => return Err(invalid("only stack and advice inputs can be declared")),
+            };
+            if count.is_some() {
+                return Err(invalid("parameters must not be repeated"));
+            }
+            let value = value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| invalid("number of inputs must be a 32-bit unsigned integer"))?;
+            *count = Some(value as usize);
+        }
+        input_spec = Some(InputSpec::new(stack, advice));
+    }
+    Ok(input_spec)
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -366,31 +799,28 @@ fn sort_procs_into_vec(proc_map: LocalProcMap) -> Vec<ProcedureAst> {
 }
 
 /// Parses a param from the op token with the specified type.
-fn parse_param<I: core::str::FromStr>(op: &Token, param_idx: usize) -> Result<I, ParsingError> {
+fn parse_param<I: core::str::FromStr + TryFrom<u64>>(
+    op: &Token,
+    param_idx: usize,
+) -> Result<I, ParsingError> {
     let param_value = op.parts()[param_idx];
-
-    let result = match param_value.parse::<I>() {
-        Ok(i) => i,
-        Err(_) => return Err(ParsingError::invalid_param(op, param_idx)),
-    };
-
-    Ok(result)
+    match param_value.strip_prefix("0x") {
+        Some(digits) if !is_hex_digits(digits) => {
+            Err(ParsingError::invalid_hex_immediate(op, param_idx))
+        }
+        _ => parse_int(param_value).ok_or_else(|| ParsingError::invalid_param(op, param_idx)),
+    }
 }
 
 /// Parses a param from the op token with the specified type and ensures that it falls within the
 /// bounds specified by the caller.
-fn parse_checked_param<I: core::str::FromStr + Ord + Display>(
+fn parse_checked_param<I: core::str::FromStr + TryFrom<u64> + Ord + Display>(
     op: &Token,
     param_idx: usize,
     lower_bound: I,
     upper_bound: I,
 ) -> Result<I, ParsingError> {
-    let param_value = op.parts()[param_idx];
-
-    let result = match param_value.parse::<I>() {
-        Ok(i) => i,
-        Err(_) => return Err(ParsingError::invalid_param(op, param_idx)),
-    };
+    let result = parse_param::<I>(op, param_idx)?;
 
     // check that the parameter is within the specified bounds
     if result < lower_bound || result > upper_bound {
@@ -407,6 +837,23 @@ fn parse_checked_param<I: core::str::FromStr + Ord + Display>(
     Ok(result)
 }
 
+/// Parses an integer written either in decimal or in hexadecimal with a `0x` prefix; returns None
+/// if the string is not a valid integer or if the value does not fit into the specified type.
+fn parse_int<I: core::str::FromStr + TryFrom<u64>>(value: &str) -> Option<I> {
+    match value.strip_prefix("0x") {
+        Some(digits) if is_hex_digits(digits) => u64::from_str_radix(digits, 16)
+            .ok()
+            .and_then(|value| I::try_from(value).ok()),
+        Some(_) => None,
+        None => value.parse::<I>().ok(),
+    }
+}
+
+/// Returns true if the specified string is a non-empty sequence of hexadecimal digits.
+fn is_hex_digits(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 /// Parses a single parameter into a valid field element.
 fn parse_element_param(op: &Token, param_idx: usize) -> Result<Felt, ParsingError> {
     // make sure that the parameter value is available
@@ -425,19 +872,35 @@ fn parse_element_param(op: &Token, param_idx: usize) -> Result<Felt, ParsingErro
 }
 
 /// Parses a decimal parameter value into valid a field element.
+///
+/// A value with a leading minus sign is parsed as the negation of its magnitude in the field
+/// (i.e., `-x` is parsed as `modulus - x`); the magnitude must be a valid field element.
 fn parse_decimal_param(
     op: &Token,
     param_idx: usize,
     param_str: &str,
 ) -> Result<Felt, ParsingError> {
-    match param_str.parse::<u64>() {
-        Ok(value) => get_valid_felt(op, param_idx, value),
+    let (magnitude, is_negative) = match param_str.strip_prefix('-') {
+        Some(magnitude) if !magnitude.starts_with('+') => (magnitude, true),
+        _ => (param_str, false),
+    };
+    match magnitude.parse::<u64>() {
+        Ok(value) => {
+            let value = get_valid_felt(op, param_idx, value)?;
+            Ok(if is_negative { -value } else { value })
+        }
         Err(_) => Err(ParsingError::invalid_param(op, param_idx)),
     }
 }
 
 /// Parses a hexadecimal parameter value into a valid field element.
+///
+/// Values which are not non-empty strings of hexadecimal digits are reported as invalid hex
+/// immediates; values which are too large are reported in the same way as decimal values.
 fn parse_hex_param(op: &Token, param_idx: usize, param_str: &str) -> Result<Felt, ParsingError> {
+    if !is_hex_digits(param_str) {
+        return Err(ParsingError::invalid_hex_immediate(op, param_idx));
+    }
     match u64::from_str_radix(param_str, 16) {
         Ok(value) => get_valid_felt(op, param_idx, value),
         Err(_) => Err(ParsingError::invalid_param(op, param_idx)),
@@ -521,23 +984,18 @@ macro_rules! validate_operation {
 
     // validate the instruction string and an optional parameter range
     ($token:expr, $instr:literal $(, $min_params:literal..$max_params:expr)?) => {
-        // split the expected instruction into a vector of parts
-        let instr_parts: Vec<Vec<&str>> = $instr
-            .split(".")
-            .map(|part| part.split("|").collect())
-            .collect();
-
         let num_parts = $token.num_parts();
-        let num_instr_parts = instr_parts.len();
+        let num_instr_parts = $instr.split(".").count();
 
         // token has too few parts to contain the full instruction
         if num_parts < num_instr_parts {
             return Err(ParsingError::invalid_op($token));
         }
 
-        // compare the parts to make sure they match
-        for (part_variants, token_part) in instr_parts.iter().zip($token.parts()) {
-            if !part_variants.contains(token_part) {
+        // compare the parts to make sure they match; the expected instruction is split into parts
+        // lazily to avoid allocating for every validated token
+        for (part_variants, token_part) in $instr.split(".").zip($token.parts()) {
+            if !part_variants.split("|").any(|variant| variant == *token_part) {
                 return Err(ParsingError::unexpected_token($token, $instr));
             }
         }
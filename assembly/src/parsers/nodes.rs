@@ -1,5 +1,5 @@
-use super::{Felt, ProcedureId, String, ToString, Vec};
-use core::fmt;
+use super::{BTreeMap, Felt, ProcedureAst, ProcedureId, SourceLocation, String, ToString, Vec};
+use core::{fmt, ops::Deref};
 
 // NODES
 // ================================================================================================
@@ -8,9 +8,93 @@ use core::fmt;
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Node {
     Instruction(Instruction),
-    IfElse(Vec<Node>, Vec<Node>),
-    Repeat(usize, Vec<Node>),
-    While(Vec<Node>),
+    IfElse(CodeBody, CodeBody),
+    Repeat(usize, CodeBody),
+    While(CodeBody),
+}
+
+// CODE BODY
+// ================================================================================================
+
+/// A sequence of nodes which forms the body of a program, of a procedure, or of a control flow
+/// block.
+///
+/// When a body is parsed from source code, each node of the body keeps the location of the token
+/// from which the node was parsed; the assembler uses these locations to report errors caused by
+/// the nodes. Locations are not a part of the program structure: they are ignored when comparing
+/// bodies and are not serialized.
+#[derive(Clone, Default, Debug, Eq)]
+pub struct CodeBody {
+    nodes: Vec<Node>,
+    /// Locations of the nodes; this is either empty, or contains a location for every node.
+    locations: Vec<SourceLocation>,
+}
+
+impl CodeBody {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [CodeBody] consisting of the specified nodes; the nodes have no locations.
+    pub fn new(nodes: Vec<Node>) -> Self {
+        Self {
+            nodes,
+            locations: Vec::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the nodes of this body.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Returns the nodes of this body together with their locations in the source code, if
+    /// known.
+    pub fn located_nodes(&self) -> impl Iterator<Item = (&Node, Option<SourceLocation>)> {
+        let locations = self.locations.iter().copied().map(Some);
+        self.nodes
+            .iter()
+            .zip(locations.chain(core::iter::repeat(None)))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the specified node parsed from the token at the specified location to this body.
+    ///
+    /// # Panics
+    /// Panics if nodes without locations were previously added to this body.
+    pub(crate) fn push(&mut self, node: Node, location: SourceLocation) {
+        assert_eq!(
+            self.nodes.len(),
+            self.locations.len(),
+            "body contains nodes without locations"
+        );
+        self.nodes.push(node);
+        self.locations.push(location);
+    }
+}
+
+impl From<Vec<Node>> for CodeBody {
+    fn from(nodes: Vec<Node>) -> Self {
+        Self::new(nodes)
+    }
+}
+
+impl Deref for CodeBody {
+    type Target = [Node];
+
+    fn deref(&self) -> &Self::Target {
+        &self.nodes
+    }
+}
+
+impl PartialEq for CodeBody {
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes
+    }
 }
 
 /// This holds the list of instructions supported in a Miden program.
@@ -19,8 +103,11 @@ pub enum Node {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Instruction {
     Assert,
+    AssertWithError(u32),
     AssertEq,
+    AssertEqWithError(u32),
     Assertz,
+    AssertzWithError(u32),
     Add,
     AddImm(Felt),
     Sub,
@@ -45,9 +132,13 @@ pub enum Instruction {
     NeqImm(Felt),
     Eqw,
     Lt,
+    LtImm(Felt),
     Lte,
+    LteImm(Felt),
     Gt,
+    GtImm(Felt),
     Gte,
+    GteImm(Felt),
 
     // ----- u32 manipulation ---------------------------------------------------------------
     U32Test,
@@ -116,12 +207,16 @@ pub enum Instruction {
     U32CheckedNeq,
     U32CheckedNeqImm(u32),
     U32CheckedLt,
+    U32CheckedLtImm(u32),
     U32UncheckedLt,
     U32CheckedLte,
+    U32CheckedLteImm(u32),
     U32UncheckedLte,
     U32CheckedGt,
+    U32CheckedGtImm(u32),
     U32UncheckedGt,
     U32CheckedGte,
+    U32CheckedGteImm(u32),
     U32UncheckedGte,
     U32CheckedMin,
     U32UncheckedMin,
@@ -213,6 +308,7 @@ pub enum Instruction {
     Locaddr(u16),
     Sdepth,
     Caller,
+    PushMastRoot,
 
     MemLoad,
     MemLoadImm(u32),
@@ -235,6 +331,7 @@ pub enum Instruction {
     AdvLoadW,
 
     AdvU64Div,
+    AdvU256Div,
     AdvKeyval,
     AdvMem(u32, u32),
 
@@ -257,8 +354,11 @@ impl fmt::Display for Instruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Assert => write!(f, "assert"),
+            Self::AssertWithError(err_code) => write!(f, "assert.err={err_code}"),
             Self::AssertEq => write!(f, "assert_eq"),
+            Self::AssertEqWithError(err_code) => write!(f, "assert_eq.err={err_code}"),
             Self::Assertz => write!(f, "assertz"),
+            Self::AssertzWithError(err_code) => write!(f, "assertz.err={err_code}"),
             Self::Add => write!(f, "add"),
             Self::AddImm(value) => write!(f, "add.{value}"),
             Self::Sub => write!(f, "sub"),
@@ -283,9 +383,13 @@ impl fmt::Display for Instruction {
             Self::NeqImm(value) => write!(f, "neq.{value}"),
             Self::Eqw => write!(f, "eqw"),
             Self::Lt => write!(f, "lt"),
+            Self::LtImm(value) => write!(f, "lt.{value}"),
             Self::Lte => write!(f, "lte"),
+            Self::LteImm(value) => write!(f, "lte.{value}"),
             Self::Gt => write!(f, "gt"),
+            Self::GtImm(value) => write!(f, "gt.{value}"),
             Self::Gte => write!(f, "gte"),
+            Self::GteImm(value) => write!(f, "gte.{value}"),
 
             // ----- u32 manipulation ---------------------------------------------------------------
             Self::U32Test => write!(f, "u32test"),
@@ -354,12 +458,16 @@ impl fmt::Display for Instruction {
             Self::U32CheckedNeq => write!(f, "u32checked_neq"),
             Self::U32CheckedNeqImm(value) => write!(f, "u32checked_neq.{value}"),
             Self::U32CheckedLt => write!(f, "u32checked_lt"),
+            Self::U32CheckedLtImm(value) => write!(f, "u32checked_lt.{value}"),
             Self::U32UncheckedLt => write!(f, "u32unchecked_lt"),
             Self::U32CheckedLte => write!(f, "u32checked_lte"),
+            Self::U32CheckedLteImm(value) => write!(f, "u32checked_lte.{value}"),
             Self::U32UncheckedLte => write!(f, "u32unchecked_lte"),
             Self::U32CheckedGt => write!(f, "u32checked_gt"),
+            Self::U32CheckedGtImm(value) => write!(f, "u32checked_gt.{value}"),
             Self::U32UncheckedGt => write!(f, "u32unchecked_gt"),
             Self::U32CheckedGte => write!(f, "u32checked_gte"),
+            Self::U32CheckedGteImm(value) => write!(f, "u32checked_gte.{value}"),
             Self::U32UncheckedGte => write!(f, "u32unchecked_gte"),
             Self::U32CheckedMin => write!(f, "u32checked_min"),
             Self::U32UncheckedMin => write!(f, "u32unchecked_min"),
@@ -458,6 +566,7 @@ impl fmt::Display for Instruction {
             Self::Locaddr(value) => write!(f, "locaddr.{value}"),
             Self::Sdepth => write!(f, "sdepth"),
             Self::Caller => write!(f, "caller"),
+            Self::PushMastRoot => write!(f, "push_mast_root"),
 
             Self::MemLoad => write!(f, "mem_load"),
             Self::MemLoadImm(value) => write!(f, "mem_load.{value}"),
@@ -480,6 +589,7 @@ impl fmt::Display for Instruction {
             Self::AdvLoadW => write!(f, "adv_loadw"),
 
             Self::AdvU64Div => write!(f, "adv.u64div"),
+            Self::AdvU256Div => write!(f, "adv.u256div"),
             Self::AdvKeyval => write!(f, "adv.keyval"),
             Self::AdvMem(start_addr, num_words) => write!(f, "adv.mem.{start_addr}.{num_words}"),
 
@@ -501,6 +611,102 @@ impl fmt::Display for Instruction {
     }
 }
 
+// SOURCE WRITER
+// ================================================================================================
+
+/// Writes AST nodes as Miden assembly source code.
+///
+/// Unlike [Instruction] display, the writer references local procedures by their names and
+/// imported procedures by their labels (if known), and thus the written source can be parsed back
+/// into the same nodes.
+pub(super) struct SourceWriter<'a> {
+    local_procs: &'a [ProcedureAst],
+    labels: &'a BTreeMap<ProcedureId, String>,
+}
+
+impl<'a> SourceWriter<'a> {
+    /// Returns a new writer for nodes of a program or a module with the specified local procedures.
+    ///
+    /// Imported procedures with IDs present in `labels` are referenced by these labels; all other
+    /// imported procedures are referenced by their IDs.
+    pub fn new(local_procs: &'a [ProcedureAst], labels: &'a BTreeMap<ProcedureId, String>) -> Self {
+        Self {
+            local_procs,
+            labels,
+        }
+    }
+
+    /// Writes the specified nodes into the formatter, one instruction or control token per line.
+    pub fn write_nodes(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        nodes: &[Node],
+        indent: usize,
+    ) -> fmt::Result {
+        let padding = indent * 4;
+        for node in nodes {
+            match node {
+                Node::Instruction(instruction) => {
+                    write!(f, "{:padding$}", "")?;
+                    self.write_instruction(f, instruction)?;
+                    writeln!(f)?;
+                }
+                Node::IfElse(t_branch, f_branch) => {
+                    writeln!(f, "{:padding$}if.true", "")?;
+                    self.write_nodes(f, t_branch, indent + 1)?;
+                    if !f_branch.is_empty() {
+                        writeln!(f, "{:padding$}else", "")?;
+                        self.write_nodes(f, f_branch, indent + 1)?;
+                    }
+                    writeln!(f, "{:padding$}end", "")?;
+                }
+                Node::Repeat(count, body) => {
+                    writeln!(f, "{:padding$}repeat.{count}", "")?;
+                    self.write_nodes(f, body, indent + 1)?;
+                    writeln!(f, "{:padding$}end", "")?;
+                }
+                Node::While(body) => {
+                    writeln!(f, "{:padding$}while.true", "")?;
+                    self.write_nodes(f, body, indent + 1)?;
+                    writeln!(f, "{:padding$}end", "")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_instruction(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        instruction: &Instruction,
+    ) -> fmt::Result {
+        match instruction {
+            Instruction::ExecLocal(index) => write!(f, "exec.{}", self.local_label(*index)),
+            Instruction::ExecImported(proc_id) => write!(f, "exec.{}", self.label(proc_id)),
+            Instruction::CallLocal(index) => write!(f, "call.{}", self.local_label(*index)),
+            Instruction::CallImported(proc_id) => write!(f, "call.{}", self.label(proc_id)),
+            Instruction::SysCall(proc_id) => write!(f, "syscall.{}", self.label(proc_id)),
+            _ => write!(f, "{instruction}"),
+        }
+    }
+
+    /// Returns the name of the local procedure at the specified index, or the index itself if
+    /// there is no such procedure.
+    fn local_label(&self, index: u16) -> String {
+        self.local_procs
+            .get(index as usize)
+            .map(|proc| proc.name.clone())
+            .unwrap_or_else(|| index.to_string())
+    }
+
+    fn label(&self, proc_id: &ProcedureId) -> String {
+        self.labels
+            .get(proc_id)
+            .cloned()
+            .unwrap_or_else(|| proc_id.to_string())
+    }
+}
+
 // TESTS
 // ================================================================================================
 
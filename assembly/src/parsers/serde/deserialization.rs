@@ -1,9 +1,14 @@
 use super::{
-    super::nodes::{Instruction, Node},
+    super::nodes::{CodeBody, Instruction, Node},
     OpCode, IF_ELSE_OPCODE, REPEAT_OPCODE, WHILE_OPCODE,
 };
-use crate::{errors::SerializationError, ProcedureId};
-use vm_core::{utils::collections::Vec, utils::string::String, Felt};
+use crate::{errors::SerializationError, InputSpec, ProcedureId};
+use vm_core::{
+    utils::{
+        collections::Vec, string::String, Deserializable as _, Serializable as _, SliceReader,
+    },
+    Felt,
+};
 
 // BYTE READER IMPLEMENTATION
 // ================================================================================================
@@ -107,6 +112,16 @@ impl<'a> ByteReader<'a> {
         ))
     }
 
+    /// Reads declared inputs using their encoding defined in `vm_core`.
+    pub fn read_input_spec(&mut self) -> Result<InputSpec, SerializationError> {
+        let mut source = SliceReader::new(&self.bytes[self.pos..]);
+        let input_spec =
+            InputSpec::read_from(&mut source).map_err(|_| SerializationError::InvalidInputSpec)?;
+        // the encoding is canonical, and thus, the number of bytes read can be recomputed
+        self.pos += input_spec.to_bytes().len();
+        Ok(input_spec)
+    }
+
     /// Checks if it is possible to read at least `num_bytes` bytes from ByteReader
     ///
     /// # Errors
@@ -127,7 +142,7 @@ pub trait Deserializable: Sized {
     fn read_from(bytes: &mut ByteReader) -> Result<Self, SerializationError>;
 }
 
-impl Deserializable for Vec<Node> {
+impl Deserializable for CodeBody {
     fn read_from(bytes: &mut ByteReader) -> Result<Self, SerializationError> {
         let mut vec_node: Vec<Node> = Vec::new();
         let vec_len = bytes.read_u16()?;
@@ -136,7 +151,7 @@ impl Deserializable for Vec<Node> {
             vec_node.push(Deserializable::read_from(bytes)?);
         }
 
-        Ok(vec_node)
+        Ok(vec_node.into())
     }
 }
 
@@ -174,8 +189,11 @@ impl Deserializable for Instruction {
 
         match opcode {
             OpCode::Assert => Ok(Instruction::Assert),
+            OpCode::AssertWithError => Ok(Instruction::AssertWithError(bytes.read_u32()?)),
             OpCode::AssertEq => Ok(Instruction::AssertEq),
+            OpCode::AssertEqWithError => Ok(Instruction::AssertEqWithError(bytes.read_u32()?)),
             OpCode::Assertz => Ok(Instruction::Assertz),
+            OpCode::AssertzWithError => Ok(Instruction::AssertzWithError(bytes.read_u32()?)),
             OpCode::Add => Ok(Instruction::Add),
             OpCode::AddImm => Ok(Instruction::AddImm(bytes.read_felt()?)),
             OpCode::Sub => Ok(Instruction::Sub),
@@ -200,9 +218,13 @@ impl Deserializable for Instruction {
             OpCode::NeqImm => Ok(Instruction::NeqImm(bytes.read_felt()?)),
             OpCode::Eqw => Ok(Instruction::Eqw),
             OpCode::Lt => Ok(Instruction::Lt),
+            OpCode::LtImm => Ok(Instruction::LtImm(bytes.read_felt()?)),
             OpCode::Lte => Ok(Instruction::Lte),
+            OpCode::LteImm => Ok(Instruction::LteImm(bytes.read_felt()?)),
             OpCode::Gt => Ok(Instruction::Gt),
+            OpCode::GtImm => Ok(Instruction::GtImm(bytes.read_felt()?)),
             OpCode::Gte => Ok(Instruction::Gte),
+            OpCode::GteImm => Ok(Instruction::GteImm(bytes.read_felt()?)),
 
             // ----- u32 manipulation ---------------------------------------------------------------
             OpCode::U32Test => Ok(Instruction::U32Test),
@@ -279,12 +301,16 @@ impl Deserializable for Instruction {
             OpCode::U32CheckedNeq => Ok(Instruction::U32CheckedNeq),
             OpCode::U32CheckedNeqImm => Ok(Instruction::U32CheckedNeqImm(bytes.read_u32()?)),
             OpCode::U32CheckedLt => Ok(Instruction::U32CheckedLt),
+            OpCode::U32CheckedLtImm => Ok(Instruction::U32CheckedLtImm(bytes.read_u32()?)),
             OpCode::U32UncheckedLt => Ok(Instruction::U32UncheckedLt),
             OpCode::U32CheckedLte => Ok(Instruction::U32CheckedLte),
+            OpCode::U32CheckedLteImm => Ok(Instruction::U32CheckedLteImm(bytes.read_u32()?)),
             OpCode::U32UncheckedLte => Ok(Instruction::U32UncheckedLte),
             OpCode::U32CheckedGt => Ok(Instruction::U32CheckedGt),
+            OpCode::U32CheckedGtImm => Ok(Instruction::U32CheckedGtImm(bytes.read_u32()?)),
             OpCode::U32UncheckedGt => Ok(Instruction::U32UncheckedGt),
             OpCode::U32CheckedGte => Ok(Instruction::U32CheckedGte),
+            OpCode::U32CheckedGteImm => Ok(Instruction::U32CheckedGteImm(bytes.read_u32()?)),
             OpCode::U32UncheckedGte => Ok(Instruction::U32UncheckedGte),
             OpCode::U32CheckedMin => Ok(Instruction::U32CheckedMin),
             OpCode::U32UncheckedMin => Ok(Instruction::U32UncheckedMin),
@@ -383,6 +409,7 @@ impl Deserializable for Instruction {
             OpCode::Locaddr => Ok(Instruction::Locaddr(bytes.read_u16()?)),
             OpCode::Sdepth => Ok(Instruction::Sdepth),
             OpCode::Caller => Ok(Instruction::Caller),
+            OpCode::PushMastRoot => Ok(Instruction::PushMastRoot),
 
             OpCode::MemLoad => Ok(Instruction::MemLoad),
             OpCode::MemLoadImm => Ok(Instruction::MemLoadImm(bytes.read_u32()?)),
@@ -401,6 +428,7 @@ impl Deserializable for Instruction {
             OpCode::AdvPipe => Ok(Instruction::AdvPipe),
 
             OpCode::AdvU64Div => Ok(Instruction::AdvU64Div),
+            OpCode::AdvU256Div => Ok(Instruction::AdvU256Div),
             OpCode::AdvKeyval => Ok(Instruction::AdvKeyval),
             OpCode::AdvMem => {
                 let start_addr = bytes.read_u32()?;
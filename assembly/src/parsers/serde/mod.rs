@@ -17,8 +17,11 @@ const WHILE_OPCODE: u8 = 255;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, TryFromPrimitive)]
 pub enum OpCode {
     Assert = 0,
+    AssertWithError = 228,
     AssertEq = 1,
+    AssertEqWithError = 229,
     Assertz = 2,
+    AssertzWithError = 230,
     Add = 3,
     AddImm = 4,
     Sub = 5,
@@ -43,9 +46,13 @@ pub enum OpCode {
     NeqImm = 24,
     Eqw = 25,
     Lt = 26,
+    LtImm = 220,
     Lte = 27,
+    LteImm = 221,
     Gt = 28,
+    GtImm = 222,
     Gte = 29,
+    GteImm = 223,
 
     // ----- u32 manipulation ---------------------------------------------------------------
     U32Test = 30,
@@ -114,12 +121,16 @@ pub enum OpCode {
     U32CheckedNeq = 93,
     U32CheckedNeqImm = 94,
     U32CheckedLt = 95,
+    U32CheckedLtImm = 224,
     U32UncheckedLt = 96,
     U32CheckedLte = 97,
+    U32CheckedLteImm = 225,
     U32UncheckedLte = 98,
     U32CheckedGt = 99,
+    U32CheckedGtImm = 226,
     U32UncheckedGt = 100,
     U32CheckedGte = 101,
+    U32CheckedGteImm = 227,
     U32UncheckedGte = 102,
     U32CheckedMin = 103,
     U32UncheckedMin = 104,
@@ -212,6 +223,7 @@ pub enum OpCode {
     Locaddr = 186,
     Sdepth = 187,
     Caller = 188,
+    PushMastRoot = 219,
 
     MemLoad = 189,
     MemLoadImm = 190,
@@ -233,6 +245,7 @@ pub enum OpCode {
     AdvLoadW = 204,
 
     AdvU64Div = 205,
+    AdvU256Div = 231,
     AdvKeyval = 206,
     AdvMem = 207,
 
@@ -1,10 +1,12 @@
 use super::{
-    super::nodes::{Instruction, Node},
+    super::nodes::{CodeBody, Instruction, Node},
     OpCode, IF_ELSE_OPCODE, REPEAT_OPCODE, WHILE_OPCODE,
 };
 use crate::{
-    errors::SerializationError, Felt, ProcedureId, StarkField, String, Vec, MAX_PROC_NAME_LEN,
+    errors::SerializationError, Felt, InputSpec, ProcedureId, StarkField, String, Vec,
+    MAX_PROC_NAME_LEN,
 };
+use vm_core::utils::Serializable as _;
 
 // BYTE WRITER IMPLEMENTATION
 // ================================================================================================
@@ -79,6 +81,11 @@ impl ByteWriter {
         self.write_u8(val as u8);
     }
 
+    /// Writes the declared inputs using their encoding defined in `vm_core`.
+    pub fn write_input_spec(&mut self, val: &InputSpec) {
+        self.0.append(&mut val.to_bytes());
+    }
+
     pub fn into_bytes(self) -> Vec<u8> {
         self.0
     }
@@ -92,11 +99,11 @@ pub trait Serializable: Sized {
     fn write_into(&self, target: &mut ByteWriter);
 }
 
-impl Serializable for Vec<Node> {
+impl Serializable for CodeBody {
     fn write_into(&self, target: &mut ByteWriter) {
         target.write_u16(self.len() as u16);
 
-        for node in self {
+        for node in self.iter() {
             node.write_into(target);
         }
     }
@@ -135,8 +142,20 @@ impl Serializable for Instruction {
     fn write_into(&self, target: &mut ByteWriter) {
         match self {
             Self::Assert => target.write_opcode(OpCode::Assert),
+            Self::AssertWithError(err_code) => {
+                target.write_opcode(OpCode::AssertWithError);
+                target.write_u32(*err_code);
+            }
             Self::AssertEq => target.write_opcode(OpCode::AssertEq),
+            Self::AssertEqWithError(err_code) => {
+                target.write_opcode(OpCode::AssertEqWithError);
+                target.write_u32(*err_code);
+            }
             Self::Assertz => target.write_opcode(OpCode::Assertz),
+            Self::AssertzWithError(err_code) => {
+                target.write_opcode(OpCode::AssertzWithError);
+                target.write_u32(*err_code);
+            }
             Self::Add => target.write_opcode(OpCode::Add),
             Self::AddImm(v) => {
                 target.write_opcode(OpCode::AddImm);
@@ -185,9 +204,25 @@ impl Serializable for Instruction {
             }
             Self::Eqw => target.write_opcode(OpCode::Eqw),
             Self::Lt => target.write_opcode(OpCode::Lt),
+            Self::LtImm(v) => {
+                target.write_opcode(OpCode::LtImm);
+                target.write_felt(*v);
+            }
             Self::Lte => target.write_opcode(OpCode::Lte),
+            Self::LteImm(v) => {
+                target.write_opcode(OpCode::LteImm);
+                target.write_felt(*v);
+            }
             Self::Gt => target.write_opcode(OpCode::Gt),
+            Self::GtImm(v) => {
+                target.write_opcode(OpCode::GtImm);
+                target.write_felt(*v);
+            }
             Self::Gte => target.write_opcode(OpCode::Gte),
+            Self::GteImm(v) => {
+                target.write_opcode(OpCode::GteImm);
+                target.write_felt(*v);
+            }
 
             // ----- u32 operations ---------------------------------------------------------------
             Self::U32Test => target.write_opcode(OpCode::U32Test),
@@ -331,12 +366,28 @@ impl Serializable for Instruction {
                 target.write_u32(*v);
             }
             Self::U32CheckedLt => target.write_opcode(OpCode::U32CheckedLt),
+            Self::U32CheckedLtImm(v) => {
+                target.write_opcode(OpCode::U32CheckedLtImm);
+                target.write_u32(*v);
+            }
             Self::U32UncheckedLt => target.write_opcode(OpCode::U32UncheckedLt),
             Self::U32CheckedLte => target.write_opcode(OpCode::U32CheckedLte),
+            Self::U32CheckedLteImm(v) => {
+                target.write_opcode(OpCode::U32CheckedLteImm);
+                target.write_u32(*v);
+            }
             Self::U32UncheckedLte => target.write_opcode(OpCode::U32UncheckedLte),
             Self::U32CheckedGt => target.write_opcode(OpCode::U32CheckedGt),
+            Self::U32CheckedGtImm(v) => {
+                target.write_opcode(OpCode::U32CheckedGtImm);
+                target.write_u32(*v);
+            }
             Self::U32UncheckedGt => target.write_opcode(OpCode::U32UncheckedGt),
             Self::U32CheckedGte => target.write_opcode(OpCode::U32CheckedGte),
+            Self::U32CheckedGteImm(v) => {
+                target.write_opcode(OpCode::U32CheckedGteImm);
+                target.write_u32(*v);
+            }
             Self::U32UncheckedGte => target.write_opcode(OpCode::U32UncheckedGte),
             Self::U32CheckedMin => target.write_opcode(OpCode::U32CheckedMin),
             Self::U32UncheckedMin => target.write_opcode(OpCode::U32UncheckedMin),
@@ -435,6 +486,7 @@ impl Serializable for Instruction {
             }
             Self::Sdepth => target.write_opcode(OpCode::Sdepth),
             Self::Caller => target.write_opcode(OpCode::Caller),
+            Self::PushMastRoot => target.write_opcode(OpCode::PushMastRoot),
 
             Self::MemLoad => target.write_opcode(OpCode::MemLoad),
             Self::MemLoadImm(v) => {
@@ -477,6 +529,7 @@ impl Serializable for Instruction {
             Self::AdvPipe => target.write_opcode(OpCode::AdvPipe),
 
             Self::AdvU64Div => target.write_opcode(OpCode::AdvU64Div),
+            Self::AdvU256Div => target.write_opcode(OpCode::AdvU256Div),
             Self::AdvKeyval => target.write_opcode(OpCode::AdvKeyval),
             Self::AdvMem(start_addr, num_words) => {
                 target.write_opcode(OpCode::AdvMem);
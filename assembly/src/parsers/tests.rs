@@ -1,6 +1,7 @@
 use super::{
-    parse_module, parse_program, BTreeMap, Felt, Instruction, LocalProcMap, ModuleAst, Node,
-    ProcedureAst, ProcedureId, ProgramAst,
+    parse_module, parse_program, BTreeMap, BTreeSet, CodeBody, Felt, InputSpec, Instruction,
+    LocalProcMap, ModuleAst, Node, ProcReExport, ProcedureAst, ProcedureId, ProgramAst,
+    SerializationError, PROGRAM_AST_VERSION,
 };
 use crate::{ONE, ZERO};
 
@@ -79,7 +80,7 @@ fn test_ast_parsing_program_proc() {
                 docs: None,
                 is_export: false,
                 num_locals: 1,
-                body: proc_body1,
+                body: proc_body1.into(),
             },
         ),
     );
@@ -93,7 +94,7 @@ fn test_ast_parsing_program_proc() {
                 docs: None,
                 is_export: false,
                 num_locals: 2,
-                body: proc_body2,
+                body: proc_body2.into(),
             },
         ),
     );
@@ -121,7 +122,7 @@ fn test_ast_parsing_module() {
                 docs: None,
                 is_export: true,
                 num_locals: 1,
-                body: proc_body,
+                body: proc_body.into(),
             },
         ),
     );
@@ -139,6 +140,128 @@ fn test_ast_parsing_module() {
     }
 }
 
+#[test]
+fn test_ast_module_procedures() {
+    let source = "\
+    #! Returns 1 if the u256 value is zero, and 0 otherwise.
+    export.iszero_unsafe
+        eq.0
+        repeat.7
+            swap
+            eq.0
+            and
+        end
+    end
+
+    proc.helper.2
+        loc_store.1
+    end";
+    let module = parse_module(source).unwrap();
+    let procedures = module.procedures().collect::<Vec<_>>();
+    assert_eq!(2, procedures.len());
+
+    let iszero = procedures[0];
+    assert_eq!("iszero_unsafe", iszero.name);
+    assert!(iszero.is_export);
+    assert_eq!(0, iszero.num_locals);
+    assert_eq!(
+        Some("Returns 1 if the u256 value is zero, and 0 otherwise."),
+        iszero.docs
+    );
+
+    let helper = procedures[1];
+    assert_eq!("helper", helper.name);
+    assert!(!helper.is_export);
+    assert_eq!(2, helper.num_locals);
+    assert_eq!(None, helper.docs);
+}
+
+#[test]
+fn test_ast_module_reexports() {
+    let source = "\
+    use.std::math::u256
+    use.std::math::u64->long
+
+    export.u256::iszero_unsafe
+    export.long::checked_add->add64
+
+    export.foo
+        exec.u256::iszero_unsafe
+    end";
+    let module = parse_module(source).unwrap();
+    assert_eq!(1, module.local_procs.len());
+    let expected = vec![
+        ProcReExport {
+            name: "iszero_unsafe".to_string(),
+            proc_id: ProcedureId::new("std::math::u256::iszero_unsafe"),
+        },
+        ProcReExport {
+            name: "add64".to_string(),
+            proc_id: ProcedureId::new("std::math::u64::checked_add"),
+        },
+    ];
+    assert_eq!(expected, module.reexported_procs);
+    assert_eq!(
+        vec!["foo", "iszero_unsafe", "add64"],
+        module.proc_names().collect::<Vec<_>>()
+    );
+
+    // re-exports are preserved by serialization
+    assert_eq!(module, ModuleAst::from_bytes(&module.to_bytes()).unwrap());
+
+    // a re-exported procedure must belong to an imported module, its name must not be taken by
+    // another procedure, and it cannot be re-exported from a program
+    let errors = [
+        "export.u256::iszero_unsafe",
+        "use.std::math::u256 export.u256::eq export.u256::eq",
+        "use.std::math::u256 export.eq push.1 end export.u256::eq",
+        "use.std::math::u256 export.u256::eq->1eq",
+        "use.std::math::u256 export.u256::eq.1",
+    ];
+    for source in errors {
+        parse_module(source).expect_err(source);
+    }
+    parse_program("use.std::math::u256 export.u256::eq begin push.1 end")
+        .expect_err("re-export in a program");
+}
+
+#[test]
+fn test_ast_module_imported_procs() {
+    let source = "\
+    use.std::math::u256
+    use.std::math::u64
+    use.std::sys
+
+    export.u256::iszero_unsafe
+
+    proc.foo
+        exec.u64::checked_add
+    end
+
+    export.bar
+        if.true
+            while.true
+                call.u64::checked_add
+            end
+        else
+            repeat.2
+                exec.sys::truncate_stack
+            end
+        end
+        exec.foo
+    end";
+    let module = parse_module(source).unwrap();
+    let expected = [
+        ProcedureId::new("std::math::u256::iszero_unsafe"),
+        ProcedureId::new("std::math::u64::checked_add"),
+        ProcedureId::new("std::sys::truncate_stack"),
+    ];
+    assert_eq!(
+        expected.into_iter().collect::<BTreeSet<_>>(),
+        module.imported_procs()
+    );
+}
+
 #[test]
 fn test_ast_parsing_adv_ops() {
     let source = "begin adv_push.1 adv_loadw end";
@@ -153,9 +276,10 @@ fn test_ast_parsing_adv_ops() {
 
 #[test]
 fn test_ast_parsing_adv_injection() {
-    let source = "begin adv.u64div adv.keyval adv.mem.1.1 end";
+    let source = "begin adv.u64div adv.u256div adv.keyval adv.mem.1.1 end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(Instruction::AdvU64Div),
+        Node::Instruction(Instruction::AdvU256Div),
         Node::Instruction(Instruction::AdvKeyval),
         Node::Instruction(Instruction::AdvMem(1, 1)),
     ];
@@ -177,6 +301,22 @@ fn test_ast_parsing_use() {
     assert_program_output(source, procedures, nodes);
 }
 
+#[test]
+fn test_ast_parsing_proc_id() {
+    let proc_id = ProcedureId::new("std::abc::foo::bar");
+    let source = format!("begin exec.{proc_id} call.{proc_id} end");
+    let procedures: LocalProcMap = BTreeMap::new();
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::ExecImported(proc_id)),
+        Node::Instruction(Instruction::CallImported(proc_id)),
+    ];
+    assert_program_output(&source, procedures, nodes);
+
+    // a procedure ID must be exactly 24 bytes long
+    let source = "begin exec.0x0102 end";
+    assert!(parse_program(source).is_err());
+}
+
 #[test]
 fn test_ast_parsing_module_nested_if() {
     let source = "\
@@ -207,16 +347,19 @@ fn test_ast_parsing_module_nested_if() {
                         Node::Instruction(Instruction::PushConstants([ZERO].to_vec())),
                         Node::Instruction(Instruction::Sub),
                     ]
-                    .to_vec(),
+                    .to_vec()
+                    .into(),
                     [
                         Node::Instruction(Instruction::PushConstants([ONE].to_vec())),
                         Node::Instruction(Instruction::Sub),
                     ]
-                    .to_vec(),
+                    .to_vec()
+                    .into(),
                 ),
             ]
-            .to_vec(),
-            vec![],
+            .to_vec()
+            .into(),
+            CodeBody::default(),
         ),
     ];
     procedures.insert(
@@ -228,7 +371,7 @@ fn test_ast_parsing_module_nested_if() {
                 docs: None,
                 is_export: false,
                 num_locals: 0,
-                body: proc_body,
+                body: proc_body.into(),
             },
         ),
     );
@@ -272,20 +415,23 @@ fn test_ast_parsing_module_sequential_if() {
                 Node::Instruction(Instruction::PushConstants([Felt::new(5)].to_vec())),
                 Node::Instruction(Instruction::PushConstants([ONE].to_vec())),
             ]
-            .to_vec(),
-            vec![],
+            .to_vec()
+            .into(),
+            CodeBody::default(),
         ),
         Node::IfElse(
             [
                 Node::Instruction(Instruction::PushConstants([ZERO].to_vec())),
                 Node::Instruction(Instruction::Sub),
             ]
-            .to_vec(),
+            .to_vec()
+            .into(),
             [
                 Node::Instruction(Instruction::PushConstants([ONE].to_vec())),
                 Node::Instruction(Instruction::Sub),
             ]
-            .to_vec(),
+            .to_vec()
+            .into(),
         ),
     ];
     procedures.insert(
@@ -297,7 +443,7 @@ fn test_ast_parsing_module_sequential_if() {
                 docs: None,
                 is_export: false,
                 num_locals: 0,
-                body: proc_body,
+                body: proc_body.into(),
             },
         ),
     );
@@ -357,7 +503,7 @@ of the comments is correctly parsed. There was a bug here earlier."
                 docs: Some(docs_foo),
                 is_export: true,
                 num_locals: 1,
-                body: proc_body_foo,
+                body: proc_body_foo.into(),
             },
         ),
     );
@@ -372,7 +518,7 @@ of the comments is correctly parsed. There was a bug here earlier."
                 docs: None,
                 is_export: false,
                 num_locals: 2,
-                body: proc_body_bar,
+                body: proc_body_bar.into(),
             },
         ),
     );
@@ -395,7 +541,7 @@ aliqua."
                 docs: Some(docs_baz),
                 is_export: true,
                 num_locals: 3,
-                body: proc_body_baz,
+                body: proc_body_baz.into(),
             },
         ),
     );
@@ -430,6 +576,174 @@ fn test_ast_parsing_module_docs_fail() {
     parse_module(source).expect_err("comment message should not have empty lines");
 }
 
+#[test]
+fn test_ast_parsing_malformed_sources() {
+    // module of an invoked procedure is not imported
+    let error = parse_program("begin exec.foo::bar end").unwrap_err();
+    assert_eq!(error.message(), "module not imported: foo");
+    let error = parse_program("use.std::foo begin call.bar::baz end").unwrap_err();
+    assert_eq!(error.message(), "module not imported: bar");
+
+    // import path without a namespace
+    let error = parse_program("use.foo begin push.1 end").unwrap_err();
+    assert_eq!(error.message(), "invalid module import path: foo");
+
+    // undefined procedure invoked at the end of the source
+    let error = parse_module("export.foo exec.bar").unwrap_err();
+    assert_eq!(error.message(), "undefined procedure: bar");
+    assert_eq!(error.operation(), "exec.bar");
+
+    // advice injector without parameters
+    assert!(parse_program("begin adv end").is_err());
+
+    // multi-element hex constant with non-ASCII characters
+    let source = format!("begin push.0x{} end", "\u{e9}".repeat(16));
+    assert!(parse_program(&source).is_err());
+}
+
+#[test]
+fn test_ast_parsing_error_locations() {
+    // errors are located at the token which caused them; comments and doc comments preceding the
+    // token do not affect its location
+    let source = "\
+    #! Doc comment of foo.
+    export.foo  /* block comment
+    spanning two lines */ push.1 # line comment
+        exec.bar
+    end";
+    let error = parse_module(source).unwrap_err();
+    assert_eq!(error.message(), "undefined procedure: bar");
+    let location = error.location().unwrap();
+    assert_eq!((4, 9, 8), (location.line, location.column, location.len));
+
+    // errors which do not occur at a token have no location
+    let error = parse_program("proc.foo push.1 end").unwrap_err();
+    assert_eq!(error.message(), "unexpected EOF");
+    assert_eq!(None, error.location());
+}
+
+#[test]
+fn test_ast_parsing_unknown_instruction_suggestions() {
+    // a close typo is reported together with the closest instruction name
+    let error = parse_program("begin pus.1 end").unwrap_err();
+    assert_eq!(
+        error.message(),
+        "instruction 'pus.1' is invalid; did you mean 'push'?"
+    );
+    assert_eq!(error.operation(), "pus.1");
+
+    let error = parse_program("begin push.1 u32checked_ad end").unwrap_err();
+    assert_eq!(
+        error.message(),
+        "instruction 'u32checked_ad' is invalid; did you mean 'u32checked_add'?"
+    );
+
+    // no suggestion is made when no instruction name is close enough
+    let error = parse_program("begin qwertyuiop end").unwrap_err();
+    assert_eq!(error.message(), "instruction 'qwertyuiop' is invalid");
+}
+
+#[test]
+fn test_ast_parsing_inputs_annotation() {
+    let source = "\
+    #! Adds the top stack value to the product of the next one and an advice value.
+    #! @inputs(stack=2, advice=1)
+    begin
+        adv_push.1 mul add
+    end";
+    let program = parse_program(source).unwrap();
+    assert_eq!(Some(InputSpec::new(Some(2), Some(1))), program.input_spec());
+
+    // the annotation is preserved by display and serialization
+    assert_eq!(program, parse_program(&program.to_string()).unwrap());
+    assert_eq!(
+        program,
+        ProgramAst::from_bytes(&program.to_bytes()).unwrap()
+    );
+
+    // either of the parameters can be omitted
+    let program = parse_program("#! @inputs(advice=6)\nbegin push.1 end").unwrap();
+    assert_eq!(Some(InputSpec::new(None, Some(6))), program.input_spec());
+
+    // doc comments without the annotation do not declare inputs
+    let program = parse_program("#! Pushes one.\nbegin push.1 end").unwrap();
+    assert_eq!(None, program.input_spec());
+
+    let error = parse_program("#! @inputs(stack=2, locals=1)\nbegin push.1 end").unwrap_err();
+    assert_eq!(
+        error.message(),
+        "invalid inputs annotation '@inputs(stack=2, locals=1)': only stack and advice inputs can \
+        be declared"
+    );
+    assert!(parse_program("#! @inputs(stack=x)\nbegin push.1 end").is_err());
+    assert!(parse_program("#! @inputs(stack=1, stack=2)\nbegin push.1 end").is_err());
+    assert!(parse_program("#! @inputs stack=1\nbegin push.1 end").is_err());
+    assert!(parse_program("#! @inputs(stack=1)\n#! @inputs(advice=1)\nbegin push.1 end").is_err());
+}
+
+// DISPLAY TESTS
+// ================================================================================================
+
+#[test]
+fn test_ast_program_display() {
+    let source = "\
+    proc.foo.1
+        loc_store.0
+    end
+
+    proc.bar
+        exec.foo
+    end
+
+    begin
+        push.1.2 exec.bar call.foo
+        if.true
+            repeat.2 add end
+        else
+            while.true push.0 end
+        end
+        if.true mul end
+    end";
+    let program = parse_program(source).unwrap();
+    let expected = "\
+proc.foo.1
+    loc_store.0
+end
+
+proc.bar
+    exec.foo
+end
+
+begin
+    push.1.2
+    exec.bar
+    call.foo
+    if.true
+        repeat.2
+            add
+        end
+    else
+        while.true
+            push.0
+        end
+    end
+    if.true
+        mul
+    end
+end";
+    assert_eq!(expected, program.to_string());
+    assert_eq!(program, parse_program(&program.to_string()).unwrap());
+
+    // imported procedures are referenced by their IDs
+    let program = parse_program("use.std::foo begin exec.foo::bar end").unwrap();
+    let proc_id = ProcedureId::new("std::foo::bar");
+    assert_eq!(
+        format!("begin\n    exec.{proc_id}\nend"),
+        program.to_string()
+    );
+    assert_eq!(program, parse_program(&program.to_string()).unwrap());
+}
+
 // SERIALIZATION AND DESERIALIZATION TESTS
 // ================================================================================================
 
@@ -443,6 +757,28 @@ fn test_ast_program_serde_simple() {
     assert_eq!(program, program_deserialized);
 }
 
+#[test]
+fn test_ast_program_serde_version() {
+    let program = parse_program("#! @inputs(stack=2)\nbegin push.1 end").unwrap();
+    let mut bytes = program.to_bytes();
+    assert_eq!(PROGRAM_AST_VERSION, bytes[0]);
+
+    // encodings of other versions are rejected
+    bytes[0] = PROGRAM_AST_VERSION + 1;
+    assert!(matches!(
+        ProgramAst::from_bytes(&bytes),
+        Err(SerializationError::UnsupportedVersion(version)) if version == PROGRAM_AST_VERSION + 1
+    ));
+
+    // declared inputs are read using the encoding defined by the VM core
+    bytes[0] = PROGRAM_AST_VERSION;
+    *bytes.last_mut().unwrap() = 2;
+    assert!(matches!(
+        ProgramAst::from_bytes(&bytes),
+        Err(SerializationError::InvalidInputSpec)
+    ));
+}
+
 #[test]
 fn test_ast_program_serde_local_procs() {
     let source = "\
@@ -518,7 +854,7 @@ fn test_ast_program_serde_control_flow() {
 
 fn assert_program_output(source: &str, procedures: LocalProcMap, body: Vec<Node>) {
     let program = parse_program(source).unwrap();
-    assert_eq!(program.body, body);
+    assert_eq!(program.body.nodes(), body);
     assert_eq!(program.local_procs.len(), procedures.len());
     for (i, proc) in program.local_procs.iter().enumerate() {
         assert_eq!(
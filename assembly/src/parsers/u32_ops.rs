@@ -457,3 +457,79 @@ pub fn parse_u32checked_neq(op: &Token) -> Result<Node, ParsingError> {
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+/// Returns `U32CheckedLt` instruction node if no immediate value is provided or
+/// `U32CheckedLtImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32checked_lt(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32checked_lt");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32CheckedLt)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32CheckedLtImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32CheckedLte` instruction node if no immediate value is provided or
+/// `U32CheckedLteImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32checked_lte(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32checked_lte");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32CheckedLte)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32CheckedLteImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32CheckedGt` instruction node if no immediate value is provided or
+/// `U32CheckedGtImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32checked_gt(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32checked_gt");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32CheckedGt)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32CheckedGtImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32CheckedGte` instruction node if no immediate value is provided or
+/// `U32CheckedGteImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32checked_gte(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32checked_gte");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32CheckedGte)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32CheckedGteImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
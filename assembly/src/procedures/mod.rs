@@ -1,4 +1,4 @@
-use super::{BTreeSet, CodeBlock, Felt, String, MODULE_PATH_DELIM};
+use super::{Arc, BTreeSet, CodeBlock, Felt, String, ToString, Vec, MODULE_PATH_DELIM};
 use core::{fmt, ops};
 use crypto::{hashers::Blake3_256, Digest, Hasher};
 
@@ -12,29 +12,39 @@ pub struct Procedure {
     label: String,
     is_export: bool,
     num_locals: u32,
-    code_root: CodeBlock,
+    max_local_frame: u64,
+    code_root: Arc<CodeBlock>,
     callset: CallSet,
+    inlining_trace: Arc<InliningTrace>,
 }
 
 impl Procedure {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new [Procedure] instantiated with the specified properties.
-    pub fn new(
-        id: ProcedureId,
-        label: String,
-        is_export: bool,
-        num_locals: u32,
-        code_root: CodeBlock,
-        callset: CallSet,
-    ) -> Self {
+    /// Returns a new [Procedure] with the specified MAST and metadata.
+    ///
+    /// The MAST and the inlining trace of the procedure are shared by all clones of the procedure
+    /// (e.g., the ones kept in procedure caches of assemblers) rather than copied.
+    pub fn new(code_root: CodeBlock, metadata: ProcedureMetadata) -> Self {
+        let ProcedureMetadata {
+            id,
+            label,
+            is_export,
+            num_locals,
+            max_local_frame,
+            callset,
+            inlining_trace,
+        } = metadata;
+
         Procedure {
             id,
             label,
             is_export,
             num_locals,
-            code_root,
+            max_local_frame,
+            code_root: Arc::new(code_root),
             callset,
+            inlining_trace: Arc::new(inlining_trace),
         }
     }
 
@@ -57,21 +67,61 @@ impl Procedure {
     }
 
     /// Returns the number of memory locals reserved by the procedure.
-    #[allow(dead_code)]
     pub fn num_locals(&self) -> u32 {
         self.num_locals
     }
 
+    /// Returns the peak number of memory locals reserved at the same time during the execution of
+    /// this procedure.
+    ///
+    /// This is the number of locals of this procedure plus the peak local frame of procedures
+    /// inlined into it, maximized over all inlined call paths. Procedures invoked via `call` or
+    /// `syscall` instructions start a new frame in their own memory context; their peak local
+    /// frames are not added to the frame of this procedure, but still bound the returned value
+    /// from below.
+    pub fn max_local_frame(&self) -> u64 {
+        self.max_local_frame
+    }
+
     /// Returns a root of this procedure's MAST.
     pub fn code_root(&self) -> &CodeBlock {
         &self.code_root
     }
 
+    /// Returns a shared reference to the root of this procedure's MAST.
+    ///
+    /// Code blocks built from the returned reference (e.g., when the procedure is inlined) share
+    /// the MAST of this procedure rather than copying it.
+    pub(crate) fn shared_code_root(&self) -> &Arc<CodeBlock> {
+        &self.code_root
+    }
+
     /// Returns a reference to a set of all procedures (identified by their IDs) which may be
     /// called during the execution of this procedure.
     pub fn callset(&self) -> &CallSet {
         &self.callset
     }
+
+    /// Returns a reference to a list of all procedures inlined into this procedure's MAST.
+    pub fn inlining_trace(&self) -> &InliningTrace {
+        &self.inlining_trace
+    }
+}
+
+// PROCEDURE METADATA
+// ================================================================================================
+
+/// Properties of a procedure other than its MAST; see the accessors of [Procedure] for the
+/// meaning of each property.
+#[derive(Debug, Clone)]
+pub struct ProcedureMetadata {
+    pub id: ProcedureId,
+    pub label: String,
+    pub is_export: bool,
+    pub num_locals: u32,
+    pub max_local_frame: u64,
+    pub callset: CallSet,
+    pub inlining_trace: InliningTrace,
 }
 
 // PROCEDURE ID
@@ -114,6 +164,9 @@ impl ProcedureId {
     /// TODO better use `MODULE_PATH_DELIM`. maybe require `const_format` crate?
     pub const KERNEL_PATH: &str = "::sys";
 
+    /// Path of the prelude module (see `Assembler::with_prelude()`)
+    pub const PRELUDE_PATH: &str = "::prelude";
+
     /// Creates a new procedure id from its path, composed by module path + name identifier.
     ///
     /// No validation is performed regarding the consistency of the path format.
@@ -146,6 +199,11 @@ impl ProcedureId {
         Self::new(path)
     }
 
+    /// Creates a new procedure ID from a name to be resolved in the prelude module.
+    pub fn from_prelude_name(name: &str) -> Self {
+        Self::from_name(name, Self::PRELUDE_PATH)
+    }
+
     /// Creates a new procedure ID from its name and module path.
     ///
     /// No validation is performed regarding the consistency of the module path or procedure name
@@ -155,6 +213,23 @@ impl ProcedureId {
         Self::new(path)
     }
 
+    /// Creates a new procedure ID from its hex representation prefixed with `0x` (i.e., the same
+    /// format which is used when a procedure ID is displayed).
+    ///
+    /// Returns None if the provided string is not a valid hex-encoded procedure ID.
+    pub fn from_hex(value: &str) -> Option<Self> {
+        let value = value.strip_prefix("0x")?;
+        if value.len() != Self::SIZE * 2 || !value.is_ascii() {
+            return None;
+        }
+
+        let mut digest = [0u8; Self::SIZE];
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&value[i * 2..i * 2 + 2], 16).ok()?;
+        }
+        Some(Self(digest))
+    }
+
     /// Creates a new procedure ID from its local index and module path.
     ///
     /// No validation is performed regarding the consistency of the module path format.
@@ -195,3 +270,89 @@ impl ops::Deref for CallSet {
         &self.0
     }
 }
+
+// INLINING TRACE
+// ================================================================================================
+
+/// Contains a list of procedures inlined into a MAST via `exec` instructions, in the order in
+/// which they were inlined.
+///
+/// Each inline site is followed by the inline sites of the inlined procedure itself. Thus, if a
+/// procedure is inlined several times, its inline sites are listed for each of its occurrences.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InliningTrace(Vec<InlineSite>);
+
+impl InliningTrace {
+    pub(crate) fn push(&mut self, site: InlineSite) {
+        self.0.push(site);
+    }
+
+    pub(crate) fn append(&mut self, other: &InliningTrace) {
+        self.0.extend_from_slice(&other.0);
+    }
+}
+
+impl ops::Deref for InliningTrace {
+    type Target = [InlineSite];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for InliningTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for site in self.0.iter() {
+            writeln!(f, "{site}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Describes a single `exec` instruction which resulted in a procedure being inlined into the
+/// body of another procedure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineSite {
+    caller: String,
+    callee: String,
+    position: usize,
+}
+
+impl InlineSite {
+    pub(crate) fn new(caller: &str, callee: &str, position: usize) -> Self {
+        Self {
+            caller: caller.to_string(),
+            callee: callee.to_string(),
+            position,
+        }
+    }
+
+    /// Returns the name of the procedure into which the callee was inlined. For the body of a
+    /// program, the name is `#main`.
+    pub fn caller(&self) -> &str {
+        &self.caller
+    }
+
+    /// Returns the name of the inlined procedure.
+    pub fn callee(&self) -> &str {
+        &self.callee
+    }
+
+    /// Returns the position of the `exec` instruction in the body of the caller.
+    ///
+    /// Instructions are counted from zero in the order in which they appear in the source code,
+    /// including instructions nested in control flow blocks.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for InlineSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} inlined {} at {}",
+            self.caller, self.callee, self.position
+        )
+    }
+}
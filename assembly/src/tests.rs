@@ -1,4 +1,21 @@
-use crate::{parse_module, Assembler, ModuleAst, ModuleProvider, NamedModuleAst, ProcedureId};
+use crate::{
+    disassemble, parse_module, Assembler, AssemblyError, AsyncModuleProvider, DisassemblyError,
+    LibraryModuleProvider, MaslLibrary, ModuleAst, ModuleFuture, ModuleProvider,
+    ModuleProviderChain, NamedModuleAst, ProcedureId, Version,
+};
+use core::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll},
+};
+use futures::executor::block_on;
+use std::{collections::BTreeSet, rc::Rc};
+use vm_core::{
+    code_blocks::CodeBlock, felt_fmt, utils::Arc, CodeBlockTable, Decorator, Felt, Operation,
+    Program,
+};
 
 // SIMPLE PROGRAMS
 // ================================================================================================
@@ -39,6 +56,58 @@ fn single_span() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn multi_value_push() {
+    let assembler = super::Assembler::default();
+
+    // a multi-value push compiles into the same span as the equivalent sequence of pushes
+    let program = assembler.compile("begin push.1.2 add end").unwrap();
+    let expected = assembler.compile("begin push.1 push.2 add end").unwrap();
+    assert_eq!(format!("{expected}"), format!("{program}"));
+    assert_eq!(expected.hash(), program.hash());
+
+    // up to 16 values can be pushed by a single instruction
+    let values = (1..=16).map(|v| v.to_string()).collect::<Vec<_>>();
+    let program = assembler
+        .compile(format!("begin push.{} end", values.join(".")))
+        .unwrap();
+    let expected = assembler
+        .compile(format!("begin push.{} end", values.join(" push.")))
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // a push without values or with more than 16 values is malformed
+    let err = assembler.compile("begin push end").unwrap_err();
+    assert!(err.to_string().contains("malformed instruction 'push'"));
+    let err = assembler
+        .compile(format!("begin push.{}.17 end", values.join(".")))
+        .unwrap_err();
+    assert!(err.to_string().contains("too many parameters provided"));
+    assert!(err
+        .to_string()
+        .contains("push accepts at most 16 values, but 17 were provided"));
+
+    // the limit applies to values of a single hex parameter as well
+    let err = assembler
+        .compile(format!(
+            "begin push.0x{} end",
+            "0000000000000001".repeat(20)
+        ))
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("push accepts at most 16 values, but 20 were provided"));
+
+    // values are pushed via PAD and INCR where possible, and hex and decimal values can be mixed
+    let program = assembler.compile("begin push.0.1 end").unwrap();
+    assert_eq!("begin span pad pad incr end end", format!("{program}"));
+    let program = assembler.compile("begin push.0x0a.11.0x0c.0 end").unwrap();
+    assert_eq!(
+        "begin span push(10) push(11) push(12) pad end end",
+        format!("{program}")
+    );
+}
+
 #[test]
 fn span_and_simple_if() {
     let assembler = super::Assembler::default();
@@ -68,6 +137,333 @@ fn span_and_simple_if() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn span_and_simple_if_pretty() {
+    let assembler = super::Assembler::default();
+    let source = "begin push.2 push.3 if.true add else mul end end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+begin
+    join
+        span push(2) push(3) end
+        if.true
+            span add end
+        else
+            span mul end
+        end
+    end
+end";
+    let pretty = program.display_pretty(4, 80);
+    assert_eq!(expected, pretty);
+    assert_eq!(
+        8,
+        pretty.lines().filter(|line| line.starts_with(' ')).count()
+    );
+
+    // spans which do not fit into the specified width are wrapped
+    let expected = "\
+begin
+  join
+    span
+      push(2)
+      push(3)
+    end
+    if.true
+      span add end
+    else
+      span mul end
+    end
+  end
+end";
+    let pretty = program.display_pretty(2, 20);
+    assert_eq!(expected, pretty);
+    assert!(pretty.lines().all(|line| line.len() <= 20));
+
+    // compact representation is not affected
+    let expected = "\
+        begin \
+            join \
+                span push(2) push(3) end \
+                if.true span add end else span mul end end \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn min_initial_stack_depth() {
+    let assembler = super::Assembler::default();
+
+    let program = assembler.compile("begin add end").unwrap();
+    assert_eq!(Some(2), program.min_initial_stack_depth());
+
+    let program = assembler
+        .compile("begin push.2 add movup.3 drop end")
+        .unwrap();
+    assert_eq!(Some(4), program.min_initial_stack_depth());
+
+    let program = assembler
+        .compile("begin push.2 push.3 add drop end")
+        .unwrap();
+    assert_eq!(Some(0), program.min_initial_stack_depth());
+
+    // only the straight-line prefix of the program is analyzed
+    let source = "begin push.2 add if.true movup.8 add else mul end end";
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(Some(1), program.min_initial_stack_depth());
+
+    // no bound can be established if the program starts with a control flow block
+    let program = assembler
+        .compile("begin if.true add else mul end end")
+        .unwrap();
+    assert_eq!(None, program.min_initial_stack_depth());
+}
+
+#[test]
+fn program_name() {
+    let source = "begin push.2 push.3 add end";
+
+    let program = super::Assembler::default().compile(source).unwrap();
+    assert_eq!(None, program.name());
+
+    // the name does not affect the program hash
+    let assembler = super::Assembler::default().with_program_name("add");
+    let named_program = assembler.compile(source).unwrap();
+    assert_eq!(Some("add"), named_program.name());
+    assert_eq!(program.hash(), named_program.hash());
+}
+
+#[test]
+fn strip_debug() {
+    let source = "\
+        proc.foo push.3 push.7 mul end \
+        begin \
+            push.2 push.3 \
+            if.true add else mul end \
+            call.foo adv.u64div \
+        end";
+    let assembler = super::Assembler::default()
+        .with_debug_mode(true)
+        .with_program_name("foo");
+    let program = assembler.compile(source).unwrap();
+    let (num_asmops, num_injectors) = count_decorators(program.root(), program.cb_table());
+    assert!(num_asmops > 0);
+    assert_eq!(1, num_injectors);
+
+    // stripping removes the source mapping and the name, but retains advice injectors
+    let stripped = program.clone().strip_debug();
+    assert_eq!(program.hash(), stripped.hash());
+    assert_eq!(None, stripped.name());
+    assert_eq!(format!("{program}"), format!("{stripped}"));
+    assert_eq!(
+        (0, 1),
+        count_decorators(stripped.root(), stripped.cb_table())
+    );
+
+    // a stripped debug program is the same as the program compiled in non-debug mode
+    let program = super::Assembler::default().compile(source).unwrap();
+    assert_eq!(program.hash(), stripped.hash());
+    assert_eq!((0, 1), count_decorators(program.root(), program.cb_table()));
+}
+
+#[test]
+fn hex_immediates() {
+    let assembler = super::Assembler::default();
+
+    // hexadecimal immediates compile into the same programs as their decimal counterparts
+    for (hex, decimal) in [
+        ("begin push.0x0a end", "begin push.10 end"),
+        (
+            "begin push.0x0A push.0x1.0x2 end",
+            "begin push.10 push.1.2 end",
+        ),
+        (
+            "begin mem_load.0x10 mem_store.0xff mem_loadw.0x0 end",
+            "begin mem_load.16 mem_store.255 mem_loadw.0 end",
+        ),
+        (
+            "proc.foo.2 loc_store.0x1 loc_load.0x1 locaddr.0x0 end begin exec.foo end",
+            "proc.foo.2 loc_store.1 loc_load.1 locaddr.0 end begin exec.foo end",
+        ),
+        (
+            "begin u32checked_add.0xff end",
+            "begin u32checked_add.255 end",
+        ),
+        (
+            "begin eq.0xdead neq.0x3fff end",
+            "begin eq.57005 neq.16383 end",
+        ),
+        (
+            "begin push.0x0000000000000001000000000000000200000000000000030000000000000004 end",
+            "begin push.1.2.3.4 end",
+        ),
+    ] {
+        let hex_program = assembler.compile(hex).unwrap();
+        let decimal_program = assembler.compile(decimal).unwrap();
+        assert_eq!(format!("{decimal_program}"), format!("{hex_program}"));
+        assert_eq!(decimal_program.hash(), hex_program.hash());
+    }
+
+    // values are validated in the same way as decimal values
+    let error = assembler
+        .compile("begin push.0xffffffff00000001 end")
+        .unwrap_err();
+    assert_eq!(
+        "malformed instruction 'push.0xffffffff00000001', parameter 0xffffffff00000001 is \
+        invalid: parameter value must be smaller than 18446744069414584321 at line 1, column 7",
+        error.to_string()
+    );
+    let error = assembler
+        .compile("begin mem_load.0x100000000 end")
+        .unwrap_err();
+    assert_eq!(
+        "malformed instruction `mem_load.0x100000000`: parameter \
+        '0x100000000' is invalid at line 1, column 7",
+        error.to_string()
+    );
+
+    // malformed hex strings are reported as such
+    for (source, param) in [
+        ("begin mem_load.0x end", "0x"),
+        ("begin mem_load.0x+1 end", "0x+1"),
+        ("begin push.0xabc end", "0xabc"),
+        ("begin push.0xzz end", "0xzz"),
+        ("begin push.0x1.0xg end", "0xg"),
+        ("begin push.0x00000000000000010 end", "0x00000000000000010"),
+    ] {
+        let error = assembler.compile(source).unwrap_err();
+        let op = &source[6..source.len() - 4];
+        assert_eq!(
+            format!(
+                "malformed instruction `{op}`: invalid hex immediate \
+                '{param}' at line 1, column 7"
+            ),
+            error.to_string()
+        );
+    }
+}
+
+#[test]
+fn negative_immediates() {
+    let assembler = super::Assembler::default();
+
+    // negative immediates are negated in the field; -0 is the same as 0
+    for (negative, positive) in [
+        ("begin push.-1 end", "begin push.18446744069414584320 end"),
+        (
+            "begin push.-0.-2 add.-5 mul.-1 end",
+            "begin push.0.18446744069414584319 add.18446744069414584316 \
+            mul.18446744069414584320 end",
+        ),
+        (
+            "begin eq.-1 neq.-7 end",
+            "begin eq.18446744069414584320 neq.18446744069414584314 end",
+        ),
+        (
+            "const.X=-1 begin push.X end",
+            "begin push.18446744069414584320 end",
+        ),
+    ] {
+        let negative_program = assembler.compile(negative).unwrap();
+        let positive_program = assembler.compile(positive).unwrap();
+        assert_eq!(format!("{positive_program}"), format!("{negative_program}"));
+        assert_eq!(positive_program.hash(), negative_program.hash());
+    }
+
+    // the magnitude must be a valid field element
+    let error = assembler
+        .compile("begin push.-18446744069414584321 end")
+        .unwrap_err();
+    assert_eq!(
+        "malformed instruction 'push.-18446744069414584321', parameter \
+        -18446744069414584321 is invalid: parameter value must be smaller than \
+        18446744069414584321 at line 1, column 7",
+        error.to_string()
+    );
+    assert!(assembler.compile("begin push.--1 end").is_err());
+    assert!(assembler.compile("begin push.-+1 end").is_err());
+}
+
+#[test]
+fn comparison_immediates() {
+    let assembler = super::Assembler::default();
+
+    // immediate values are pushed onto the stack before the comparison
+    for (imm, explicit) in [
+        ("begin eq.7 neq.3 end", "begin push.7 eq push.3 eq not end"),
+        (
+            "begin lt.7 lte.0 gt.1 gte.-1 end",
+            "begin push.7 lt push.0 lte push.1 gt push.-1 gte end",
+        ),
+        (
+            "begin u32checked_lt.7 u32checked_lte.0 u32checked_gt.1 u32checked_gte.0xff end",
+            "begin push.7 u32checked_lt push.0 u32checked_lte push.1 u32checked_gt \
+            push.255 u32checked_gte end",
+        ),
+    ] {
+        let imm_program = assembler.compile(imm).unwrap();
+        let explicit_program = assembler.compile(explicit).unwrap();
+        assert_eq!(format!("{explicit_program}"), format!("{imm_program}"));
+        assert_eq!(explicit_program.hash(), imm_program.hash());
+    }
+
+    // immediate values of u32 comparisons must be u32 values
+    let error = assembler
+        .compile("begin u32checked_gte.4294967296 end")
+        .unwrap_err();
+    assert_eq!(
+        "malformed instruction `u32checked_gte.4294967296`: parameter \
+        '4294967296' is invalid at line 1, column 7",
+        error.to_string()
+    );
+    assert!(assembler.compile("begin lt.1.2 end").is_err());
+}
+
+#[test]
+fn assertion_error_codes() {
+    let assembler = super::Assembler::default();
+
+    // error codes do not affect the operations of the program, and thus, its hash
+    let program = assembler
+        .compile("begin assert.err=1 assertz.err=0x2 assert_eq.err=3 end")
+        .unwrap();
+    let plain_program = assembler
+        .compile("begin assert assertz assert_eq end")
+        .unwrap();
+    assert_eq!(plain_program.hash(), program.hash());
+
+    // the error code of each assertion precedes its ASSERT operation
+    let span = match program.root() {
+        CodeBlock::Span(span) => span,
+        block => panic!("expected a span block, but got {block:?}"),
+    };
+    assert_eq!(
+        &vec![
+            (0, Decorator::ErrorCode(1)),
+            (2, Decorator::ErrorCode(2)),
+            (4, Decorator::ErrorCode(3)),
+        ],
+        span.decorators()
+    );
+
+    // error codes must be u32 values specified via the `err=` parameter
+    let error = assembler
+        .compile("begin assert.err=4294967296 end")
+        .unwrap_err();
+    assert_eq!(
+        "malformed instruction 'assert.err=4294967296', parameter err=4294967296 is invalid: \
+        error code must be a 32-bit unsigned integer at line 1, column 7",
+        error.to_string()
+    );
+    let error = assembler.compile("begin assertz.code=1 end").unwrap_err();
+    assert_eq!(
+        "malformed instruction `assertz.code=1`: parameter 'code=1' is \
+        invalid at line 1, column 7",
+        error.to_string()
+    );
+    assert!(assembler.compile("begin assert_eq.err=1.2 end").is_err());
+}
+
 // NESTED CONTROL BLOCKS
 // ================================================================================================
 
@@ -109,6 +505,145 @@ fn nested_control_blocks() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn nested_control_blocks_iter() {
+    let assembler = super::Assembler::default();
+    let source = "begin \
+        push.2 push.3 \
+        if.true \
+            add while.true push.7 push.11 add end \
+        else \
+            mul repeat.2 push.8 end if.true mul end  \
+        end
+        push.3 add
+        end";
+    let program = assembler.compile(source).unwrap();
+
+    // blocks are visited in pre-order, with children of each block in the execution order
+    let blocks = program
+        .root()
+        .iter()
+        .map(|block| match block {
+            CodeBlock::Span(_) => "span",
+            CodeBlock::Join(_) => "join",
+            CodeBlock::Split(_) => "split",
+            CodeBlock::Loop(_) => "loop",
+            CodeBlock::Call(_) => "call",
+            CodeBlock::Proxy(_) => "proxy",
+        })
+        .collect::<Vec<_>>();
+    let expected = vec![
+        "join", "join", "span", "split", "join", "span", "loop", "span", "join", "span", "split",
+        "span", "span", "span",
+    ];
+    assert_eq!(expected, blocks);
+
+    // the last visited block is the span after the if-else block
+    let last = program.root().iter().last().unwrap();
+    assert_eq!(
+        CodeBlock::new_span(vec![Operation::Push(Felt::new(3)), Operation::Add]).hash(),
+        last.hash()
+    );
+}
+
+#[test]
+fn if_else_branch_stack_effects() {
+    let assembler = super::Assembler::default();
+
+    // branches with the same net stack effect are accepted
+    let source = "begin push.2 push.3 if.true add push.1 else push.4 swap drop end end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            join \
+                span push(2) push(3) end \
+                if.true \
+                    span add pad incr end \
+                else \
+                    span push(4) swap drop end \
+                end \
+            end \
+        end";
+    assert_eq!(expected, format!("{}", program));
+
+    // branches with different net stack effects are rejected
+    let source = "begin push.2 push.3 if.true push.1 else swap end end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "if/else branches have mismatched stack effects (+1 vs 0) at line 1, column 21",
+        error.to_string()
+    );
+
+    let source = "begin push.2 push.3 if.true drop else dup.1 end end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "if/else branches have mismatched stack effects (-1 vs +1) at line 1, column 21",
+        error.to_string()
+    );
+
+    // branches containing loops or nested conditionals are not checked
+    let source = "begin push.2 push.3 if.true push.0 while.true push.0 end else swap end end";
+    assert!(assembler.compile(source).is_ok());
+
+    let source = "begin push.2 push.3 if.true push.1 if.true drop end else swap end end";
+    assert!(assembler.compile(source).is_ok());
+
+    // an if without else may modify the stack
+    let source = "begin push.2 push.3 if.true push.1 end end";
+    assert!(assembler.compile(source).is_ok());
+}
+
+#[test]
+fn program_with_identical_repeat_bodies_dedup() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        begin \
+            repeat.2 if.true add else mul end end \
+            push.1 \
+            repeat.2 if.true add else mul end end \
+        end";
+    let program = assembler.compile(source).unwrap();
+
+    // iterations of a repeat block share the split block of its body, but identical bodies of
+    // different repeat blocks are compiled separately
+    let splits = collect_split_blocks(program.root());
+    assert_eq!(4, splits.len());
+    assert_eq!(2, splits.iter().collect::<BTreeSet<_>>().len());
+
+    // after deduplication, all iterations share the same split block, and the program hash and
+    // structure are unchanged
+    let deduped = program.clone().dedup_blocks();
+    assert_eq!(program.hash(), deduped.hash());
+    assert_eq!(program.to_string(), deduped.to_string());
+
+    let splits = collect_split_blocks(deduped.root());
+    assert_eq!(4, splits.len());
+    assert_eq!(1, splits.iter().collect::<BTreeSet<_>>().len());
+
+    // the original program is not affected
+    assert_eq!(4, collect_split_blocks(program.root()).len());
+    assert_eq!(
+        2,
+        collect_split_blocks(program.root())
+            .iter()
+            .collect::<BTreeSet<_>>()
+            .len()
+    );
+}
+
+/// Returns addresses of all split blocks reachable from the specified block via join blocks.
+fn collect_split_blocks(block: &CodeBlock) -> Vec<*const CodeBlock> {
+    match block {
+        CodeBlock::Join(join) => {
+            let mut result = collect_split_blocks(join.first());
+            result.extend(collect_split_blocks(join.second()));
+            result
+        }
+        CodeBlock::Split(_) => vec![block as *const CodeBlock],
+        _ => Vec::new(),
+    }
+}
+
 // PROGRAMS WITH PROCEDURES
 // ================================================================================================
 
@@ -121,6 +656,57 @@ fn program_with_one_procedure() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn program_with_called_procedure() {
+    let assembler = super::Assembler::default();
+    let source = "proc.foo push.3 push.7 mul end begin push.2 call.foo end";
+    let program = assembler.compile(source).unwrap();
+
+    // the called procedure is not inlined; instead, the call block references its MAST root
+    let foo = CodeBlock::new_span(vec![
+        Operation::Push(Felt::new(3)),
+        Operation::Push(Felt::new(7)),
+        Operation::Mul,
+    ]);
+    let expected = format!(
+        "begin join span push(2) end call.0x{} end end",
+        felt_fmt::format_digest(&foo.hash())
+    );
+    assert_eq!(expected, format!("{program}"));
+    assert!(program.cb_table().has(foo.hash()));
+}
+
+#[test]
+fn program_with_one_procedure_span_at_op_index() {
+    let assembler = super::Assembler::default();
+    let source = "proc.foo push.3 push.7 mul end begin push.2 push.3 add exec.foo end";
+    let program = assembler.compile(source).unwrap();
+
+    // the 5th operation is push(7) inlined from foo
+    let (span, offset) = program.span_at_op_index(4).unwrap();
+    assert_eq!(4, offset);
+    assert_eq!(
+        "span push(2) push(3) add push(3) push(7) mul end",
+        span.to_string()
+    );
+    assert_eq!(
+        Some(&Operation::Push(Felt::new(7))),
+        span.op_batches()
+            .iter()
+            .flat_map(|batch| batch.ops())
+            .nth(offset)
+    );
+    assert_eq!(
+        program.operations().nth(4),
+        Some(&Operation::Push(Felt::new(7)))
+    );
+
+    // indexes beyond the last operation are not mapped to any span
+    assert_eq!(6, program.operations().count());
+    assert!(program.span_at_op_index(5).is_some());
+    assert!(program.span_at_op_index(6).is_none());
+}
+
 #[test]
 fn program_with_nested_procedure() {
     let assembler = super::Assembler::default();
@@ -134,6 +720,90 @@ fn program_with_nested_procedure() {
         push(11) push(5) push(3) push(7) mul add neg add \
         end end";
     assert_eq!(expected, format!("{}", program));
+
+    // the program consists of a single span with 14 operations in two batches, which the VM
+    // executes together with SPAN, RESPAN, and END operations
+    assert_eq!(17, program.root().num_operations());
+}
+
+#[test]
+fn program_with_procedure_inlined_twice() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        proc.foo if.true push.3 mul else push.5 add end end \
+        begin exec.foo exec.foo end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin \
+        join \
+            if.true span push(3) mul end else span push(5) add end end \
+            if.true span push(3) mul end else span push(5) add end end \
+        end \
+        end";
+    assert_eq!(expected, format!("{}", program));
+
+    // both call sites of foo share the MAST of the procedure rather than copying it
+    let join = match program.root() {
+        CodeBlock::Join(join) => join,
+        block => panic!("expected a join block, got {block:?}"),
+    };
+    assert!(Arc::ptr_eq(&join.children()[0], &join.children()[1]));
+}
+
+#[test]
+fn program_with_nested_procedure_operation_histogram() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        proc.foo push.3 push.7 mul end \
+        proc.bar push.5 exec.foo add end \
+        begin push.2 push.4 add exec.foo push.11 exec.bar sub end";
+    let program = assembler.compile(source).unwrap();
+
+    // the program compiles into a single span: push(2) push(4) add push(3) push(7) mul push(11)
+    // push(5) push(3) push(7) mul add neg add; immediate values of the pushes do not fit into a
+    // single batch, and thus, the span consists of two batches separated by a respan
+    let histogram = program.operation_histogram();
+    assert_eq!(Some(&2), histogram.get("mul"));
+    assert_eq!(Some(&3), histogram.get("add"));
+    assert_eq!(Some(&8), histogram.get("push"));
+    assert_eq!(Some(&1), histogram.get("neg"));
+    assert_eq!(Some(&1), histogram.get("span"));
+    assert_eq!(Some(&1), histogram.get("end"));
+    assert_eq!(Some(&1), histogram.get("respan"));
+    assert_eq!(7, histogram.len());
+}
+
+#[test]
+fn program_with_nested_procedure_inlining_trace() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        proc.foo push.3 push.7 mul end \
+        proc.bar push.5 exec.foo add end \
+        begin push.2 push.4 add exec.foo push.11 exec.bar sub end";
+    let (program, trace) = assembler.compile_with_inlining_trace(source).unwrap();
+    assert_eq!(assembler.compile(source).unwrap().hash(), program.hash());
+
+    // foo is inlined into the program directly and via bar
+    let expected = "\
+        #main inlined foo at 3\n\
+        #main inlined bar at 5\n\
+        bar inlined foo at 1\n";
+    assert_eq!(expected, trace.to_string());
+
+    let site = &trace[2];
+    assert_eq!(
+        ("bar", "foo", 1),
+        (site.caller(), site.callee(), site.position())
+    );
+
+    // instructions in nested blocks are counted; procedures which are called are not inlined
+    let source = "\
+        proc.foo push.3 push.7 mul end \
+        begin push.1 if.true exec.foo else call.foo end exec.foo end";
+    let (_, trace) = assembler.compile_with_inlining_trace(source).unwrap();
+    assert_eq!(
+        "#main inlined foo at 1\n#main inlined foo at 3\n",
+        trace.to_string()
+    );
 }
 
 #[test]
@@ -166,6 +836,121 @@ fn program_with_proc_locals() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn proc_locals_bounds() {
+    let assembler = super::Assembler::default();
+
+    // indices of locals are checked against the number of locals declared by the procedure
+    for instruction in [
+        "loc_load",
+        "loc_store",
+        "loc_loadw",
+        "loc_storew",
+        "locaddr",
+    ] {
+        for index in [0, 1] {
+            let source = format!("proc.foo.2 {instruction}.{index} end begin exec.foo end");
+            assert!(assembler.compile(&source).is_ok(), "{source}");
+        }
+
+        let source = format!("proc.foo.2 {instruction}.2 end begin exec.foo end");
+        let error = assembler.compile(&source).unwrap_err();
+        assert_eq!(
+            &AssemblyError::local_index_out_of_bounds(2, 2),
+            error.unlocated()
+        );
+        assert_eq!(
+            "local index 2 is out of bounds: the procedure declares 2 locals",
+            error.unlocated().to_string()
+        );
+    }
+
+    // procedures without locals (including the main procedure) cannot access any locals
+    for source in [
+        "proc.foo loc_load.0 end begin exec.foo end",
+        "proc.foo.0 loc_store.0 end begin exec.foo end",
+        "begin locaddr.0 end",
+    ] {
+        let error = assembler.compile(source).unwrap_err();
+        assert_eq!(
+            &AssemblyError::local_index_out_of_bounds(0, 0),
+            error.unlocated(),
+            "{source}"
+        );
+    }
+
+    // the number of locals is limited by the u16 range
+    let source = "proc.foo.65535 loc_load.65534 end begin exec.foo end";
+    assert!(assembler.compile(source).is_ok());
+    let error = assembler
+        .compile("proc.foo.65536 loc_load.0 end begin exec.foo end")
+        .unwrap_err();
+    assert_eq!(
+        "number of procedure locals (65536) exceeds maximum of 65535 in \
+        procedure 'foo' at line 1, column 1",
+        error.to_string()
+    );
+    let error = parse_module("export.bar.70000 push.1 end").unwrap_err();
+    assert!(
+        error.to_string().ends_with(
+            "number of procedure locals (70000) exceeds maximum of 65535 in procedure 'bar'"
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn program_with_nested_proc_locals_max_local_frame() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        proc.foo.2 \
+            loc_load.1 drop \
+        end \
+        proc.bar.3 \
+            loc_load.2 drop \
+            exec.foo \
+        end \
+        proc.baz.1 \
+            loc_load.0 drop \
+            exec.bar \
+            exec.foo \
+        end \
+        proc.qux.4 \
+            loc_load.3 drop \
+            exec.foo \
+        end \
+        begin \
+            exec.foo \
+            exec.baz \
+            call.qux \
+        end";
+
+    // baz -> bar -> foo is the deepest inlined call path: 1 + 3 + 2 locals; qux is invoked via
+    // call, and thus its frame of 4 + 2 locals is reserved in a separate memory context
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(6, program.max_local_frame());
+
+    // when the frame of a called procedure is larger than any inlined frame, it is the peak
+    let source = "\
+        proc.foo.2 \
+            loc_load.1 drop \
+        end \
+        proc.qux.7 \
+            loc_load.6 drop \
+            exec.foo \
+        end \
+        begin \
+            exec.foo \
+            call.qux \
+        end";
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(9, program.max_local_frame());
+
+    // programs without procedure locals have no local frame
+    let program = assembler.compile("begin push.1 push.2 add end").unwrap();
+    assert_eq!(0, program.max_local_frame());
+}
+
 #[test]
 fn program_with_exported_procedure() {
     let assembler = super::Assembler::default();
@@ -173,12 +958,251 @@ fn program_with_exported_procedure() {
     assert!(assembler.compile(source).is_err());
 }
 
-// IMPORTS
+// LIBRARY MODULES
 // ================================================================================================
 
 #[test]
-fn program_with_one_import() {
-    const MODULE: &str = "dummy::math::u256";
+fn compile_module() {
+    let assembler = Assembler::default();
+    let source = "\
+        export.double
+            dup add
+        end
+        export.quad
+            exec.double
+            exec.double
+        end";
+    let exports = assembler.compile_module("acme::math", source).unwrap();
+    assert_eq!(2, exports.len());
+
+    // compiled procedures can be imported by programs
+    let program = assembler
+        .compile("use.acme::math begin exec.math::quad end")
+        .unwrap();
+    assert_eq!(exports[1], program.hash());
+
+    // the module cannot be compiled again, and its procedures cannot be shadowed by another module
+    let err = assembler
+        .compile_module("acme::math", "export.double push.0 end")
+        .unwrap_err();
+    assert_eq!(AssemblyError::duplicate_module_path("acme::math"), err);
+
+    // paths of the kernel and of the prelude are reserved
+    for path in [ProcedureId::KERNEL_PATH, ProcedureId::PRELUDE_PATH] {
+        let err = assembler.compile_module(path, source).unwrap_err();
+        assert_eq!(AssemblyError::reserved_module_path(path), err);
+    }
+
+    // modules which can be resolved via the module provider of the assembler cannot be shadowed
+    // either
+    let modules = vec![("acme::util".to_string(), parse_module(source).unwrap())];
+    let library = MaslLibrary::new("acme", Version::new(1, 0, 0), Vec::new(), modules).unwrap();
+    let assembler = Assembler::default().with_module_provider(library);
+    let err = assembler
+        .compile_module("acme::util", "export.quad push.0 end")
+        .unwrap_err();
+    assert_eq!(AssemblyError::duplicate_module_path("acme::util"), err);
+}
+
+#[test]
+fn library_exports_mast_roots() {
+    let assembler = Assembler::default();
+    let source = "\
+        export.inc
+            push.1 add
+        end
+        proc.helper
+            mul
+        end
+        export.inc_if
+            if.true
+                exec.inc
+            end
+        end";
+
+    // the roots of the exported procedures are the hashes of their code blocks, and they are the
+    // same as the roots returned via compile_module()
+    let library = assembler.compile_library("acme::util", source).unwrap();
+    let exports = library.exports().collect::<Vec<_>>();
+    let inc = CodeBlock::new_span(vec![Operation::Pad, Operation::Incr, Operation::Add]);
+    let inc_if = CodeBlock::new_split(inc.clone(), CodeBlock::new_span(vec![Operation::Noop]));
+    assert_eq!(
+        vec![("inc", inc.hash()), ("inc_if", inc_if.hash())],
+        exports
+    );
+    let roots = Assembler::default()
+        .compile_module("acme::util", source)
+        .unwrap();
+    assert_eq!(vec![inc.hash(), inc_if.hash()], roots);
+
+    // the compiled procedures are not cached by the assembler which compiled the library
+    let source = "use.acme::util begin call.util::inc_if end";
+    assert!(assembler.compile(source).is_err());
+
+    // a program invoking an exported procedure via call refers to the same root
+    let provider = LibraryModuleProvider::new().with_library(library);
+    let assembler = Assembler::default()
+        .with_library_module_provider(provider)
+        .unwrap();
+    let program = assembler.compile(source).unwrap();
+    assert_eq!(CodeBlock::new_call(inc_if.hash()).hash(), program.hash());
+}
+
+#[test]
+fn compile_library_code_blocks() {
+    let assembler = Assembler::default();
+    let source = "\
+        proc.sum
+            add
+        end
+        export.sum_inc
+            exec.sum push.1 add
+        end
+        export.call_sum
+            call.sum
+        end";
+    let library = assembler.compile_library("acme::util", source).unwrap();
+
+    // the exported procedures are followed by the procedure they call
+    let procs = library
+        .code_blocks()
+        .map(|(id, name, block)| (*id, name, block.hash()))
+        .collect::<Vec<_>>();
+    let names = procs.iter().map(|(_, name, _)| *name).collect::<Vec<_>>();
+    assert_eq!(vec!["sum_inc", "call_sum", "sum"], names);
+    assert_eq!(ProcedureId::from_name("sum_inc", "acme::util"), procs[0].0);
+
+    // the MAST of an exported procedure is the same as the inlined body of a program which
+    // executes the procedure
+    let provider = LibraryModuleProvider::new().with_library(library.clone());
+    let assembler = Assembler::default()
+        .with_library_module_provider(provider)
+        .unwrap();
+    let program = assembler
+        .compile("use.acme::util begin exec.util::sum_inc end")
+        .unwrap();
+    assert_eq!(program.hash(), procs[0].2);
+    let program = assembler
+        .compile("use.acme::util begin exec.util::call_sum end")
+        .unwrap();
+    assert_eq!(program.hash(), procs[1].2);
+    assert_eq!(CodeBlock::new_call(procs[2].2).hash(), procs[1].2);
+}
+
+#[test]
+fn compile_module_with_unused_broken_export() {
+    // the module parses successfully, but its second export invokes a procedure from a module
+    // which does not exist
+    let source = "\
+        use.std::math::u512
+        export.used
+            push.1
+        end
+        export.broken
+            exec.u512::iszero_unsafe
+        end";
+    assert!(parse_module(source).is_ok());
+
+    // the broken export is never invoked, but compiling the module still fails
+    let assembler = Assembler::default();
+    assert!(assembler.compile_module("acme::math", source).is_err());
+
+    // the same applies to exports which invoke kernel procedures not present in the kernel
+    let source = "\
+        export.used
+            push.1
+        end
+        export.broken
+            syscall.foo
+        end";
+    assert!(parse_module(source).is_ok());
+    assert!(assembler.compile_module("acme::math", source).is_err());
+}
+
+#[test]
+fn compile_library_and_load_via_provider() {
+    const MODULE: &str = "dummy::math::u256";
+    const SOURCE: &str = "\
+        proc.zero
+            push.0
+        end
+        export.iszero_unsafe
+            eq.0
+            repeat.7
+                swap
+                eq.0
+                and
+            end
+        end
+        export.call_zero
+            call.zero
+        end";
+
+    // the library contains the exports of the module and the internal procedure they call
+    let library = Assembler::default()
+        .compile_library(MODULE, SOURCE)
+        .unwrap();
+    assert_eq!(MODULE, library.path());
+    let exports = library.exports().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(vec!["iszero_unsafe", "call_zero"], exports);
+
+    // the library is loaded from bytes, and thus, the module source is never parsed
+    let bytes = library.to_bytes().unwrap();
+    let provider = LibraryModuleProvider::new().load(&bytes).unwrap();
+    let assembler = Assembler::default()
+        .with_library_module_provider(provider.clone())
+        .unwrap();
+    let source = format!(
+        "use.{MODULE}\nbegin push.4 push.3 exec.u256::iszero_unsafe exec.u256::call_zero end"
+    );
+    let program = assembler.compile(&source).unwrap();
+
+    // the expansion matches the one of the program compiled against the module source
+    let source_assembler = Assembler::default();
+    source_assembler.compile_module(MODULE, SOURCE).unwrap();
+    let expected = source_assembler.compile(&source).unwrap();
+    assert_eq!(expected.to_string(), program.to_string());
+    assert_eq!(expected.hash(), program.hash());
+    let zero = CodeBlock::new_span(vec![Operation::Pad]);
+    assert!(program.cb_table().has(zero.hash()));
+
+    // procedures of a library cannot replace procedures already loaded into the assembler
+    let err = assembler
+        .with_library_module_provider(provider)
+        .err()
+        .unwrap();
+    assert!(matches!(err, AssemblyError::ConflictingLibraryProc(_, path) if path == MODULE));
+
+    // procedures whose IDs do not match the path of the library cannot be imported
+    let forged_path = "dummy::math::u255";
+    let path_offset = bytes
+        .windows(MODULE.len())
+        .position(|window| window == MODULE.as_bytes())
+        .unwrap();
+    let mut forged_bytes = bytes.clone();
+    forged_bytes[path_offset..path_offset + MODULE.len()].copy_from_slice(forged_path.as_bytes());
+    let provider = LibraryModuleProvider::new().load(&forged_bytes).unwrap();
+    let assembler = Assembler::default()
+        .with_library_module_provider(provider)
+        .unwrap();
+    assert!(assembler.compile(&source).is_err());
+    let forged_source = source
+        .replace(MODULE, forged_path)
+        .replace("u256::", "u255::");
+    assert!(assembler.compile(forged_source).is_err());
+
+    // malformed libraries are rejected
+    let mut bytes = bytes;
+    bytes.pop();
+    assert!(LibraryModuleProvider::new().load(&bytes).is_err());
+}
+
+// IMPORTS
+// ================================================================================================
+
+#[test]
+fn program_with_one_import() {
+    const MODULE: &str = "dummy::math::u256";
     const PROCEDURE: &str = r#"
         export.iszero_unsafe
             eq.0
@@ -214,55 +1238,739 @@ fn program_with_one_import() {
         }
     }
 
-    let assembler = super::Assembler::new().with_module_provider(DummyProvider::default());
-    let source = format!(
-        r#"
-        use.{}
-        begin
-            push.4 push.3
-            exec.u256::iszero_unsafe
-        end"#,
-        MODULE
-    );
+    let assembler = super::Assembler::new().with_module_provider(DummyProvider::default());
+    let source = format!(
+        r#"
+        use.{}
+        begin
+            push.4 push.3
+            exec.u256::iszero_unsafe
+        end"#,
+        MODULE
+    );
+    let program = assembler.compile(&source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                push(4) push(3) \
+                eqz \
+                swap eqz and \
+                swap eqz and \
+                swap eqz and \
+                swap eqz and \
+                swap eqz and \
+                swap eqz and \
+                swap eqz and \
+            end \
+        end";
+    assert_eq!(expected, format!("{}", program));
+
+    // the resolution of the imported procedure is reported to the callback
+    let resolved = Rc::new(RefCell::new(Vec::new()));
+    let events = resolved.clone();
+    let assembler = super::Assembler::new()
+        .with_module_provider(DummyProvider::default())
+        .on_resolve(move |context, proc_id| {
+            let module_path = context.module_path().map(|path| path.to_string());
+            let event = (module_path, context.proc_name().to_string(), *proc_id);
+            events.borrow_mut().push(event);
+        });
+    assembler.compile(&source).unwrap();
+
+    let expected_id = ProcedureId::from_name("iszero_unsafe", MODULE);
+    assert_eq!(
+        vec![(None, "#main".to_string(), expected_id)],
+        *resolved.borrow()
+    );
+}
+
+#[test]
+fn program_with_import_errors() {
+    // --- non-existent import ------------------------------------------------
+    let assembler = super::Assembler::default();
+    let source = "\
+        use.std::math::u512
+        begin \
+            push.4 push.3 \
+            exec.u512::iszero_unsafe \
+        end";
+    assert!(assembler.compile(source).is_err());
+
+    // --- non-existent import passes syntax check ----------------------------
+    assert!(super::Assembler::check_syntax(source).is_ok());
+
+    // --- non-existent procedure in import -----------------------------------
+    let assembler = super::Assembler::default();
+    let source = "\
+        use.std::math::u256
+        begin \
+            push.4 push.3 \
+            exec.u256::foo \
+        end";
+    assert!(assembler.compile(source).is_err());
+}
+
+#[test]
+fn program_with_aliased_imports() {
+    const FOO: &str = "foo::utils";
+    const BAR: &str = "bar::utils";
+
+    struct DummyProvider {
+        foo: ModuleAst,
+        bar: ModuleAst,
+    }
+
+    impl ModuleProvider for DummyProvider {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            if id == &ProcedureId::from_name("value", FOO) {
+                Some(NamedModuleAst::new(FOO, &self.foo))
+            } else if id == &ProcedureId::from_name("value", BAR) {
+                Some(NamedModuleAst::new(BAR, &self.bar))
+            } else {
+                None
+            }
+        }
+    }
+
+    let provider = DummyProvider {
+        foo: parse_module("export.value push.1 end").unwrap(),
+        bar: parse_module("export.value push.2 end").unwrap(),
+    };
+    let assembler = super::Assembler::new().with_module_provider(provider);
+
+    // modules with the same name can be told apart by importing one of them under an alias;
+    // procedures are still resolved against the actual module paths
+    let source =
+        format!("use.{FOO}->foo_utils use.{BAR} begin exec.foo_utils::value exec.utils::value end");
+    let program = assembler.compile(&source).unwrap();
+    assert_eq!("begin span pad incr push(2) end end", format!("{program}"));
+
+    // an aliased module cannot be referenced by its own name
+    let source = format!("use.{FOO}->fu begin exec.utils::value end");
+    let error = assembler.compile(&source).unwrap_err();
+    assert_eq!(
+        "module utils is imported under alias fu; use the alias instead at line 1, column 26",
+        error.to_string()
+    );
+
+    // aliases must be unique and valid
+    let source = format!("use.{FOO}->u use.{BAR}->u begin exec.u::value end");
+    let error = assembler.compile(&source).unwrap_err();
+    assert_eq!(
+        "duplicate module alias: u is already used by another import at line 1, column 19",
+        error.to_string()
+    );
+    let source = format!("use.{FOO} use.{BAR}->utils begin exec.utils::value end");
+    let error = assembler.compile(&source).unwrap_err();
+    assert_eq!(
+        "duplicate module alias: utils is already used by another import at line 1, column 16",
+        error.to_string()
+    );
+    let source = format!("use.{FOO}->1u begin push.1 end");
+    let error = assembler.compile(&source).unwrap_err();
+    assert_eq!(
+        "invalid module alias: 1u at line 1, column 1",
+        error.to_string()
+    );
+}
+
+#[test]
+fn program_with_async_import() {
+    const MODULE: &str = "dummy::math::u256";
+    const PROCEDURE: &str = r#"
+        export.iszero_unsafe
+            eq.0
+            repeat.7
+                swap
+                eq.0
+                and
+            end
+        end"#;
+
+    /// Resolves the module only after a simulated delay, and counts the number of requests.
+    #[derive(Default)]
+    struct DelayedProvider {
+        num_requests: Arc<AtomicUsize>,
+    }
+
+    impl AsyncModuleProvider for DelayedProvider {
+        fn get_module(&self, id: &ProcedureId) -> ModuleFuture<'_> {
+            let id = *id;
+            Box::pin(async move {
+                self.num_requests.fetch_add(1, Ordering::Relaxed);
+                Delay::new(3).await;
+                let module = parse_module(PROCEDURE).unwrap();
+                module
+                    .local_procs
+                    .iter()
+                    .any(|proc| ProcedureId::from_name(&proc.name, MODULE) == id)
+                    .then(|| (MODULE.to_string(), module))
+            })
+        }
+    }
+
+    let source = format!(
+        r#"
+        use.{}
+        begin
+            push.4 push.3
+            exec.u256::iszero_unsafe
+            exec.u256::iszero_unsafe
+        end"#,
+        MODULE
+    );
+
+    // the module cannot be resolved via the sync path
+    let assembler = Assembler::new().with_async_module_provider(DelayedProvider::default());
+    assert!(assembler.compile(&source).is_err());
+
+    // but it is resolved when the program is compiled asynchronously; the module is fetched only
+    // once, even though the procedure is invoked twice
+    let provider = DelayedProvider::default();
+    let num_requests = provider.num_requests.clone();
+    let assembler = Assembler::new().with_async_module_provider(provider);
+    let program = block_on(assembler.compile_async(&source)).unwrap();
+    let iszero =
+        "eqz swap eqz and swap eqz and swap eqz and swap eqz and swap eqz and swap eqz and \
+        swap eqz and";
+    let expected = format!("begin span push(4) push(3) {iszero} {iszero} end end");
+    assert_eq!(expected, format!("{}", program));
+    assert_eq!(1, num_requests.load(Ordering::Relaxed));
+
+    // procedures which the async provider cannot find result in an error
+    let source = format!("use.{MODULE}\nbegin push.4 push.3 exec.u256::foo end");
+    assert!(block_on(assembler.compile_async(&source)).is_err());
+}
+
+/// A future which becomes ready after being polled the specified number of times.
+struct Delay {
+    polls_left: usize,
+}
+
+impl Delay {
+    fn new(num_polls: usize) -> Self {
+        Self {
+            polls_left: num_polls,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.polls_left == 0 {
+            return Poll::Ready(());
+        }
+        self.polls_left -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[test]
+fn module_provider_chain() {
+    fn library(namespace: &str, modules: &[(&str, &str)]) -> MaslLibrary {
+        let modules = modules
+            .iter()
+            .map(|(path, source)| (path.to_string(), parse_module(source).unwrap()))
+            .collect();
+        MaslLibrary::new(namespace, Version::new(1, 0, 0), Vec::new(), modules).unwrap()
+    }
+
+    // the project shadows acme::util::double, but not acme::math::inc
+    let project = || {
+        library(
+            "acme",
+            &[("acme::util", "export.double dup dup add add end")],
+        )
+    };
+    let acme = || {
+        library(
+            "acme",
+            &[
+                ("acme::util", "export.double dup add end"),
+                ("acme::math", "export.inc push.1 add end"),
+            ],
+        )
+    };
+
+    let source = "use.acme::util use.acme::math begin exec.util::double exec.math::inc end";
+    let chain = ModuleProviderChain::new()
+        .with_provider(project())
+        .with_provider(acme());
+    let program = Assembler::default()
+        .with_module_provider(chain)
+        .compile(source)
+        .unwrap();
+    let expected = Assembler::default()
+        .compile("begin dup dup add add push.1 add end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // in the reversed order, the module of the project is shadowed
+    let chain = ModuleProviderChain::new()
+        .with_provider(acme())
+        .with_provider(project());
+    let program = Assembler::default()
+        .with_module_provider(chain)
+        .compile(source)
+        .unwrap();
+    let expected = Assembler::default()
+        .compile("begin dup add push.1 add end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // in strict mode, only the procedure provided by both providers fails to resolve
+    let assembler = Assembler::default().with_module_provider(
+        ModuleProviderChain::new()
+            .with_provider(project())
+            .with_provider(acme())
+            .with_strict_mode(true),
+    );
+    assert!(assembler
+        .compile("use.acme::math begin exec.math::inc end")
+        .is_ok());
+    let err = assembler.compile(source).unwrap_err();
+    assert!(matches!(
+        err.unlocated(),
+        AssemblyError::ConflictingModuleProviders(_, path) if path == "acme::util"
+    ));
+
+    // a strict chain nested in another chain remains strict
+    let assembler = Assembler::default().with_module_provider(
+        ModuleProviderChain::new().with_provider(
+            ModuleProviderChain::new()
+                .with_provider(project())
+                .with_provider(acme())
+                .with_strict_mode(true),
+        ),
+    );
+    let err = assembler.compile(source).unwrap_err();
+    assert!(matches!(
+        err.unlocated(),
+        AssemblyError::ConflictingModuleProviders(_, path) if path == "acme::util"
+    ));
+}
+
+#[test]
+fn program_with_reexported_procs() {
+    fn library(modules: &[(&str, &str)]) -> MaslLibrary {
+        let modules = modules
+            .iter()
+            .map(|(path, source)| (path.to_string(), parse_module(source).unwrap()))
+            .collect();
+        MaslLibrary::new("acme", Version::new(1, 0, 0), Vec::new(), modules).unwrap()
+    }
+
+    // acme::math re-exports procedures of acme::math::u64 (one of them renamed), and acme::all
+    // re-exports the procedures re-exported from acme::math
+    let acme = || {
+        library(&[
+            (
+                "acme::math::u64",
+                "export.inc push.1 add end export.double dup add end",
+            ),
+            (
+                "acme::math",
+                "use.acme::math::u64 export.u64::inc export.u64::double->twice",
+            ),
+            (
+                "acme::all",
+                "use.acme::math export.math::inc export.math::twice->dbl",
+            ),
+        ])
+    };
+
+    // re-exported procedures resolve to the original procedures, and thus, add no code
+    let source = "\
+        use.acme::all use.acme::math \
+        begin exec.all::inc exec.all::dbl call.math::twice end";
+    let program = Assembler::default()
+        .with_module_provider(acme())
+        .compile(source)
+        .unwrap();
+    let expected = Assembler::default()
+        .with_module_provider(acme())
+        .compile("use.acme::math::u64 begin exec.u64::inc exec.u64::double call.u64::double end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // a renamed procedure is re-exported only under its new name
+    let assembler = Assembler::default().with_module_provider(acme());
+    let err = assembler
+        .compile("use.acme::all begin exec.all::twice end")
+        .unwrap_err();
+    assert_eq!(
+        &AssemblyError::imported_proc_module_not_found(&ProcedureId::from_name(
+            "twice",
+            "acme::all"
+        )),
+        err.unlocated()
+    );
+
+    // a chain of re-exports which leads back to a re-exported procedure is reported as circular
+    let cyclic = library(&[
+        ("acme::a", "use.acme::b export.b::foo"),
+        ("acme::b", "use.acme::a export.a::foo"),
+    ]);
+    let assembler = Assembler::default().with_module_provider(cyclic);
+    let err = assembler
+        .compile("use.acme::a begin exec.a::foo end")
+        .unwrap_err();
+    let module_chain = ["acme::a", "acme::b", "acme::a"].map(String::from);
+    assert_eq!(
+        &AssemblyError::circular_reexport(&module_chain),
+        err.unlocated()
+    );
+    assert_eq!(
+        "circular re-export: acme::a -> acme::b -> acme::a at line 1, column 19",
+        err.to_string()
+    );
+}
+
+#[test]
+fn program_with_circular_imports() {
+    /// Serves the specified modules, each located at its own path.
+    struct DummyProvider {
+        modules: Vec<(String, ModuleAst)>,
+    }
+
+    impl DummyProvider {
+        fn new(modules: &[(&str, &str)]) -> Self {
+            let modules = modules
+                .iter()
+                .map(|(path, source)| (path.to_string(), parse_module(source).unwrap()))
+                .collect();
+            Self { modules }
+        }
+    }
+
+    impl ModuleProvider for DummyProvider {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            self.modules.iter().find_map(|(path, module)| {
+                let module = NamedModuleAst::new(path.as_str(), module);
+                module.get_procedure(id).is_some().then_some(module)
+            })
+        }
+    }
+
+    let source = "use.ns::a begin exec.a::foo end";
+    let circular_dependency = |modules: &[(&str, &str)]| {
+        Assembler::default()
+            .with_module_provider(DummyProvider::new(modules))
+            .compile(source)
+            .unwrap_err()
+            .unlocated()
+            .clone()
+    };
+
+    // a pair of modules invoking procedures of each other
+    let err = circular_dependency(&[
+        (
+            "ns::a",
+            "use.ns::b export.foo exec.b::bar end export.baz push.1 end",
+        ),
+        ("ns::b", "use.ns::a export.bar exec.a::baz end"),
+    ]);
+    let dep_chain = ["ns::a", "ns::b", "ns::a"].map(String::from);
+    assert_eq!(AssemblyError::circular_module_dependency(&dep_chain), err);
+    assert_eq!(
+        "circular module dependency: ns::a -> ns::b -> ns::a",
+        err.to_string()
+    );
+
+    // a longer chain of modules
+    let err = circular_dependency(&[
+        ("ns::a", "use.ns::b export.foo exec.b::foo end"),
+        ("ns::b", "use.ns::c export.foo exec.c::foo end"),
+        ("ns::c", "use.ns::a export.foo exec.a::foo end"),
+    ]);
+    let dep_chain = ["ns::a", "ns::b", "ns::c", "ns::a"].map(String::from);
+    assert_eq!(AssemblyError::circular_module_dependency(&dep_chain), err);
+
+    // a module imported via two different paths is not a circular dependency
+    let provider = DummyProvider::new(&[
+        (
+            "ns::a",
+            "use.ns::b use.ns::c export.foo exec.b::foo exec.c::foo end",
+        ),
+        ("ns::b", "use.ns::d export.foo exec.d::foo end"),
+        ("ns::c", "use.ns::d export.foo exec.d::foo push.1 end"),
+        ("ns::d", "export.foo push.2 end"),
+    ]);
+    let program = Assembler::default()
+        .with_module_provider(provider)
+        .compile(source)
+        .unwrap();
+    let expected = Assembler::default()
+        .compile("begin push.2 push.2 push.1 end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+}
+
+// PRELUDE
+// ================================================================================================
+
+#[test]
+fn program_with_prelude() {
+    let prelude = "\
+        export.double
+            dup add
+        end
+        export.quadruple
+            exec.double exec.double
+        end
+        proc.helper
+            push.0 add
+        end";
+    let assembler = Assembler::default().with_prelude(prelude).unwrap();
+
+    // exported prelude procedures are invoked without an import
+    let source = "begin push.1 exec.quadruple exec.double end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                pad incr \
+                dup0 add dup0 add \
+                dup0 add \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // internal prelude procedures are not available to programs
+    let source = "begin push.1 exec.helper end";
+    assert!(assembler.compile(source).is_err());
+
+    // programs without a prelude cannot reference prelude procedures
+    let source = "begin push.1 exec.double end";
+    assert!(Assembler::default().compile(source).is_err());
+
+    // local procedures with the same name as a prelude procedure are rejected
+    let source = "proc.double push.2 mul end begin push.1 exec.double end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(1, error.location().unwrap().column);
+    match error.unlocated() {
+        AssemblyError::ParsingError(message) => assert_eq!(
+            "procedure label double collides with a prelude procedure",
+            message
+        ),
+        err => panic!("expected a parsing error, but got {err:?}"),
+    }
+
+    // local procedures with other names are not affected
+    let source = "proc.triple dup dup add add end begin push.1 exec.triple exec.double end";
+    assert!(assembler.compile(source).is_ok());
+}
+
+// CONSTANTS
+// ================================================================================================
+
+#[test]
+fn program_with_constants() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        const.ADDR=0x10
+        const.COUNT=2
+        proc.foo
+            push.ADDR mem_load.ADDR
+        end
+        begin
+            push.COUNT.3 exec.foo repeat.COUNT add end
+        end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span push(2) push(3) push(16) push(16) mload add add end end";
+    assert_eq!(expected, format!("{program}"));
+
+    // constants are resolved in the same way as literal values
+    let literal = assembler
+        .compile("begin push.16 mem_storew.16 repeat.2 dropw end end")
+        .unwrap();
+    let constant = assembler
+        .compile("const.A=16 const.N=2 begin push.A mem_storew.A repeat.N dropw end end")
+        .unwrap();
+    assert_eq!(literal.hash(), constant.hash());
+
+    // names of invoked procedures are not resolved as constants, even if they look like ones
+    let source = "const.INIT=1 proc.INIT push.INIT end begin exec.INIT call.INIT end";
+    let program = assembler.compile(source).unwrap();
+    let expected = assembler
+        .compile("proc.init push.1 end begin exec.init call.init end")
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+}
+
+#[test]
+fn program_with_constant_errors() {
+    let assembler = super::Assembler::default();
+
+    // redefinition of a constant
+    let source = "const.FOO=1 const.FOO=2 begin push.FOO end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "duplicate constant name: FOO at line 1, column 13",
+        error.to_string()
+    );
+
+    // reference to an undefined constant
+    let source = "const.FOO=1 begin push.FOO mem_load.BAR end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "undefined constant: BAR at line 1, column 28",
+        error.to_string()
+    );
+    let source = "begin repeat.COUNT push.1 end end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "undefined constant: COUNT at line 1, column 7",
+        error.to_string()
+    );
+
+    // invalid declarations
+    let source = "const.foo=1 begin push.1 end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "invalid constant name: foo at line 1, column 1",
+        error.to_string()
+    );
+    let source = "const.FOO begin push.1 end";
+    assert!(assembler.compile(source).is_err());
+    let source = "const.FOO=18446744069414584321 begin push.FOO end";
+    assert!(assembler.compile(source).is_err());
+
+    // constants must be declared before procedures
+    let source = "proc.foo push.1 end const.FOO=1 begin exec.foo end";
+    assert!(assembler.compile(source).is_err());
+}
+
+#[test]
+fn constants_are_not_imported() {
+    const MODULE: &str = "dummy::consts";
+    const SOURCE: &str = "\
+        const.VALUE=7
+        export.value
+            push.VALUE
+        end";
+
+    struct DummyProvider {
+        module: ModuleAst,
+    }
+
+    impl ModuleProvider for DummyProvider {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            (id == &ProcedureId::from_name("value", MODULE))
+                .then_some(NamedModuleAst::new(MODULE, &self.module))
+        }
+    }
+
+    let provider = DummyProvider {
+        module: parse_module(SOURCE).unwrap(),
+    };
+    let assembler = super::Assembler::new().with_module_provider(provider);
+
+    // constants of an imported module are used by its procedures
+    let source = format!("use.{MODULE} begin exec.consts::value end");
     let program = assembler.compile(&source).unwrap();
-    let expected = "\
-        begin \
-            span \
-                push(4) push(3) \
-                eqz \
-                swap eqz and \
-                swap eqz and \
-                swap eqz and \
-                swap eqz and \
-                swap eqz and \
-                swap eqz and \
-                swap eqz and \
-            end \
-        end";
-    assert_eq!(expected, format!("{}", program));
+    assert_eq!("begin span push(7) end end", format!("{program}"));
+
+    // but they are not visible in the importing program
+    let source = format!("use.{MODULE} begin exec.consts::value push.VALUE end");
+    let error = assembler.compile(&source).unwrap_err();
+    assert_eq!(
+        "undefined constant: VALUE at line 1, column 44",
+        error.to_string()
+    );
 }
 
+// DISASSEMBLY
+// ================================================================================================
+
 #[test]
-fn program_with_import_errors() {
-    // --- non-existent import ------------------------------------------------
+fn disassemble_round_trip() {
     let assembler = super::Assembler::default();
-    let source = "\
-        use.std::math::u512
-        begin \
-            push.4 push.3 \
-            exec.u512::iszero_unsafe \
-        end";
-    assert!(assembler.compile(source).is_err());
+    let sources = [
+        "begin push.2 push.3 add push.1 push.0 mem_store.0 mem_load.0 swap dup.1 drop end",
+        "begin push.1 push.2 adv.u64div drop drop adv.mem.4.2 end",
+        "begin push.3 if.true push.4 add else push.5 mul end push.1 if.true push.0 drop end end",
+        "begin push.1 while.true push.7 repeat.2 push.0 end drop drop end push.9 end",
+        "begin push.1 assert.err=7 push.2 push.2 assert_eq.err=8 push.0 assertz end",
+        "proc.foo push.3 push.7 mul end \
+        proc.bar call.foo drop end \
+        begin push.2 call.foo call.bar call.foo end",
+    ];
 
-    // --- non-existent procedure in import -----------------------------------
+    for source in sources {
+        let program = assembler.compile(source).unwrap();
+        let disassembled = disassemble(&program).unwrap().to_string();
+        let recompiled = assembler.compile(&disassembled).unwrap();
+        assert_eq!(
+            program.hash(),
+            recompiled.hash(),
+            "{source}\n{disassembled}"
+        );
+    }
+
+    // error codes of assertions are not committed to by the hash, but they are preserved as well
+    let program = assembler.compile("begin push.1 assert.err=7 end").unwrap();
+    let disassembled = disassemble(&program).unwrap().to_string();
+    assert!(disassembled.contains("assert.err=7"), "{disassembled}");
+}
+
+#[test]
+fn disassemble_called_procedures() {
     let assembler = super::Assembler::default();
     let source = "\
-        use.std::math::u256
-        begin \
-            push.4 push.3 \
-            exec.u256::foo \
-        end";
-    assert!(assembler.compile(source).is_err());
+        proc.foo push.3 push.7 mul end \
+        proc.bar call.foo drop end \
+        begin push.2 call.bar call.foo end";
+    let program = assembler.compile(source).unwrap();
+    let ast = disassemble(&program).unwrap();
+
+    // procedures are declared before they are invoked, and each procedure is declared only once
+    let names = ast
+        .local_procs
+        .iter()
+        .map(|proc| proc.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["proc_0", "proc_1"], names);
+    let source = ast.to_string();
+    assert!(source.contains("call.proc_0"));
+    assert!(source.contains("call.proc_1"));
+}
+
+#[test]
+fn disassemble_errors() {
+    // procedure locals are managed via operations which have no equivalent instructions
+    let assembler = super::Assembler::default();
+    let source = "proc.foo.1 loc_store.0 end begin push.1 exec.foo end";
+    let program = assembler.compile(source).unwrap();
+    assert!(matches!(
+        disassemble(&program),
+        Err(DisassemblyError::UnsupportedOperation(_))
+    ));
+
+    // kernel procedures are not part of the program
+    let kernel = "export.foo add end";
+    let assembler = super::Assembler::default().with_kernel(kernel).unwrap();
+    let program = assembler.compile("begin syscall.foo end").unwrap();
+    assert_eq!(
+        Err(DisassemblyError::UnsupportedBlock("syscall")),
+        disassemble(&program)
+    );
+
+    // a called procedure must be present in the code block table of the program
+    let foo = CodeBlock::new_span(vec![Operation::Add]);
+    let program = Program::new(CodeBlock::new_call(foo.hash()));
+    let error = disassemble(&program).unwrap_err();
+    assert_eq!(DisassemblyError::CallTargetNotFound(foo.hash()), error);
+    assert_eq!(
+        format!(
+            "code block 0x{} of a call target not found",
+            felt_fmt::format_digest(&foo.hash())
+        ),
+        error.to_string()
+    );
 }
 
 // COMMENTS
@@ -335,6 +2043,102 @@ fn comment_after_program() {
     assert_eq!(expected, format!("{}", program));
 }
 
+#[test]
+fn block_comment_simple() {
+    let assembler = super::Assembler::default();
+    let source = "begin /* simple comment */ push.1 push.2/* no spaces */add end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span pad incr push(2) add end end";
+    assert_eq!(expected, format!("{}", program));
+}
+
+#[test]
+fn block_comment_in_nested_control_blocks() {
+    let assembler = super::Assembler::default();
+
+    // block comments span multiple lines and can be nested; line comments inside block comments
+    // are ignored
+    let source = "begin \
+        push.1 push.2 \
+        if.true \
+            /* nested comment \n\
+            # with a line comment */ \
+            add while.true push.7 push.11 add end \
+        else \
+            mul repeat.2 push.8 end /* if.true mul end */ if.true mul end \
+            /* outer /* inner \n\
+            end */ still commented */ \
+        end
+        /* push.3 add
+        */ push.3 add
+        end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            join \
+                join \
+                    span pad incr push(2) end \
+                    if.true \
+                        join \
+                            span add end \
+                            while.true span push(7) push(11) add end end \
+                        end \
+                    else \
+                        join \
+                            span mul push(8) push(8) end \
+                            if.true span mul end else span noop end end \
+                        end \
+                    end \
+                end \
+            span push(3) add end \
+            end \
+        end";
+    assert_eq!(expected, format!("{}", program));
+}
+
+#[test]
+fn block_comment_around_procedure() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        /* proc.foo
+            /* a procedure which is commented out */
+            push.1
+        end */
+        # a line comment with /* which does not open a block comment
+        begin push.1 push.2 add end /* closing comment */";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span pad incr push(2) add end end";
+    assert_eq!(expected, format!("{}", program));
+
+    // token locations account for block comments
+    let source = "begin /* a\n comment */ push.1 bogus end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "instruction 'bogus' is invalid at line 2, column 20",
+        error.to_string()
+    );
+}
+
+#[test]
+fn unterminated_block_comment() {
+    let assembler = super::Assembler::default();
+
+    // the error is reported at the start of the outermost unterminated comment
+    let source = "begin\n    push.1 /* outer /* inner */\n    add\nend";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "unterminated block comment at line 2, column 12",
+        error.to_string()
+    );
+
+    let source = "begin push.1 end /* closing comment";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        "unterminated block comment at line 1, column 18",
+        error.to_string()
+    );
+}
+
 // ERRORS
 // ================================================================================================
 
@@ -361,7 +2165,7 @@ fn invalid_program() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "unexpected token: expected 'begin' but was 'none'"
+            "unexpected token: expected 'begin' but was 'none' at line 1, column 1"
         );
     }
 
@@ -369,7 +2173,10 @@ fn invalid_program() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "begin without matching end");
+        assert_eq!(
+            error.to_string(),
+            "begin without matching end at line 1, column 1"
+        );
     }
 
     let source = "begin end";
@@ -378,7 +2185,7 @@ fn invalid_program() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "a code block must contain at least one instruction"
+            "a code block must contain at least one instruction at line 1, column 7"
         );
     }
 
@@ -386,10 +2193,153 @@ fn invalid_program() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "dangling instructions after program end");
+        assert_eq!(
+            error.to_string(),
+            "dangling instructions after program end at line 1, column 15"
+        );
+    }
+}
+
+#[test]
+fn error_locations() {
+    // parsing errors are reported at the location of the erroneous token
+    let source = "\
+        begin
+            push.1 push.2
+            add
+            exec.bar
+        end";
+    let error = super::Assembler::default().compile(source).unwrap_err();
+    assert_eq!(
+        "undefined procedure: bar at line 4, column 13",
+        error.to_string()
+    );
+    assert_eq!(4, error.location().unwrap().line);
+
+    // errors detected during compilation are reported at the location of the node which caused
+    // them; comments and multi-line procedures do not affect the reported location
+    let source = "\
+        # the procedure below is valid
+        proc.foo  # with a trailing comment
+            push.1 push.2
+            add
+        end
+
+        begin
+            exec.foo
+            while.true
+                # the branches leave the stack at different depths
+                if.true drop else dup end
+            end
+        end";
+    let error = super::Assembler::default().compile(source).unwrap_err();
+    assert_eq!(
+        "if/else branches have mismatched stack effects (-1 vs +1) at line 11, column 17",
+        error.to_string()
+    );
+    let location = error.location().unwrap();
+    assert_eq!((11, 17, 7), (location.line, location.column, location.len));
+
+    // errors which occur while compiling an imported module are reported at the location at
+    // which the program invokes the imported procedure
+    const MODULE: &str = "dummy::broken";
+
+    struct DummyProvider {
+        module: ModuleAst,
+    }
+
+    impl ModuleProvider for DummyProvider {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            (id == &ProcedureId::from_name("foo", MODULE))
+                .then_some(NamedModuleAst::new(MODULE, &self.module))
+        }
+    }
+
+    let provider = DummyProvider {
+        module: parse_module("export.foo repeat.100000 push.1 end end").unwrap(),
+    };
+    let assembler = super::Assembler::new().with_module_provider(provider);
+    let source = format!("use.{MODULE}\nbegin\n    push.1\n    exec.broken::foo\nend");
+    let error = assembler.compile(&source).unwrap_err();
+    assert_eq!(
+        "repeat count 100000 exceeds maximum 65536 at line 4, column 5",
+        error.to_string()
+    );
+    assert_eq!(
+        &AssemblyError::repeat_count_too_large(100000, 65536),
+        error.unlocated()
+    );
+}
+
+#[test]
+fn invalid_program_syntax() {
+    let assembler = super::Assembler::default();
+    for source in [
+        "",
+        "none",
+        "begin add",
+        "begin if.true add end",
+        "begin add end end",
+    ] {
+        let errors = super::Assembler::check_syntax(source).unwrap_err();
+        assert_eq!(1, errors.len());
+
+        // syntax errors are the same as the errors reported by the compiler
+        let error = assembler.compile(source).unwrap_err();
+        assert_eq!(error, AssemblyError::from(errors[0].clone()));
     }
 }
 
+#[test]
+fn compile_checked_reports_all_errors() {
+    let assembler = super::Assembler::default();
+    let source = "\
+        proc.foo
+            push.1 bogus
+            exec.bar
+        end
+        proc.baz
+            add else
+        end
+        begin
+            exec.foo exec.baz push.COUNT
+        end";
+
+    // the empty procedure kept in place of `baz` does not cause its invocation to be reported
+    let errors = assembler.compile_checked(source).unwrap_err();
+    let expected = [
+        "instruction 'bogus' is invalid at line 2, column 20",
+        "undefined procedure: bar at line 3, column 13",
+        "else without matching if at line 6, column 17",
+        "undefined constant: COUNT at line 9, column 31",
+    ];
+    assert_eq!(
+        expected.to_vec(),
+        errors.iter().map(|err| err.to_string()).collect::<Vec<_>>()
+    );
+
+    // the first of the errors is the one reported by compile() and check_syntax()
+    assert_eq!(errors[0], assembler.compile(source).unwrap_err());
+    let syntax_errors = super::Assembler::check_syntax(source).unwrap_err();
+    assert_eq!(expected.len(), syntax_errors.len());
+
+    // a procedure with an unmatched block is skipped up to the next procedure
+    let source = "proc.foo if.true add proc.bar push.1 end begin exec.foo exec.bar end";
+    let errors = assembler.compile_checked(source).unwrap_err();
+    assert_eq!(1, errors.len());
+    assert_eq!(
+        "unexpected body termination: invalid token 'proc.bar' at line 1, column 22",
+        errors[0].to_string()
+    );
+
+    // a valid program compiles into the same program as with compile()
+    let source = "proc.foo push.1 end begin exec.foo add end";
+    assert_eq!(
+        assembler.compile(source).unwrap().hash(),
+        assembler.compile_checked(source).unwrap().hash()
+    );
+}
+
 #[test]
 fn invalid_proc() {
     let assembler = Assembler::default();
@@ -400,7 +2350,7 @@ fn invalid_proc() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "unexpected body termination: invalid token 'begin'"
+            "unexpected body termination: invalid token 'begin' at line 1, column 18"
         );
     }
 
@@ -410,7 +2360,7 @@ fn invalid_proc() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "unexpected body termination: invalid token 'proc.bar'"
+            "unexpected body termination: invalid token 'proc.bar' at line 1, column 18"
         );
     }
 
@@ -418,21 +2368,30 @@ fn invalid_proc() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "undefined procedure: bar");
+        assert_eq!(
+            error.to_string(),
+            "undefined procedure: bar at line 1, column 35"
+        );
     }
 
     let source = "proc.123 add mul end begin push.1 exec.123 end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "invalid procedure label: 123");
+        assert_eq!(
+            error.to_string(),
+            "invalid procedure label: 123 at line 1, column 1"
+        );
     }
 
     let source = "proc.foo add mul end proc.foo push.3 end begin push.1 end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "duplicate procedure label: foo");
+        assert_eq!(
+            error.to_string(),
+            "duplicate procedure label: foo at line 1, column 22"
+        );
     }
 }
 
@@ -445,7 +2404,10 @@ fn invalid_if_else() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "if without matching else/end");
+        assert_eq!(
+            error.to_string(),
+            "if without matching else/end at line 1, column 18"
+        );
     }
 
     // --- unmatched else -------------------------------------------------------------------------
@@ -453,28 +2415,40 @@ fn invalid_if_else() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching if");
+        assert_eq!(
+            error.to_string(),
+            "else without matching if at line 1, column 18"
+        );
     }
 
     let source = "begin push.1 while.true add else mul end end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching if");
+        assert_eq!(
+            error.to_string(),
+            "else without matching if at line 1, column 29"
+        );
     }
 
     let source = "begin push.1 if.true add else mul else push.1 end end end";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching if");
+        assert_eq!(
+            error.to_string(),
+            "else without matching if at line 1, column 35"
+        );
     }
 
     let source = "begin push.1 add if.true mul else add";
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "else without matching end");
+        assert_eq!(
+            error.to_string(),
+            "else without matching end at line 1, column 30"
+        );
     }
 }
 
@@ -487,7 +2461,10 @@ fn invalid_repeat() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "repeat without matching end");
+        assert_eq!(
+            error.to_string(),
+            "repeat without matching end at line 1, column 18"
+        );
     }
 
     // invalid iter count
@@ -497,9 +2474,71 @@ fn invalid_repeat() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "malformed instruction `repeat.23x3`: parameter '23x3' is invalid"
+            "malformed instruction `repeat.23x3`: parameter '23x3' is invalid at line 1, column 18"
+        );
+    }
+
+    // iter count which does not fit into u32
+    let source = "begin push.1 add repeat.4294967296 mul end end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(
+            error.to_string(),
+            "malformed instruction `repeat.4294967296`: parameter '4294967296' is invalid at line \
+            1, column 18"
+        );
+    }
+
+    // iter count which exceeds the default maximum
+    let source = "begin push.1 add repeat.100000000 mul end end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(
+            error.to_string(),
+            "repeat count 100000000 exceeds maximum 65536 at line 1, column 18"
+        );
+    }
+}
+
+#[test]
+fn repeat_with_max_count() {
+    let assembler = super::Assembler::default().with_max_repeat_count(2);
+
+    // counts within the limit are unrolled as before
+    let source = "begin push.1 repeat.2 push.8 add end end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "begin span pad incr push(8) add push(8) add end end";
+    assert_eq!(expected, format!("{program}"));
+
+    // counts above the limit result in an error, including in nested blocks
+    let source = "begin push.1 if.true repeat.3 push.8 add end end end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(
+            error.to_string(),
+            "repeat count 3 exceeds maximum 2 at line 1, column 22"
         );
     }
+
+    // the limit applies to the product of the counts of nested blocks
+    let assembler = super::Assembler::default().with_max_repeat_count(6);
+    let source = "begin repeat.2 repeat.3 push.1 end end end";
+    assert!(assembler.compile(source).is_ok());
+    let source = "begin repeat.2 push.1 if.true repeat.2 repeat.2 push.1 end end end end end";
+    assert_eq!(
+        "repeat count 8 exceeds maximum 6 at line 1, column 7",
+        assembler.compile(source).unwrap_err().to_string()
+    );
+
+    let assembler = super::Assembler::default();
+    let source = "begin repeat.65536 repeat.65536 push.1 end end end";
+    assert_eq!(
+        "repeat count 4294967296 exceeds maximum 65536 at line 1, column 7",
+        assembler.compile(source).unwrap_err().to_string()
+    );
 }
 
 #[test]
@@ -512,7 +2551,7 @@ fn invalid_while() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "malformed instruction 'while': missing required parameter"
+            "malformed instruction 'while': missing required parameter at line 1, column 18"
         );
     }
 
@@ -522,7 +2561,7 @@ fn invalid_while() {
     if let Err(error) = program {
         assert_eq!(
             error.to_string(),
-            "malformed instruction `while.abc`: parameter 'abc' is invalid"
+            "malformed instruction `while.abc`: parameter 'abc' is invalid at line 1, column 18"
         );
     }
 
@@ -530,6 +2569,38 @@ fn invalid_while() {
     let program = assembler.compile(source);
     assert!(program.is_err());
     if let Err(error) = program {
-        assert_eq!(error.to_string(), "while without matching end");
+        assert_eq!(
+            error.to_string(),
+            "while without matching end at line 1, column 18"
+        );
+    }
+}
+
+/// Returns the number of assembly operation decorators and the number of advice injectors in the
+/// specified block and in all blocks called from it.
+fn count_decorators(block: &CodeBlock, cb_table: &CodeBlockTable) -> (usize, usize) {
+    let sum = |a: (usize, usize), b: (usize, usize)| (a.0 + b.0, a.1 + b.1);
+    match block {
+        CodeBlock::Span(span) => span
+            .decorators()
+            .iter()
+            .fold((0, 0), |acc, (_, decorator)| match decorator {
+                Decorator::AsmOp(_) => (acc.0 + 1, acc.1),
+                Decorator::Advice(_) => (acc.0, acc.1 + 1),
+                Decorator::ErrorCode(_) => acc,
+            }),
+        CodeBlock::Join(block) => sum(
+            count_decorators(block.first(), cb_table),
+            count_decorators(block.second(), cb_table),
+        ),
+        CodeBlock::Split(block) => sum(
+            count_decorators(block.on_true(), cb_table),
+            count_decorators(block.on_false(), cb_table),
+        ),
+        CodeBlock::Loop(block) => count_decorators(block.body(), cb_table),
+        CodeBlock::Call(block) => {
+            count_decorators(cb_table.get(block.fn_hash()).unwrap(), cb_table)
+        }
+        CodeBlock::Proxy(_) => (0, 0),
     }
 }
@@ -1,9 +1,14 @@
-use super::{BTreeMap, ParsingError, String, ToString, Vec};
+use super::{
+    BTreeMap, ParsingError, ProcedureId, SourceLocation, String, ToString, Vec, MODULE_PATH_DELIM,
+};
 use core::fmt;
 
 mod stream;
 pub use stream::TokenStream;
 
+/// Delimiter between the path of an imported module and its alias (e.g., `use.std::math::u256->big`).
+const IMPORT_ALIAS_DELIM: &str = "->";
+
 // TOKEN
 // ================================================================================================
 /// TODO: add comments
@@ -11,6 +16,7 @@ pub use stream::TokenStream;
 pub struct Token<'a> {
     parts: Vec<&'a str>,
     pos: usize,
+    location: SourceLocation,
 }
 
 impl<'a> Token<'a> {
@@ -18,6 +24,7 @@ impl<'a> Token<'a> {
     // --------------------------------------------------------------------------------------------
 
     pub const USE: &'static str = "use";
+    pub const CONST: &'static str = "const";
     pub const PROC: &'static str = "proc";
     pub const EXPORT: &'static str = "export";
 
@@ -33,15 +40,17 @@ impl<'a> Token<'a> {
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new token created from the specified string and position.
+    /// Returns a new token created from the specified string, position and location in the
+    /// source code.
     ///
     /// # Panics
     /// Panic is the `token` parameter is an empty string.
-    pub fn new(token: &'a str, pos: usize) -> Self {
+    pub fn new(token: &'a str, pos: usize, location: SourceLocation) -> Self {
         assert!(!token.is_empty(), "token cannot be an empty string");
         Self {
             parts: token.split('.').collect(),
             pos,
+            location,
         }
     }
 
@@ -53,13 +62,18 @@ impl<'a> Token<'a> {
         self.pos
     }
 
+    /// Returns the location of this token in the source code.
+    pub fn location(&self) -> SourceLocation {
+        self.location
+    }
+
     /// Returns the number of parts in this token.
     pub fn num_parts(&self) -> usize {
         self.parts.len()
     }
 
     /// Returns a reference to this token's parts.
-    pub fn parts(&self) -> &[&str] {
+    pub fn parts(&self) -> &[&'a str] {
         &self.parts
     }
 
@@ -68,6 +82,7 @@ impl<'a> Token<'a> {
         matches!(
             self.parts()[0],
             Self::USE
+                | Self::CONST
                 | Self::PROC
                 | Self::EXPORT
                 | Self::BEGIN
@@ -84,25 +99,57 @@ impl<'a> Token<'a> {
 
     // STATE MUTATOR
     // --------------------------------------------------------------------------------------------
-    /// Updates the contents of this token from the specified string and position.
+    /// Updates the contents of this token from the specified string, position and location in
+    /// the source code.
     ///
     /// # Panics
     /// Panic is the `token` parameter is an empty string.
-    pub fn update(&mut self, token: &'a str, pos: usize) {
+    pub fn update(&mut self, token: &'a str, pos: usize, location: SourceLocation) {
         assert!(!token.is_empty(), "token cannot be an empty string");
         self.parts.clear();
         token.split('.').for_each(|part| self.parts.push(part));
         self.pos = pos;
+        self.location = location;
     }
 
     // CONTROL TOKEN PARSERS / VALIDATORS
     // --------------------------------------------------------------------------------------------
 
-    pub fn parse_use(&self) -> Result<String, ParsingError> {
+    /// Parses an import of the form `use.PATH` or `use.PATH->ALIAS` and returns the path of the
+    /// imported module together with its alias, if one is specified.
+    pub fn parse_use(&self) -> Result<(String, Option<&'a str>), ParsingError> {
         assert_eq!(Self::USE, self.parts[0], "not a use");
         match self.num_parts() {
             1 => Err(ParsingError::missing_param(self)),
-            2 => validate_import_path(self.parts[1], self),
+            2 => match self.parts[1].split_once(IMPORT_ALIAS_DELIM) {
+                Some((path, alias)) => {
+                    let path = validate_import_path(path, self)?;
+                    if !is_module_alias(alias) {
+                        return Err(ParsingError::invalid_module_alias(self, alias));
+                    }
+                    Ok((path, Some(alias)))
+                }
+                None => Ok((validate_import_path(self.parts[1], self)?, None)),
+            },
+            _ => Err(ParsingError::extra_param(self)),
+        }
+    }
+
+    /// Parses a constant declaration of the form `const.NAME=VALUE` and returns the name and the
+    /// unparsed value of the declared constant.
+    pub fn parse_const(&self) -> Result<(&'a str, &'a str), ParsingError> {
+        assert_eq!(Self::CONST, self.parts[0], "not a const");
+        match self.num_parts() {
+            1 => Err(ParsingError::missing_param(self)),
+            2 => {
+                let (name, value) = self.parts[1]
+                    .split_once('=')
+                    .ok_or_else(|| ParsingError::invalid_param(self, 1))?;
+                if !is_const_name(name) {
+                    return Err(ParsingError::invalid_const_name(self, name));
+                }
+                Ok((name, value))
+            }
             _ => Err(ParsingError::extra_param(self)),
         }
     }
@@ -130,13 +177,45 @@ impl<'a> Token<'a> {
             }
             3 => {
                 let label = validate_proc_declaration_label(self.parts[1], self)?;
-                let num_locals = validate_proc_locals(self.parts[2], self)?;
+                let num_locals = validate_proc_locals(self.parts[2], &label, self)?;
                 Ok((label, num_locals, is_export))
             }
             _ => Err(ParsingError::extra_param(self)),
         }
     }
 
+    /// Returns true if this token is a re-export of an imported procedure (e.g.,
+    /// `export.u256::iszero_unsafe`) rather than a procedure declaration.
+    pub fn is_reexport(&self) -> bool {
+        self.parts[0] == Self::EXPORT
+            && self
+                .parts
+                .get(1)
+                .map_or(false, |label| label.contains(MODULE_PATH_DELIM))
+    }
+
+    /// Parses a re-export of the form `export.MODULE::NAME` or `export.MODULE::NAME->ALIAS` and
+    /// returns the short name of the re-exported procedure (e.g., `u256::iszero_unsafe`) together
+    /// with the name under which the procedure is re-exported.
+    pub fn parse_reexport(&self) -> Result<(&'a str, String), ParsingError> {
+        assert!(self.is_reexport(), "not a re-export");
+        match self.num_parts() {
+            2 => {
+                let (short_name, alias) = match self.parts[1].split_once(IMPORT_ALIAS_DELIM) {
+                    Some((short_name, alias)) => (short_name, Some(alias)),
+                    None => (self.parts[1], None),
+                };
+                let short_name = validate_proc_invocation_label(short_name, self)?;
+                let (_, proc_name) = short_name
+                    .rsplit_once(MODULE_PATH_DELIM)
+                    .expect("no module path delimiter");
+                let label = validate_proc_declaration_label(alias.unwrap_or(proc_name), self)?;
+                Ok((short_name, label))
+            }
+            _ => Err(ParsingError::extra_param(self)),
+        }
+    }
+
     pub fn validate_if(&self) -> Result<(), ParsingError> {
         assert_eq!(Self::IF, self.parts[0], "not an if");
         match self.num_parts() {
@@ -187,7 +266,7 @@ impl<'a> Token<'a> {
         }
     }
 
-    pub fn parse_exec(&self) -> Result<String, ParsingError> {
+    pub fn parse_exec(&self) -> Result<&'a str, ParsingError> {
         assert_eq!(Self::EXEC, self.parts[0], "not an exec");
         match self.num_parts() {
             1 => Err(ParsingError::missing_param(self)),
@@ -196,7 +275,7 @@ impl<'a> Token<'a> {
         }
     }
 
-    pub fn parse_call(&self) -> Result<String, ParsingError> {
+    pub fn parse_call(&self) -> Result<&'a str, ParsingError> {
         assert_eq!(Self::CALL, self.parts[0], "not a call");
         match self.num_parts() {
             1 => Err(ParsingError::missing_param(self)),
@@ -205,7 +284,7 @@ impl<'a> Token<'a> {
         }
     }
 
-    pub fn parse_syscall(&self) -> Result<String, ParsingError> {
+    pub fn parse_syscall(&self) -> Result<&'a str, ParsingError> {
         assert_eq!(Self::SYSCALL, self.parts[0], "not a syscall");
         match self.num_parts() {
             1 => Err(ParsingError::missing_param(self)),
@@ -250,13 +329,45 @@ fn validate_proc_declaration_label(label: &str, token: &Token) -> Result<String,
     Ok(label.to_string())
 }
 
+/// Returns true if the specified string is a valid constant name. A constant name must comply with
+/// the following rules:
+/// - It must start with an uppercase ascii letter.
+/// - It can contain only uppercase ascii letters, numbers, or underscores.
+///
+/// Parameters of instructions which comply with these rules are treated as references to
+/// constants, except for targets of procedure invocations (e.g., `exec.INIT`) which are always
+/// treated as procedure names.
+pub fn is_const_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
 /// A label of an invoked procedure must comply with the following rules:
 /// - It must start with an ascii letter.
 /// - It can contain only ascii letters, numbers, underscores, or colons.
 ///
 /// As compared to procedure declaration label, colons are allowed here to support invocation
 /// of imported procedures.
-fn validate_proc_invocation_label(label: &str, token: &Token) -> Result<String, ParsingError> {
+///
+/// Alternatively, a label can be a hex-encoded procedure ID prefixed with `0x`.
+///
+/// The label is returned as a slice of the source to avoid allocating a string for every procedure
+/// invocation.
+fn validate_proc_invocation_label<'a>(
+    label: &'a str,
+    token: &Token,
+) -> Result<&'a str, ParsingError> {
+    // a label could be a procedure ID specified directly
+    if label.starts_with("0x") {
+        return ProcedureId::from_hex(label)
+            .map(|_| label)
+            .ok_or_else(|| ParsingError::invalid_proc_label(token, label));
+    }
+
     // a label must start with a letter
     if label.is_empty() || !label.chars().next().unwrap().is_ascii_alphabetic() {
         return Err(ParsingError::invalid_proc_label(token, label));
@@ -270,20 +381,18 @@ fn validate_proc_invocation_label(label: &str, token: &Token) -> Result<String,
         return Err(ParsingError::invalid_proc_label(token, label));
     }
 
-    Ok(label.to_string())
+    Ok(label)
 }
 
 /// Procedure locals must be a 16-bit integer.
-fn validate_proc_locals(locals: &str, token: &Token) -> Result<u16, ParsingError> {
-    match locals.parse::<u64>() {
-        Ok(num_locals) => {
-            if num_locals > u16::MAX as u64 {
-                return Err(ParsingError::invalid_proc_locals(token, locals));
-            }
-            Ok(num_locals as u16)
-        }
-        Err(_) => Err(ParsingError::invalid_proc_locals(token, locals)),
+fn validate_proc_locals(locals: &str, label: &str, token: &Token) -> Result<u16, ParsingError> {
+    if locals.is_empty() || !locals.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParsingError::invalid_proc_locals(token, locals));
     }
+    // a string of digits fails to parse only if the number does not fit into u16
+    locals
+        .parse::<u16>()
+        .map_err(|_| ParsingError::too_many_proc_locals(token, label, locals))
 }
 
 /// A module import path must comply with the following rules:
@@ -305,3 +414,10 @@ fn validate_import_path(path: &str, token: &Token) -> Result<String, ParsingErro
 
     Ok(path.to_string())
 }
+
+/// A module alias must start with an ascii letter and can contain only ascii letters, numbers, or
+/// underscores.
+fn is_module_alias(alias: &str) -> bool {
+    alias.starts_with(|c: char| c.is_ascii_alphabetic())
+        && alias.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -1,15 +1,17 @@
-use super::{BTreeMap, ParsingError, String, Token, Vec};
+use super::{BTreeMap, ParsingError, SourceLocation, String, Token, Vec};
 use core::fmt;
 
 pub const DOC_COMMENT_PREFIX: &str = "#!";
 pub const LINE_COMMENT_PREFIX: &str = "#";
+pub const BLOCK_COMMENT_START: &str = "/*";
+pub const BLOCK_COMMENT_END: &str = "*/";
 
 // TOKEN STREAM
 // ================================================================================================
 
 #[derive(Debug)]
 pub struct TokenStream<'a> {
-    tokens: Vec<&'a str>,
+    tokens: Vec<(&'a str, SourceLocation)>,
     current: Token<'a>,
     pos: usize,
     temp: Token<'a>,
@@ -28,29 +30,34 @@ impl<'a> TokenStream<'a> {
         let mut doc_comments = BTreeMap::new();
 
         let mut comment = Comment(None);
+        let mut scanner = LineScanner::default();
 
-        for line in source.lines() {
-            let line = line.trim();
-            if line.starts_with(DOC_COMMENT_PREFIX) {
-                comment.append_line(line);
-            } else if line.is_empty() && !comment.is_empty() {
+        for (line_idx, line) in source.lines().enumerate() {
+            let trimmed = line.trim();
+            if !scanner.in_block_comment() && trimmed.starts_with(DOC_COMMENT_PREFIX) {
+                comment.append_line(trimmed);
+            } else if trimmed.is_empty() && !comment.is_empty() {
                 return Err(ParsingError::malformed_doc_comment(tokens.len()));
             } else {
                 if !comment.is_empty() {
                     doc_comments.insert(tokens.len(), comment.take_content());
                 }
-                let mut line_tokens = line
-                    .split_whitespace()
-                    .take_while(|&token| !token.starts_with(LINE_COMMENT_PREFIX))
-                    .collect::<Vec<_>>();
-                tokens.append(&mut line_tokens);
+                scanner.scan_line(line_idx, line, &mut tokens);
             }
         }
 
+        if let Some(location) = scanner.unterminated_comment() {
+            return Err(ParsingError::unterminated_block_comment(
+                tokens.len(),
+                location,
+            ));
+        }
+
         if tokens.is_empty() {
             return Err(ParsingError::empty_source());
         }
-        let current = Token::new(tokens[0], 0);
+        let (token, location) = tokens[0];
+        let current = Token::new(token, 0, location);
         Ok(Self {
             tokens,
             current,
@@ -68,6 +75,12 @@ impl<'a> TokenStream<'a> {
         self.pos
     }
 
+    /// Returns the location in the source code of the current token in this stream, or None if
+    /// all the tokens have been read.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.read().map(Token::location)
+    }
+
     /// Returns 'true' all tokens from this stream have been read.
     pub fn eof(&self) -> bool {
         self.pos == self.tokens.len()
@@ -78,7 +91,7 @@ impl<'a> TokenStream<'a> {
 
     /// Returns a token from this stream located at the current position. If all the tokens have
     /// been read, returns None.
-    pub fn read(&self) -> Option<&Token> {
+    pub fn read(&self) -> Option<&Token<'a>> {
         if self.eof() {
             None
         } else {
@@ -91,12 +104,13 @@ impl<'a> TokenStream<'a> {
     ///
     /// # Panics
     /// Panics if the specified position is greater than the current token position in the stream.
-    pub fn read_at(&mut self, pos: usize) -> Option<&Token> {
+    pub fn read_at(&mut self, pos: usize) -> Option<&Token<'a>> {
         assert!(pos <= self.pos, "cannot read from future positions");
         if pos == self.pos {
             self.read()
         } else {
-            self.temp.update(self.tokens[pos], pos);
+            let (token, location) = self.tokens[pos];
+            self.temp.update(token, pos, location);
             Some(&self.temp)
         }
     }
@@ -106,7 +120,8 @@ impl<'a> TokenStream<'a> {
         if !self.eof() {
             self.pos += 1;
             if !self.eof() {
-                self.current.update(self.tokens[self.pos], self.pos);
+                let (token, location) = self.tokens[self.pos];
+                self.current.update(token, self.pos, location);
             }
         }
     }
@@ -122,6 +137,102 @@ impl<'a> fmt::Display for TokenStream<'a> {
     }
 }
 
+// LINE SCANNER
+// ================================================================================================
+
+/// Splits lines of the source into tokens, skipping line comments and block comments.
+///
+/// Block comments can span multiple lines and can be nested; thus, the scanner keeps track of the
+/// block comments which are open at the end of a line.
+#[derive(Debug, Default)]
+struct LineScanner {
+    /// Number of block comments which are currently open.
+    depth: usize,
+    /// Location of the start of the outermost block comment which is currently open.
+    opening: Option<SourceLocation>,
+}
+
+impl LineScanner {
+    /// Returns true if the scanner is inside a block comment.
+    fn in_block_comment(&self) -> bool {
+        self.depth > 0
+    }
+
+    /// Returns the location of the start of the outermost block comment which was not closed, if
+    /// any.
+    fn unterminated_comment(&self) -> Option<SourceLocation> {
+        self.opening
+    }
+
+    /// Appends the tokens of the specified line together with their locations in the source code
+    /// to the provided list of tokens.
+    ///
+    /// A token starting with the line comment prefix ends the line (unless it is inside a block
+    /// comment); tokens are also split at the start of a block comment.
+    fn scan_line<'a>(
+        &mut self,
+        line_idx: usize,
+        line: &'a str,
+        tokens: &mut Vec<(&'a str, SourceLocation)>,
+    ) {
+        let mut token_start = None;
+        let mut chars = line.char_indices().peekable();
+        while let Some((idx, c)) = chars.next() {
+            let rest = &line[idx..];
+            if self.depth > 0 {
+                if rest.starts_with(BLOCK_COMMENT_START) {
+                    self.depth += 1;
+                    chars.next();
+                } else if rest.starts_with(BLOCK_COMMENT_END) {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        self.opening = None;
+                    }
+                    chars.next();
+                }
+            } else if rest.starts_with(BLOCK_COMMENT_START) {
+                if let Some(start) = token_start.take() {
+                    tokens.push(locate(line_idx, line, start, idx));
+                }
+                self.depth = 1;
+                self.opening = Some(SourceLocation {
+                    line: line_idx + 1,
+                    column: line[..idx].chars().count() + 1,
+                    len: BLOCK_COMMENT_START.len(),
+                });
+                chars.next();
+            } else if c.is_whitespace() {
+                if let Some(start) = token_start.take() {
+                    tokens.push(locate(line_idx, line, start, idx));
+                }
+            } else if token_start.is_none() {
+                if rest.starts_with(LINE_COMMENT_PREFIX) {
+                    return;
+                }
+                token_start = Some(idx);
+            }
+        }
+        if let Some(start) = token_start {
+            tokens.push(locate(line_idx, line, start, line.len()));
+        }
+    }
+}
+
+/// Returns the token spanning the specified byte range of the specified line together with the
+/// location of the token in the source code.
+fn locate(line_idx: usize, line: &str, start: usize, end: usize) -> (&str, SourceLocation) {
+    let token = &line[start..end];
+    let location = SourceLocation {
+        line: line_idx + 1,
+        column: line[..start].chars().count() + 1,
+        len: token.chars().count(),
+    };
+    (token, location)
+}
+
+// COMMENTS
+// ================================================================================================
+
 #[derive(Debug)]
 pub struct Comment(Option<String>);
 
@@ -0,0 +1,69 @@
+//! Makes sure that parsing does not allocate for every token of the source. The test binary uses a
+//! global allocator which counts allocations made by the current thread.
+use miden_assembly::parse_module;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+mod common;
+
+// COUNTING ALLOCATOR
+// ================================================================================================
+
+thread_local! {
+    static NUM_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = NUM_ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = NUM_ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made by the current thread while executing `f`.
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let start = NUM_ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, NUM_ALLOCATIONS.with(Cell::get) - start)
+}
+
+// TESTS
+// ================================================================================================
+
+const NUM_PROCS: usize = 200;
+const PROC_LEN: usize = 100;
+
+/// Number of allocations made while parsing the synthetic module by the tokenizer which allocated
+/// a string for every token; measured before the tokenizer was switched to slices of the source.
+const NUM_ALLOCATIONS_BEFORE: usize = 42450;
+
+#[test]
+fn parse_large_module_allocations() {
+    let source = common::build_synthetic_module(NUM_PROCS, PROC_LEN);
+
+    let (module, num_allocations) = count_allocations(|| parse_module(&source));
+    let module = module.unwrap();
+    assert_eq!(NUM_PROCS, module.local_procs.len());
+
+    // parsing must make at least 2x fewer allocations than it did with the allocating tokenizer
+    assert!(
+        num_allocations * 2 <= NUM_ALLOCATIONS_BEFORE,
+        "{num_allocations} allocations, previously {NUM_ALLOCATIONS_BEFORE}"
+    );
+}
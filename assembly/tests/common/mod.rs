@@ -0,0 +1,35 @@
+//! Fixtures shared by the integration tests and benchmarks of the assembler.
+
+/// Instructions which make up the bodies of the procedures in the synthetic module.
+const INSTRUCTIONS: [&str; 12] = [
+    "push.1",
+    "push.0x0000000000000002",
+    "add",
+    "dup.3",
+    "movup.2",
+    "u32checked_add",
+    "swap",
+    "mul.7",
+    "drop",
+    "push.1.2.3",
+    "eq",
+    "drop",
+];
+
+/// Builds a module of `num_procs` procedures with `proc_len` instructions each (one instruction
+/// per line); every procedure other than the first one also invokes the previous procedure.
+pub fn build_synthetic_module(num_procs: usize, proc_len: usize) -> String {
+    let mut source = String::new();
+    for i in 0..num_procs {
+        source.push_str(&format!("proc.proc_{i}\n"));
+        for j in 0..proc_len {
+            let instruction = match j {
+                0 if i > 0 => format!("exec.proc_{}", i - 1),
+                _ => INSTRUCTIONS[j % INSTRUCTIONS.len()].to_string(),
+            };
+            source.push_str(&format!("    {instruction}\n"));
+        }
+        source.push_str("end\n\n");
+    }
+    source
+}
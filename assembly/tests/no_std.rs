@@ -0,0 +1,19 @@
+//! Makes sure that the assembler can be used in environments without the standard library. When
+//! the crate is built without default features, the only requirement is the `alloc` crate.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::format;
+use miden_assembly::Assembler;
+
+#[test]
+fn compile_simple_program() {
+    let program = Assembler::new()
+        .compile("begin push.1 push.2 add end")
+        .unwrap();
+    assert_eq!(
+        "begin span pad incr push(2) add end end",
+        format!("{program}")
+    );
+}
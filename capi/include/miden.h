@@ -0,0 +1,129 @@
+#ifndef MIDEN_H
+#define MIDEN_H
+
+/* Warning: this file is generated by cbindgen; do not modify it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Returned by fallible functions on success.
+ */
+#define MIDEN_OK 0
+
+/**
+ * Returned by fallible functions on failure.
+ */
+#define MIDEN_ERROR 1
+
+/**
+ * An opaque handle to a compiled Miden program.
+ */
+typedef struct MidenProgram MidenProgram;
+
+/**
+ * Compiles Miden assembly source code against Miden standard library; on success, a handle to
+ * the compiled program is written into `out_program`.
+ *
+ * # Safety
+ * `source` must point to `len` bytes of UTF-8 encoded source code (the source does not need to
+ * be NUL-terminated). `out_program` and `out_err` must be valid pointers or NULL.
+ */
+int32_t miden_compile(const char *source,
+                      size_t len,
+                      MidenProgram **out_program,
+                      char **out_err);
+
+/**
+ * Writes the hex-encoded hash of the specified program into `out_hash_hex`.
+ *
+ * # Safety
+ * `program` must be a handle returned by [miden_compile()] which has not been released yet.
+ * `out_hash_hex` and `out_err` must be valid pointers or NULL.
+ */
+int32_t miden_program_hash(const MidenProgram *program, char **out_hash_hex, char **out_err);
+
+/**
+ * Executes the specified program against the specified inputs; on success, outputs of the
+ * program are written into `out_outputs_json`.
+ *
+ * If `inputs_json` is NULL, the program is executed with no inputs.
+ *
+ * # Safety
+ * `program` must be a handle returned by [miden_compile()] which has not been released yet.
+ * `inputs_json` must be a NUL-terminated string or NULL. `out_outputs_json` and `out_err` must be
+ * valid pointers or NULL.
+ */
+int32_t miden_execute(const MidenProgram *program,
+                      const char *inputs_json,
+                      char **out_outputs_json,
+                      char **out_err);
+
+/**
+ * Executes the specified program against the specified inputs and generates a proof of the
+ * execution using default proof options; on success, outputs of the program are written into
+ * `out_outputs_json`, and the serialized proof is written into `out_proof` and `out_proof_len`.
+ *
+ * The proof is serialized in the same format as proof files written by Miden CLI (i.e., it is
+ * preceded by the artifact header), and this is the format expected by [miden_verify()].
+ *
+ * If `inputs_json` is NULL, the program is executed with no inputs.
+ *
+ * # Safety
+ * `program` must be a handle returned by [miden_compile()] which has not been released yet.
+ * `inputs_json` must be a NUL-terminated string or NULL. `out_outputs_json`, `out_proof`,
+ * `out_proof_len`, and `out_err` must be valid pointers or NULL.
+ */
+int32_t miden_prove(const MidenProgram *program,
+                    const char *inputs_json,
+                    char **out_outputs_json,
+                    uint8_t **out_proof,
+                    size_t *out_proof_len,
+                    char **out_err);
+
+/**
+ * Returns true if the specified proof attests that a program with the specified hash, executed
+ * against the specified inputs, produced the specified outputs.
+ *
+ * Only stack inputs are used for verification (advice inputs are secret). Returns false if any
+ * of the arguments is malformed.
+ *
+ * # Safety
+ * `hash_hex`, `inputs_json`, and `outputs_json` must be NUL-terminated strings, and `proof` must
+ * point to `proof_len` bytes.
+ */
+bool miden_verify(const char *hash_hex,
+                  const char *inputs_json,
+                  const char *outputs_json,
+                  const uint8_t *proof,
+                  size_t proof_len);
+
+/**
+ * Releases a program handle returned by [miden_compile()]. Does nothing if `program` is NULL.
+ *
+ * # Safety
+ * `program` must be a handle returned by [miden_compile()] which has not been released yet.
+ */
+void miden_free_program(MidenProgram *program);
+
+/**
+ * Releases a string returned by any function of this library. Does nothing if `value` is NULL.
+ *
+ * # Safety
+ * `value` must be a string returned by this library which has not been released yet.
+ */
+void miden_free_string(char *value);
+
+/**
+ * Releases a proof returned by [miden_prove()]. Does nothing if `proof` is NULL.
+ *
+ * # Safety
+ * `proof` and `proof_len` must be the values returned by [miden_prove()], and the proof must not
+ * have been released yet.
+ */
+void miden_free_proof(uint8_t *proof, size_t proof_len);
+
+#endif /* MIDEN_H */
@@ -0,0 +1,347 @@
+//! C bindings for compiling, executing, proving, and verifying Miden programs.
+//!
+//! All functions which can fail return [MIDEN_OK] on success and [MIDEN_ERROR] on failure; in the
+//! latter case, a description of the error is written into `out_err` as a NUL-terminated UTF-8
+//! string. Every string, proof, and program handle returned by this library is owned by the
+//! caller and must be released via the corresponding `miden_free_*()` function.
+//!
+//! Program inputs and outputs are passed as JSON strings which use the same encoding as input and
+//! output files of Miden CLI (e.g., `{"stack_init": ["1", "2"], "advice_tape": ["3"]}`).
+//!
+//! Panics never cross the FFI boundary: a panic is reported in the same way as any other error.
+
+use assembly::Assembler;
+use std::{
+    any::Any,
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+use stdlib::StdLibrary;
+use verifier::StarkProof;
+use vm_core::{
+    artifact::{strip_header, with_header, ArtifactKind},
+    felt_fmt, Program, ProgramInputs, ProgramOutputs, StarkField,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Returned by fallible functions on success.
+pub const MIDEN_OK: i32 = 0;
+
+/// Returned by fallible functions on failure.
+pub const MIDEN_ERROR: i32 = 1;
+
+// PROGRAM HANDLE
+// ================================================================================================
+
+/// An opaque handle to a compiled Miden program.
+pub struct MidenProgram(Program);
+
+// COMPILE
+// ================================================================================================
+
+/// Compiles Miden assembly source code against Miden standard library; on success, a handle to
+/// the compiled program is written into `out_program`.
+///
+/// # Safety
+/// `source` must point to `len` bytes of UTF-8 encoded source code (the source does not need to
+/// be NUL-terminated). `out_program` and `out_err` must be valid pointers or NULL.
+#[no_mangle]
+pub unsafe extern "C" fn miden_compile(
+    source: *const c_char,
+    len: usize,
+    out_program: *mut *mut MidenProgram,
+    out_err: *mut *mut c_char,
+) -> i32 {
+    guard(out_err, || {
+        check_out_param(out_program, "out_program")?;
+        if source.is_null() {
+            return Err("source is NULL".to_string());
+        }
+        let source = slice::from_raw_parts(source as *const u8, len);
+        let source =
+            std::str::from_utf8(source).map_err(|err| format!("Invalid source - {err}"))?;
+
+        let program = Assembler::new()
+            .with_module_provider(StdLibrary::default())
+            .compile(source)
+            .map_err(|err| format!("Failed to compile program - {err}"))?;
+
+        *out_program = Box::into_raw(Box::new(MidenProgram(program)));
+        Ok(())
+    })
+}
+
+/// Writes the hex-encoded hash of the specified program into `out_hash_hex`.
+///
+/// # Safety
+/// `program` must be a handle returned by [miden_compile()] which has not been released yet.
+/// `out_hash_hex` and `out_err` must be valid pointers or NULL.
+#[no_mangle]
+pub unsafe extern "C" fn miden_program_hash(
+    program: *const MidenProgram,
+    out_hash_hex: *mut *mut c_char,
+    out_err: *mut *mut c_char,
+) -> i32 {
+    guard(out_err, || {
+        check_out_param(out_hash_hex, "out_hash_hex")?;
+        let program = get_program(program)?;
+        *out_hash_hex = into_c_string(felt_fmt::format_digest(&program.hash()));
+        Ok(())
+    })
+}
+
+// EXECUTE AND PROVE
+// ================================================================================================
+
+/// Executes the specified program against the specified inputs; on success, outputs of the
+/// program are written into `out_outputs_json`.
+///
+/// If `inputs_json` is NULL, the program is executed with no inputs.
+///
+/// # Safety
+/// `program` must be a handle returned by [miden_compile()] which has not been released yet.
+/// `inputs_json` must be a NUL-terminated string or NULL. `out_outputs_json` and `out_err` must be
+/// valid pointers or NULL.
+#[no_mangle]
+pub unsafe extern "C" fn miden_execute(
+    program: *const MidenProgram,
+    inputs_json: *const c_char,
+    out_outputs_json: *mut *mut c_char,
+    out_err: *mut *mut c_char,
+) -> i32 {
+    guard(out_err, || {
+        check_out_param(out_outputs_json, "out_outputs_json")?;
+        let program = get_program(program)?;
+        let inputs = parse_inputs(inputs_json)?;
+
+        let trace = processor::execute(program, &inputs)
+            .map_err(|err| format!("Failed to execute program - {err}"))?;
+
+        *out_outputs_json = into_c_string(to_json(&trace.program_outputs())?);
+        Ok(())
+    })
+}
+
+/// Executes the specified program against the specified inputs and generates a proof of the
+/// execution using default proof options; on success, outputs of the program are written into
+/// `out_outputs_json`, and the serialized proof is written into `out_proof` and `out_proof_len`.
+///
+/// The proof is serialized in the same format as proof files written by Miden CLI (i.e., it is
+/// preceded by the artifact header), and this is the format expected by [miden_verify()].
+///
+/// If `inputs_json` is NULL, the program is executed with no inputs.
+///
+/// # Safety
+/// `program` must be a handle returned by [miden_compile()] which has not been released yet.
+/// `inputs_json` must be a NUL-terminated string or NULL. `out_outputs_json`, `out_proof`,
+/// `out_proof_len`, and `out_err` must be valid pointers or NULL.
+#[no_mangle]
+pub unsafe extern "C" fn miden_prove(
+    program: *const MidenProgram,
+    inputs_json: *const c_char,
+    out_outputs_json: *mut *mut c_char,
+    out_proof: *mut *mut u8,
+    out_proof_len: *mut usize,
+    out_err: *mut *mut c_char,
+) -> i32 {
+    guard(out_err, || {
+        check_out_param(out_outputs_json, "out_outputs_json")?;
+        check_out_param(out_proof, "out_proof")?;
+        check_out_param(out_proof_len, "out_proof_len")?;
+        let program = get_program(program)?;
+        let inputs = parse_inputs(inputs_json)?;
+
+        let (outputs, proof) = prover::prove(program, &inputs, &Default::default())
+            .map_err(|err| format!("Failed to prove program - {err}"))?;
+        let outputs_json = to_json(&outputs)?;
+
+        let proof = with_header(ArtifactKind::Proof, &proof.to_bytes()).into_boxed_slice();
+        *out_proof_len = proof.len();
+        *out_proof = Box::into_raw(proof) as *mut u8;
+        *out_outputs_json = into_c_string(outputs_json);
+        Ok(())
+    })
+}
+
+// VERIFY
+// ================================================================================================
+
+/// Returns true if the specified proof attests that a program with the specified hash, executed
+/// against the specified inputs, produced the specified outputs.
+///
+/// Only stack inputs are used for verification (advice inputs are secret). Returns false if any
+/// of the arguments is malformed.
+///
+/// # Safety
+/// `hash_hex`, `inputs_json`, and `outputs_json` must be NUL-terminated strings, and `proof` must
+/// point to `proof_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_verify(
+    hash_hex: *const c_char,
+    inputs_json: *const c_char,
+    outputs_json: *const c_char,
+    proof: *const u8,
+    proof_len: usize,
+) -> bool {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        verify_proof(hash_hex, inputs_json, outputs_json, proof, proof_len)
+    }));
+    matches!(result, Ok(Ok(())))
+}
+
+// DEALLOCATION
+// ================================================================================================
+
+/// Releases a program handle returned by [miden_compile()]. Does nothing if `program` is NULL.
+///
+/// # Safety
+/// `program` must be a handle returned by [miden_compile()] which has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn miden_free_program(program: *mut MidenProgram) {
+    if !program.is_null() {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(program))));
+    }
+}
+
+/// Releases a string returned by any function of this library. Does nothing if `value` is NULL.
+///
+/// # Safety
+/// `value` must be a string returned by this library which has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn miden_free_string(value: *mut c_char) {
+    if !value.is_null() {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(CString::from_raw(value))));
+    }
+}
+
+/// Releases a proof returned by [miden_prove()]. Does nothing if `proof` is NULL.
+///
+/// # Safety
+/// `proof` and `proof_len` must be the values returned by [miden_prove()], and the proof must not
+/// have been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn miden_free_proof(proof: *mut u8, proof_len: usize) {
+    if !proof.is_null() {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                proof, proof_len,
+            )))
+        }));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes the specified closure and converts its result into a return code; errors and panics
+/// are written into `out_err` (if it is not NULL).
+unsafe fn guard<F>(out_err: *mut *mut c_char, f: F) -> i32
+where
+    F: FnOnce() -> Result<(), String>,
+{
+    if !out_err.is_null() {
+        *out_err = ptr::null_mut();
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(format!("Panicked - {}", panic_message(&*payload))));
+
+    match result {
+        Ok(()) => MIDEN_OK,
+        Err(err) => {
+            if !out_err.is_null() {
+                *out_err = into_c_string(err);
+            }
+            MIDEN_ERROR
+        }
+    }
+}
+
+unsafe fn verify_proof(
+    hash_hex: *const c_char,
+    inputs_json: *const c_char,
+    outputs_json: *const c_char,
+    proof: *const u8,
+    proof_len: usize,
+) -> Result<(), String> {
+    if proof.is_null() {
+        return Err("proof is NULL".to_string());
+    }
+
+    let program_hash =
+        felt_fmt::parse_digest(read_str(hash_hex, "hash_hex")?).map_err(|e| e.to_string())?;
+    let inputs = parse_inputs(inputs_json)?;
+    let outputs: ProgramOutputs =
+        serde_json::from_str(read_str(outputs_json, "outputs_json")?).map_err(|e| e.to_string())?;
+    let proof = strip_header(slice::from_raw_parts(proof, proof_len), ArtifactKind::Proof)
+        .map_err(|e| e.to_string())?;
+    let proof = StarkProof::from_bytes(proof).map_err(|e| e.to_string())?;
+
+    // stack inputs are stored with the top of the stack first, while the verifier expects them
+    // in the order in which they were pushed onto the stack
+    let stack_inputs = inputs
+        .stack_init()
+        .iter()
+        .rev()
+        .map(|v| v.as_int())
+        .collect::<Vec<_>>();
+
+    verifier::verify(program_hash, &stack_inputs, &outputs, proof).map_err(|e| e.to_string())
+}
+
+unsafe fn get_program<'a>(program: *const MidenProgram) -> Result<&'a Program, String> {
+    program
+        .as_ref()
+        .map(|program| &program.0)
+        .ok_or_else(|| "program is NULL".to_string())
+}
+
+/// Parses program inputs from a JSON string; NULL is interpreted as no inputs.
+unsafe fn parse_inputs(inputs_json: *const c_char) -> Result<ProgramInputs, String> {
+    if inputs_json.is_null() {
+        return Ok(ProgramInputs::none());
+    }
+    serde_json::from_str(read_str(inputs_json, "inputs_json")?)
+        .map_err(|err| format!("Invalid program inputs - {err}"))
+}
+
+unsafe fn read_str<'a>(value: *const c_char, name: &str) -> Result<&'a str, String> {
+    if value.is_null() {
+        return Err(format!("{name} is NULL"));
+    }
+    CStr::from_ptr(value)
+        .to_str()
+        .map_err(|err| format!("{name} is not a valid UTF-8 string - {err}"))
+}
+
+fn check_out_param<T>(param: *mut T, name: &str) -> Result<(), String> {
+    if param.is_null() {
+        return Err(format!("{name} is NULL"));
+    }
+    Ok(())
+}
+
+fn to_json(outputs: &ProgramOutputs) -> Result<String, String> {
+    serde_json::to_string(outputs).map_err(|err| format!("Failed to serialize outputs - {err}"))
+}
+
+/// Converts the specified string into a C string owned by the caller. Interior NUL bytes (which
+/// may appear only in error messages) are replaced.
+fn into_c_string(value: String) -> *mut c_char {
+    let value = CString::new(value.replace('\0', "\\0")).expect("no interior NUL bytes");
+    value.into_raw()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * Drives the full compile -> execute -> prove -> verify pipeline via the C API. Exits with a
+ * non-zero status (and prints the reason) if any of the checks fails.
+ */
+#include <stdio.h>
+#include <string.h>
+
+#include "miden.h"
+
+#define CHECK(condition, message)                                       \
+    do {                                                                \
+        if (!(condition)) {                                             \
+            fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, message); \
+            return 1;                                                   \
+        }                                                               \
+    } while (0)
+
+static const char *SOURCE = "begin add end";
+static const char *INPUTS = "{\"stack_init\": [\"1\", \"2\"]}";
+
+static int check_compile_error(void) {
+    const char *source = "begin add";
+    MidenProgram *program = NULL;
+    char *err = NULL;
+
+    int32_t result = miden_compile(source, strlen(source), &program, &err);
+    CHECK(result == MIDEN_ERROR, "invalid program compiled");
+    CHECK(program == NULL, "program handle returned on error");
+    CHECK(err != NULL && strstr(err, "Failed to compile program") != NULL, "unexpected error");
+
+    miden_free_string(err);
+    return 0;
+}
+
+static int check_execute_error(const MidenProgram *program) {
+    char *outputs = NULL;
+    char *err = NULL;
+
+    int32_t result = miden_execute(program, "{\"stack_init\": [\"one\"]}", &outputs, &err);
+    CHECK(result == MIDEN_ERROR, "invalid inputs accepted");
+    CHECK(outputs == NULL, "outputs returned on error");
+    CHECK(err != NULL && strstr(err, "Invalid program inputs") != NULL, "unexpected error");
+
+    miden_free_string(err);
+
+    /* values which are not field elements are rejected in the same way as by Miden CLI */
+    result = miden_execute(program, "{\"stack_init\": [\"18446744069414584321\"]}", &outputs, &err);
+    CHECK(result == MIDEN_ERROR, "value outside of the field accepted");
+    CHECK(err != NULL && strstr(err, "valid field element") != NULL, "unexpected error");
+
+    miden_free_string(err);
+    return 0;
+}
+
+static int run_pipeline(void) {
+    MidenProgram *program = NULL;
+    char *hash = NULL;
+    char *outputs = NULL;
+    char *proof_outputs = NULL;
+    uint8_t *proof = NULL;
+    size_t proof_len = 0;
+    char *err = NULL;
+
+    /* compile */
+    CHECK(miden_compile(SOURCE, strlen(SOURCE), &program, &err) == MIDEN_OK, "compile failed");
+    CHECK(miden_program_hash(program, &hash, &err) == MIDEN_OK, "hash failed");
+    CHECK(strlen(hash) == 64, "unexpected hash length");
+
+    /* execute */
+    CHECK(miden_execute(program, INPUTS, &outputs, &err) == MIDEN_OK, "execute failed");
+    CHECK(strncmp(outputs, "{\"stack\":[\"3\",\"0\"", 17) == 0, "unexpected outputs");
+    if (check_execute_error(program) != 0) {
+        return 1;
+    }
+
+    /* prove */
+    int32_t result = miden_prove(program, INPUTS, &proof_outputs, &proof, &proof_len, &err);
+    CHECK(result == MIDEN_OK, "prove failed");
+    CHECK(strcmp(outputs, proof_outputs) == 0, "proof outputs differ from execution outputs");
+    CHECK(proof != NULL && proof_len > 0, "empty proof");
+
+    /* verify */
+    CHECK(miden_verify(hash, INPUTS, outputs, proof, proof_len), "valid proof rejected");
+    CHECK(!miden_verify(hash, "{\"stack_init\": [\"2\", \"2\"]}", outputs, proof, proof_len),
+          "proof accepted for wrong inputs");
+    CHECK(!miden_verify("1234", INPUTS, outputs, proof, proof_len), "malformed hash accepted");
+
+    /* program hashes are parsed in the same way as by Miden CLI: a 0x prefix is accepted, and
+     * elements which are not field elements are rejected */
+    char prefixed_hash[67] = "0x";
+    strcat(prefixed_hash, hash);
+    CHECK(miden_verify(prefixed_hash, INPUTS, outputs, proof, proof_len), "0x prefix rejected");
+    char invalid_hash[65];
+    memset(invalid_hash, 'f', 64);
+    invalid_hash[64] = '\0';
+    CHECK(!miden_verify(invalid_hash, INPUTS, outputs, proof, proof_len),
+          "non-canonical hash accepted");
+    CHECK(!miden_verify(hash, INPUTS, outputs, proof, proof_len - 1), "truncated proof accepted");
+    CHECK(!miden_verify(NULL, INPUTS, outputs, proof, proof_len), "NULL hash accepted");
+
+    miden_free_proof(proof, proof_len);
+    miden_free_string(proof_outputs);
+    miden_free_string(outputs);
+    miden_free_string(hash);
+    miden_free_program(program);
+    return 0;
+}
+
+int main(void) {
+    if (check_compile_error() != 0 || run_pipeline() != 0) {
+        return 1;
+    }
+    printf("ok\n");
+    return 0;
+}
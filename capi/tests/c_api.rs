@@ -0,0 +1,54 @@
+//! Compiles the C test program in `tests/c` against the static library built from this crate
+//! and runs it. The C compiler is taken from the `CC` environment variable (defaults to `cc`).
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[test]
+fn c_pipeline() {
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    // the static library is built into the same directory as the test executable
+    let deps_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let library = find_static_library(&deps_dir);
+    let executable = deps_dir.join("miden_capi_pipeline");
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(&compiler)
+        .arg(crate_dir.join("tests/c/pipeline.c"))
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg(library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&executable)
+        .status()
+        .unwrap_or_else(|err| panic!("failed to run C compiler '{compiler}': {err}"));
+    assert!(status.success(), "failed to compile C test program");
+
+    let output = Command::new(&executable).output().unwrap();
+    assert!(
+        output.status.success(),
+        "C test program failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Returns the path to the most recently built static library of this crate; a static library
+/// is built with each build of the crate since `staticlib` is one of its crate types.
+fn find_static_library(deps_dir: &Path) -> PathBuf {
+    deps_dir
+        .read_dir()
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("libmiden_capi") && name.ends_with(".a")
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok())
+        .map(|entry| entry.path())
+        .expect("static library of miden-capi not found")
+}
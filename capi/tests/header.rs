@@ -0,0 +1,149 @@
+//! Checks that the C header in `include/miden.h` declares exactly the constants and functions
+//! exported from this crate, with the signatures which cbindgen generates for them. This keeps the
+//! header from drifting away from the Rust API between regenerations of the header.
+
+use std::{fs, path::PathBuf};
+
+#[test]
+fn header_matches_rust_api() {
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let source = fs::read_to_string(crate_dir.join("src/lib.rs")).unwrap();
+    let header = normalize(&fs::read_to_string(crate_dir.join("include/miden.h")).unwrap());
+
+    // constants
+    let constants = rust_constants(&source);
+    assert!(!constants.is_empty());
+    for (name, value) in constants.iter() {
+        let define = format!("#define {name} {value}");
+        assert!(
+            header.contains(&define),
+            "header does not contain '{define}'"
+        );
+    }
+
+    // functions
+    let functions = rust_functions(&source);
+    assert!(!functions.is_empty());
+    for (_, declaration) in functions.iter() {
+        assert!(
+            header.contains(declaration.as_str()),
+            "header does not contain '{declaration}'"
+        );
+    }
+
+    // the header does not declare functions which are not exported anymore
+    let num_declarations = header
+        .split(' ')
+        .filter(|word| word.starts_with("miden_") && word.contains('('))
+        .count();
+    assert_eq!(
+        functions.len(),
+        num_declarations,
+        "header declares unknown functions"
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns names and values of `pub const` items declared in the specified source.
+fn rust_constants(source: &str) -> Vec<(String, String)> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub const "))
+        .map(|line| {
+            let (name, rest) = line.split_once(':').unwrap();
+            let value = rest.split_once('=').unwrap().1.trim().trim_end_matches(';');
+            (name.trim().to_string(), value.to_string())
+        })
+        .collect()
+}
+
+/// Returns names of `extern "C"` functions declared in the specified source together with their
+/// declarations in C, formatted in the same way as in the normalized header.
+fn rust_functions(source: &str) -> Vec<(String, String)> {
+    const PREFIX: &str = "pub unsafe extern \"C\" fn ";
+
+    let mut functions = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find(PREFIX) {
+        rest = &rest[start + PREFIX.len()..];
+        let (name, signature) = rest.split_once('(').unwrap();
+        let (params, signature) = signature.split_once(')').unwrap();
+        let signature = signature.split_once('{').unwrap().0.trim();
+        let return_type = match signature.strip_prefix("->") {
+            Some(return_type) => c_type(return_type.trim()),
+            None => "void".to_string(),
+        };
+
+        let params = params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+            .map(|param| {
+                let (param_name, param_type) = param.split_once(':').unwrap();
+                c_param(param_type.trim(), param_name.trim())
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        functions.push((name.to_string(), format!("{return_type} {name}({params});")));
+    }
+    functions
+}
+
+/// Returns the declaration of a parameter of the specified Rust type in C.
+fn c_param(rust_type: &str, name: &str) -> String {
+    let c_type = c_type(rust_type);
+    if c_type.ends_with('*') {
+        format!("{c_type}{name}")
+    } else {
+        format!("{c_type} {name}")
+    }
+}
+
+/// Returns the C type which cbindgen generates for the specified Rust type (e.g., `const char *`
+/// for `*const c_char`).
+fn c_type(rust_type: &str) -> String {
+    let mut stars = String::new();
+    let mut is_const = false;
+    let mut base = rust_type;
+    loop {
+        if let Some(inner) = base.strip_prefix("*const ") {
+            is_const = true;
+            base = inner;
+        } else if let Some(inner) = base.strip_prefix("*mut ") {
+            is_const = false;
+            base = inner;
+        } else {
+            break;
+        }
+        stars.push('*');
+    }
+
+    let base = match base {
+        "bool" => "bool",
+        "c_char" => "char",
+        "i32" => "int32_t",
+        "u8" => "uint8_t",
+        "usize" => "size_t",
+        "MidenProgram" => "MidenProgram",
+        other => panic!("no C equivalent defined for Rust type '{other}'"),
+    };
+
+    match (is_const, stars.is_empty()) {
+        (_, true) => base.to_string(),
+        (true, false) => format!("const {base} {stars}"),
+        (false, false) => format!("{base} {stars}"),
+    }
+}
+
+/// Collapses all whitespace in the header into single spaces and removes whitespace following
+/// opening parentheses; this undoes the line wrapping of long declarations.
+fn normalize(header: &str) -> String {
+    header
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("( ", "(")
+}
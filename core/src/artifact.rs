@@ -0,0 +1,285 @@
+use super::utils::{collections::Vec, ByteReader, ByteWriter, DeserializationError, SliceReader};
+use core::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bytes in a serialized [ArtifactHeader].
+pub const HEADER_LEN: usize = 8;
+
+/// The oldest format version which readers of binary artifacts support; together with the current
+/// version of each artifact kind (see [ArtifactKind::format_version()]), this defines the range of
+/// format versions which can be read.
+pub const MIN_FORMAT_VERSION: u16 = 1;
+
+// ARTIFACT KIND
+// ================================================================================================
+
+/// Kinds of binary artifacts produced and consumed by Miden VM components.
+///
+/// Every binary artifact starts with an [ArtifactHeader] which identifies the kind of the
+/// artifact. This makes it possible to report a meaningful error when an artifact of one kind is
+/// passed to a reader of another kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactKind {
+    /// A compiled program (`.masb`).
+    Program,
+    /// A library of Miden assembly modules (`.masl`).
+    Library,
+    /// A library of procedures compiled into MAST (`.masc`).
+    CompiledLibrary,
+    /// A STARK proof of a program's execution (`.proof`).
+    Proof,
+    /// A verification bundle which combines a proof with the data needed to verify it.
+    Bundle,
+}
+
+impl ArtifactKind {
+    /// All kinds of binary artifacts.
+    pub const ALL: [ArtifactKind; 5] = [
+        Self::Program,
+        Self::Library,
+        Self::CompiledLibrary,
+        Self::Proof,
+        Self::Bundle,
+    ];
+
+    /// Returns the magic bytes which identify artifacts of this kind.
+    pub const fn magic(&self) -> &'static [u8; 4] {
+        match self {
+            Self::Program => b"MASB",
+            Self::Library => b"MASL",
+            Self::CompiledLibrary => b"MASC",
+            Self::Proof => b"MPRF",
+            Self::Bundle => b"MVBN",
+        }
+    }
+
+    /// Returns the current format version of artifacts of this kind; artifacts are always written
+    /// using this version.
+    ///
+    /// Version 2 of compiled programs adds the inputs declared by the program, and version 2 of
+    /// libraries adds the procedures re-exported by their modules. Compiled libraries moved to
+    /// version 2 together with libraries; both of their versions share the same encoding. All
+    /// other kinds of artifacts are at version 1.
+    pub const fn format_version(&self) -> u16 {
+        match self {
+            Self::Program | Self::Library | Self::CompiledLibrary => 2,
+            Self::Proof | Self::Bundle => 1,
+        }
+    }
+
+    /// Returns the kind of artifacts identified by the specified magic bytes, or None if the
+    /// bytes do not identify any known kind of artifacts.
+    pub fn from_magic(magic: &[u8; 4]) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.magic() == magic)
+    }
+}
+
+impl fmt::Display for ArtifactKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Program => write!(f, "compiled program (.masb)"),
+            Self::Library => write!(f, "library (.masl)"),
+            Self::CompiledLibrary => write!(f, "compiled library (.masc)"),
+            Self::Proof => write!(f, "proof file"),
+            Self::Bundle => write!(f, "verification bundle"),
+        }
+    }
+}
+
+// ARTIFACT HEADER
+// ================================================================================================
+
+/// Header with which every binary artifact starts.
+///
+/// The header is serialized as follows:
+/// - 4 magic bytes identifying the kind of the artifact (see [ArtifactKind::magic()]).
+/// - format version of the artifact (u16).
+/// - flags (u16); no flags are currently defined, and thus, this field must be set to zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArtifactHeader {
+    pub kind: ArtifactKind,
+    pub format_version: u16,
+    pub flags: u16,
+}
+
+/// Writes the header of an artifact of the specified kind into the target.
+///
+/// The header is written using the current format version of the specified kind.
+pub fn write_header<W: ByteWriter>(target: &mut W, kind: ArtifactKind) {
+    target.write_u8_slice(kind.magic());
+    target.write_u16(kind.format_version());
+    target.write_u16(0);
+}
+
+/// Reads an artifact header from the source and makes sure that it is a header of an artifact of
+/// the expected kind which can be read by the current version of the VM.
+///
+/// # Errors
+/// Returns an error if:
+/// - The source does not start with the magic bytes of the expected kind; in this case, the error
+///   message names the kind of the artifact which was found instead, if it is known.
+/// - The format version is outside of the range of supported versions.
+/// - Any of the flags is set.
+pub fn read_and_check_header<R: ByteReader>(
+    source: &mut R,
+    expected: ArtifactKind,
+) -> Result<ArtifactHeader, DeserializationError> {
+    let magic: [u8; 4] = source.read_u8_array()?;
+    if &magic != expected.magic() {
+        let message = match ArtifactKind::from_magic(&magic) {
+            Some(found) => format!("expected a {expected} but found a {found}"),
+            None => format!("expected a {expected} but found an unrecognized file"),
+        };
+        return Err(DeserializationError::InvalidValue(message));
+    }
+
+    let format_version = source.read_u16()?;
+    if !(MIN_FORMAT_VERSION..=expected.format_version()).contains(&format_version) {
+        return Err(DeserializationError::InvalidValue(format!(
+            "{expected} format version {format_version} is not supported; supported versions \
+            are {MIN_FORMAT_VERSION} through {}",
+            expected.format_version()
+        )));
+    }
+
+    let flags = source.read_u16()?;
+    if flags != 0 {
+        return Err(DeserializationError::InvalidValue(format!(
+            "unsupported {expected} flags: {flags}"
+        )));
+    }
+
+    Ok(ArtifactHeader {
+        kind: expected,
+        format_version,
+        flags,
+    })
+}
+
+/// Returns the specified payload preceded by the header of an artifact of the specified kind.
+///
+/// This is intended for artifacts whose payload is serialized by external types (e.g., STARK
+/// proofs) and thus cannot write the header themselves.
+pub fn with_header(kind: ArtifactKind, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    write_header(&mut bytes, kind);
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// Checks the header of an artifact of the specified kind and returns the payload which follows
+/// the header.
+///
+/// # Errors
+/// Returns an error if the header is not valid (see [read_and_check_header()]).
+pub fn strip_header(bytes: &[u8], kind: ArtifactKind) -> Result<&[u8], DeserializationError> {
+    read_and_check_header(&mut SliceReader::new(bytes), kind)?;
+    Ok(&bytes[HEADER_LEN..])
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_and_check_header, strip_header, with_header, write_header, ArtifactKind, HEADER_LEN,
+    };
+    use crate::utils::{DeserializationError, SliceReader};
+
+    fn header_bytes(kind: ArtifactKind) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_header(&mut bytes, kind);
+        bytes
+    }
+
+    fn read_error(bytes: &[u8], expected: ArtifactKind) -> String {
+        match read_and_check_header(&mut SliceReader::new(bytes), expected) {
+            Err(DeserializationError::InvalidValue(message)) => message,
+            result => panic!("expected an invalid value error, but got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn header_round_trip() {
+        for kind in ArtifactKind::ALL {
+            let bytes = header_bytes(kind);
+            assert_eq!(HEADER_LEN, bytes.len());
+
+            let header = read_and_check_header(&mut SliceReader::new(&bytes), kind).unwrap();
+            assert_eq!(kind, header.kind);
+            assert_eq!(kind.format_version(), header.format_version);
+            assert_eq!(0, header.flags);
+        }
+    }
+
+    #[test]
+    fn payload_with_header() {
+        let bytes = with_header(ArtifactKind::Proof, &[1, 2, 3]);
+        assert_eq!(header_bytes(ArtifactKind::Proof), bytes[..HEADER_LEN]);
+        assert_eq!(
+            Ok(&[1, 2, 3][..]),
+            strip_header(&bytes, ArtifactKind::Proof)
+        );
+        assert!(strip_header(&bytes, ArtifactKind::Bundle).is_err());
+        assert!(strip_header(&[1, 2, 3], ArtifactKind::Proof).is_err());
+    }
+
+    #[test]
+    fn header_kind_mismatch() {
+        for found in ArtifactKind::ALL {
+            let bytes = header_bytes(found);
+            for expected in ArtifactKind::ALL.into_iter().filter(|&kind| kind != found) {
+                assert_eq!(
+                    format!("expected a {expected} but found a {found}"),
+                    read_error(&bytes, expected)
+                );
+            }
+        }
+
+        assert_eq!(
+            "expected a compiled program (.masb) but found a proof file",
+            read_error(&header_bytes(ArtifactKind::Proof), ArtifactKind::Program)
+        );
+        assert_eq!(
+            "expected a library (.masl) but found a verification bundle",
+            read_error(&header_bytes(ArtifactKind::Bundle), ArtifactKind::Library)
+        );
+        assert_eq!(
+            "expected a proof file but found an unrecognized file",
+            read_error(b"{\"stack\": []}", ArtifactKind::Proof)
+        );
+    }
+
+    #[test]
+    fn header_unsupported_version_and_flags() {
+        let mut bytes = header_bytes(ArtifactKind::Library);
+        bytes[4] = 3;
+        assert_eq!(
+            "library (.masl) format version 3 is not supported; supported versions are 1 through 2",
+            read_error(&bytes, ArtifactKind::Library)
+        );
+
+        bytes[4] = 0;
+        assert!(read_error(&bytes, ArtifactKind::Library).contains("version 0"));
+
+        let mut bytes = header_bytes(ArtifactKind::Library);
+        bytes[6] = 1;
+        assert_eq!(
+            "unsupported library (.masl) flags: 1",
+            read_error(&bytes, ArtifactKind::Library)
+        );
+
+        // truncated header
+        let bytes = header_bytes(ArtifactKind::Library);
+        assert_eq!(
+            Err(DeserializationError::UnexpectedEOF),
+            read_and_check_header(
+                &mut SliceReader::new(&bytes[..HEADER_LEN - 1]),
+                ArtifactKind::Library
+            )
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use super::Word;
-use crate::utils::collections::Vec;
+use crate::utils::{collections::Vec, string::String};
+use core::fmt;
 
 #[derive(Clone, Debug)]
 pub enum InputError {
@@ -18,3 +19,57 @@ pub enum AdviceSetError {
     InvalidPath(Vec<Word>),
     NodeNotInSet(u64),
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramError {
+    EmptyCommitment,
+    EmptySpan,
+    InvalidDecoratorIndex(usize, usize),
+}
+
+impl fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyCommitment => write!(f, "program has invalid (empty) commitment"),
+            Self::EmptySpan => write!(f, "span block contains no operations"),
+            Self::InvalidDecoratorIndex(op_idx, num_ops) => write!(
+                f,
+                "decorator at operation index {op_idx} is out of bounds for a span with {num_ops} operations"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProgramError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeltParseError {
+    Empty,
+    Negative(String),
+    InvalidDigits(String),
+    NotFieldElement(String),
+    InvalidDigest(String),
+}
+
+impl fmt::Display for FeltParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use FeltParseError::*;
+        match self {
+            Empty => write!(f, "an empty string is not a valid field element"),
+            Negative(value) => write!(
+                f,
+                "value {value} is negative and thus is not a valid field element"
+            ),
+            InvalidDigits(value) => write!(
+                f,
+                "value `{value}` is not a decimal or a 0x-prefixed hexadecimal integer"
+            ),
+            NotFieldElement(value) => write!(f, "value {value} is not a valid field element"),
+            InvalidDigest(value) => write!(f, "value `{value}` is not a valid hexadecimal digest"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeltParseError {}
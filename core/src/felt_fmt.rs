@@ -0,0 +1,188 @@
+//! Conversion of field elements and digests to and from strings.
+//!
+//! Field elements are written either in decimal (e.g., `42`) or in hexadecimal with a `0x` prefix
+//! (e.g., `0x2a`). When parsing, the radix is detected from the prefix, and values are required
+//! to be canonical field elements, i.e., integers smaller than the field modulus. Digests are
+//! written as 64 hexadecimal characters encoding the 32 bytes of the digest; when parsing, an
+//! optional `0x` prefix is accepted as well.
+
+use super::{
+    chiplets::hasher::Digest,
+    errors::FeltParseError,
+    utils::{
+        string::{String, ToString},
+        Deserializable, SliceReader,
+    },
+    Felt, FieldElement, StarkField,
+};
+use crypto::Digest as _;
+
+// RADIX
+// ================================================================================================
+
+/// Radix in which field elements are written.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Radix {
+    #[default]
+    Decimal,
+    /// Hexadecimal with a `0x` prefix.
+    Hex,
+}
+
+// FIELD ELEMENTS
+// ================================================================================================
+
+/// Parses a field element from a decimal or a `0x`-prefixed hexadecimal string.
+///
+/// # Errors
+/// Returns an error if the string is empty, is negative, contains characters which are not
+/// digits of the detected radix, or if the value is not smaller than the field modulus.
+pub fn parse(value: &str) -> Result<Felt, FeltParseError> {
+    if value.is_empty() {
+        return Err(FeltParseError::Empty);
+    }
+    if value.starts_with('-') {
+        return Err(FeltParseError::Negative(value.to_string()));
+    }
+
+    let (digits, radix) = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(digits) => (digits, 16),
+        None => (value, 10),
+    };
+    // `from_str_radix()` accepts a leading `+`, and thus, digits are validated beforehand
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(FeltParseError::InvalidDigits(value.to_string()));
+    }
+
+    match u64::from_str_radix(digits, radix) {
+        Ok(int) if int < Felt::MODULUS => Ok(Felt::new(int)),
+        _ => Err(FeltParseError::NotFieldElement(value.to_string())),
+    }
+}
+
+/// Returns the specified field element written in the specified radix.
+pub fn format(value: Felt, radix: Radix) -> String {
+    match radix {
+        Radix::Decimal => value.as_int().to_string(),
+        Radix::Hex => format!("{:#x}", value.as_int()),
+    }
+}
+
+// DIGESTS
+// ================================================================================================
+
+/// Parses a digest from a string of 64 hexadecimal characters, optionally prefixed with `0x`.
+///
+/// # Errors
+/// Returns an error if the string is not a hexadecimal encoding of 32 bytes, or if the bytes do
+/// not encode a valid digest.
+pub fn parse_digest(value: &str) -> Result<Digest, FeltParseError> {
+    let invalid = || FeltParseError::InvalidDigest(value.to_string());
+
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    if digits.len() != 64 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+
+    // read the elements one by one (rather than the digest as a whole) so that non-canonical
+    // field elements are rejected
+    let mut source = SliceReader::new(&bytes);
+    let mut elements = [Felt::ZERO; 4];
+    for element in elements.iter_mut() {
+        *element = Felt::read_from(&mut source).map_err(|_| invalid())?;
+    }
+    Ok(Digest::from(elements))
+}
+
+/// Returns the specified digest written as 64 lowercase hexadecimal characters without a prefix.
+pub fn format_digest(digest: &Digest) -> String {
+    digest
+        .as_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{format, format_digest, parse, parse_digest, Felt, FeltParseError, Radix};
+    use crate::{chiplets::hasher, StarkField};
+
+    #[test]
+    fn parse_felt() {
+        // zero and the largest field element in both radixes
+        assert_eq!(Ok(Felt::new(0)), parse("0"));
+        assert_eq!(Ok(Felt::new(0)), parse("0x0"));
+        let max = Felt::MODULUS - 1;
+        assert_eq!(Ok(Felt::new(max)), parse(&max.to_string()));
+        assert_eq!(Ok(Felt::new(max)), parse(&format!("{max:#x}")));
+        assert_eq!(Ok(Felt::new(42)), parse("0X2A"));
+
+        // the modulus and values which do not fit into u64 are out of range
+        let modulus = Felt::MODULUS.to_string();
+        assert_eq!(
+            Err(FeltParseError::NotFieldElement(modulus.clone())),
+            parse(&modulus)
+        );
+        let modulus_hex = format!("{:#x}", Felt::MODULUS);
+        assert_eq!(
+            Err(FeltParseError::NotFieldElement(modulus_hex.clone())),
+            parse(&modulus_hex)
+        );
+        let too_large = "18446744073709551616";
+        assert_eq!(
+            Err(FeltParseError::NotFieldElement(too_large.into())),
+            parse(too_large)
+        );
+
+        // malformed values
+        assert_eq!(Err(FeltParseError::Empty), parse(""));
+        assert_eq!(Err(FeltParseError::Negative("-1".into())), parse("-1"));
+        assert_eq!(Err(FeltParseError::InvalidDigits("+1".into())), parse("+1"));
+        assert_eq!(Err(FeltParseError::InvalidDigits("0x".into())), parse("0x"));
+        assert_eq!(Err(FeltParseError::InvalidDigits("2a".into())), parse("2a"));
+        assert_eq!(
+            Err(FeltParseError::InvalidDigits("0xg".into())),
+            parse("0xg")
+        );
+        assert_eq!(Err(FeltParseError::InvalidDigits(" 1".into())), parse(" 1"));
+    }
+
+    #[test]
+    fn format_felt() {
+        assert_eq!("0", format(Felt::new(0), Radix::Decimal));
+        assert_eq!("0x0", format(Felt::new(0), Radix::Hex));
+        assert_eq!("42", format(Felt::new(42), Radix::Decimal));
+        assert_eq!("0x2a", format(Felt::new(42), Radix::Hex));
+
+        // formatted values are parsed back into the same values
+        let value = Felt::new(Felt::MODULUS - 1);
+        for radix in [Radix::Decimal, Radix::Hex] {
+            assert_eq!(Ok(value), parse(&format(value, radix)));
+        }
+    }
+
+    #[test]
+    fn digest_round_trip() {
+        let digest = hasher::hash_elements(&[Felt::new(1), Felt::new(2)]);
+        let hex = format_digest(&digest);
+        assert_eq!(64, hex.len());
+        assert_eq!(Ok(digest), parse_digest(&hex));
+        assert_eq!(Ok(digest), parse_digest(&format!("0x{hex}")));
+
+        assert!(parse_digest(&hex[1..]).is_err());
+        assert!(parse_digest(&hex.replace(&hex[..1], "g")).is_err());
+        assert!(parse_digest(&"ff".repeat(32)).is_err());
+    }
+}
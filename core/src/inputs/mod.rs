@@ -1,8 +1,9 @@
 use super::{
     chiplets::hasher,
     errors::{AdviceSetError, InputError},
+    stack::STACK_TOP_SIZE,
     utils::IntoBytes,
-    Felt, FieldElement, Word,
+    Felt, FieldElement, StarkField, Word,
 };
 use core::convert::TryInto;
 use winter_utils::collections::{BTreeMap, Vec};
@@ -26,6 +27,11 @@ pub use advice::AdviceSet;
 ///    of elements and (2) a list of advice sets, which are used to provide nondeterministic
 ///    inputs for instructions which work with Merkle trees.
 ///
+/// Initial stack values beyond the top 16 slots are placed into the stack overflow table before
+/// execution begins. These can be supplied either as part of `stack_init`, or explicitly via
+/// [ProgramInputs::with_stack_overflow()], which places the provided values beneath the top 16
+/// stack slots.
+///
 /// TODO: add more detailed explanation.
 #[derive(Clone, Debug)]
 pub struct ProgramInputs {
@@ -126,6 +132,46 @@ impl ProgramInputs {
         Self::new(stack_init, &[], vec![])
     }
 
+    /// Returns these [ProgramInputs] with the specified values placed into the stack overflow
+    /// table, beneath the top 16 slots of the stack.
+    ///
+    /// The values in `overflow_init` are ordered in the same way as `stack_init` values: the first
+    /// value in the list will be the deepest item on the stack, and the last value will be the
+    /// closest to the top of the stack. The values are placed beneath all stack items already
+    /// specified by these inputs. If fewer than 16 initial stack values were provided, the
+    /// provided values remain at the top of the stack, and the slots between them and the
+    /// overflow values (i.e., the deepest of the top 16 slots) are filled with ZEROs.
+    ///
+    /// # Errors
+    /// Returns an error if any of the overflow values is not a valid field element (i.e., it is
+    /// greater than or equal to the field modulus).
+    pub fn with_stack_overflow(mut self, overflow_init: &[u64]) -> Result<Self, InputError> {
+        if overflow_init.is_empty() {
+            return Ok(self);
+        }
+
+        // make sure the top of the stack is fully populated so that the overflow values end up
+        // in the overflow table
+        if self.stack_init.len() < STACK_TOP_SIZE {
+            self.stack_init.resize(STACK_TOP_SIZE, Felt::ZERO);
+        }
+
+        // stack_init is stored with the top of the stack first; thus, the overflow values are
+        // appended in reverse order
+        self.stack_init.reserve(overflow_init.len());
+        for &value in overflow_init.iter().rev() {
+            if value >= Felt::MODULUS {
+                return Err(InputError::NotFieldElement(
+                    value,
+                    "initial stack overflow value",
+                ));
+            }
+            self.stack_init.push(Felt::new(value));
+        }
+
+        Ok(self)
+    }
+
     /// Returns [ProgramInputs] with no input values.
     pub fn none() -> Self {
         Self {
@@ -136,6 +182,42 @@ impl ProgramInputs {
         }
     }
 
+    // MERGING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns [ProgramInputs] resulting from layering `other` inputs over these inputs.
+    ///
+    /// The inputs are merged as follows:
+    /// - If `other` specifies initial stack values, these values replace the initial stack values
+    ///   of these inputs (including the values in the stack overflow table).
+    /// - The advice tape of `other` either replaces or is appended to the advice tape of these
+    ///   inputs, as specified by `advice_tape`. An empty advice tape in `other` does not replace
+    ///   the advice tape of these inputs.
+    /// - Entries of the advice map of `other` are added to the advice map of these inputs;
+    ///   entries with the same key are replaced by the entries of `other`.
+    /// - The result contains the union of advice sets of both inputs. Advice sets with the same
+    ///   root commit to the same data, and thus, only one of them is retained.
+    pub fn merge(mut self, other: ProgramInputs, advice_tape: AdviceTapeMerge) -> Self {
+        let (stack_init, tape, advice_map, advice_sets) = other.into_parts();
+
+        if !stack_init.is_empty() {
+            self.stack_init = stack_init;
+        }
+
+        match advice_tape {
+            AdviceTapeMerge::Replace if !tape.is_empty() => self.advice_tape = tape,
+            AdviceTapeMerge::Replace => (),
+            AdviceTapeMerge::Append => self.advice_tape.extend(tape),
+        }
+
+        self.advice_map.extend(advice_map);
+        for (root, advice_set) in advice_sets {
+            self.advice_sets.entry(root).or_insert(advice_set);
+        }
+
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -144,6 +226,19 @@ impl ProgramInputs {
         &self.stack_init
     }
 
+    /// Returns a reference to the initial stack values which do not fit into the top 16 slots of
+    /// the stack, and thus, will be placed into the stack overflow table.
+    ///
+    /// The values are ordered such that the value directly beneath the 16th stack slot comes
+    /// first, and the deepest item on the stack comes last.
+    pub fn stack_overflow(&self) -> &[Felt] {
+        if self.stack_init.len() > STACK_TOP_SIZE {
+            &self.stack_init[STACK_TOP_SIZE..]
+        } else {
+            &[]
+        }
+    }
+
     /// Returns a reference to the advice tape.
     pub fn advice_tape(&self) -> &[Felt] {
         &self.advice_tape
@@ -172,3 +267,73 @@ impl ProgramInputs {
         (stack_init, advice_tape, advice_map, advice_sets)
     }
 }
+
+// ADVICE TAPE MERGE
+// ================================================================================================
+
+/// Specifies how advice tapes are combined when [ProgramInputs] are merged via
+/// [ProgramInputs::merge()].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AdviceTapeMerge {
+    /// The advice tape of the layered inputs replaces the original advice tape, unless it is
+    /// empty.
+    #[default]
+    Replace,
+    /// The advice tape of the layered inputs is appended to the original advice tape.
+    Append,
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AdviceTapeMerge, BTreeMap, Felt, InputError, ProgramInputs, StarkField, STACK_TOP_SIZE,
+    };
+
+    #[test]
+    fn merge_inputs() {
+        let advice_map = BTreeMap::from([([1; 32], vec![Felt::new(1)]), ([2; 32], vec![])]);
+        let file = ProgramInputs::with_advice_map(&[5, 6], &[2], advice_map, vec![])
+            .unwrap()
+            .with_stack_overflow(&[7])
+            .unwrap();
+
+        // initial stack values (including the overflow values) are replaced; the advice tape is
+        // appended to; advice map entries with the same key are replaced
+        let advice_map = BTreeMap::from([([2; 32], vec![Felt::new(2)])]);
+        let args = ProgramInputs::with_advice_map(&[1, 2], &[3], advice_map, vec![]).unwrap();
+        let merged = file.clone().merge(args, AdviceTapeMerge::Append);
+        assert_eq!(&[Felt::new(2), Felt::new(1)], merged.stack_init());
+        assert_eq!(&[Felt::new(2), Felt::new(3)], merged.advice_tape());
+        let (_, _, advice_map, _) = merged.into_parts();
+        assert_eq!(vec![Felt::new(1)], advice_map[&[1; 32]]);
+        assert_eq!(vec![Felt::new(2)], advice_map[&[2; 32]]);
+
+        // the advice tape is replaced, unless the layered advice tape is empty
+        let args = ProgramInputs::new(&[], &[3, 4], vec![]).unwrap();
+        let merged = file.clone().merge(args, AdviceTapeMerge::Replace);
+        assert_eq!(&[Felt::new(3), Felt::new(4)], merged.advice_tape());
+        assert_eq!(STACK_TOP_SIZE + 1, merged.stack_init().len());
+
+        let merged = file.merge(ProgramInputs::none(), AdviceTapeMerge::Replace);
+        assert_eq!(&[Felt::new(2)], merged.advice_tape());
+    }
+
+    #[test]
+    fn stack_overflow_rejects_non_field_elements() {
+        let inputs = ProgramInputs::from_stack_inputs(&[1]).unwrap();
+        let overflow = [Felt::MODULUS - 1, Felt::MODULUS];
+        assert!(matches!(
+            inputs.clone().with_stack_overflow(&overflow),
+            Err(InputError::NotFieldElement(value, _)) if value == Felt::MODULUS
+        ));
+
+        let inputs = inputs.with_stack_overflow(&overflow[..1]).unwrap();
+        assert_eq!(
+            Felt::new(Felt::MODULUS - 1),
+            inputs.stack_init()[STACK_TOP_SIZE]
+        );
+    }
+}
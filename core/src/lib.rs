@@ -6,9 +6,11 @@ extern crate alloc;
 
 use core::ops::Range;
 
+pub mod artifact;
 pub mod chiplets;
 pub mod decoder;
 pub mod errors;
+pub mod felt_fmt;
 pub mod range;
 pub mod stack;
 
@@ -18,7 +20,7 @@ pub use math::{
 };
 
 mod program;
-pub use program::{blocks as code_blocks, CodeBlockTable, Kernel, Program};
+pub use program::{blocks as code_blocks, CodeBlockTable, InputSpec, Kernel, Program};
 
 mod operations;
 pub use operations::{
@@ -26,14 +28,17 @@ pub use operations::{
 };
 
 mod inputs;
-pub use inputs::{AdviceSet, ProgramInputs};
+pub use inputs::{AdviceSet, AdviceTapeMerge, ProgramInputs};
 
 mod outputs;
-pub use outputs::ProgramOutputs;
+pub use outputs::{OutputDiff, OutputMismatch, ProgramOutputs};
 
 pub mod utils;
 use utils::range;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
 // TYPE ALIASES
 // ================================================================================================
 
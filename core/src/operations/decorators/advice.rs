@@ -19,6 +19,22 @@ pub enum AdviceInjector {
     /// then the quotient is injected.
     DivResultU64,
 
+    /// Injects the result of division of an unsigned integer of up to 512 bits by a 256-bit
+    /// unsigned integer at the head of the advice tape. Both operands are read from memory; the
+    /// stack is expected to be arranged as follows (from the top):
+    /// - memory address of the dividend, 1 element
+    /// - memory address of the divisor, 1 element
+    ///
+    /// The dividend is expected to be stored as 16 32-bit limbs and the divisor as 8 32-bit
+    /// limbs, one limb per memory address (in the first element of each word), with the least
+    /// significant limb located at the lowest address.
+    ///
+    /// The result is injected into the advice tape as follows: first the 8 limbs of the remainder
+    /// are injected, then the 8 least significant limbs of the quotient are injected. When read
+    /// from the advice tape, the limbs of both values are read starting with the least significant
+    /// limb.
+    DivResultU256,
+
     /// Injects a list of field elements at the front of the advice tape. The list is looked up in
     /// the key-value map maintained by the advice provider using the top 4 elements on the stack
     /// as the key.
@@ -26,6 +42,15 @@ pub enum AdviceInjector {
 
     /// Injects a list of words from the memory starting from the specified start address.
     Memory(u32, u32),
+
+    /// Injects the hash of the program being executed (i.e., the root of its MAST) at the head of
+    /// the advice tape. The hash is injected so that reading 4 elements from the advice tape
+    /// leaves the hash on the stack in the same order as the hash put onto the stack by `caller`
+    /// instruction (i.e., with the last element of the hash at the top of the stack).
+    ///
+    /// The injected values are not constrained by the AIR: as with all other advice, a dishonest
+    /// prover can inject arbitrary values instead.
+    ProgramHash,
 }
 
 impl fmt::Display for AdviceInjector {
@@ -33,8 +58,10 @@ impl fmt::Display for AdviceInjector {
         match self {
             Self::MerkleNode => write!(f, "merkle_node"),
             Self::DivResultU64 => write!(f, "div_result_u64"),
+            Self::DivResultU256 => write!(f, "div_result_u256"),
             Self::MapValue => write!(f, "map_value"),
             Self::Memory(start_addr, num_words) => write!(f, "mem({start_addr}, {num_words})"),
+            Self::ProgramHash => write!(f, "program_hash"),
         }
     }
 }
@@ -17,6 +17,9 @@ pub enum Decorator {
     /// Adds information about the assembly instruction at a particular index
     /// (only applicable in debug mode)
     AsmOp(AssemblyOp),
+    /// Specifies the error code which is reported if the next assertion executed by the VM fails.
+    /// This decorator does not affect the state of the VM, and does not advance VM clock.
+    ErrorCode(u32),
 }
 
 impl fmt::Display for Decorator {
@@ -31,6 +34,7 @@ impl fmt::Display for Decorator {
                     assembly_op.num_cycles()
                 )
             }
+            Self::ErrorCode(code) => write!(f, "errorCode({code})"),
         }
     }
 }
@@ -509,6 +509,127 @@ impl Operation {
         }
     }
 
+    /// Returns the mnemonic of this operation.
+    ///
+    /// Parameters of operations are not included in the mnemonic; for example, the mnemonic of
+    /// all `Push` operations is `push`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            // ----- system operations ------------------------------------------------------------
+            Self::Noop => "noop",
+            Self::Assert => "assert",
+
+            Self::FmpAdd => "fmpadd",
+            Self::FmpUpdate => "fmpupdate",
+
+            Self::SDepth => "sdepth",
+            Self::Caller => "caller",
+
+            // ----- flow control operations ------------------------------------------------------
+            Self::Join => "join",
+            Self::Split => "split",
+            Self::Loop => "loop",
+            Self::Call => "call",
+            Self::SysCall => "syscall",
+            Self::Span => "span",
+            Self::End => "end",
+            Self::Repeat => "repeat",
+            Self::Respan => "respan",
+            Self::Halt => "halt",
+
+            // ----- field operations -------------------------------------------------------------
+            Self::Add => "add",
+            Self::Neg => "neg",
+            Self::Mul => "mul",
+            Self::Inv => "inv",
+            Self::Incr => "incr",
+
+            Self::And => "and",
+            Self::Or => "or",
+            Self::Not => "not",
+
+            Self::Eq => "eq",
+            Self::Eqz => "eqz",
+
+            Self::Expacc => "expacc",
+
+            // ----- u32 operations ---------------------------------------------------------------
+            Self::U32assert2 => "u32assert2",
+            Self::U32split => "u32split",
+            Self::U32add => "u32add",
+            Self::U32add3 => "u32add3",
+            Self::U32sub => "u32sub",
+            Self::U32mul => "u32mul",
+            Self::U32madd => "u32madd",
+            Self::U32div => "u32div",
+
+            Self::U32and => "u32and",
+            Self::U32xor => "u32xor",
+
+            // ----- stack manipulation -----------------------------------------------------------
+            Self::Drop => "drop",
+            Self::Pad => "pad",
+
+            Self::Dup0 => "dup0",
+            Self::Dup1 => "dup1",
+            Self::Dup2 => "dup2",
+            Self::Dup3 => "dup3",
+            Self::Dup4 => "dup4",
+            Self::Dup5 => "dup5",
+            Self::Dup6 => "dup6",
+            Self::Dup7 => "dup7",
+            Self::Dup9 => "dup9",
+            Self::Dup11 => "dup11",
+            Self::Dup13 => "dup13",
+            Self::Dup15 => "dup15",
+
+            Self::Swap => "swap",
+            Self::SwapW => "swapw",
+            Self::SwapW2 => "swapw2",
+            Self::SwapW3 => "swapw3",
+            Self::SwapDW => "swapdw",
+
+            Self::MovUp2 => "movup2",
+            Self::MovUp3 => "movup3",
+            Self::MovUp4 => "movup4",
+            Self::MovUp5 => "movup5",
+            Self::MovUp6 => "movup6",
+            Self::MovUp7 => "movup7",
+            Self::MovUp8 => "movup8",
+
+            Self::MovDn2 => "movdn2",
+            Self::MovDn3 => "movdn3",
+            Self::MovDn4 => "movdn4",
+            Self::MovDn5 => "movdn5",
+            Self::MovDn6 => "movdn6",
+            Self::MovDn7 => "movdn7",
+            Self::MovDn8 => "movdn8",
+
+            Self::CSwap => "cswap",
+            Self::CSwapW => "cswapw",
+
+            // ----- input / output ---------------------------------------------------------------
+            Self::Push(_) => "push",
+
+            Self::Read => "read",
+            Self::ReadW => "readw",
+
+            Self::MLoadW => "mloadw",
+            Self::MStoreW => "mstorew",
+
+            Self::MLoad => "mload",
+            Self::MStore => "mstore",
+
+            Self::MStream => "mstream",
+            Self::Pipe => "pipe",
+
+            // ----- cryptographic operations -----------------------------------------------------
+            Self::RpPerm => "rpperm",
+            Self::MpVerify => "mpverify",
+            Self::MrUpdate(_) => "mrupdate",
+        }
+    }
+
     /// Returns an immediate value carried by this operation.
     pub fn imm_value(&self) -> Option<Felt> {
         match self {
@@ -538,117 +659,8 @@ impl Operation {
 impl fmt::Display for Operation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            // ----- system operations ------------------------------------------------------------
-            Self::Noop => write!(f, "noop"),
-            Self::Assert => write!(f, "assert"),
-
-            Self::FmpAdd => write!(f, "fmpadd"),
-            Self::FmpUpdate => write!(f, "fmpupdate"),
-
-            Self::SDepth => write!(f, "sdepth"),
-            Self::Caller => write!(f, "caller"),
-
-            // ----- flow control operations ------------------------------------------------------
-            Self::Join => write!(f, "join"),
-            Self::Split => write!(f, "split"),
-            Self::Loop => write!(f, "loop"),
-            Self::Call => writeln!(f, "call"),
-            Self::SysCall => writeln!(f, "syscall"),
-            Self::Span => write!(f, "span"),
-            Self::End => write!(f, "end"),
-            Self::Repeat => write!(f, "repeat"),
-            Self::Respan => write!(f, "respan"),
-            Self::Halt => write!(f, "halt"),
-
-            // ----- field operations -------------------------------------------------------------
-            Self::Add => write!(f, "add"),
-            Self::Neg => write!(f, "neg"),
-            Self::Mul => write!(f, "mul"),
-            Self::Inv => write!(f, "inv"),
-            Self::Incr => write!(f, "incr"),
-
-            Self::And => write!(f, "and"),
-            Self::Or => write!(f, "or"),
-            Self::Not => write!(f, "not"),
-
-            Self::Eq => write!(f, "eq"),
-            Self::Eqz => write!(f, "eqz"),
-
-            Self::Expacc => write!(f, "expacc"),
-
-            // ----- u32 operations ---------------------------------------------------------------
-            Self::U32assert2 => write!(f, "u32assert2"),
-            Self::U32split => write!(f, "u32split"),
-            Self::U32add => write!(f, "u32add"),
-            Self::U32add3 => write!(f, "u32add3"),
-            Self::U32sub => write!(f, "u32sub"),
-            Self::U32mul => write!(f, "u32mul"),
-            Self::U32madd => write!(f, "u32madd"),
-            Self::U32div => write!(f, "u32div"),
-
-            Self::U32and => write!(f, "u32and"),
-            Self::U32xor => write!(f, "u32xor"),
-
-            // ----- stack manipulation -----------------------------------------------------------
-            Self::Drop => write!(f, "drop"),
-            Self::Pad => write!(f, "pad"),
-
-            Self::Dup0 => write!(f, "dup0"),
-            Self::Dup1 => write!(f, "dup1"),
-            Self::Dup2 => write!(f, "dup2"),
-            Self::Dup3 => write!(f, "dup3"),
-            Self::Dup4 => write!(f, "dup4"),
-            Self::Dup5 => write!(f, "dup5"),
-            Self::Dup6 => write!(f, "dup6"),
-            Self::Dup7 => write!(f, "dup7"),
-            Self::Dup9 => write!(f, "dup9"),
-            Self::Dup11 => write!(f, "dup11"),
-            Self::Dup13 => write!(f, "dup13"),
-            Self::Dup15 => write!(f, "dup15"),
-
-            Self::Swap => write!(f, "swap"),
-            Self::SwapW => write!(f, "swapw"),
-            Self::SwapW2 => write!(f, "swapw2"),
-            Self::SwapW3 => write!(f, "swapw3"),
-            Self::SwapDW => write!(f, "swapdw"),
-
-            Self::MovUp2 => write!(f, "movup2"),
-            Self::MovUp3 => write!(f, "movup3"),
-            Self::MovUp4 => write!(f, "movup4"),
-            Self::MovUp5 => write!(f, "movup5"),
-            Self::MovUp6 => write!(f, "movup6"),
-            Self::MovUp7 => write!(f, "movup7"),
-            Self::MovUp8 => write!(f, "movup8"),
-
-            Self::MovDn2 => write!(f, "movdn2"),
-            Self::MovDn3 => write!(f, "movdn3"),
-            Self::MovDn4 => write!(f, "movdn4"),
-            Self::MovDn5 => write!(f, "movdn5"),
-            Self::MovDn6 => write!(f, "movdn6"),
-            Self::MovDn7 => write!(f, "movdn7"),
-            Self::MovDn8 => write!(f, "movdn8"),
-
-            Self::CSwap => write!(f, "cswap"),
-            Self::CSwapW => write!(f, "cswapw"),
-
-            // ----- input / output ---------------------------------------------------------------
+            Self::Call | Self::SysCall => writeln!(f, "{}", self.name()),
             Self::Push(value) => write!(f, "push({value})"),
-
-            Self::Read => write!(f, "read"),
-            Self::ReadW => write!(f, "readw"),
-
-            Self::MLoadW => write!(f, "mloadw"),
-            Self::MStoreW => write!(f, "mstorew"),
-
-            Self::MLoad => write!(f, "mload"),
-            Self::MStore => write!(f, "mstore"),
-
-            Self::MStream => write!(f, "mstream"),
-            Self::Pipe => write!(f, "pipe"),
-
-            // ----- cryptographic operations -----------------------------------------------------
-            Self::RpPerm => write!(f, "rpperm"),
-            Self::MpVerify => write!(f, "mpverify"),
             Self::MrUpdate(copy) => {
                 if *copy {
                     write!(f, "mrupdate(copy)")
@@ -656,6 +668,7 @@ impl fmt::Display for Operation {
                     write!(f, "mrupdate(move)")
                 }
             }
+            _ => write!(f, "{}", self.name()),
         }
     }
 }
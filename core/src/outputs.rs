@@ -1,6 +1,7 @@
-use crate::{stack::STACK_TOP_SIZE, StackTopState};
+use crate::{chiplets::hasher::Digest, stack::STACK_TOP_SIZE, AdviceSet, StackTopState};
 
-use super::{Felt, StarkField};
+use super::{Felt, FieldElement, StarkField, Word, WORD_LEN};
+use core::fmt;
 use winter_utils::collections::Vec;
 
 // PROGRAM OUTPUTS
@@ -20,6 +21,12 @@ use winter_utils::collections::Vec;
 /// overflow table (the row representing the deepest element in the stack) and then be followed by
 /// the address (`clk` value) of each row in the table starting from the deepest element in the
 /// stack and finishing with the row which was added to the table last.
+///
+/// The values of the overflow table are not stored separately: they are the items of `stack`
+/// beyond the top 16 slots (see [ProgramOutputs::overflow_values()]). The address at position
+/// `i + 1` in `overflow_addrs` corresponds to the overflow value at position `n - 1 - i` in the
+/// list returned by [ProgramOutputs::overflow_values()], where `n` is the number of overflow
+/// values. [ProgramOutputs::stack_overflow()] returns the values paired with their addresses.
 #[derive(Debug, Clone, Default)]
 pub struct ProgramOutputs {
     /// The elements on the stack at the end of execution.
@@ -96,6 +103,19 @@ impl ProgramOutputs {
         &self.overflow_addrs
     }
 
+    /// Returns the values which were in the stack overflow table at the end of execution (i.e.,
+    /// stack items beyond the top 16 slots) converted to integers.
+    ///
+    /// The values are ordered as if they were popped off the stack one by one: the value directly
+    /// beneath the 16th stack slot comes first, and the deepest item on the stack comes last.
+    pub fn overflow_values(&self) -> &[u64] {
+        if self.stack.len() > STACK_TOP_SIZE {
+            &self.stack[STACK_TOP_SIZE..]
+        } else {
+            &[]
+        }
+    }
+
     /// Returns true if the overflow table outputs are non-empty.
     pub fn has_overflow(&self) -> bool {
         !self.overflow_addrs.is_empty()
@@ -122,6 +142,58 @@ impl ProgramOutputs {
         overflow
     }
 
+    /// Returns the differences between these outputs and the specified expected outputs.
+    ///
+    /// Stack outputs and overflow addresses are compared position by position; a position is
+    /// reported as a mismatch if the values at this position differ, or if the value is present
+    /// in only one of the outputs.
+    pub fn diff(&self, expected: &ProgramOutputs) -> OutputDiff {
+        OutputDiff {
+            stack: diff_values(&expected.stack, &self.stack),
+            overflow_addrs: diff_values(&expected.overflow_addrs, &self.overflow_addrs),
+        }
+    }
+
+    // COMMITMENT
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of a Merkle tree built over the stack outputs.
+    ///
+    /// The leaves of the tree are built as follows:
+    /// - the stack outputs are split into words of 4 consecutive elements, starting from the top
+    ///   of the stack; the last word is padded with zeros if the number of outputs is not a
+    ///   multiple of 4.
+    /// - the list of words is padded with words of zeros until its length is a power of two, but
+    ///   at least two.
+    ///
+    /// Each word is used as a leaf as is (i.e., it is not hashed), and internal nodes are computed
+    /// by merging their two children with the hash function of the VM. Because of the padding,
+    /// outputs which differ only in trailing zeros have the same commitment; a verifier is
+    /// expected to know the number of outputs being committed to.
+    ///
+    /// Overflow addresses are not a part of the commitment.
+    pub fn commitment(&self) -> Digest {
+        let mut leaves = self
+            .stack
+            .chunks(WORD_LEN)
+            .map(|chunk| {
+                let mut word = [Felt::ZERO; WORD_LEN];
+                for (element, &value) in word.iter_mut().zip(chunk) {
+                    *element = Felt::new(value);
+                }
+                word
+            })
+            .collect::<Vec<Word>>();
+        leaves.resize(
+            leaves.len().next_power_of_two().max(2),
+            [Felt::ZERO; WORD_LEN],
+        );
+
+        let tree = AdviceSet::new_merkle_tree(leaves)
+            .expect("number of leaves is a power of two greater than one");
+        Digest::new(tree.root())
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -133,9 +205,80 @@ impl ProgramOutputs {
     }
 }
 
+// OUTPUT DIFF
+// ================================================================================================
+
+/// Differences between the expected and the actual outputs of a program.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutputDiff {
+    /// Mismatches between the expected and the actual stack outputs.
+    pub stack: Vec<OutputMismatch>,
+    /// Mismatches between the expected and the actual overflow table addresses.
+    pub overflow_addrs: Vec<OutputMismatch>,
+}
+
+impl OutputDiff {
+    /// Returns true if the expected and the actual outputs are identical.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty() && self.overflow_addrs.is_empty()
+    }
+}
+
+impl fmt::Display for OutputDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "outputs match");
+        }
+
+        for mismatch in self.stack.iter() {
+            writeln!(f, "stack[{}]: {mismatch}", mismatch.index)?;
+        }
+        for mismatch in self.overflow_addrs.iter() {
+            writeln!(f, "overflow_addrs[{}]: {mismatch}", mismatch.index)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single position at which the expected and the actual outputs differ. A value is `None` if
+/// the corresponding outputs do not contain a value at this position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputMismatch {
+    pub index: usize,
+    pub expected: Option<u64>,
+    pub actual: Option<u64>,
+}
+
+impl fmt::Display for OutputMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected ")?;
+        match self.expected {
+            Some(value) => write!(f, "{value}")?,
+            None => write!(f, "<none>")?,
+        }
+        write!(f, ", actual ")?;
+        match self.actual {
+            Some(value) => write!(f, "{value}"),
+            None => write!(f, "<none>"),
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a list of positions at which the expected and the actual values differ.
+fn diff_values(expected: &[u64], actual: &[u64]) -> Vec<OutputMismatch> {
+    (0..expected.len().max(actual.len()))
+        .map(|index| OutputMismatch {
+            index,
+            expected: expected.get(index).copied(),
+            actual: actual.get(index).copied(),
+        })
+        .filter(|mismatch| mismatch.expected != mismatch.actual)
+        .collect()
+}
+
 /// Verify that each element in the provided slice of outputs is a valid field element.
 fn are_valid_elements(outputs: &[u64]) -> bool {
     for val in outputs {
@@ -145,3 +288,95 @@ fn are_valid_elements(outputs: &[u64]) -> bool {
     }
     true
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, OutputMismatch, ProgramOutputs, WORD_LEN};
+    use crate::{chiplets::hasher, AdviceSet};
+
+    #[test]
+    fn diff_outputs() {
+        let expected = ProgramOutputs::new(vec![1, 2, 3, 4], vec![]);
+
+        let actual = ProgramOutputs::new(vec![1, 2, 3, 4], vec![]);
+        assert!(actual.diff(&expected).is_empty());
+
+        let actual = ProgramOutputs::new(vec![1, 7, 3, 4], vec![]);
+        let diff = actual.diff(&expected);
+        assert_eq!(
+            vec![OutputMismatch {
+                index: 1,
+                expected: Some(2),
+                actual: Some(7)
+            }],
+            diff.stack
+        );
+        assert!(diff.overflow_addrs.is_empty());
+        assert_eq!("stack[1]: expected 2, actual 7\n", diff.to_string());
+
+        let actual = ProgramOutputs::new(vec![1, 2, 3], vec![5]);
+        let diff = actual.diff(&expected);
+        assert_eq!(
+            vec![OutputMismatch {
+                index: 3,
+                expected: Some(4),
+                actual: None
+            }],
+            diff.stack
+        );
+        assert_eq!(
+            vec![OutputMismatch {
+                index: 0,
+                expected: None,
+                actual: Some(5)
+            }],
+            diff.overflow_addrs
+        );
+    }
+
+    #[test]
+    fn outputs_commitment() {
+        let stack = (1..=16).collect::<Vec<u64>>();
+        let outputs = ProgramOutputs::new(stack.clone(), vec![]);
+
+        // the commitment is deterministic and matches the documented layout
+        let commitment = outputs.commitment();
+        assert_eq!(
+            commitment,
+            ProgramOutputs::new(stack.clone(), vec![]).commitment()
+        );
+
+        let leaves = stack
+            .chunks(WORD_LEN)
+            .map(|chunk| {
+                let word: [u64; WORD_LEN] = chunk.try_into().unwrap();
+                word.map(Felt::new)
+            })
+            .collect::<Vec<_>>();
+        let tree = AdviceSet::new_merkle_tree(leaves).unwrap();
+        assert_eq!(hasher::Digest::new(tree.root()), commitment);
+
+        // changing any of the elements changes the commitment
+        for i in 0..stack.len() {
+            let mut changed = stack.clone();
+            changed[i] += 1;
+            assert_ne!(
+                commitment,
+                ProgramOutputs::new(changed, vec![]).commitment()
+            );
+        }
+
+        // overflow addresses do not affect the commitment, and short outputs are padded with zeros
+        assert_eq!(commitment, ProgramOutputs::new(stack, vec![7]).commitment());
+        let short = ProgramOutputs::new(vec![1, 2, 3], vec![]);
+        let padded = ProgramOutputs::new(vec![1, 2, 3, 0, 0, 0, 0, 0], vec![]);
+        assert_eq!(short.commitment(), padded.commitment());
+        assert_ne!(
+            short.commitment(),
+            ProgramOutputs::new(vec![], vec![]).commitment()
+        );
+    }
+}
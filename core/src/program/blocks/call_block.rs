@@ -12,7 +12,7 @@ use super::{fmt, hasher, Digest};
 /// zero.
 ///
 /// TODO: update hashing methodology to make it different from Loop block.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Call {
     hash: Digest,
     fn_hash: Digest,
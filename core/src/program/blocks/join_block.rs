@@ -1,4 +1,4 @@
-use super::{fmt, hasher, Box, CodeBlock, Digest};
+use super::{fmt, hasher, write_display_parts, Arc, CodeBlock, Digest, DisplayPart};
 
 // JOIN BLOCKS
 // ================================================================================================
@@ -8,9 +8,9 @@ use super::{fmt, hasher, Box, CodeBlock, Digest};
 ///
 /// Hash of a Join block is computed by hashing a concatenation of the hashes of joined blocks.
 /// TODO: update hashing methodology to make it different from Split block.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Join {
-    body: Box<[CodeBlock; 2]>,
+    body: [Arc<CodeBlock>; 2],
     hash: Digest,
 }
 
@@ -20,12 +20,22 @@ impl Join {
     /// Returns a new [Join] block instantiated with the specified code blocks.
     pub fn new(body: [CodeBlock; 2]) -> Self {
         let hash = hasher::merge(&[body[0].hash(), body[1].hash()]);
+        let [first, second] = body;
         Self {
-            body: Box::new(body),
+            body: [Arc::new(first), Arc::new(second)],
             hash,
         }
     }
 
+    /// Returns a new [Join] block instantiated with the specified shared code blocks.
+    ///
+    /// The code blocks are not copied, and thus, the same code block (e.g., the body of a
+    /// procedure inlined at several call sites) can be shared by any number of join blocks.
+    pub fn new_shared(body: [Arc<CodeBlock>; 2]) -> Self {
+        let hash = hasher::merge(&[body[0].hash(), body[1].hash()]);
+        Self { body, hash }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -45,10 +55,59 @@ impl Join {
     pub fn second(&self) -> &CodeBlock {
         &self.body[1]
     }
+
+    /// Removes debug info from both joined blocks; this does not affect the hash of this block.
+    pub(super) fn strip_debug_info(&mut self) {
+        Arc::make_mut(&mut self.body[0]).strip_debug_info();
+        Arc::make_mut(&mut self.body[1]).strip_debug_info();
+    }
+
+    /// Returns shared references to the joined blocks.
+    ///
+    /// Joined blocks may be shared with other code blocks, which can be checked by comparing the
+    /// returned references via [Arc::ptr_eq()].
+    pub fn children(&self) -> &[Arc<CodeBlock>] {
+        &self.body
+    }
+
+    /// Returns mutable shared references to the children of this block; the caller must not
+    /// change the hashes of the children as the hash of this block is not recomputed.
+    pub(super) fn children_mut(&mut self) -> &mut [Arc<CodeBlock>] {
+        &mut self.body
+    }
 }
 
 impl fmt::Display for Join {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "join {} {} end", self.body[0], self.body[1])
+        write_display_parts(f, DisplayPart::Join(self))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Arc, CodeBlock, Join};
+    use crate::Operation;
+
+    #[test]
+    fn shared_children() {
+        let body = Arc::new(CodeBlock::new_span(vec![Operation::Add, Operation::Mul]));
+        let join = Join::new_shared([body.clone(), body.clone()]);
+        assert!(Arc::ptr_eq(&join.body[0], &join.body[1]));
+        assert!(Arc::ptr_eq(&body, &join.body[0]));
+        assert_eq!(3, Arc::strong_count(&body));
+
+        // sharing children does not affect the hash or the structure of the block
+        let expected = Join::new([body.as_ref().clone(), body.as_ref().clone()]);
+        assert_eq!(expected.hash(), join.hash());
+        assert_eq!(expected, join);
+
+        // cloning a join block shares its children rather than copying them
+        let clone = join.clone();
+        assert!(Arc::ptr_eq(&join.body[0], &clone.body[0]));
+        assert!(Arc::ptr_eq(&join.body[1], &clone.body[1]));
+        assert_eq!(5, Arc::strong_count(&body));
     }
 }
@@ -1,4 +1,4 @@
-use super::{fmt, hasher, Box, CodeBlock, Digest};
+use super::{fmt, hasher, write_display_parts, Arc, CodeBlock, Digest, DisplayPart};
 
 // LOOP BLOCK
 // ================================================================================================
@@ -12,9 +12,9 @@ use super::{fmt, hasher, Box, CodeBlock, Digest};
 /// while the top of the stack remains `1` at the end of each loop iteration.
 ///
 /// Hash of a Loop block is computed by hashing a concatenation of the loop's body hash with zero.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Loop {
-    body: Box<CodeBlock>,
+    body: Arc<CodeBlock>,
     hash: Digest,
 }
 
@@ -25,7 +25,7 @@ impl Loop {
     pub fn new(body: CodeBlock) -> Self {
         let hash = hasher::merge(&[body.hash(), Digest::default()]);
         Self {
-            body: Box::new(body),
+            body: Arc::new(body),
             hash,
         }
     }
@@ -42,10 +42,26 @@ impl Loop {
     pub fn body(&self) -> &CodeBlock {
         &self.body
     }
+
+    /// Removes debug info from the body of the loop; this does not affect the hash of this block.
+    pub(super) fn strip_debug_info(&mut self) {
+        Arc::make_mut(&mut self.body).strip_debug_info();
+    }
+
+    /// Returns the body of the loop as a shared reference.
+    pub(super) fn children(&self) -> &[Arc<CodeBlock>] {
+        core::slice::from_ref(&self.body)
+    }
+
+    /// Returns a mutable shared reference to the body of the loop; the caller must not change
+    /// the hash of the body as the hash of this block is not recomputed.
+    pub(super) fn children_mut(&mut self) -> &mut [Arc<CodeBlock>] {
+        core::slice::from_mut(&mut self.body)
+    }
 }
 
 impl fmt::Display for Loop {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "while.true {} end", self.body)
+        write_display_parts(f, DisplayPart::Loop(self))
     }
 }
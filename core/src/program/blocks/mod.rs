@@ -1,4 +1,4 @@
-use super::{hasher, Box, Digest, Felt, FieldElement, Operation, Vec};
+use super::{hasher, Arc, BTreeMap, Digest, Felt, FieldElement, Operation, Vec};
 use crate::DecoratorList;
 use core::fmt;
 
@@ -19,10 +19,21 @@ pub use span_block::{
 };
 pub use split_block::Split;
 
+// TYPE ALIASES
+// ================================================================================================
+
+/// A map of code block hashes to shared instances of code blocks with these hashes; used to find
+/// structurally-identical blocks when sharing them.
+pub(crate) type BlockCache = BTreeMap<[u8; 32], Vec<Arc<CodeBlock>>>;
+
 // PROGRAM BLOCK
 // ================================================================================================
 /// TODO: add comments
-#[derive(Clone, Debug)]
+///
+/// Children of control blocks are held behind shared references. Thus, cloning a block does not
+/// clone its children, and structurally-identical children can be shared between blocks (see
+/// [Program::dedup_blocks()](super::Program::dedup_blocks)).
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CodeBlock {
     Span(Span),
     Join(Join),
@@ -95,17 +106,245 @@ impl CodeBlock {
             CodeBlock::Proxy(block) => block.hash(),
         }
     }
+
+    /// Returns the net change in stack depth caused by executing this code block, or None if the
+    /// change cannot be determined statically.
+    ///
+    /// The change can be determined only for straight-line code, i.e., for blocks which consist
+    /// solely of `span` and `join` blocks.
+    pub fn net_stack_effect(&self) -> Option<isize> {
+        let mut analysis = super::StackDepthAnalysis::default();
+        if analysis.visit(self) {
+            Some(analysis.height)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of operations which the VM executes for this code block, counting
+    /// bodies of loops once and operations of both branches of split blocks.
+    ///
+    /// Control flow operations are counted as well: a span block counts its `SPAN`, `RESPAN`, and
+    /// `END` operations, and a join, split, loop, or call block counts the operations which enter
+    /// and exit the block (the body of a procedure invoked via a call block is not a part of the
+    /// block). `REPEAT` operations and proxy blocks are not counted. This is the same definition
+    /// as used by [Program::operation_histogram()](super::Program::operation_histogram).
+    pub fn num_operations(&self) -> usize {
+        let mut num_ops = 0;
+        super::for_each_operation(self, &mut |_| num_ops += 1);
+        num_ops
+    }
+
+    /// Returns an iterator over this code block and all of its descendants in pre-order.
+    ///
+    /// Children of a block are visited in the order in which they are executed (i.e., the first
+    /// block of a join before the second one, and the true branch of a split before the false
+    /// one). Call and proxy blocks have no children, and thus, procedures invoked via call blocks
+    /// are not visited. The traversal does not recurse, and thus, it can be used for arbitrarily
+    /// deep blocks.
+    pub fn iter(&self) -> CodeBlockIter<'_> {
+        CodeBlockIter { stack: vec![self] }
+    }
+
+    // DEBUG INFO
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes debug info (i.e., assembly operation decorators) from this code block and all of
+    /// its children. Debug info is not a part of the MAST, and thus, this does not affect the
+    /// hash of the block.
+    pub(crate) fn strip_debug_info(&mut self) {
+        match self {
+            CodeBlock::Span(block) => block.strip_debug_info(),
+            CodeBlock::Join(block) => block.strip_debug_info(),
+            CodeBlock::Split(block) => block.strip_debug_info(),
+            CodeBlock::Loop(block) => block.strip_debug_info(),
+            CodeBlock::Call(_) | CodeBlock::Proxy(_) => (),
+        }
+    }
+
+    // BLOCK SHARING
+    // --------------------------------------------------------------------------------------------
+
+    /// Replaces children of this code block (and, transitively, their children) with identical
+    /// blocks from the cache; children which are not in the cache yet are added to it.
+    ///
+    /// Descendants are visited in post-order using an explicit stack rather than via recursion:
+    /// a block is looked up in the cache only after all of its children have been replaced, and
+    /// thus, identical blocks in the cache can be found by comparing shared children by reference.
+    pub(crate) fn dedup_children(&mut self, cache: &mut BlockCache) {
+        enum Visit {
+            Enter(Arc<CodeBlock>),
+            Exit(Arc<CodeBlock>),
+        }
+
+        let mut stack = self
+            .children()
+            .iter()
+            .rev()
+            .map(|child| Visit::Enter(child.clone()))
+            .collect::<Vec<_>>();
+        let mut results: Vec<Arc<CodeBlock>> = Vec::new();
+        while let Some(visit) = stack.pop() {
+            match visit {
+                Visit::Enter(block) if block.children().is_empty() => {
+                    results.push(dedup_block(block, cache));
+                }
+                Visit::Enter(block) => {
+                    let children = block.children().to_vec();
+                    stack.push(Visit::Exit(block));
+                    stack.extend(children.into_iter().rev().map(Visit::Enter));
+                }
+                Visit::Exit(mut block) => {
+                    // a shared block is copied (without its children) only if any of its children
+                    // has been replaced
+                    let children = results.split_off(results.len() - block.children().len());
+                    let is_unchanged = block
+                        .children()
+                        .iter()
+                        .zip(children.iter())
+                        .all(|(child, deduped)| Arc::ptr_eq(child, deduped));
+                    if !is_unchanged {
+                        Arc::make_mut(&mut block)
+                            .children_mut()
+                            .clone_from_slice(&children);
+                    }
+                    results.push(dedup_block(block, cache));
+                }
+            }
+        }
+
+        self.children_mut().clone_from_slice(&results);
+    }
+
+    /// Returns the children of this code block; span, call, and proxy blocks have no children.
+    fn children(&self) -> &[Arc<CodeBlock>] {
+        match self {
+            CodeBlock::Join(block) => block.children(),
+            CodeBlock::Split(block) => block.children(),
+            CodeBlock::Loop(block) => block.children(),
+            CodeBlock::Span(_) | CodeBlock::Call(_) | CodeBlock::Proxy(_) => &[],
+        }
+    }
+
+    /// Returns mutable references to the children of this code block.
+    fn children_mut(&mut self) -> &mut [Arc<CodeBlock>] {
+        match self {
+            CodeBlock::Join(block) => block.children_mut(),
+            CodeBlock::Split(block) => block.children_mut(),
+            CodeBlock::Loop(block) => block.children_mut(),
+            CodeBlock::Span(_) | CodeBlock::Call(_) | CodeBlock::Proxy(_) => &mut [],
+        }
+    }
+}
+
+// CODE BLOCK ITERATOR
+// ================================================================================================
+
+/// An iterator over a code block and all of its descendants in pre-order (see [CodeBlock::iter()]).
+pub struct CodeBlockIter<'a> {
+    stack: Vec<&'a CodeBlock>,
+}
+
+impl<'a> Iterator for CodeBlockIter<'a> {
+    type Item = &'a CodeBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let block = self.stack.pop()?;
+
+        // children are pushed in reverse order so that they are popped in the execution order
+        match block {
+            CodeBlock::Join(block) => {
+                self.stack.push(block.second());
+                self.stack.push(block.first());
+            }
+            CodeBlock::Split(block) => {
+                self.stack.push(block.on_false());
+                self.stack.push(block.on_true());
+            }
+            CodeBlock::Loop(block) => self.stack.push(block.body()),
+            CodeBlock::Span(_) | CodeBlock::Call(_) | CodeBlock::Proxy(_) => (),
+        }
+
+        Some(block)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an identical block from the cache, or adds the specified block to the cache and
+/// returns it if there is no such block there.
+fn dedup_block(block: Arc<CodeBlock>, cache: &mut BlockCache) -> Arc<CodeBlock> {
+    let key: [u8; 32] = block.hash().into();
+    let candidates = cache.entry(key).or_default();
+    match candidates
+        .iter()
+        .find(|candidate| candidate.as_ref() == block.as_ref())
+    {
+        Some(candidate) => candidate.clone(),
+        None => {
+            candidates.push(block.clone());
+            block
+        }
+    }
 }
 
 impl fmt::Display for CodeBlock {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            CodeBlock::Span(block) => write!(f, "{block}"),
-            CodeBlock::Join(block) => write!(f, "{block}"),
-            CodeBlock::Split(block) => write!(f, "{block}"),
-            CodeBlock::Loop(block) => write!(f, "{block}"),
-            CodeBlock::Call(block) => write!(f, "{block}"),
-            CodeBlock::Proxy(block) => write!(f, "{block}"),
+        write_display_parts(f, DisplayPart::Block(self))
+    }
+}
+
+/// A part of the textual representation of a code block.
+enum DisplayPart<'a> {
+    Block(&'a CodeBlock),
+    Join(&'a Join),
+    Split(&'a Split),
+    Loop(&'a Loop),
+    Text(&'static str),
+}
+
+/// Writes the specified part of a code block into the formatter.
+///
+/// Nested blocks are written using an explicit stack of the remaining parts rather than via
+/// recursion, and thus, writing deeply nested blocks does not overflow the call stack.
+fn write_display_parts(f: &mut fmt::Formatter<'_>, part: DisplayPart<'_>) -> fmt::Result {
+    let mut parts = vec![part];
+    while let Some(part) = parts.pop() {
+        // parts are pushed in reverse order so that they are popped in the order of writing
+        match part {
+            DisplayPart::Block(block) => match block {
+                CodeBlock::Span(block) => write!(f, "{block}")?,
+                CodeBlock::Join(block) => parts.push(DisplayPart::Join(block)),
+                CodeBlock::Split(block) => parts.push(DisplayPart::Split(block)),
+                CodeBlock::Loop(block) => parts.push(DisplayPart::Loop(block)),
+                CodeBlock::Call(block) => write!(f, "{block}")?,
+                CodeBlock::Proxy(block) => write!(f, "{block}")?,
+            },
+            DisplayPart::Join(block) => {
+                f.write_str("join ")?;
+                parts.extend([
+                    DisplayPart::Text(" end"),
+                    DisplayPart::Block(block.second()),
+                    DisplayPart::Text(" "),
+                    DisplayPart::Block(block.first()),
+                ]);
+            }
+            DisplayPart::Split(block) => {
+                f.write_str("if.true ")?;
+                parts.extend([
+                    DisplayPart::Text(" end"),
+                    DisplayPart::Block(block.on_false()),
+                    DisplayPart::Text(" else "),
+                    DisplayPart::Block(block.on_true()),
+                ]);
+            }
+            DisplayPart::Loop(block) => {
+                f.write_str("while.true ")?;
+                parts.extend([DisplayPart::Text(" end"), DisplayPart::Block(block.body())]);
+            }
+            DisplayPart::Text(text) => f.write_str(text)?,
         }
     }
+    Ok(())
 }
@@ -1,5 +1,5 @@
 use super::{fmt, hasher, Digest, Felt, FieldElement, Operation, Vec};
-use crate::{DecoratorIterator, DecoratorList};
+use crate::{Decorator, DecoratorIterator, DecoratorList};
 use winter_utils::flatten_slice_elements;
 
 // CONSTANTS
@@ -35,7 +35,7 @@ const MAX_OPS_PER_BATCH: usize = GROUP_SIZE * BATCH_SIZE;
 /// up to 72 operations can fit into a single batch.
 ///
 /// TODO: describe how Span hash is computed.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Span {
     op_batches: Vec<OpBatch>,
     hash: Digest,
@@ -117,6 +117,14 @@ impl Span {
         &self.decorators
     }
 
+    /// Removes all decorators which carry debug info (i.e., [Decorator::AsmOp]) from this span
+    /// block. Decorators which affect execution (i.e., advice injectors and error codes) are
+    /// retained.
+    pub(super) fn strip_debug_info(&mut self) {
+        self.decorators
+            .retain(|(_, decorator)| !matches!(decorator, Decorator::AsmOp(_)));
+    }
+
     /// Returns a [DecoratorIterator] which allows us to iterate through the decorator list of this span
     /// block while executing operation batches of this span block
     pub fn decorator_iter(&self) -> DecoratorIterator {
@@ -155,7 +163,7 @@ impl fmt::Display for Span {
 ///
 /// An operation batch consists of up to 8 operation groups, with each group containing up to 9
 /// operations or a single immediate value.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OpBatch {
     ops: Vec<Operation>,
     groups: [Felt; BATCH_SIZE],
@@ -376,7 +384,9 @@ pub fn get_span_op_group_count(op_batches: &[OpBatch]) -> usize {
 
 /// Checks if a given decorators list is valid (only checked in debug mode)
 /// - Assert the decorator list is in ascending order.
-/// - Assert the last op index in decorator list is less than the number of operations.
+/// - Assert the last op index in decorator list is not greater than the number of operations.
+///   Decorators with index equal to the number of operations are executed after the last
+///   operation of the span.
 #[cfg(debug_assertions)]
 fn validate_decorators(operations: &[Operation], decorators: &DecoratorList) {
     if !decorators.is_empty() {
@@ -387,10 +397,10 @@ fn validate_decorators(operations: &[Operation], decorators: &DecoratorList) {
                 "unsorted decorators list"
             );
         }
-        // assert the last index in decorator list is less than operations vector length
+        // assert the last index in decorator list is not greater than operations vector length
         debug_assert!(
-            operations.len() > decorators.last().expect("empty decorators list").0,
-            "last op index in decorator list should be less than number of ops"
+            operations.len() >= decorators.last().expect("empty decorators list").0,
+            "last op index in decorator list should not be greater than number of ops"
         );
     }
 }
@@ -1,4 +1,4 @@
-use super::{fmt, hasher, Box, CodeBlock, Digest};
+use super::{fmt, hasher, write_display_parts, Arc, CodeBlock, Digest, DisplayPart};
 
 // SPLIT BLOCK
 // ================================================================================================
@@ -11,9 +11,9 @@ use super::{fmt, hasher, Box, CodeBlock, Digest};
 ///
 /// Hash of a Split block is computed by hashing a concatenation of the true and the false branch
 /// hashes.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Split {
-    branches: Box<[CodeBlock; 2]>,
+    branches: [Arc<CodeBlock>; 2],
     hash: Digest,
 }
 
@@ -24,7 +24,7 @@ impl Split {
     pub fn new(t_branch: CodeBlock, f_branch: CodeBlock) -> Self {
         let hash = hasher::merge(&[t_branch.hash(), f_branch.hash()]);
         Self {
-            branches: Box::new([t_branch, f_branch]),
+            branches: [Arc::new(t_branch), Arc::new(f_branch)],
             hash,
         }
     }
@@ -48,14 +48,27 @@ impl Split {
     pub fn on_false(&self) -> &CodeBlock {
         &self.branches[1]
     }
+
+    /// Removes debug info from both branches; this does not affect the hash of this block.
+    pub(super) fn strip_debug_info(&mut self) {
+        Arc::make_mut(&mut self.branches[0]).strip_debug_info();
+        Arc::make_mut(&mut self.branches[1]).strip_debug_info();
+    }
+
+    /// Returns the true and the false branches as shared references.
+    pub(super) fn children(&self) -> &[Arc<CodeBlock>] {
+        &self.branches
+    }
+
+    /// Returns mutable shared references to the children of this block; the caller must not
+    /// change the hashes of the children as the hash of this block is not recomputed.
+    pub(super) fn children_mut(&mut self) -> &mut [Arc<CodeBlock>] {
+        &mut self.branches
+    }
 }
 
 impl fmt::Display for Split {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "if.true {} else {} end",
-            self.branches[0], self.branches[1]
-        )
+        write_display_parts(f, DisplayPart::Split(self))
     }
 }
@@ -1,15 +1,19 @@
 use super::{
     chiplets::hasher::{self, Digest},
+    errors::ProgramError,
     utils::{
         collections::{BTreeMap, Vec},
-        Box,
+        string::{String, ToString},
+        Arc,
     },
     Felt, FieldElement, Operation,
 };
-use core::fmt;
+use core::fmt::{self, Write};
 
 pub mod blocks;
-use blocks::CodeBlock;
+use blocks::{BlockCache, CodeBlock, Span};
+
+mod serialization;
 
 // PROGRAM
 // ================================================================================================
@@ -18,11 +22,18 @@ use blocks::CodeBlock;
 /// A program is described by a Merkelized Abstract Syntax Tree (MAST), where each node is a
 /// [CodeBlock]. Internal nodes describe control flow semantics of the program, while leaf nodes
 /// contain linear sequences of instructions which contain no control flow.
+///
+/// A program can also carry a human-readable name, the peak local frame computed by the
+/// assembler, and the inputs which the program declares to expect. These are metadata only: they
+/// are not a part of the MAST and thus do not affect the hash of the program.
 #[derive(Clone, Debug)]
 pub struct Program {
     root: CodeBlock,
     kernel: Kernel,
     cb_table: CodeBlockTable,
+    name: Option<String>,
+    max_local_frame: u64,
+    input_spec: Option<InputSpec>,
 }
 
 impl Program {
@@ -39,9 +50,35 @@ impl Program {
             root,
             kernel,
             cb_table,
+            name: None,
+            max_local_frame: 0,
+            input_spec: None,
         }
     }
 
+    /// Sets the human-readable name of this program; the name does not affect the program hash.
+    pub fn with_name<S>(mut self, name: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        self.name = Some(name.as_ref().to_string());
+        self
+    }
+
+    /// Sets the peak number of memory locals reserved at the same time during execution of this
+    /// program; this does not affect the program hash.
+    pub fn with_max_local_frame(mut self, max_local_frame: u64) -> Self {
+        self.max_local_frame = max_local_frame;
+        self
+    }
+
+    /// Sets the inputs which this program declares to expect; this does not affect the program
+    /// hash.
+    pub fn with_input_spec(mut self, input_spec: InputSpec) -> Self {
+        self.input_spec = Some(input_spec);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -64,6 +101,216 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    /// Returns the human-readable name of this program, if one was set.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the peak number of memory locals reserved at the same time along any inlined call
+    /// path of this program.
+    ///
+    /// When a procedure with locals is invoked via `exec` instruction, its locals are reserved on
+    /// top of the locals of the invoking procedure. Thus, the returned value is the maximum, over
+    /// all chains of nested `exec` invocations, of the total number of locals declared by the
+    /// procedures in the chain. Procedures invoked via `call` or `syscall` instructions reserve
+    /// their locals in a separate memory context, and thus, start a new chain.
+    ///
+    /// The value is computed by the assembler; for programs not built by the assembler, 0 is
+    /// returned unless the value was set via [Program::with_max_local_frame()].
+    pub fn max_local_frame(&self) -> u64 {
+        self.max_local_frame
+    }
+
+    /// Returns the inputs which this program declares to expect, if the program declares them.
+    ///
+    /// In Miden assembly, the inputs are declared via an `@inputs` annotation in the doc comment
+    /// of the program body.
+    pub fn input_spec(&self) -> Option<&InputSpec> {
+        self.input_spec.as_ref()
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that this program commits to actual code.
+    ///
+    /// A program built via the assembler always passes this check. A program built directly from
+    /// code blocks fails it if its root is a proxy block: although a proxy block has a non-zero
+    /// hash, it does not contain the code it commits to, and thus, such a program cannot be
+    /// executed.
+    ///
+    /// # Errors
+    /// Returns an error if the hash of this program is the zero digest, or if the root of this
+    /// program is a proxy block (and thus, the code of the program is not available).
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.hash() == Digest::default() || matches!(self.root, CodeBlock::Proxy(_)) {
+            return Err(ProgramError::EmptyCommitment);
+        }
+        Ok(())
+    }
+
+    // DEBUG INFO
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns this program with all debug info removed.
+    ///
+    /// Specifically, assembly operation decorators (which map VM operations to the source
+    /// instructions in debug mode) are removed from all code blocks of the program, including
+    /// blocks in the code block table, and the name of the program is cleared. Advice injectors
+    /// are retained as they affect execution.
+    ///
+    /// Debug info is not a part of the program MAST, and thus, the stripped program has the same
+    /// hash and is executed in exactly the same way as the original program.
+    #[must_use]
+    pub fn strip_debug(mut self) -> Self {
+        self.root.strip_debug_info();
+        self.cb_table.strip_debug_info();
+        self.name = None;
+        self
+    }
+
+    // MAST MINIMIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns this program with structurally-identical code blocks shared.
+    ///
+    /// Code blocks of the program (including blocks in the code block table) which are identical
+    /// (i.e., have the same hash and the same decorators) are replaced with references to a
+    /// single instance of the block. This reduces the memory footprint of programs with repeated
+    /// sub-trees (e.g., bodies of `repeat` instructions), and since blocks are content-addressed,
+    /// does not change the hash of the program.
+    #[must_use]
+    pub fn dedup_blocks(mut self) -> Self {
+        let mut cache = BlockCache::default();
+        self.root.dedup_children(&mut cache);
+        self.cb_table.dedup_blocks(&mut cache);
+        self
+    }
+
+    // REWRITING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new program in which operations of every span block of this program are replaced
+    /// with the operations returned by `f` for the operations of that block.
+    ///
+    /// The MAST of the new program is rebuilt via block constructors, and thus, hashes of all
+    /// blocks enclosing the rewritten spans are recomputed. Decorators of a span are retained at
+    /// the same operation indexes.
+    ///
+    /// Only the blocks of the program MAST are rewritten; blocks in the code block table (i.e.,
+    /// bodies of procedures invoked via `call` and `syscall` instructions) are kept as is, since
+    /// rewriting them would change the hashes by which they are invoked.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `f` returns an empty list of operations.
+    /// - A decorator of a span would be located beyond the end of the rewritten operations, as
+    ///   such a decorator cannot be attached to the operation it was meant for.
+    pub fn rewrite_spans(
+        &self,
+        f: impl Fn(&[Operation]) -> Vec<Operation>,
+    ) -> Result<Program, ProgramError> {
+        let mut program = self.clone();
+        program.root = rewrite_block(&self.root, &f)?;
+        Ok(program)
+    }
+
+    // ANALYSIS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the minimum number of initial stack elements read by this program.
+    ///
+    /// The analysis covers only the straight-line prefix of the program - i.e., operations which
+    /// are executed before the first `split`, `loop`, `call`, or `syscall` block. Thus, for
+    /// programs without such blocks, the returned value is exact, and for other programs, it is a
+    /// lower bound. If the program starts with one of these blocks, None is returned as no bound
+    /// can be established statically.
+    pub fn min_initial_stack_depth(&self) -> Option<usize> {
+        let mut analysis = StackDepthAnalysis::default();
+        let is_complete = analysis.visit(&self.root);
+        if !is_complete && analysis.num_ops == 0 {
+            None
+        } else {
+            Some(analysis.min_depth)
+        }
+    }
+
+    /// Returns the number of occurrences of each operation in this program, keyed by operation
+    /// mnemonic (see [Operation::name()]).
+    ///
+    /// Operations are counted statically: each operation in the MAST of the program (including
+    /// blocks in the code block table) is counted once, regardless of how many times it would be
+    /// executed. Besides operations of span blocks, control flow operations which the VM executes
+    /// to enter and to exit control blocks (e.g., `join` and `end`) are counted as well, except for
+    /// `repeat` operations as their number depends on execution.
+    pub fn operation_histogram(&self) -> BTreeMap<&'static str, usize> {
+        let mut histogram = BTreeMap::new();
+        let mut count_op = |op: Operation| *histogram.entry(op.name()).or_insert(0) += 1;
+        for_each_operation(&self.root, &mut count_op);
+        for block in self.cb_table.0.values() {
+            for_each_operation(block, &mut count_op);
+        }
+        histogram
+    }
+
+    // OPERATION LOOKUP
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an iterator over operations of all span blocks in the MAST of this program.
+    ///
+    /// Span blocks are visited in the order of execution: children of `join` blocks are visited
+    /// first to second, the `true` branch of a `split` block is visited before its `false` branch,
+    /// and bodies of loops are visited once. Bodies of procedures invoked via `call` and `syscall`
+    /// are not a part of the program MAST, and thus, their operations are not returned.
+    ///
+    /// Only the operations contained in span blocks are returned; control flow operations which
+    /// the VM executes to enter and to exit blocks (e.g., `SPAN` and `END`) are not, unlike in
+    /// [CodeBlock::num_operations()] and [Program::operation_histogram()].
+    pub fn operations(&self) -> impl Iterator<Item = &Operation> {
+        let mut spans = Vec::new();
+        collect_spans(&self.root, &mut spans);
+        spans.into_iter().flat_map(span_ops)
+    }
+
+    /// Returns the span block containing the operation with the specified index in the sequence
+    /// of operations returned by [Program::operations()], together with the offset of the
+    /// operation within the span block; returns None if the program has fewer operations.
+    pub fn span_at_op_index(&self, index: usize) -> Option<(&Span, usize)> {
+        let mut spans = Vec::new();
+        collect_spans(&self.root, &mut spans);
+
+        let mut offset = index;
+        for span in spans {
+            let num_ops = span_ops(span).count();
+            if offset < num_ops {
+                return Some((span, offset));
+            }
+            offset -= num_ops;
+        }
+        None
+    }
+
+    // FORMATTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a multi-line representation of this program.
+    ///
+    /// Unlike the compact [Display](fmt::Display) representation, each control block is printed
+    /// on its own lines, and children of `join`, `split` and `loop` blocks are indented by
+    /// `indent` spaces relative to their parent. Call and proxy blocks are printed on a single
+    /// line. Span blocks are printed on a single line as well, unless the line would be longer
+    /// than `width` characters; in such a case, operations of the span are wrapped into indented
+    /// lines of at most `width` characters each (a single operation which does not fit into
+    /// `width` is still printed on its own line).
+    pub fn display_pretty(&self, indent: usize, width: usize) -> String {
+        let mut result = String::new();
+        writeln!(result, "begin")
+            .and_then(|_| fmt_block_pretty(&mut result, &self.root, indent, width, 1))
+            .and_then(|_| write!(result, "end"))
+            .expect("writing to a string should not fail");
+        result
+    }
 }
 
 impl fmt::Display for Program {
@@ -72,6 +319,299 @@ impl fmt::Display for Program {
     }
 }
 
+/// A part of the indented textual representation of a code block; each part is written at the
+/// specified nesting depth.
+enum PrettyPart<'a> {
+    Block(&'a CodeBlock, usize),
+    Line(&'static str, usize),
+}
+
+/// Writes the specified code block into `out`, starting each line with `indent * depth` spaces
+/// and wrapping span blocks which do not fit into `width` characters.
+///
+/// Nested blocks are written using an explicit stack rather than via recursion, and thus,
+/// writing deeply nested blocks does not overflow the call stack.
+fn fmt_block_pretty(
+    out: &mut String,
+    block: &CodeBlock,
+    indent: usize,
+    width: usize,
+    depth: usize,
+) -> fmt::Result {
+    let mut parts = vec![PrettyPart::Block(block, depth)];
+    while let Some(part) = parts.pop() {
+        let (block, depth) = match part {
+            PrettyPart::Block(block, depth) => (block, depth),
+            PrettyPart::Line(line, depth) => {
+                writeln!(out, "{:pad$}{line}", "", pad = indent * depth)?;
+                continue;
+            }
+        };
+
+        // parts are pushed in reverse order so that they are popped in the order of writing
+        let pad = indent * depth;
+        match block {
+            CodeBlock::Join(block) => {
+                writeln!(out, "{:pad$}join", "")?;
+                parts.extend([
+                    PrettyPart::Line("end", depth),
+                    PrettyPart::Block(block.second(), depth + 1),
+                    PrettyPart::Block(block.first(), depth + 1),
+                ]);
+            }
+            CodeBlock::Split(block) => {
+                writeln!(out, "{:pad$}if.true", "")?;
+                parts.extend([
+                    PrettyPart::Line("end", depth),
+                    PrettyPart::Block(block.on_false(), depth + 1),
+                    PrettyPart::Line("else", depth),
+                    PrettyPart::Block(block.on_true(), depth + 1),
+                ]);
+            }
+            CodeBlock::Loop(block) => {
+                writeln!(out, "{:pad$}while.true", "")?;
+                parts.extend([
+                    PrettyPart::Line("end", depth),
+                    PrettyPart::Block(block.body(), depth + 1),
+                ]);
+            }
+            CodeBlock::Span(span) => fmt_span_pretty(out, span, indent, width, depth)?,
+            _ => writeln!(out, "{:pad$}{block}", "")?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes the specified span block into `out` at the specified nesting depth.
+///
+/// If the span does not fit into a single line of `width` characters, `span` and `end` are
+/// written on separate lines, and operations are written in between them one nesting level
+/// deeper, with as many operations per line as fit into `width` characters.
+fn fmt_span_pretty(
+    out: &mut String,
+    span: &Span,
+    indent: usize,
+    width: usize,
+    depth: usize,
+) -> fmt::Result {
+    let pad = indent * depth;
+    let line = span.to_string();
+    if pad + line.len() <= width {
+        return writeln!(out, "{:pad$}{line}", "");
+    }
+
+    writeln!(out, "{:pad$}span", "")?;
+    let op_pad = pad + indent;
+    let mut line = String::new();
+    for op in span_ops(span) {
+        let op = op.to_string();
+        if !line.is_empty() && op_pad + line.len() + 1 + op.len() > width {
+            writeln!(out, "{:op_pad$}{line}", "")?;
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&op);
+    }
+    if !line.is_empty() {
+        writeln!(out, "{:op_pad$}{line}", "")?;
+    }
+    writeln!(out, "{:pad$}end", "")
+}
+
+// OPERATION TRAVERSAL
+// ================================================================================================
+
+/// Invokes the callback for each operation which the VM executes for the specified block; bodies
+/// of loops are visited once, and proxy blocks contain no operations. `REPEAT` operations are not
+/// reported as their number depends on execution.
+///
+/// Blocks are visited via [CodeBlock::iter()], and the `END` operation of a control block is
+/// reported right after the operation which opens the block; thus, operations are not reported
+/// in the order of execution.
+fn for_each_operation<F: FnMut(Operation)>(block: &CodeBlock, f: &mut F) {
+    for block in block.iter() {
+        match block {
+            CodeBlock::Span(block) => {
+                f(Operation::Span);
+                for (i, batch) in block.op_batches().iter().enumerate() {
+                    if i > 0 {
+                        f(Operation::Respan);
+                    }
+                    batch.ops().iter().copied().for_each(&mut *f);
+                }
+            }
+            CodeBlock::Join(_) => f(Operation::Join),
+            CodeBlock::Split(_) => f(Operation::Split),
+            CodeBlock::Loop(_) => f(Operation::Loop),
+            CodeBlock::Call(block) if block.is_syscall() => f(Operation::SysCall),
+            CodeBlock::Call(_) => f(Operation::Call),
+            CodeBlock::Proxy(_) => continue,
+        }
+        f(Operation::End);
+    }
+}
+
+/// Appends all span blocks of the specified block to `spans` in the order of execution (see
+/// [Program::operations()]).
+fn collect_spans<'a>(block: &'a CodeBlock, spans: &mut Vec<&'a Span>) {
+    spans.extend(block.iter().filter_map(|block| match block {
+        CodeBlock::Span(block) => Some(block),
+        _ => None,
+    }));
+}
+
+/// Returns an iterator over operations of the specified span block.
+fn span_ops(span: &Span) -> impl Iterator<Item = &Operation> {
+    span.op_batches()
+        .iter()
+        .flat_map(|batch| batch.ops().iter())
+}
+
+// SPAN REWRITING
+// ================================================================================================
+
+/// Returns a copy of the specified block in which operations of all span blocks are replaced
+/// with the operations returned by `f`.
+///
+/// The copy is built bottom-up from the blocks visited by [CodeBlock::iter()] in reverse order:
+/// when a block is rebuilt, the rebuilt copies of its children are on top of the stack of results
+/// (the first child at the very top). Thus, rewriting does not recurse.
+fn rewrite_block<F: Fn(&[Operation]) -> Vec<Operation>>(
+    block: &CodeBlock,
+    f: &F,
+) -> Result<CodeBlock, ProgramError> {
+    let blocks = block.iter().collect::<Vec<_>>();
+    let mut results: Vec<CodeBlock> = Vec::with_capacity(blocks.len());
+    for block in blocks.into_iter().rev() {
+        let mut next_child = || results.pop().expect("missing rewritten child block");
+        let result = match block {
+            CodeBlock::Span(block) => {
+                let ops = block
+                    .op_batches()
+                    .iter()
+                    .flat_map(|batch| batch.ops())
+                    .copied()
+                    .collect::<Vec<_>>();
+                let new_ops = f(&ops);
+                if new_ops.is_empty() {
+                    return Err(ProgramError::EmptySpan);
+                }
+
+                // decorators may be located right after the last operation, but not beyond it
+                if let Some((op_idx, _)) = block.decorators().last() {
+                    if *op_idx > new_ops.len() {
+                        return Err(ProgramError::InvalidDecoratorIndex(*op_idx, new_ops.len()));
+                    }
+                }
+                CodeBlock::new_span_with_decorators(new_ops, block.decorators().clone())
+            }
+            CodeBlock::Join(_) => {
+                let first = next_child();
+                let second = next_child();
+                CodeBlock::new_join([first, second])
+            }
+            CodeBlock::Split(_) => {
+                let on_true = next_child();
+                let on_false = next_child();
+                CodeBlock::new_split(on_true, on_false)
+            }
+            CodeBlock::Loop(_) => CodeBlock::new_loop(next_child()),
+            CodeBlock::Call(_) | CodeBlock::Proxy(_) => block.clone(),
+        };
+        results.push(result);
+    }
+    Ok(results.pop().expect("missing rewritten root block"))
+}
+
+// STACK DEPTH ANALYSIS
+// ================================================================================================
+
+/// Tracks the number of initial stack elements read by a straight-line sequence of operations.
+#[derive(Default)]
+struct StackDepthAnalysis {
+    /// Stack depth relative to the initial stack; negative if initial elements have been removed.
+    height: isize,
+    /// Number of initial stack elements read so far.
+    min_depth: usize,
+    /// Number of operations analyzed so far.
+    num_ops: usize,
+}
+
+impl StackDepthAnalysis {
+    /// Analyzes operations of the specified block until the first block which is not a `span` or
+    /// a `join` block is encountered. Returns true if the entire block has been analyzed.
+    fn visit(&mut self, block: &CodeBlock) -> bool {
+        // in a tree of join blocks, span blocks are visited in pre-order in the execution order
+        for block in block.iter() {
+            match block {
+                CodeBlock::Span(block) => {
+                    for op in block.op_batches().iter().flat_map(|batch| batch.ops()) {
+                        self.apply(op);
+                    }
+                }
+                CodeBlock::Join(_) => (),
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Updates the analysis with the effect of the specified operation.
+    fn apply(&mut self, op: &Operation) {
+        let (num_inputs, depth_change) = stack_effect(op);
+        let required_depth = num_inputs as isize - self.height;
+        if required_depth > self.min_depth as isize {
+            self.min_depth = required_depth as usize;
+        }
+        self.height += depth_change;
+        self.num_ops += 1;
+    }
+}
+
+/// Returns the number of top stack elements read by the specified operation, and the change in
+/// stack depth caused by this operation.
+fn stack_effect(op: &Operation) -> (usize, isize) {
+    use Operation::*;
+    match op {
+        Noop | Caller | ReadW => (0, 0),
+        Join | Split | Loop | Call | SysCall | Span | End | Repeat | Respan | Halt => (0, 0),
+        SDepth | Pad | Push(_) | Read => (0, 1),
+        Assert | FmpUpdate | Drop => (1, -1),
+        FmpAdd | Neg | Inv | Incr | Not | Eqz | MLoad => (1, 0),
+        U32split | Dup0 => (1, 1),
+        Add | Mul | And | Or | Eq | U32and | U32xor | MStore => (2, -1),
+        Swap | U32add | U32assert2 | U32sub | U32mul | U32div => (2, 0),
+        Dup1 => (2, 1),
+        U32add3 | U32madd | CSwap => (3, -1),
+        MovUp2 | MovDn2 => (3, 0),
+        Dup2 => (3, 1),
+        Expacc | MovUp3 | MovDn3 => (4, 0),
+        Dup3 => (4, 1),
+        MLoadW | MStoreW => (5, -1),
+        MovUp4 | MovDn4 => (5, 0),
+        Dup4 => (5, 1),
+        MovUp5 | MovDn5 => (6, 0),
+        Dup5 => (6, 1),
+        MovUp6 | MovDn6 => (7, 0),
+        Dup6 => (7, 1),
+        SwapW | MovUp7 | MovDn7 => (8, 0),
+        Dup7 => (8, 1),
+        CSwapW => (9, -1),
+        MovUp8 | MovDn8 => (9, 0),
+        MpVerify => (10, 0),
+        Dup9 => (10, 1),
+        SwapW2 | RpPerm => (12, 0),
+        Dup11 => (12, 1),
+        MStream | Pipe => (13, 0),
+        MrUpdate(_) => (14, 0),
+        Dup13 => (14, 1),
+        SwapW3 | SwapDW => (16, 0),
+        Dup15 => (16, 1),
+    }
+}
+
 // CODE BLOCK TABLE
 // ================================================================================================
 
@@ -107,6 +647,63 @@ impl CodeBlockTable {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Removes debug info from all code blocks in this table.
+    fn strip_debug_info(&mut self) {
+        self.0.values_mut().for_each(CodeBlock::strip_debug_info);
+    }
+
+    /// Shares structurally-identical children of all code blocks in this table.
+    fn dedup_blocks(&mut self, cache: &mut BlockCache) {
+        self.0
+            .values_mut()
+            .for_each(|block| block.dedup_children(cache));
+    }
+}
+
+// INPUT SPECIFICATION
+// ================================================================================================
+
+/// Numbers of inputs which a program declares to expect.
+///
+/// The declaration is not enforced by the VM; it allows tools to warn users when the inputs
+/// supplied to a program do not match what the program expects. Either of the numbers can be
+/// omitted, in which case the corresponding inputs are not checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InputSpec {
+    stack: Option<usize>,
+    advice: Option<usize>,
+}
+
+impl InputSpec {
+    /// Returns a new [InputSpec] with the specified numbers of stack and advice tape inputs.
+    pub fn new(stack: Option<usize>, advice: Option<usize>) -> Self {
+        Self { stack, advice }
+    }
+
+    /// Returns the number of initial stack values which the program expects, if declared.
+    pub fn stack(&self) -> Option<usize> {
+        self.stack
+    }
+
+    /// Returns the number of advice tape values which the program expects, if declared.
+    pub fn advice(&self) -> Option<usize> {
+        self.advice
+    }
+}
+
+impl fmt::Display for InputSpec {
+    /// Writes this specification as an `@inputs` annotation, e.g. `@inputs(stack=3, advice=6)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "@inputs(")?;
+        match (self.stack, self.advice) {
+            (Some(stack), Some(advice)) => write!(f, "stack={stack}, advice={advice}")?,
+            (Some(stack), None) => write!(f, "stack={stack}")?,
+            (None, Some(advice)) => write!(f, "advice={advice}")?,
+            (None, None) => (),
+        }
+        write!(f, ")")
+    }
 }
 
 // KERNEL
@@ -147,3 +744,129 @@ impl Kernel {
         &self.0
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeBlock, Digest, Felt, Operation, Program, ProgramError};
+    use crate::{utils::Serializable, AdviceInjector, Decorator};
+
+    /// Builds a program from spans with the specified operations; the first span is followed by
+    /// a loop with the second span as its body.
+    fn build_program(first: Vec<Operation>, second: Vec<Operation>) -> Program {
+        let decorators = vec![(1, Decorator::Advice(AdviceInjector::MerkleNode))];
+        Program::new(CodeBlock::new_join([
+            CodeBlock::new_span_with_decorators(first, decorators),
+            CodeBlock::new_loop(CodeBlock::new_span(second)),
+        ]))
+    }
+
+    #[test]
+    fn rewrite_spans() {
+        use Operation::*;
+        let program = build_program(vec![Push(Felt::new(3)), Add], vec![Mul]);
+
+        // returning the same operations results in the same program
+        let rewritten = program.rewrite_spans(|ops| ops.to_vec()).unwrap();
+        assert_eq!(program.hash(), rewritten.hash());
+        assert_eq!(program.root(), rewritten.root());
+
+        // appending an operation to every span changes hashes of the spans and of all enclosing
+        // blocks
+        let rewritten = program
+            .rewrite_spans(|ops| [ops, &[Incr]].concat())
+            .unwrap();
+        let expected = build_program(vec![Push(Felt::new(3)), Add, Incr], vec![Mul, Incr]);
+        assert_ne!(program.hash(), rewritten.hash());
+        assert_eq!(expected.hash(), rewritten.hash());
+        assert_eq!(expected.root(), rewritten.root());
+
+        // decorators may be located right after the last rewritten operation
+        let rewritten = program.rewrite_spans(|ops| ops[..1].to_vec()).unwrap();
+        match rewritten.root() {
+            CodeBlock::Join(block) => match block.first() {
+                CodeBlock::Span(span) => assert_eq!(1, span.decorators()[0].0),
+                _ => panic!("expected a span block"),
+            },
+            _ => panic!("expected a join block"),
+        }
+
+        // but not beyond it
+        let decorators = vec![(2, Decorator::Advice(AdviceInjector::MerkleNode))];
+        let program = Program::new(CodeBlock::new_span_with_decorators(
+            vec![Push(Felt::new(3)), Add, Mul],
+            decorators,
+        ));
+        let err = program.rewrite_spans(|_| vec![Add]).unwrap_err();
+        assert_eq!(ProgramError::InvalidDecoratorIndex(2, 1), err);
+
+        // spans cannot be rewritten into empty spans
+        let err = program.rewrite_spans(|_| Vec::new()).unwrap_err();
+        assert_eq!(ProgramError::EmptySpan, err);
+    }
+
+    #[test]
+    fn format_deeply_nested_program() {
+        // a long sequence of operations may be compiled into a deeply nested chain of joins
+        const NUM_SPANS: usize = 4000;
+        let mut root = CodeBlock::new_span(vec![Operation::Add]);
+        for _ in 1..NUM_SPANS {
+            root = CodeBlock::new_join([root, CodeBlock::new_span(vec![Operation::Add])]);
+        }
+        let program = Program::new(root);
+
+        let display = program.to_string();
+        let prefix = format!(
+            "begin {}span add end span add end end",
+            "join ".repeat(NUM_SPANS - 1)
+        );
+        assert!(display.starts_with(&prefix));
+        assert!(display.ends_with("span add end end span add end end end"));
+        assert_eq!(NUM_SPANS, display.matches("span add end").count());
+
+        // every join is written on two lines, and every span on a single line
+        let display = program.display_pretty(0, usize::MAX);
+        assert_eq!(3 * NUM_SPANS, display.lines().count());
+        assert_eq!(
+            NUM_SPANS,
+            display
+                .lines()
+                .filter(|line| *line == "span add end")
+                .count()
+        );
+
+        let histogram = program.operation_histogram();
+        assert_eq!(Some(&NUM_SPANS), histogram.get("add"));
+        assert_eq!(Some(&(NUM_SPANS - 1)), histogram.get("join"));
+        assert_eq!(Some(&(2 * NUM_SPANS - 1)), histogram.get("end"));
+        assert_eq!(NUM_SPANS, program.operations().count());
+
+        // rewriting and sharing blocks does not recurse either
+        let rewritten = program.rewrite_spans(|_| vec![Operation::Mul]).unwrap();
+        assert_eq!(
+            NUM_SPANS,
+            rewritten.to_string().matches("span mul end").count()
+        );
+        let deduped = program.clone().dedup_blocks();
+        assert_eq!(program.hash(), deduped.hash());
+    }
+
+    #[test]
+    fn validate() {
+        use Operation::*;
+        let program = build_program(vec![Push(Felt::new(3)), Add], vec![Mul]);
+        assert_eq!(Ok(()), program.validate());
+
+        // a program with the zero digest as its root does not commit to any code
+        let empty = Program::new(CodeBlock::new_proxy(Digest::default()));
+        let err = empty.validate().unwrap_err();
+        assert_eq!(ProgramError::EmptyCommitment, err);
+        assert_eq!("program has invalid (empty) commitment", err.to_string());
+
+        // such programs are rejected when deserialized as well
+        let bytes = empty.to_bytes();
+        assert!(Program::read_from_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,703 @@
+use super::{
+    blocks::CodeBlock, CodeBlockTable, Digest, Felt, InputSpec, Kernel, Operation, Program, String,
+    ToString, Vec,
+};
+use crate::{
+    artifact::{read_and_check_header, write_header, ArtifactKind},
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, DisplayHex, Serializable,
+        SliceReader,
+    },
+    AdviceInjector, AssemblyOp, Decorator, DecoratorList, StarkField, ZERO,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum nesting depth of code blocks in a serialized program; this bounds the depth of
+/// blocks read from malicious inputs, as some operations on code blocks (e.g., dropping them)
+/// still recurse into their children.
+const MAX_BLOCK_DEPTH: usize = 1024;
+
+// code block tags
+const SPAN: u8 = 0;
+const JOIN: u8 = 1;
+const SPLIT: u8 = 2;
+const LOOP: u8 = 3;
+const CALL: u8 = 4;
+const SYSCALL: u8 = 5;
+const PROXY: u8 = 6;
+
+// decorator tags
+const ADVICE: u8 = 0;
+const ASM_OP: u8 = 1;
+const ERROR_CODE: u8 = 2;
+
+/// Operations which can be contained in a span block and which do not carry any parameters.
+const PARAMETERLESS_OPS: [Operation; 72] = {
+    use Operation::*;
+    [
+        Noop, Eqz, Neg, Inv, Incr, Not, FmpAdd, MLoad, Swap, Caller, MovUp2, MovDn2, MovUp3,
+        MovDn3, ReadW, Expacc, MovUp4, MovDn4, MovUp5, MovDn5, MovUp6, MovDn6, MovUp7, MovDn7,
+        SwapW, MovUp8, MovDn8, SwapW2, SwapW3, SwapDW, Assert, Eq, Add, Mul, And, Or, U32and,
+        U32xor, Drop, CSwap, CSwapW, MLoadW, MStore, MStoreW, FmpUpdate, Pad, Dup0, Dup1, Dup2,
+        Dup3, Dup4, Dup5, Dup6, Dup7, Dup9, Dup11, Dup13, Dup15, Read, SDepth, U32add, U32sub,
+        U32mul, U32div, U32split, U32assert2, U32add3, U32madd, RpPerm, MpVerify, Pipe, MStream,
+    ]
+};
+
+// PROGRAM SERIALIZATION
+// ================================================================================================
+
+/// A serialized program consists of the following:
+/// - artifact header of the [ArtifactKind::Program] kind (see [crate::artifact]).
+/// - the root code block of the program.
+/// - number of kernel procedures (u16) followed by the hash of each procedure.
+/// - number of blocks in the code block table (u32) followed by each block.
+/// - a flag (u8) indicating whether the program has a name, followed by the name if it does.
+/// - the peak local frame of the program (u64).
+/// - a flag (u8) indicating whether the program declares its inputs, followed by the numbers of
+///   stack and advice tape inputs if it does; each number is written as a flag (u8) followed by
+///   the number (u32) if it is declared. This field is absent in format version 1.
+///
+/// A code block is serialized as a tag (u8) identifying the type of the block followed by:
+/// - for span blocks: number of operations (u32) followed by each operation, and number of
+///   decorators (u32) followed by the index of the operation (u32) and the body of each decorator.
+/// - for join and split blocks: both child blocks.
+/// - for loop blocks: the body of the loop.
+/// - for call, syscall and proxy blocks: the hash of the referenced code.
+///
+/// Hashes of blocks are not serialized; instead, they are recomputed when blocks are read.
+impl Serializable for Program {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_header(target, ArtifactKind::Program);
+        write_block(target, &self.root);
+
+        let proc_hashes = self.kernel.proc_hashes();
+        target.write_u16(proc_hashes.len() as u16);
+        for proc_hash in proc_hashes {
+            proc_hash.write_into(target);
+        }
+
+        target.write_u32(self.cb_table.0.len() as u32);
+        for block in self.cb_table.0.values() {
+            write_block(target, block);
+        }
+
+        match &self.name {
+            Some(name) => {
+                target.write_u8(1);
+                write_string(target, name);
+            }
+            None => target.write_u8(0),
+        }
+        target.write_u64(self.max_local_frame);
+
+        match &self.input_spec {
+            Some(input_spec) => {
+                target.write_u8(1);
+                input_spec.write_into(target);
+            }
+            None => target.write_u8(0),
+        }
+    }
+}
+
+impl Deserializable for Program {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = read_and_check_header(source, ArtifactKind::Program)?;
+        let root = read_block(source)?;
+
+        let num_procs = source.read_u16()? as usize;
+        let mut proc_hashes = Vec::with_capacity(num_procs);
+        for _ in 0..num_procs {
+            proc_hashes.push(Digest::read_from(source)?);
+        }
+
+        let num_blocks = source.read_u32()?;
+        let mut cb_table = CodeBlockTable::default();
+        for _ in 0..num_blocks {
+            cb_table.insert(read_block(source)?);
+        }
+
+        let name = match source.read_u8()? {
+            0 => None,
+            1 => Some(read_string(source)?),
+            flag => return Err(invalid_value(format!("invalid program name flag {flag}"))),
+        };
+        let max_local_frame = source.read_u64()?;
+
+        let input_spec = if header.format_version < 2 {
+            None
+        } else {
+            match source.read_u8()? {
+                0 => None,
+                1 => Some(InputSpec::read_from(source)?),
+                flag => return Err(invalid_value(format!("invalid input spec flag {flag}"))),
+            }
+        };
+
+        let program = Self {
+            root,
+            kernel: Kernel::new(&proc_hashes),
+            cb_table,
+            name,
+            max_local_frame,
+            input_spec,
+        };
+        program
+            .validate()
+            .map_err(|err| invalid_value(err.to_string()))?;
+        Ok(program)
+    }
+}
+
+impl Program {
+    /// Returns a program deserialized from the specified bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized program, or if there are unread
+    /// bytes left after the program has been deserialized.
+    pub fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let program = Self::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(program)
+    }
+
+    /// Returns a program deserialized from the specified bytes, making sure that the hash of the
+    /// program (i.e., the root of its MAST) is equal to the expected root.
+    ///
+    /// The hashes of all code blocks are recomputed while the program is read, and thus, any
+    /// modification of the MAST of the program results in a hash mismatch. Decorators and metadata
+    /// of the program (e.g., its name) are not committed to by the program hash, and thus, are not
+    /// covered by this check.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized program, or if the hash of the
+    /// deserialized program does not match the expected root.
+    pub fn read_verified(
+        bytes: &[u8],
+        expected_root: Digest,
+    ) -> Result<Self, DeserializationError> {
+        let program = Self::read_from_bytes(bytes)?;
+        let root = program.hash();
+        if root != expected_root {
+            let root: [u8; 32] = root.into();
+            let expected_root: [u8; 32] = expected_root.into();
+            return Err(invalid_value(format!(
+                "program hash 0x{} does not match the expected hash 0x{}",
+                DisplayHex(&root),
+                DisplayHex(&expected_root)
+            )));
+        }
+        Ok(program)
+    }
+}
+
+// CODE BLOCK SERIALIZATION
+// ================================================================================================
+
+/// A code block is serialized in the same way as the blocks of a serialized [Program] (without an
+/// artifact header); this allows other artifacts (e.g., compiled libraries) to embed MAST.
+///
+/// Writing a block panics if blocks are nested deeper than [MAX_BLOCK_DEPTH] levels; the same
+/// applies to programs.
+impl Serializable for CodeBlock {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_block(target, self);
+    }
+}
+
+impl Deserializable for CodeBlock {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_block(source)
+    }
+}
+
+/// Writes the specified block followed by all of its descendants in pre-order.
+///
+/// Nested blocks are written using an explicit stack rather than via recursion, and thus, writing
+/// deeply nested blocks does not overflow the call stack.
+///
+/// # Panics
+/// Panics if blocks are nested deeper than [MAX_BLOCK_DEPTH] levels, as such blocks could not be
+/// read back.
+fn write_block<W: ByteWriter>(target: &mut W, block: &CodeBlock) {
+    let mut stack = vec![(block, 0)];
+    while let Some((block, depth)) = stack.pop() {
+        assert!(
+            depth < MAX_BLOCK_DEPTH,
+            "code blocks are nested deeper than {MAX_BLOCK_DEPTH} levels"
+        );
+
+        // children are pushed in reverse order so that they are written in the execution order
+        match block {
+            CodeBlock::Span(block) => {
+                target.write_u8(SPAN);
+                let num_ops = block
+                    .op_batches()
+                    .iter()
+                    .map(|batch| batch.ops().len())
+                    .sum::<usize>();
+                target.write_u32(num_ops as u32);
+                for op in block.op_batches().iter().flat_map(|batch| batch.ops()) {
+                    write_operation(target, op);
+                }
+
+                target.write_u32(block.decorators().len() as u32);
+                for (op_idx, decorator) in block.decorators() {
+                    target.write_u32(*op_idx as u32);
+                    write_decorator(target, decorator);
+                }
+            }
+            CodeBlock::Join(block) => {
+                target.write_u8(JOIN);
+                stack.push((block.second(), depth + 1));
+                stack.push((block.first(), depth + 1));
+            }
+            CodeBlock::Split(block) => {
+                target.write_u8(SPLIT);
+                stack.push((block.on_false(), depth + 1));
+                stack.push((block.on_true(), depth + 1));
+            }
+            CodeBlock::Loop(block) => {
+                target.write_u8(LOOP);
+                stack.push((block.body(), depth + 1));
+            }
+            CodeBlock::Call(block) => {
+                target.write_u8(if block.is_syscall() { SYSCALL } else { CALL });
+                block.fn_hash().write_into(target);
+            }
+            CodeBlock::Proxy(block) => {
+                target.write_u8(PROXY);
+                block.hash().write_into(target);
+            }
+        }
+    }
+}
+
+/// A control block whose children are being read; see [read_block()].
+enum PendingBlock {
+    Join(Option<CodeBlock>),
+    Split(Option<CodeBlock>),
+    Loop,
+}
+
+/// Reads a code block together with all of its descendants written in pre-order; blocks are
+/// instantiated via their constructors, and thus, their hashes are recomputed from their
+/// contents.
+///
+/// Control blocks whose children have not been read yet are kept on an explicit stack rather
+/// than on the call stack, and thus, reading deeply nested blocks does not overflow the call
+/// stack. Blocks nested deeper than [MAX_BLOCK_DEPTH] levels are rejected.
+fn read_block<R: ByteReader>(source: &mut R) -> Result<CodeBlock, DeserializationError> {
+    let mut pending = Vec::new();
+    loop {
+        if pending.len() == MAX_BLOCK_DEPTH {
+            return Err(invalid_value(format!(
+                "code blocks are nested deeper than {MAX_BLOCK_DEPTH} levels"
+            )));
+        }
+
+        let mut block = match source.read_u8()? {
+            SPAN => read_span(source)?,
+            JOIN => {
+                pending.push(PendingBlock::Join(None));
+                continue;
+            }
+            SPLIT => {
+                pending.push(PendingBlock::Split(None));
+                continue;
+            }
+            LOOP => {
+                pending.push(PendingBlock::Loop);
+                continue;
+            }
+            CALL => CodeBlock::new_call(Digest::read_from(source)?),
+            SYSCALL => CodeBlock::new_syscall(Digest::read_from(source)?),
+            PROXY => CodeBlock::new_proxy(Digest::read_from(source)?),
+            tag => return Err(invalid_value(format!("invalid code block tag {tag}"))),
+        };
+
+        // attach the block to its parent; every parent whose children have all been read is
+        // completed, and is attached to its own parent in turn
+        loop {
+            block = match pending.pop() {
+                None => return Ok(block),
+                Some(PendingBlock::Join(None)) => {
+                    pending.push(PendingBlock::Join(Some(block)));
+                    break;
+                }
+                Some(PendingBlock::Split(None)) => {
+                    pending.push(PendingBlock::Split(Some(block)));
+                    break;
+                }
+                Some(PendingBlock::Join(Some(first))) => CodeBlock::new_join([first, block]),
+                Some(PendingBlock::Split(Some(on_true))) => CodeBlock::new_split(on_true, block),
+                Some(PendingBlock::Loop) => CodeBlock::new_loop(block),
+            };
+        }
+    }
+}
+
+/// Reads a span block following its tag.
+fn read_span<R: ByteReader>(source: &mut R) -> Result<CodeBlock, DeserializationError> {
+    let num_ops = source.read_u32()? as usize;
+    if num_ops == 0 {
+        return Err(invalid_value("span block contains no operations".into()));
+    }
+    let mut ops = Vec::new();
+    for _ in 0..num_ops {
+        ops.push(read_operation(source)?);
+    }
+
+    let num_decorators = source.read_u32()? as usize;
+    let mut decorators = DecoratorList::new();
+    for _ in 0..num_decorators {
+        let op_idx = source.read_u32()? as usize;
+        // decorators must be sorted by the index of the operation which they precede
+        let prev_idx = decorators.last().map_or(0, |(idx, _)| *idx);
+        if op_idx < prev_idx || op_idx > num_ops {
+            return Err(invalid_value(format!("invalid decorator index {op_idx}")));
+        }
+        decorators.push((op_idx, read_decorator(source)?));
+    }
+
+    Ok(CodeBlock::new_span_with_decorators(ops, decorators))
+}
+
+// INPUT SPEC SERIALIZATION
+// ================================================================================================
+
+/// Declared inputs are serialized as the numbers of stack and advice tape inputs; each number is
+/// written as a flag (u8) followed by the number (u32) if it is declared.
+///
+/// This is the only encoding of declared inputs; it is used by serialized programs as well as by
+/// serialized program ASTs of the assembler.
+impl Serializable for InputSpec {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_input_count(target, self.stack());
+        write_input_count(target, self.advice());
+    }
+}
+
+impl Deserializable for InputSpec {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack = read_input_count(source)?;
+        let advice = read_input_count(source)?;
+        Ok(InputSpec::new(stack, advice))
+    }
+}
+
+fn write_input_count<W: ByteWriter>(target: &mut W, count: Option<usize>) {
+    match count {
+        Some(count) => {
+            target.write_u8(1);
+            target.write_u32(count as u32);
+        }
+        None => target.write_u8(0),
+    }
+}
+
+fn read_input_count<R: ByteReader>(source: &mut R) -> Result<Option<usize>, DeserializationError> {
+    match read_bool(source)? {
+        true => Ok(Some(source.read_u32()? as usize)),
+        false => Ok(None),
+    }
+}
+
+// OPERATION SERIALIZATION
+// ================================================================================================
+
+/// Writes the opcode of the operation followed by its parameter, if the operation has one.
+fn write_operation<W: ByteWriter>(target: &mut W, op: &Operation) {
+    target.write_u8(op.op_code());
+    match op {
+        Operation::Push(value) => target.write_u64(value.as_int()),
+        Operation::MrUpdate(copy) => target.write_u8(*copy as u8),
+        _ => (),
+    }
+}
+
+/// Reads an operation of a span block; control flow operations are rejected since they cannot
+/// be contained in span blocks.
+fn read_operation<R: ByteReader>(source: &mut R) -> Result<Operation, DeserializationError> {
+    let op_code = source.read_u8()?;
+    if op_code == Operation::Push(ZERO).op_code() {
+        let value = source.read_u64()?;
+        if value >= Felt::MODULUS {
+            return Err(invalid_value(format!(
+                "push value {value} is not a valid field element"
+            )));
+        }
+        Ok(Operation::Push(Felt::new(value)))
+    } else if op_code == Operation::MrUpdate(false).op_code() {
+        Ok(Operation::MrUpdate(read_bool(source)?))
+    } else {
+        PARAMETERLESS_OPS
+            .into_iter()
+            .find(|op| op.op_code() == op_code)
+            .ok_or_else(|| invalid_value(format!("invalid span operation code {op_code}")))
+    }
+}
+
+// DECORATOR SERIALIZATION
+// ================================================================================================
+
+fn write_decorator<W: ByteWriter>(target: &mut W, decorator: &Decorator) {
+    match decorator {
+        Decorator::Advice(injector) => {
+            target.write_u8(ADVICE);
+            match injector {
+                AdviceInjector::MerkleNode => target.write_u8(0),
+                AdviceInjector::DivResultU64 => target.write_u8(1),
+                AdviceInjector::DivResultU256 => target.write_u8(2),
+                AdviceInjector::MapValue => target.write_u8(3),
+                AdviceInjector::Memory(start_addr, num_words) => {
+                    target.write_u8(4);
+                    target.write_u32(*start_addr);
+                    target.write_u32(*num_words);
+                }
+                AdviceInjector::ProgramHash => target.write_u8(5),
+            }
+        }
+        Decorator::AsmOp(assembly_op) => {
+            target.write_u8(ASM_OP);
+            write_string(target, assembly_op.op());
+            target.write_u8(assembly_op.num_cycles());
+        }
+        Decorator::ErrorCode(code) => {
+            target.write_u8(ERROR_CODE);
+            target.write_u32(*code);
+        }
+    }
+}
+
+fn read_decorator<R: ByteReader>(source: &mut R) -> Result<Decorator, DeserializationError> {
+    match source.read_u8()? {
+        ADVICE => {
+            let injector = match source.read_u8()? {
+                0 => AdviceInjector::MerkleNode,
+                1 => AdviceInjector::DivResultU64,
+                2 => AdviceInjector::DivResultU256,
+                3 => AdviceInjector::MapValue,
+                4 => AdviceInjector::Memory(source.read_u32()?, source.read_u32()?),
+                5 => AdviceInjector::ProgramHash,
+                tag => return Err(invalid_value(format!("invalid advice injector tag {tag}"))),
+            };
+            Ok(Decorator::Advice(injector))
+        }
+        ASM_OP => {
+            let op = read_string(source)?;
+            Ok(Decorator::AsmOp(AssemblyOp::new(op, source.read_u8()?)))
+        }
+        ERROR_CODE => Ok(Decorator::ErrorCode(source.read_u32()?)),
+        tag => Err(invalid_value(format!("invalid decorator tag {tag}"))),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the string as UTF-8 bytes prefixed with their length (u16).
+fn write_string<W: ByteWriter>(target: &mut W, value: &str) {
+    target.write_u16(value.len() as u16);
+    target.write_u8_slice(value.as_bytes());
+}
+
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_u16()? as usize;
+    let bytes = source.read_u8_vec(len)?;
+    String::from_utf8(bytes).map_err(|err| invalid_value(format!("invalid string: {err}")))
+}
+
+fn read_bool<R: ByteReader>(source: &mut R) -> Result<bool, DeserializationError> {
+    match source.read_u8()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        value => Err(invalid_value(format!("invalid boolean value {value}"))),
+    }
+}
+
+fn invalid_value(message: String) -> DeserializationError {
+    DeserializationError::InvalidValue(message)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AdviceInjector, AssemblyOp, CodeBlock, CodeBlockTable, Decorator, Deserializable,
+        DeserializationError, Felt, InputSpec, Kernel, Operation, Program, Serializable,
+        SliceReader, StarkField, MAX_BLOCK_DEPTH, PARAMETERLESS_OPS,
+    };
+    use crate::chiplets::hasher;
+
+    /// Builds a program which contains blocks of all types, operations with parameters, and
+    /// decorators.
+    fn build_program() -> Program {
+        let kernel_proc = CodeBlock::new_span(vec![Operation::Add]);
+        let local_proc = CodeBlock::new_span(vec![Operation::Mul]);
+
+        let span = CodeBlock::new_span_with_decorators(
+            vec![
+                Operation::Push(Felt::new(0x1234_5678)),
+                Operation::Read,
+                Operation::MrUpdate(true),
+                Operation::Drop,
+            ],
+            vec![
+                (1, Decorator::Advice(AdviceInjector::Memory(3, 2))),
+                (1, Decorator::AsmOp(AssemblyOp::new("adv_push.1".into(), 1))),
+                (3, Decorator::Advice(AdviceInjector::ProgramHash)),
+                (3, Decorator::ErrorCode(0x0bad_c0de)),
+            ],
+        );
+        let body = CodeBlock::new_join([
+            CodeBlock::new_split(span, CodeBlock::new_call(local_proc.hash())),
+            CodeBlock::new_loop(CodeBlock::new_join([
+                CodeBlock::new_syscall(kernel_proc.hash()),
+                CodeBlock::new_proxy(hasher::merge(&[kernel_proc.hash(), local_proc.hash()])),
+            ])),
+        ]);
+
+        let mut cb_table = CodeBlockTable::default();
+        cb_table.insert(local_proc);
+        Program::with_kernel(body, Kernel::new(&[kernel_proc.hash()]), cb_table)
+            .with_name("test")
+            .with_max_local_frame(7)
+            .with_input_spec(InputSpec::new(None, Some(6)))
+    }
+
+    #[test]
+    fn program_round_trip() {
+        let program = build_program();
+        let bytes = program.to_bytes();
+        let deserialized = Program::read_verified(&bytes, program.hash()).unwrap();
+
+        assert_eq!(program.root(), deserialized.root());
+        assert_eq!(program.kernel(), deserialized.kernel());
+        assert_eq!(program.name(), deserialized.name());
+        assert_eq!(program.max_local_frame(), deserialized.max_local_frame());
+        assert_eq!(program.input_spec(), deserialized.input_spec());
+        let local_proc_hash = CodeBlock::new_span(vec![Operation::Mul]).hash();
+        assert!(deserialized.cb_table().has(local_proc_hash));
+        assert_eq!(bytes, deserialized.to_bytes());
+        assert_eq!(program.to_string(), deserialized.to_string());
+    }
+
+    #[test]
+    fn read_format_version_1() {
+        // programs written in format version 1 do not carry the declared inputs
+        let program = build_program();
+        let mut bytes = program.to_bytes();
+        bytes.truncate(bytes.len() - 7);
+        bytes.push(0);
+        assert!(Program::read_from_bytes(&bytes).is_ok());
+
+        bytes[4] = 1;
+        assert!(Program::read_from_bytes(&bytes).is_err());
+        bytes.pop();
+        let deserialized = Program::read_from_bytes(&bytes).unwrap();
+        assert_eq!(program.root(), deserialized.root());
+        assert_eq!(None, deserialized.input_spec());
+    }
+
+    #[test]
+    fn all_operations_round_trip() {
+        let mut ops = PARAMETERLESS_OPS.to_vec();
+        ops.extend([
+            Operation::Push(Felt::new(Felt::MODULUS - 1)),
+            Operation::MrUpdate(false),
+            Operation::MrUpdate(true),
+        ]);
+        let program = Program::new(CodeBlock::new_span(ops));
+        let deserialized = Program::read_from_bytes(&program.to_bytes()).unwrap();
+        assert_eq!(program.root(), deserialized.root());
+    }
+
+    #[test]
+    fn read_verified_rejects_tampered_program() {
+        let program = build_program();
+        let bytes = program.to_bytes();
+
+        // the push value immediately follows the header (8 bytes), the tags of the root join
+        // block and of the split block, the tag and the number of operations of the span block
+        // (1 + 4 bytes), and the push opcode
+        let value_offset = 8 + 2 + 5 + 1;
+        assert_eq!(0x78, bytes[value_offset]);
+        let mut tampered = bytes.clone();
+        tampered[value_offset] ^= 1;
+
+        // the tampered program is still a valid program, but its hash is different
+        assert!(Program::read_from_bytes(&tampered).is_ok());
+        assert!(matches!(
+            Program::read_verified(&tampered, program.hash()),
+            Err(DeserializationError::InvalidValue(message)) if message.contains("does not match")
+        ));
+
+        // a valid program is rejected if the expected root is different
+        let other_root = CodeBlock::new_span(vec![Operation::Noop]).hash();
+        assert!(Program::read_verified(&bytes, other_root).is_err());
+
+        // malformed programs are rejected as well
+        assert!(Program::read_verified(&bytes[..bytes.len() - 1], program.hash()).is_err());
+        let mut extended = bytes;
+        extended.push(0);
+        assert!(matches!(
+            Program::read_verified(&extended, program.hash()),
+            Err(DeserializationError::UnconsumedBytes)
+        ));
+    }
+
+    #[test]
+    fn read_malformed_span() {
+        let read = |ops: Vec<u8>| {
+            let mut bytes = Program::new(CodeBlock::new_span(vec![Operation::Noop])).to_bytes();
+            // replace the span block with the provided operation codes
+            bytes.truncate(8);
+            bytes.push(0);
+            bytes.extend_from_slice(&(ops.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&ops);
+            bytes.extend_from_slice(&0_u32.to_le_bytes());
+            bytes.extend_from_slice(&0_u16.to_le_bytes());
+            bytes.extend_from_slice(&0_u32.to_le_bytes());
+            bytes.push(0);
+            bytes.extend_from_slice(&0_u64.to_le_bytes());
+            bytes.push(0);
+            Program::read_from_bytes(&bytes)
+        };
+
+        assert!(read(vec![Operation::Noop.op_code()]).is_ok());
+        assert!(read(vec![]).is_err());
+        assert!(read(vec![Operation::End.op_code()]).is_err());
+        assert!(read(vec![0b0001_1001]).is_err());
+    }
+
+    #[test]
+    fn deeply_nested_blocks() {
+        let nested_loops = |depth: usize| {
+            let mut block = CodeBlock::new_span(vec![Operation::Add]);
+            for _ in 1..depth {
+                block = CodeBlock::new_loop(block);
+            }
+            block
+        };
+
+        // blocks nested up to the maximum depth are written and read back without recursion
+        let block = nested_loops(MAX_BLOCK_DEPTH);
+        let bytes = block.to_bytes();
+        let deserialized = CodeBlock::read_from(&mut SliceReader::new(&bytes)).unwrap();
+        assert_eq!(block.hash(), deserialized.hash());
+
+        // deeper blocks could not be read back, and thus, they cannot be written either
+        let block = nested_loops(MAX_BLOCK_DEPTH + 1);
+        let result = std::panic::catch_unwind(|| block.to_bytes());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,386 @@
+//! Serde support for public types of this crate; available when the `serde` feature is enabled.
+//!
+//! The representations described below are stable: they will not change in a backward
+//! incompatible way without a major version bump. Human-readable formats (e.g., JSON) use the
+//! same encoding as the files used by Miden CLI, while binary formats (e.g., bincode) use a
+//! compact encoding:
+//!
+//! - [Digest] is serialized as a 0x-prefixed hex string in human-readable formats (the prefix is
+//!   optional when deserializing), and as 32 bytes otherwise. Since [Digest] is defined outside of
+//!   this crate, it does not implement serde traits itself; use the [digest] module via
+//!   `#[serde(with = "miden_core::serde::digest")]` for fields of this type.
+//! - [ProgramOutputs] is serialized as a struct with `stack` and `overflow_addrs` fields.
+//! - [ProgramInputs] is serialized as a struct with `stack_init`, `overflow_init`, and
+//!   `advice_tape` fields. Stack values are ordered as if they were pushed onto the stack one by
+//!   one, and `overflow_init` and `advice_tape` may be omitted when deserializing. Advice map and
+//!   advice sets are not serialized, and thus, deserialized inputs never contain them.
+//! - [Kernel] is serialized as a sequence of procedure hashes, each encoded as a [Digest].
+//!
+//! Field element values (i.e., items of all lists except for procedure hashes) are serialized as
+//! decimal strings in human-readable formats and as integers otherwise; human-readable formats
+//! accept `0x`-prefixed hexadecimal strings as well (see [felt_fmt](crate::felt_fmt)).
+//! Deserialization fails if any of the values is not a valid field element.
+
+use super::{
+    chiplets::hasher::Digest,
+    felt_fmt::{self, Radix},
+    utils::{collections::Vec, string::String, Deserializable, SliceReader},
+    Felt, Kernel, ProgramInputs, ProgramOutputs, StarkField,
+};
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use core::fmt;
+use crypto::Digest as _;
+
+// DIGEST
+// ================================================================================================
+
+/// Serialization and deserialization functions for [Digest] which can be used with serde's
+/// `with` attribute.
+pub mod digest {
+    use super::{Deserializer, Digest, SerdeDigest, Serializer};
+    use ::serde::{Deserialize, Serialize};
+
+    /// Serializes the specified digest.
+    pub fn serialize<S: Serializer>(digest: &Digest, serializer: S) -> Result<S::Ok, S::Error> {
+        SerdeDigest(*digest).serialize(serializer)
+    }
+
+    /// Deserializes a digest.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Digest, D::Error> {
+        SerdeDigest::deserialize(deserializer).map(|digest| digest.0)
+    }
+}
+
+/// A wrapper which implements serde traits for [Digest].
+struct SerdeDigest(Digest);
+
+impl Serialize for SerdeDigest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let hex = felt_fmt::format_digest(&self.0);
+            serializer.serialize_str(&format!("0x{hex}"))
+        } else {
+            serializer.serialize_bytes(&self.0.as_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeDigest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DigestVisitor)
+        } else {
+            deserializer.deserialize_bytes(DigestVisitor)
+        }
+    }
+}
+
+struct DigestVisitor;
+
+impl<'de> de::Visitor<'de> for DigestVisitor {
+    type Value = SerdeDigest;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a 32-byte digest")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        felt_fmt::parse_digest(value)
+            .map(SerdeDigest)
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        if value.len() != 32 {
+            return Err(E::invalid_length(value.len(), &self));
+        }
+        Digest::read_from(&mut SliceReader::new(value))
+            .map(SerdeDigest)
+            .map_err(E::custom)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(32);
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+// PROGRAM OUTPUTS
+// ================================================================================================
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "::serde")]
+struct OutputsRepr<T> {
+    stack: Vec<T>,
+    overflow_addrs: Vec<T>,
+}
+
+impl Serialize for ProgramOutputs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            OutputsRepr {
+                stack: to_strings(self.stack()),
+                overflow_addrs: to_strings(self.overflow_addrs()),
+            }
+            .serialize(serializer)
+        } else {
+            OutputsRepr {
+                stack: self.stack().to_vec(),
+                overflow_addrs: self.overflow_addrs().to_vec(),
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProgramOutputs {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = if deserializer.is_human_readable() {
+            let repr = OutputsRepr::<String>::deserialize(deserializer)?;
+            OutputsRepr {
+                stack: parse_values(&repr.stack)?,
+                overflow_addrs: parse_values(&repr.overflow_addrs)?,
+            }
+        } else {
+            OutputsRepr::<u64>::deserialize(deserializer)?
+        };
+
+        validate_values(&repr.stack)?;
+        validate_values(&repr.overflow_addrs)?;
+        Ok(ProgramOutputs::new(repr.stack, repr.overflow_addrs))
+    }
+}
+
+// PROGRAM INPUTS
+// ================================================================================================
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "::serde")]
+struct InputsRepr<T> {
+    stack_init: Vec<T>,
+    #[serde(default)]
+    overflow_init: Vec<T>,
+    #[serde(default)]
+    advice_tape: Vec<T>,
+}
+
+impl Serialize for ProgramInputs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // inputs store the stack with the top of the stack first; the serialized values are
+        // ordered as if they were pushed onto the stack one by one
+        let num_overflow = self.stack_overflow().len();
+        let num_top = self.stack_init().len() - num_overflow;
+        let stack_init = stack_to_ints(&self.stack_init()[..num_top]);
+        let overflow_init = stack_to_ints(self.stack_overflow());
+        let advice_tape = self
+            .advice_tape()
+            .iter()
+            .map(|v| v.as_int())
+            .collect::<Vec<_>>();
+
+        if serializer.is_human_readable() {
+            InputsRepr {
+                stack_init: to_strings(&stack_init),
+                overflow_init: to_strings(&overflow_init),
+                advice_tape: to_strings(&advice_tape),
+            }
+            .serialize(serializer)
+        } else {
+            InputsRepr {
+                stack_init,
+                overflow_init,
+                advice_tape,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProgramInputs {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = if deserializer.is_human_readable() {
+            let repr = InputsRepr::<String>::deserialize(deserializer)?;
+            InputsRepr {
+                stack_init: parse_values(&repr.stack_init)?,
+                overflow_init: parse_values(&repr.overflow_init)?,
+                advice_tape: parse_values(&repr.advice_tape)?,
+            }
+        } else {
+            InputsRepr::<u64>::deserialize(deserializer)?
+        };
+
+        ProgramInputs::new(&repr.stack_init, &repr.advice_tape, Vec::new())
+            .and_then(|inputs| inputs.with_stack_overflow(&repr.overflow_init))
+            .map_err(|err| de::Error::custom(format!("{err:?}")))
+    }
+}
+
+// KERNEL
+// ================================================================================================
+
+impl Serialize for Kernel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.proc_hashes().iter().map(|&hash| SerdeDigest(hash)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Kernel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hashes = Vec::<SerdeDigest>::deserialize(deserializer)?
+            .into_iter()
+            .map(|hash| hash.0)
+            .collect::<Vec<_>>();
+        Ok(Kernel::new(&hashes))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts stack values stored with the top of the stack first into integers ordered as if they
+/// were pushed onto the stack one by one.
+fn stack_to_ints(values: &[Felt]) -> Vec<u64> {
+    values.iter().rev().map(|v| v.as_int()).collect()
+}
+
+fn to_strings(values: &[u64]) -> Vec<String> {
+    values
+        .iter()
+        .map(|&v| felt_fmt::format(Felt::new(v), Radix::Decimal))
+        .collect()
+}
+
+/// Parses a list of decimal or hexadecimal strings into a list of integers; fails if any of the
+/// values is not a valid field element.
+fn parse_values<E: de::Error>(values: &[String]) -> Result<Vec<u64>, E> {
+    values
+        .iter()
+        .map(|value| {
+            felt_fmt::parse(value)
+                .map(|v| v.as_int())
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &"a valid field element"))
+        })
+        .collect()
+}
+
+/// Returns an error if any of the values is not a valid field element.
+fn validate_values<E: de::Error>(values: &[u64]) -> Result<(), E> {
+    match values.iter().find(|&&v| v >= Felt::MODULUS) {
+        Some(&value) => Err(E::invalid_value(
+            de::Unexpected::Unsigned(value),
+            &"a valid field element",
+        )),
+        None => Ok(()),
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{digest, Digest, Kernel, ProgramInputs, ProgramOutputs};
+    use crate::{chiplets::hasher, Felt, StarkField};
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(crate = "::serde")]
+    struct ProgramHash(#[serde(with = "digest")] Digest);
+
+    #[test]
+    fn digest_round_trip() {
+        let hash = ProgramHash(hasher::hash_elements(&[Felt::new(1), Felt::new(2)]));
+
+        let json = serde_json::to_string(&hash).unwrap();
+        let hex = json.trim_matches('"');
+        assert!(hex.starts_with("0x") && hex.len() == 66);
+        assert_eq!(hash, serde_json::from_str(&json).unwrap());
+
+        // the 0x prefix is optional
+        let json = format!("\"{}\"", &hex[2..]);
+        assert_eq!(hash, serde_json::from_str(&json).unwrap());
+
+        let bytes = bincode::serialize(&hash).unwrap();
+        assert_eq!(hash, bincode::deserialize(&bytes).unwrap());
+
+        // digests must consist of 32 bytes of valid field elements
+        assert!(serde_json::from_str::<ProgramHash>("\"0x1234\"").is_err());
+        let json = format!("\"0x{}\"", "ff".repeat(32));
+        assert!(serde_json::from_str::<ProgramHash>(&json).is_err());
+    }
+
+    #[test]
+    fn outputs_round_trip() {
+        let outputs = ProgramOutputs::new(vec![3, 2, 1, Felt::MODULUS - 1], vec![0, 7]);
+
+        let json = serde_json::to_string(&outputs).unwrap();
+        let expected = format!(
+            r#"{{"stack":["3","2","1","{}"],"overflow_addrs":["0","7"]}}"#,
+            Felt::MODULUS - 1
+        );
+        assert_eq!(expected, json);
+        assert_outputs_eq(&outputs, &serde_json::from_str(&json).unwrap());
+
+        let bytes = bincode::serialize(&outputs).unwrap();
+        assert_outputs_eq(&outputs, &bincode::deserialize(&bytes).unwrap());
+
+        // values which are not valid field elements are rejected
+        let json = format!(r#"{{"stack":["{}"],"overflow_addrs":[]}}"#, Felt::MODULUS);
+        assert!(serde_json::from_str::<ProgramOutputs>(&json).is_err());
+        let bytes = bincode::serialize(&(vec![u64::MAX], Vec::<u64>::new())).unwrap();
+        assert!(bincode::deserialize::<ProgramOutputs>(&bytes).is_err());
+    }
+
+    #[test]
+    fn inputs_round_trip() {
+        let stack_init = (1..=16).collect::<Vec<u64>>();
+        let inputs = ProgramInputs::new(&stack_init, &[5, 6], vec![])
+            .unwrap()
+            .with_stack_overflow(&[17, 18])
+            .unwrap();
+
+        let json = serde_json::to_string(&inputs).unwrap();
+        assert!(json.starts_with(r#"{"stack_init":["1","2","#));
+        assert!(json.ends_with(r#""overflow_init":["17","18"],"advice_tape":["5","6"]}"#));
+        assert_inputs_eq(&inputs, &serde_json::from_str(&json).unwrap());
+
+        let bytes = bincode::serialize(&inputs).unwrap();
+        assert_inputs_eq(&inputs, &bincode::deserialize(&bytes).unwrap());
+
+        // inputs in the format used by CLI input files
+        let json = r#"{"stack_init":["1","2"]}"#;
+        let expected = ProgramInputs::new(&[1, 2], &[], vec![]).unwrap();
+        assert_inputs_eq(&expected, &serde_json::from_str(json).unwrap());
+    }
+
+    #[test]
+    fn kernel_round_trip() {
+        let hashes = [
+            hasher::hash_elements(&[Felt::new(1)]),
+            hasher::hash_elements(&[Felt::new(2)]),
+        ];
+        let kernel = Kernel::new(&hashes);
+
+        let json = serde_json::to_string(&kernel).unwrap();
+        assert_eq!(kernel, serde_json::from_str(&json).unwrap());
+
+        let bytes = bincode::serialize(&kernel).unwrap();
+        assert_eq!(kernel, bincode::deserialize(&bytes).unwrap());
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn assert_outputs_eq(expected: &ProgramOutputs, actual: &ProgramOutputs) {
+        assert_eq!(expected.stack(), actual.stack());
+        assert_eq!(expected.overflow_addrs(), actual.overflow_addrs());
+    }
+
+    fn assert_inputs_eq(expected: &ProgramInputs, actual: &ProgramInputs) {
+        assert_eq!(expected.stack_init(), actual.stack_init());
+        assert_eq!(expected.advice_tape(), actual.advice_tape());
+    }
+}
@@ -1,15 +1,18 @@
 use super::{Felt, StarkField};
-use core::{fmt::Debug, ops::Range};
+use core::{
+    fmt::{self, Debug},
+    ops::Range,
+};
 use winter_utils::collections::Vec;
 
 // FEATURE BASED RE-EXPORT
 // ================================================================================================
 
 #[cfg(not(feature = "std"))]
-pub use alloc::boxed::Box;
+pub use alloc::{boxed::Box, sync::Arc};
 
 #[cfg(feature = "std")]
-pub use std::boxed::Box;
+pub use std::{boxed::Box, sync::Arc};
 
 // RE-EXPORTS
 // ================================================================================================
@@ -89,6 +92,21 @@ pub const fn range(start: usize, len: usize) -> Range<usize> {
     }
 }
 
+// HEX FORMATTING
+// ================================================================================================
+
+/// A wrapper which displays the wrapped bytes as a lowercase hex string (without `0x` prefix).
+pub struct DisplayHex<'a>(pub &'a [u8]);
+
+impl fmt::Display for DisplayHex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
 // ARRAY CONSTRUCTORS
 // ================================================================================================
 
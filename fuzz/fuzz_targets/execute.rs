@@ -0,0 +1,62 @@
+//! Compiles a generated program and executes it via the trace-generating execution path, making
+//! sure that the stack outputs and the program hash tracked by the processor agree with the ones
+//! independently derived from the execution trace and by the assembler.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use assembly::arbitrary::GeneratedProgram;
+use libfuzzer_sys::fuzz_target;
+use processor::ProgramInputs;
+
+/// The maximum number of stack inputs of a program; values beyond the top 16 go into the
+/// overflow table.
+const MAX_STACK_INPUTS: usize = 32;
+
+/// The maximum number of values on the advice tape.
+const MAX_ADVICE_INPUTS: usize = 64;
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    program: GeneratedProgram,
+    stack_inputs: Vec<u64>,
+    advice_tape: Vec<u64>,
+}
+
+fuzz_target!(|input: Input| {
+    let program = match input.program.compile() {
+        Ok(program) => program,
+        Err(err) => panic!(
+            "failed to compile generated program: {err}\n{}",
+            input.program
+        ),
+    };
+
+    let stack_inputs = &input.stack_inputs[..input.stack_inputs.len().min(MAX_STACK_INPUTS)];
+    let advice_tape = &input.advice_tape[..input.advice_tape.len().min(MAX_ADVICE_INPUTS)];
+    let inputs = match ProgramInputs::new(stack_inputs, advice_tape, Vec::new()) {
+        Ok(inputs) => inputs,
+        // inputs are not valid field elements
+        Err(_) => return,
+    };
+
+    // the outputs of the program are tracked by the stack directly, while the final stack state
+    // and the program hash are read back from the trace columns populated by the stack and by the
+    // decoder (which hashes code blocks via the hasher chiplet); these must agree with the outputs
+    // and with the hash computed by the assembler respectively
+    let trace = match processor::execute(&program, &inputs) {
+        Ok(trace) => trace,
+        Err(_) => return,
+    };
+    assert_eq!(
+        trace.program_outputs().stack_top(),
+        trace.last_stack_state(),
+        "stack outputs diverged from the trace\n{}",
+        input.program
+    );
+    assert_eq!(
+        trace.program_hash(),
+        program.hash(),
+        "program hash diverged from the trace\n{}",
+        input.program
+    );
+});
@@ -0,0 +1,22 @@
+//! Makes sure that the parser never panics: every input is either parsed successfully or rejected
+//! with an error.
+//!
+//! The input bytes are parsed both directly (if they are valid UTF-8) and as entropy for a
+//! near-miss source, which is a slightly malformed version of a valid program.
+#![no_main]
+
+use arbitrary::{Arbitrary, Unstructured};
+use assembly::{arbitrary::NearMissSource, parse_module, parse_program};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source) = core::str::from_utf8(data) {
+        let _ = parse_program(source);
+        let _ = parse_module(source);
+    }
+
+    if let Ok(source) = NearMissSource::arbitrary_take_rest(Unstructured::new(data)) {
+        let _ = parse_program(source.as_str());
+        let _ = parse_module(source.as_str());
+    }
+});
@@ -25,5 +25,20 @@ fn program_compilation(c: &mut Criterion) {
     group.finish();
 }
 
+fn stdlib_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stdlib_construction");
+
+    group.bench_function("serialized", |bench| {
+        bench.iter(|| Assembler::new().with_module_provider(StdLibrary::default()));
+    });
+
+    group.bench_function("sources", |bench| {
+        bench.iter(|| Assembler::new().with_module_provider(StdLibrary::from_sources()));
+    });
+
+    group.finish();
+}
+
 criterion_group!(sha256_group, program_compilation);
-criterion_main!(sha256_group);
+criterion_group!(stdlib_group, stdlib_construction);
+criterion_main!(sha256_group, stdlib_group);
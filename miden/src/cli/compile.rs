@@ -1,7 +1,7 @@
-use super::data::ProgramFile;
-use crypto::Digest;
+use super::data::{ErrorFormat, ProgramFile};
 use std::path::PathBuf;
 use structopt::StructOpt;
+use vm_core::felt_fmt;
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Compile", about = "Compile a miden program")]
@@ -9,6 +9,15 @@ pub struct CompileCmd {
     /// Path to .masm assembly file
     #[structopt(short = "a", long = "assembly", parse(from_os_str))]
     assembly_file: PathBuf,
+    /// Paths to .masl library files whose modules can be imported by the program
+    #[structopt(short = "l", long = "library", parse(from_os_str))]
+    library_files: Vec<PathBuf>,
+    /// Format of compilation errors: plain, color, or json
+    #[structopt(long = "error-format", default_value = "plain")]
+    error_format: ErrorFormat,
+    /// Path to the output file for the compiled program (.masb)
+    #[structopt(short = "o", long = "output", parse(from_os_str))]
+    output_file: Option<PathBuf>,
 }
 
 impl CompileCmd {
@@ -18,10 +27,19 @@ impl CompileCmd {
         println!("============================================================");
 
         // load and compile program file
-        let program = ProgramFile::read(&self.assembly_file)?;
+        let program =
+            ProgramFile::read(&self.assembly_file, &self.library_files, self.error_format)?;
 
         // report program hash to user
-        println!("program hash is {}", hex::encode(program.hash().as_bytes()));
+        println!(
+            "program hash is {}",
+            felt_fmt::format_digest(&program.hash())
+        );
+
+        // write the compiled program so that it can be run without being compiled again
+        if let Some(output_file) = &self.output_file {
+            ProgramFile::write_compiled(&program, output_file)?;
+        }
 
         Ok(())
     }
@@ -1,12 +1,24 @@
-use miden::Assembler;
+use crypto::Digest as _;
+use miden::{
+    Assembler, ColoredRenderer, Diagnostic, DiagnosticRenderer, JsonRenderer, MaslLibrary,
+    PlainRenderer, VerificationBundle,
+};
 use prover::StarkProof;
 use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
-use std::{fs, io::Write, time::Instant};
+use std::{fs, io::Write, str::FromStr};
 use stdlib::StdLibrary;
+use structopt::StructOpt;
+use tracing::{info, warn};
 use vm_core::ProgramOutputs;
-use vm_core::{chiplets::hasher::Digest, Program, ProgramInputs};
-use winter_utils::{Deserializable, SliceReader};
+use vm_core::{
+    artifact::{strip_header, with_header, ArtifactKind},
+    chiplets::hasher::Digest,
+    felt_fmt::{self, Radix},
+    AdviceTapeMerge, Felt, Program, ProgramInputs, StarkField,
+};
+use winter_utils::Serializable;
 
 // INPUT FILE
 // ================================================================================================
@@ -15,7 +27,10 @@ use winter_utils::{Deserializable, SliceReader};
 #[derive(Deserialize, Debug)]
 pub struct InputFile {
     pub stack_init: Vec<String>,
+    pub overflow_init: Option<Vec<String>>,
     pub advice_tape: Option<Vec<String>>,
+    pub advice_map: Option<BTreeMap<String, Vec<String>>>,
+    pub constants: Option<BTreeMap<String, String>>,
 }
 
 /// Helper methods to interact with the input file
@@ -26,7 +41,10 @@ impl InputFile {
         if !inputs_path.is_some() && !program_path.with_extension("inputs").exists() {
             return Ok(Self {
                 stack_init: Vec::new(),
+                overflow_init: None,
                 advice_tape: Some(Vec::new()),
+                advice_map: None,
+                constants: None,
             });
         }
 
@@ -37,68 +55,388 @@ impl InputFile {
             None => program_path.with_extension("inputs"),
         };
 
-        println!("Reading input file `{}`", path.display());
+        info!("Reading input file `{}`", path.display());
 
         // read input file to string
         let inputs_file = fs::read_to_string(&path)
             .map_err(|err| format!("Failed to open input file `{}` - {}", path.display(), err))?;
 
         // deserialize input data
-        let inputs: InputFile = serde_json::from_str(&inputs_file)
+        let mut inputs: InputFile = serde_json::from_str(&inputs_file)
             .map_err(|err| format!("Failed to deserialize input data - {}", err))?;
+        inputs.resolve_constants()?;
+
+        // make sure that all values are valid field elements before they are used
+        inputs.stack_init()?;
+        inputs.overflow_init()?;
+        inputs.advice_tape()?;
+        inputs.advice_map()?;
 
         Ok(inputs)
     }
 
+    /// Replaces references to named constants in `stack_init`, `overflow_init`, `advice_tape`, and
+    /// values of `advice_map` with the values of the constants defined in the `constants` map.
+    ///
+    /// A value is treated as a reference to a constant if it starts with a letter or an
+    /// underscore. Constants may be defined via other constants, but the definitions must not be
+    /// cyclic.
+    fn resolve_constants(&mut self) -> Result<(), String> {
+        let constants = self.constants.take().unwrap_or_default();
+
+        let mut resolved = BTreeMap::new();
+        for name in constants.keys() {
+            let value = resolve_constant(name, &constants, &mut Vec::new())?;
+            resolved.insert(name.as_str(), value);
+        }
+
+        let resolve_values = |values: &mut Vec<String>| -> Result<(), String> {
+            for value in values.iter_mut() {
+                if is_constant_name(value) {
+                    *value = resolved
+                        .get(value.as_str())
+                        .ok_or_else(|| {
+                            format!("Input value references undefined constant `{value}`")
+                        })?
+                        .to_string();
+                }
+            }
+            Ok(())
+        };
+        resolve_values(&mut self.stack_init)?;
+        if let Some(overflow_init) = self.overflow_init.as_mut() {
+            resolve_values(overflow_init)?;
+        }
+        if let Some(advice_tape) = self.advice_tape.as_mut() {
+            resolve_values(advice_tape)?;
+        }
+        if let Some(advice_map) = self.advice_map.as_mut() {
+            for values in advice_map.values_mut() {
+                resolve_values(values)?;
+            }
+        }
+
+        self.constants = Some(constants);
+        Ok(())
+    }
+
     /// Returns program inputs.
-    pub fn get_program_inputs(&self) -> ProgramInputs {
-        ProgramInputs::new(&self.stack_init(), &self.advice_tape(), Vec::new()).unwrap()
+    ///
+    /// # Errors
+    /// Returns an error if any of the input values is not a valid field element.
+    pub fn get_program_inputs(&self) -> Result<ProgramInputs, String> {
+        let overflow_init = self.overflow_init()?;
+        ProgramInputs::with_advice_map(
+            &self.stack_init()?,
+            &self.advice_tape()?,
+            self.advice_map()?,
+            Vec::new(),
+        )
+        .and_then(|inputs| inputs.with_stack_overflow(&overflow_init))
+        .map_err(|err| format!("Failed to build program inputs - {:?}", err))
     }
 
     /// Parse stack_init vector of strings to a vector of u64
-    pub fn stack_init(&self) -> Vec<u64> {
-        self.stack_init
-            .iter()
-            .map(|v| v.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>()
+    pub fn stack_init(&self) -> Result<Vec<u64>, String> {
+        parse_input_values("stack_init", &self.stack_init)
+    }
+
+    /// Parse overflow_init vector of strings to a vector of u64
+    pub fn overflow_init(&self) -> Result<Vec<u64>, String> {
+        parse_input_values(
+            "overflow_init",
+            self.overflow_init.as_deref().unwrap_or(&[]),
+        )
     }
 
     /// Parse advice_tape vector of strings to a vector of u64
-    pub fn advice_tape(&self) -> Vec<u64> {
-        self.advice_tape
-            .as_ref()
-            .unwrap_or(&vec![])
-            .iter()
-            .map(|v| v.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>()
+    pub fn advice_tape(&self) -> Result<Vec<u64>, String> {
+        parse_input_values("advice_tape", self.advice_tape.as_deref().unwrap_or(&[]))
+    }
+
+    /// Parse advice_map of strings to a map from word keys (as bytes) to vectors of field elements.
+    ///
+    /// Keys are words written as 64 hexadecimal characters encoding the 32 bytes of the word in
+    /// the same way as digests (see [felt_fmt::parse_digest()]).
+    pub fn advice_map(&self) -> Result<BTreeMap<[u8; 32], Vec<Felt>>, String> {
+        let mut advice_map = BTreeMap::new();
+        for (key, values) in self.advice_map.iter().flatten() {
+            let word = felt_fmt::parse_digest(key)
+                .map_err(|err| format!("Invalid `advice_map` key - {}", err))?;
+            let values = values
+                .iter()
+                .map(|value| parse_element(value).map(Felt::new))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| format!("Invalid `advice_map` value for key `{key}` - {err}"))?;
+            advice_map.insert(word.as_bytes(), values);
+        }
+        Ok(advice_map)
+    }
+}
+
+/// Parses values of the specified input field.
+fn parse_input_values(field: &str, values: &[String]) -> Result<Vec<u64>, String> {
+    values
+        .iter()
+        .map(|value| parse_element(value))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Invalid `{}` input - {}", field, err))
+}
+
+/// Returns the value of the constant with the specified name, resolving references to other
+/// constants in its definition; `path` holds the names of the constants being resolved and is
+/// used to detect cyclic definitions.
+fn resolve_constant<'a>(
+    name: &'a str,
+    constants: &'a BTreeMap<String, String>,
+    path: &mut Vec<&'a str>,
+) -> Result<u64, String> {
+    if path.contains(&name) {
+        path.push(name);
+        return Err(format!(
+            "Input constants have a cyclic definition: {}",
+            path.join(" -> ")
+        ));
+    }
+
+    let value = match constants.get(name) {
+        Some(value) => value,
+        None => match path.last() {
+            Some(parent) => {
+                return Err(format!(
+                    "Input constant `{parent}` references undefined constant `{name}`"
+                ))
+            }
+            None => return Err(format!("Input constant `{name}` is not defined")),
+        },
+    };
+
+    if is_constant_name(value) {
+        path.push(name);
+        let result = resolve_constant(value, constants, path);
+        path.pop();
+        result
+    } else {
+        parse_element(value)
+            .map_err(|err| format!("Invalid value of input constant `{name}` - {err}"))
+    }
+}
+
+/// Returns true if the specified input value is a reference to a named constant rather than a
+/// number.
+fn is_constant_name(value: &str) -> bool {
+    value
+        .chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+}
+
+// INPUT ARGUMENTS
+// ================================================================================================
+
+/// Program inputs specified via command-line arguments.
+///
+/// Values can be written in decimal or in hexadecimal (with `0x` prefix) format. Inputs specified
+/// via command-line arguments are layered over the inputs of the input file via
+/// [ProgramInputs::merge()]: `--stack` option, when present, replaces `stack_init` and
+/// `overflow_init` fields of the input file, and `--advice` option, when present, replaces
+/// `advice_tape` field of the input file or, if `--append-advice` flag is set, is appended to it;
+/// the remaining fields are read from the input file, if one exists.
+#[derive(StructOpt, Debug)]
+pub struct InputArgs {
+    /// Initial stack values; the last value is placed at the top of the stack
+    #[structopt(long = "stack", parse(try_from_str = parse_element))]
+    stack: Option<Vec<u64>>,
+    /// Initial advice tape values
+    #[structopt(long = "advice", parse(try_from_str = parse_element))]
+    advice: Option<Vec<u64>>,
+    /// Append advice tape values specified via --advice to the advice tape of the input file
+    /// instead of replacing it
+    #[structopt(long = "append-advice")]
+    append_advice: bool,
+}
+
+impl InputArgs {
+    /// Returns the inputs of the specified input file with the inputs specified via arguments
+    /// layered over them.
+    ///
+    /// # Errors
+    /// Returns an error if any of the input values is not a valid field element.
+    pub fn program_inputs(&self, input_file: &InputFile) -> Result<ProgramInputs, String> {
+        let stack = self.stack.as_deref().unwrap_or(&[]);
+        let advice = self.advice.as_deref().unwrap_or(&[]);
+        let inputs = ProgramInputs::new(stack, advice, Vec::new())
+            .map_err(|err| format!("Failed to build program inputs - {:?}", err))?;
+        Ok(input_file
+            .get_program_inputs()?
+            .merge(inputs, self.advice_tape_merge()))
+    }
+
+    /// Returns the way in which advice tape values specified via arguments are combined with the
+    /// advice tape of the input file.
+    pub fn advice_tape_merge(&self) -> AdviceTapeMerge {
+        if self.append_advice {
+            AdviceTapeMerge::Append
+        } else {
+            AdviceTapeMerge::Replace
+        }
+    }
+}
+
+/// Parses a field element from a decimal or a `0x`-prefixed hexadecimal string (see
+/// [felt_fmt::parse()]).
+pub fn parse_element(value: &str) -> Result<u64, String> {
+    felt_fmt::parse(value)
+        .map(|value| value.as_int())
+        .map_err(|err| err.to_string())
+}
+
+/// Parses a 32-byte seed from a hexadecimal string, optionally prefixed with `0x`.
+pub fn parse_seed(value: &str) -> Result<[u8; 32], String> {
+    let hex_value = value.strip_prefix("0x").unwrap_or(value);
+    let bytes = hex::decode(hex_value).map_err(|err| format!("invalid seed {value}: {err}"))?;
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("seed must be 32 bytes long, but was {}", bytes.len()))
+}
+
+// INPUT CHECKS
+// ================================================================================================
+
+/// Logs a warning if the initial stack of the specified inputs is shallower than the stack
+/// depth which the program is known to read.
+pub fn check_stack_depth(inputs: &ProgramInputs, program: &Program) {
+    let num_inputs = inputs.stack_init().len();
+    if let Some(min_depth) = program.min_initial_stack_depth() {
+        if num_inputs < min_depth {
+            warn!(
+                "program reads at least {} initial stack values, but only {} were \
+                provided; missing values are set to zeros",
+                min_depth, num_inputs
+            );
+        }
+    }
+}
+
+/// Logs a warning for each kind of the specified inputs whose number does not match the
+/// number of inputs declared by the program via an `@inputs` annotation.
+pub fn check_declared_inputs(inputs: &ProgramInputs, program: &Program) {
+    let input_spec = match program.input_spec() {
+        Some(input_spec) => input_spec,
+        None => return,
+    };
+
+    let num_stack_inputs = inputs.stack_init().len();
+    if let Some(expected) = input_spec.stack() {
+        if num_stack_inputs != expected {
+            warn!(
+                "program declares {} stack inputs, but {} were provided",
+                expected, num_stack_inputs
+            );
+        }
+    }
+
+    let num_advice_inputs = inputs.advice_tape().len();
+    if let Some(expected) = input_spec.advice() {
+        if num_advice_inputs != expected {
+            warn!(
+                "program declares {} advice inputs, but {} were provided",
+                expected, num_advice_inputs
+            );
+        }
     }
 }
 
 // OUTPUT FILE
 // ================================================================================================
 
+/// Radix in which values are written into an output file.
+///
+/// The same radix is used for all fields of the file (i.e., for both `stack` and
+/// `overflow_addrs`). With [OutputFormat::Both], values are written in decimal, and their
+/// hexadecimal representations are written into additional `stack_hex` and
+/// `overflow_addrs_hex` fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Decimal,
+    Hex,
+    Both,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "decimal" => Ok(Self::Decimal),
+            "hex" => Ok(Self::Hex),
+            "both" => Ok(Self::Both),
+            _ => Err(format!(
+                "{} is not a valid output format; expected decimal, hex, or both",
+                value
+            )),
+        }
+    }
+}
+
 /// Output file struct
+///
+/// Values can be written in decimal or in hexadecimal (with `0x` prefix) format; the radix is
+/// detected for each value individually, and thus, files which mix both radixes can be read as
+/// well. When `stack_hex` and `overflow_addrs_hex` fields are present, they must contain the same
+/// values as `stack` and `overflow_addrs` fields respectively.
+///
+/// The optional `commitment` field contains the Merkle commitment to the stack outputs (see
+/// [ProgramOutputs::commitment()]) as a hexadecimal string; when present, it must match the values
+/// of the `stack` field.
 #[derive(Deserialize, Serialize, Debug)]
 pub struct OutputFile {
     pub stack: Vec<String>,
     pub overflow_addrs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack_hex: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overflow_addrs_hex: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commitment: Option<String>,
 }
 
 /// Helper methods to interact with the output file
 impl OutputFile {
-    /// Returns a new [OutputFile] from the specified outputs vectors
-    pub fn new(outputs: ProgramOutputs) -> Self {
-        Self {
-            stack: outputs
-                .stack()
+    /// Returns a new [OutputFile] from the specified outputs vectors with values written in the
+    /// specified format.
+    pub fn new(outputs: ProgramOutputs, format: OutputFormat) -> Self {
+        let format_values = |values: &[u64], radix| {
+            values
                 .iter()
-                .map(|&v| v.to_string())
-                .collect::<Vec<String>>(),
-            overflow_addrs: outputs
-                .overflow_addrs()
-                .iter()
-                .map(|&v| v.to_string())
-                .collect::<Vec<String>>(),
+                .map(|&v| felt_fmt::format(Felt::new(v), radix))
+                .collect::<Vec<_>>()
+        };
+        let decimal = |values: &[u64]| format_values(values, Radix::Decimal);
+        let hex = |values: &[u64]| format_values(values, Radix::Hex);
+
+        match format {
+            OutputFormat::Decimal => Self {
+                stack: decimal(outputs.stack()),
+                overflow_addrs: decimal(outputs.overflow_addrs()),
+                stack_hex: None,
+                overflow_addrs_hex: None,
+                commitment: None,
+            },
+            OutputFormat::Hex => Self {
+                stack: hex(outputs.stack()),
+                overflow_addrs: hex(outputs.overflow_addrs()),
+                stack_hex: None,
+                overflow_addrs_hex: None,
+                commitment: None,
+            },
+            OutputFormat::Both => Self {
+                stack: decimal(outputs.stack()),
+                overflow_addrs: decimal(outputs.overflow_addrs()),
+                stack_hex: Some(hex(outputs.stack())),
+                overflow_addrs_hex: Some(hex(outputs.overflow_addrs())),
+                commitment: None,
+            },
         }
     }
 
@@ -111,7 +449,7 @@ impl OutputFile {
             None => program_path.with_extension("outputs"),
         };
 
-        println!("Reading output file `{}`", path.display());
+        info!("Reading output file `{}`", path.display());
 
         // read outputs file to string
         let outputs_file = fs::read_to_string(&path)
@@ -124,12 +462,51 @@ impl OutputFile {
         Ok(outputs)
     }
 
-    /// Write the output file
-    pub fn write(outputs: ProgramOutputs, path: &PathBuf) -> Result<(), String> {
+    /// Sets the `commitment` field of this file to the Merkle commitment to the specified outputs.
+    pub fn with_commitment(mut self, outputs: &ProgramOutputs) -> Self {
+        self.commitment = Some(felt_fmt::format_digest(&outputs.commitment()));
+        self
+    }
+
+    /// Write the output file with values written in the specified format.
+    ///
+    /// If `commitment` is true, the Merkle commitment to the stack outputs is written into the
+    /// file as well.
+    ///
+    /// If `skip_unchanged` is true and the file at the specified path already contains exactly
+    /// the data which would be written, the file is not written (and thus, its modification time
+    /// does not change).
+    pub fn write(
+        outputs: ProgramOutputs,
+        path: &PathBuf,
+        format: OutputFormat,
+        commitment: bool,
+        skip_unchanged: bool,
+    ) -> Result<(), String> {
+        let mut output_file = Self::new(outputs.clone(), format);
+        if commitment {
+            output_file = output_file.with_commitment(&outputs);
+        }
+        let data = serde_json::to_string_pretty(&output_file)
+            .map_err(|err| format!("Failed to serialize output data - {}", err))?;
+
+        if skip_unchanged {
+            // a file which cannot be read is treated as changed; errors are reported on write
+            if let Ok(existing) = fs::read(path) {
+                if existing == data.as_bytes() {
+                    info!(
+                        "Output file `{}` not written: outputs unchanged",
+                        path.display()
+                    );
+                    return Ok(());
+                }
+            }
+        }
+
         // if path provided, create output file
-        println!("Creating output file `{}`", path.display());
+        info!("Creating output file `{}`", path.display());
 
-        let file = fs::File::create(&path).map_err(|err| {
+        let mut file = fs::File::create(&path).map_err(|err| {
             format!(
                 "Failed to create output file `{}` - {}",
                 path.display(),
@@ -137,58 +514,202 @@ impl OutputFile {
             )
         })?;
 
-        println!("Writing data to output file");
+        info!("Writing data to output file");
 
         // write outputs to output file
-        serde_json::to_writer_pretty(file, &Self::new(outputs))
+        file.write_all(data.as_bytes())
             .map_err(|err| format!("Failed to write output data - {}", err))
     }
 
     /// Converts outputs vectors for stack and overflow addresses to [ProgramOutputs].
-    pub fn outputs(&self) -> ProgramOutputs {
-        let stack = self
-            .stack
-            .iter()
-            .map(|v| v.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>();
+    ///
+    /// # Errors
+    /// Returns an error if any of the values is not a valid field element, if the hexadecimal
+    /// copy of a field does not match the field, or if the commitment does not match the stack
+    /// outputs.
+    pub fn outputs(&self) -> Result<ProgramOutputs, String> {
+        let stack = parse_output_values("stack", &self.stack, &self.stack_hex)?;
+        let overflow_addrs = parse_output_values(
+            "overflow_addrs",
+            &self.overflow_addrs,
+            &self.overflow_addrs_hex,
+        )?;
+        let outputs = ProgramOutputs::new(stack, overflow_addrs);
+
+        if let Some(commitment) = &self.commitment {
+            let expected = felt_fmt::format_digest(&outputs.commitment());
+            if !commitment.eq_ignore_ascii_case(&expected) {
+                return Err(format!(
+                    "Output commitment {} does not match values of `stack` output",
+                    commitment
+                ));
+            }
+        }
 
-        let overflow_addrs = self
-            .overflow_addrs
-            .iter()
-            .map(|v| v.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>();
+        Ok(outputs)
+    }
+}
 
-        ProgramOutputs::new(stack, overflow_addrs)
+/// Parses values of the specified output field and makes sure that they match the hexadecimal
+/// copy of the field, if one is provided.
+fn parse_output_values(
+    field: &str,
+    values: &[String],
+    hex_values: &Option<Vec<String>>,
+) -> Result<Vec<u64>, String> {
+    let parse = |values: &[String]| {
+        values
+            .iter()
+            .map(|value| parse_element(value))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| format!("Invalid `{}` output - {}", field, err))
+    };
+
+    let parsed = parse(values)?;
+    if let Some(hex_values) = hex_values {
+        if parse(hex_values)? != parsed {
+            return Err(format!(
+                "Values of `{}` output do not match values of `{}_hex` output",
+                field, field
+            ));
+        }
     }
+
+    Ok(parsed)
 }
 
 // PROGRAM FILE
 // ================================================================================================
 
+/// Format in which errors which occur while compiling a program are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    Plain,
+    Color,
+    Json,
+}
+
+impl ErrorFormat {
+    /// Returns a renderer which formats diagnostics in this format.
+    fn renderer(&self) -> Box<dyn DiagnosticRenderer> {
+        match self {
+            Self::Plain => Box::new(PlainRenderer),
+            Self::Color => Box::new(ColoredRenderer),
+            Self::Json => Box::new(JsonRenderer),
+        }
+    }
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "plain" => Ok(Self::Plain),
+            "color" => Ok(Self::Color),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "{} is not a valid error format; expected plain, color, or json",
+                value
+            )),
+        }
+    }
+}
+
+/// File extension of compiled programs.
+pub const COMPILED_PROGRAM_EXTENSION: &str = "masb";
+
 pub struct ProgramFile;
 
 /// Helper methods to interact with masm program file
 impl ProgramFile {
-    pub fn read(path: &PathBuf) -> Result<Program, String> {
-        println!("Reading program file `{}`", path.display());
+    /// Reads and compiles the program at the specified path; modules of the libraries at the
+    /// specified library paths can be imported by the program.
+    ///
+    /// If the path has the `.masb` extension, the program is read as a compiled program via
+    /// [ProgramFile::read_compiled()] instead, and the libraries are not used.
+    ///
+    /// Compilation errors are reported in the specified format; JSON-formatted errors are
+    /// returned as is so that they can be consumed by other tools.
+    pub fn read(
+        path: &PathBuf,
+        library_paths: &[PathBuf],
+        error_format: ErrorFormat,
+    ) -> Result<Program, String> {
+        if path
+            .extension()
+            .map_or(false, |ext| ext == COMPILED_PROGRAM_EXTENSION)
+        {
+            return Self::read_compiled(path);
+        }
+
+        // read libraries from files
+        let libraries = library_paths
+            .iter()
+            .map(|path| {
+                info!("Reading library file `{}`", path.display());
+                MaslLibrary::read_from_file(path)
+                    .map_err(|err| format!("Failed to read library - {}", err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        info!("Reading program file `{}`", path.display());
 
         // read program file to string
         let program_file = fs::read_to_string(&path)
             .map_err(|err| format!("Failed to open program file `{}` - {}", path.display(), err))?;
 
-        print!("Compiling program... ");
-        let now = Instant::now();
-
-        // compile program
+        // compile program; the program is named after the file it was read from
+        let program_name = path.file_stem().unwrap_or_default().to_string_lossy();
         let program = Assembler::new()
             .with_module_provider(StdLibrary::default())
+            .with_libraries(libraries)
+            .map_err(|err| format!("Failed to register libraries - {}", err))?
+            .with_program_name(&program_name)
             .compile(&program_file)
-            .map_err(|err| format!("Failed to compile program - {}", err))?;
-
-        println!("done ({} ms)", now.elapsed().as_millis());
+            .map_err(|err| {
+                let diagnostic = Diagnostic::from_assembly_error(&err, &program_file);
+                let rendered = error_format.renderer().render(&diagnostic);
+                match error_format {
+                    ErrorFormat::Json => rendered,
+                    _ => format!("Failed to compile program:\n{}", rendered),
+                }
+            })?;
 
         Ok(program)
     }
+
+    /// Reads a program which was compiled into the specified file via
+    /// [ProgramFile::write_compiled()].
+    pub fn read_compiled(path: &Path) -> Result<Program, String> {
+        info!("Reading compiled program file `{}`", path.display());
+
+        let bytes = fs::read(path).map_err(|err| {
+            format!(
+                "Failed to open compiled program file `{}` - {}",
+                path.display(),
+                err
+            )
+        })?;
+
+        // the header of the file is checked while the program is deserialized
+        Program::read_from_bytes(&bytes)
+            .map_err(|err| format!("Failed to decode compiled program - {}", err))
+    }
+
+    /// Writes the specified compiled program into the specified file; the program can be read
+    /// back via [ProgramFile::read_compiled()] without being compiled again.
+    pub fn write_compiled(program: &Program, path: &Path) -> Result<(), String> {
+        info!("Creating compiled program file `{}`", path.display());
+
+        fs::write(path, program.to_bytes()).map_err(|err| {
+            format!(
+                "Failed to write compiled program file `{}` - {}",
+                path.display(),
+                err
+            )
+        })
+    }
 }
 
 // PROOF FILE
@@ -207,20 +728,23 @@ impl ProofFile {
             None => program_path.with_extension("proof"),
         };
 
-        println!("Reading proof file `{}`", path.display());
+        info!("Reading proof file `{}`", path.display());
 
         // read the file to bytes
         let file = fs::read(&path)
             .map_err(|err| format!("Failed to open proof file `{}` - {}", path.display(), err))?;
 
-        // deserialize bytes into a stark proof
-        StarkProof::from_bytes(&file)
+        // make sure the file is a proof file and deserialize the rest of its bytes into a stark
+        // proof
+        let proof_bytes = strip_header(&file, ArtifactKind::Proof)
+            .map_err(|err| format!("Failed to decode proof data - {}", err))?;
+        StarkProof::from_bytes(proof_bytes)
             .map_err(|err| format!("Failed to decode proof data - {}", err))
     }
 
     /// Write stark proof to file
     pub fn write(
-        proof: StarkProof,
+        proof: &StarkProof,
         proof_path: &Option<PathBuf>,
         program_path: &Path,
     ) -> Result<(), String> {
@@ -231,15 +755,16 @@ impl ProofFile {
             None => program_path.with_extension("proof"),
         };
 
-        println!("Creating proof file `{}`", path.display());
+        info!("Creating proof file `{}`", path.display());
 
         // create output fille
         let mut file = fs::File::create(&path)
             .map_err(|err| format!("Failed to create proof file `{}` - {}", path.display(), err))?;
 
-        let proof_bytes = proof.to_bytes();
+        // proof bytes are preceded by the header which identifies the file as a proof file
+        let proof_bytes = with_header(ArtifactKind::Proof, &proof.to_bytes());
 
-        println!(
+        info!(
             "Writing data to proof file - size {} KB",
             proof_bytes.len() / 1024
         );
@@ -251,6 +776,52 @@ impl ProofFile {
     }
 }
 
+// BUNDLE FILE
+// ================================================================================================
+
+pub struct BundleFile;
+
+/// Helper methods to interact with verification bundle file
+impl BundleFile {
+    /// Read verification bundle from file
+    pub fn read(path: &PathBuf) -> Result<VerificationBundle, String> {
+        info!("Reading bundle file `{}`", path.display());
+
+        // read the file to bytes
+        let file = fs::read(&path)
+            .map_err(|err| format!("Failed to open bundle file `{}` - {}", path.display(), err))?;
+
+        // deserialize bytes into a verification bundle
+        VerificationBundle::read_from_bytes(&file)
+            .map_err(|err| format!("Failed to decode bundle data - {}", err))
+    }
+
+    /// Write verification bundle to file
+    pub fn write(bundle: &VerificationBundle, path: &PathBuf) -> Result<(), String> {
+        info!("Creating bundle file `{}`", path.display());
+
+        // create bundle file
+        let mut file = fs::File::create(&path).map_err(|err| {
+            format!(
+                "Failed to create bundle file `{}` - {}",
+                path.display(),
+                err
+            )
+        })?;
+
+        let bundle_bytes = bundle.to_bytes();
+
+        info!(
+            "Writing data to bundle file - size {} KB",
+            bundle_bytes.len() / 1024
+        );
+
+        // write bundle bytes to file
+        file.write_all(&bundle_bytes)
+            .map_err(|err| format!("Failed to write bundle data - {}", err))
+    }
+}
+
 // PROGRAM HASH
 // ================================================================================================
 
@@ -259,17 +830,7 @@ pub struct ProgramHash;
 /// Helper method to parse program hash from hex
 impl ProgramHash {
     pub fn read(hash_hex_string: &String) -> Result<Digest, String> {
-        // decode hex to bytes
-        let program_hash_bytes = hex::decode(hash_hex_string)
-            .map_err(|err| format!("Failed to convert program hash to bytes {}", err))?;
-
-        // create slice reader from bytes
-        let mut program_hash_slice = SliceReader::new(&program_hash_bytes);
-
-        // create hash digest from slice
-        let program_hash = Digest::read_from(&mut program_hash_slice)
-            .map_err(|err| format!("Failed to deserialize program hash from bytes - {}", err))?;
-
-        Ok(program_hash)
+        felt_fmt::parse_digest(hash_hex_string)
+            .map_err(|err| format!("Failed to parse program hash - {}", err))
     }
 }
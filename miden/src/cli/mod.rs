@@ -4,6 +4,7 @@ mod prove;
 mod repl;
 mod run;
 mod verify;
+mod verify_bundle;
 
 pub use compile::CompileCmd;
 pub use data::InputFile;
@@ -11,3 +12,4 @@ pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
 pub use verify::VerifyCmd;
+pub use verify_bundle::VerifyBundleCmd;
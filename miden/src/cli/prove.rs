@@ -1,10 +1,13 @@
-use super::data::{InputFile, OutputFile, ProgramFile, ProofFile};
+use super::data::{
+    check_declared_inputs, check_stack_depth, parse_seed, BundleFile, ErrorFormat, InputArgs,
+    InputFile, OutputFile, OutputFormat, ProgramFile, ProofFile,
+};
 use air::ProofOptions;
-use crypto::Digest;
-use std::io::Write;
+use miden::VerificationBundle;
 use std::path::PathBuf;
-use std::time::Instant;
 use structopt::StructOpt;
+use tracing::info;
+use vm_core::{felt_fmt, StarkField};
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Prove", about = "Prove a miden program")]
@@ -12,21 +15,48 @@ pub struct ProveCmd {
     /// Path to .masm assembly file
     #[structopt(short = "a", long = "assembly", parse(from_os_str))]
     assembly_file: PathBuf,
+    /// Paths to .masl library files whose modules can be imported by the program
+    #[structopt(short = "l", long = "library", parse(from_os_str))]
+    library_files: Vec<PathBuf>,
+    /// Format of compilation errors: plain, color, or json
+    #[structopt(long = "error-format", default_value = "plain")]
+    error_format: ErrorFormat,
     /// Path to input file
     #[structopt(short = "i", long = "input", parse(from_os_str))]
     input_file: Option<PathBuf>,
+    /// Inputs which take precedence over the input file
+    #[structopt(flatten)]
+    input_args: InputArgs,
     /// Number of ouptuts
     #[structopt(short = "n", long = "num-outputs", default_value = "16")]
     num_outputs: usize,
     /// Path to output file
     #[structopt(short = "o", long = "output", parse(from_os_str))]
     output_file: Option<PathBuf>,
+    /// Radix of values written into the output file: decimal, hex, or both
+    #[structopt(long = "output-format", default_value = "decimal")]
+    output_format: OutputFormat,
+    /// Write a Merkle commitment to the stack outputs into the output file
+    #[structopt(long = "commitment")]
+    commitment: bool,
+    /// Do not rewrite the output file if it already contains the same outputs
+    #[structopt(long = "skip-unchanged")]
+    skip_unchanged: bool,
     /// Path to proof file
     #[structopt(short = "p", long = "proof", parse(from_os_str))]
     proof_file: Option<PathBuf>,
+    /// Path to verification bundle file; when provided, the program hash, stack inputs, outputs,
+    /// and proof are also written into this file
+    #[structopt(short = "b", long = "bundle", parse(from_os_str))]
+    bundle_file: Option<PathBuf>,
     /// Security level for execution proofs generated by the VM
     #[structopt(short = "s", long = "security", default_value = "96bits")]
     security: String,
+    /// Seed (32 bytes, hex-encoded) for the random values injected into the execution trace;
+    /// by default, the program hash is used as the seed, and thus, proofs are byte-identical
+    /// across runs
+    #[structopt(long = "seed", parse(try_from_str = parse_seed))]
+    seed: Option<[u8; 32]>,
 }
 
 impl ProveCmd {
@@ -43,51 +73,68 @@ impl ProveCmd {
         println!("Prove program");
         println!("============================================================");
 
-        // configure logging
-        env_logger::Builder::new()
-            .format(|buf, record| writeln!(buf, "{}", record.args()))
-            .filter_level(log::LevelFilter::Debug)
-            .init();
-
         // load program from file and compile
-        let program = ProgramFile::read(&self.assembly_file)?;
+        let program =
+            ProgramFile::read(&self.assembly_file, &self.library_files, self.error_format)?;
 
-        // load input data from file
+        // load input data from file and layer the inputs specified via arguments over it
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let program_inputs = self.input_args.program_inputs(&input_data)?;
+        check_stack_depth(&program_inputs, &program);
+        check_declared_inputs(&program_inputs, &program);
 
-        println!(
-            "Proving program with hash {}...",
-            hex::encode(program.hash().as_bytes())
+        info!(
+            "Proving program with hash {}",
+            felt_fmt::format_digest(&program.hash())
         );
-        let now = Instant::now();
-
-        // execute program and generate proof
-        let (outputs, proof) = prover::prove(
-            &program,
-            &input_data.get_program_inputs(),
-            &self.get_proof_security(),
-        )
-        .map_err(|err| format!("Failed to prove program - {:?}", err))?;
 
-        println!(
-            "Program with hash {} proved in {} ms",
-            hex::encode(program.hash().as_bytes()),
-            now.elapsed().as_millis()
-        );
+        // execute program and generate proof; all other prover randomness is derived from public
+        // data, and thus, the proof is determined by the program, its inputs, and the seed
+        let proof_options = self.get_proof_security();
+        let seed = self.seed.unwrap_or_else(|| program.hash().into());
+        let (outputs, proof) =
+            prover::prove_with_seed(&program, &program_inputs, &proof_options, seed)
+                .map_err(|err| format!("Failed to prove program - {}", err))?;
 
         // write proof to file
-        ProofFile::write(proof, &self.proof_file, &self.assembly_file)?;
+        ProofFile::write(&proof, &self.proof_file, &self.assembly_file)?;
+
+        // write verification bundle to file; stack inputs are written in the same order as they
+        // are specified in the input file (i.e., the last value ends up on top of the stack)
+        if let Some(bundle_path) = &self.bundle_file {
+            let stack_inputs = program_inputs
+                .stack_init()
+                .iter()
+                .rev()
+                .map(|value| value.as_int())
+                .collect();
+            let bundle =
+                VerificationBundle::new(program.hash(), stack_inputs, outputs.clone(), proof);
+            BundleFile::write(&bundle, bundle_path)?;
+        }
 
         // provide outputs
         if let Some(output_path) = &self.output_file {
             // write all outputs to specified file.
-            OutputFile::write(outputs, output_path)?;
+            OutputFile::write(
+                outputs,
+                output_path,
+                self.output_format,
+                self.commitment,
+                self.skip_unchanged,
+            )?;
         } else {
             // if no output path was provided, get the stack outputs for printing to the screen.
             let stack_outputs = outputs.stack_outputs(self.num_outputs).to_vec();
 
             // write all outputs to default location if none was provided
-            OutputFile::write(outputs, &self.assembly_file.with_extension("outputs"))?;
+            OutputFile::write(
+                outputs,
+                &self.assembly_file.with_extension("outputs"),
+                self.output_format,
+                self.commitment,
+                self.skip_unchanged,
+            )?;
 
             // print stack outputs to screen.
             println!("Output: {:?}", stack_outputs);
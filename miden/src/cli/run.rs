@@ -1,8 +1,12 @@
-use super::data::{InputFile, OutputFile, ProgramFile};
-use crypto::Digest;
+use super::data::{
+    check_declared_inputs, check_stack_depth, ErrorFormat, InputArgs, InputFile, OutputFile,
+    OutputFormat, ProgramFile,
+};
+use processor::ExecutionTrace;
 use std::path::PathBuf;
-use std::time::Instant;
 use structopt::StructOpt;
+use tracing::info;
+use vm_core::felt_fmt;
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Run", about = "Run a miden program")]
@@ -10,15 +14,39 @@ pub struct RunCmd {
     /// Path to .masm assembly file
     #[structopt(short = "a", long = "assembly", parse(from_os_str))]
     assembly_file: PathBuf,
+    /// Paths to .masl library files whose modules can be imported by the program
+    #[structopt(short = "l", long = "library", parse(from_os_str))]
+    library_files: Vec<PathBuf>,
+    /// Format of compilation errors: plain, color, or json
+    #[structopt(long = "error-format", default_value = "plain")]
+    error_format: ErrorFormat,
     /// Path to input file
     #[structopt(short = "i", long = "input", parse(from_os_str))]
     input_file: Option<PathBuf>,
+    /// Inputs which take precedence over the input file
+    #[structopt(flatten)]
+    input_args: InputArgs,
     /// Number of ouptuts
     #[structopt(short = "n", long = "num-outputs", default_value = "16")]
     num_outputs: usize,
     /// Path to output file
     #[structopt(short = "o", long = "output", parse(from_os_str))]
     output_file: Option<PathBuf>,
+    /// Radix of values written into the output file: decimal, hex, or both
+    #[structopt(long = "output-format", default_value = "decimal")]
+    output_format: OutputFormat,
+    /// Write a Merkle commitment to the stack outputs into the output file
+    #[structopt(long = "commitment")]
+    commitment: bool,
+    /// Do not rewrite the output file if it already contains the same outputs
+    #[structopt(long = "skip-unchanged")]
+    skip_unchanged: bool,
+    /// Path to a file with expected outputs; fails if the program outputs do not match them
+    #[structopt(short = "e", long = "expected", parse(from_os_str))]
+    expected_file: Option<PathBuf>,
+    /// Print a summary of the execution (cycles, stack depth, consumed advice) to stdout
+    #[structopt(long = "summary")]
+    summary: bool,
 }
 
 impl RunCmd {
@@ -28,26 +56,46 @@ impl RunCmd {
         println!("============================================================");
 
         // load program from file and compile
-        let program = ProgramFile::read(&self.assembly_file)?;
+        let program =
+            ProgramFile::read(&self.assembly_file, &self.library_files, self.error_format)?;
 
-        // load input data from file
+        // load input data from file and layer the inputs specified via arguments over it
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
+        let program_inputs = self.input_args.program_inputs(&input_data)?;
+        check_stack_depth(&program_inputs, &program);
+        check_declared_inputs(&program_inputs, &program);
 
-        print!(
-            "Executing program with hash {}... ",
-            hex::encode(program.hash().as_bytes())
+        info!(
+            "Executing program with hash {}",
+            felt_fmt::format_digest(&program.hash())
         );
-        let now = Instant::now();
 
         // execute program and generate outputs
-        let trace = processor::execute(&program, &input_data.get_program_inputs())
-            .map_err(|err| format!("Failed to generate exection trace = {:?}", err))?;
+        let trace = processor::execute(&program, &program_inputs)
+            .map_err(|err| format!("Failed to generate execution trace - {}", err))?;
 
-        println!("done ({} ms)", now.elapsed().as_millis());
+        // compare the outputs against the expected outputs, if these were provided
+        if let Some(expected_path) = &self.expected_file {
+            let expected = OutputFile::read(&Some(expected_path.clone()), &self.assembly_file)?;
+            let diff = trace.program_outputs().diff(&expected.outputs()?);
+            if !diff.is_empty() {
+                return Err(format!(
+                    "Program outputs do not match expected outputs:\n{}",
+                    diff
+                ));
+            }
+            println!("Program outputs match expected outputs");
+        }
 
         if let Some(output_path) = &self.output_file {
             // write outputs to file if one was specified
-            OutputFile::write(trace.program_outputs(), output_path)?;
+            OutputFile::write(
+                trace.program_outputs(),
+                output_path,
+                self.output_format,
+                self.commitment,
+                self.skip_unchanged,
+            )?;
         } else {
             // write the stack outputs to the screen.
             println!(
@@ -56,6 +104,32 @@ impl RunCmd {
             );
         }
 
+        if self.summary {
+            print_summary(&trace, program_inputs.advice_tape().len());
+        }
+
         Ok(())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Prints a human-readable summary of the execution which resulted in the provided trace. All
+/// reported values are tracked by the processor during execution anyway, and thus, collecting them
+/// does not require executing the program again.
+fn print_summary(trace: &ExecutionTrace, advice_tape_len: usize) {
+    println!("Execution summary:");
+    println!("  cycles executed:        {}", trace.num_cycles());
+    println!(
+        "  final stack depth:      {}",
+        trace.program_outputs().stack().len()
+    );
+    println!(
+        "  advice values consumed: {} of {}",
+        // advice injectors may write values onto the tape, in which case more values can remain
+        // on the tape than were initially provided
+        advice_tape_len.saturating_sub(trace.advice_remaining()),
+        advice_tape_len
+    );
+}
@@ -1,7 +1,7 @@
 use super::data::{InputFile, OutputFile, ProgramHash, ProofFile};
 use std::path::PathBuf;
-use std::time::Instant;
 use structopt::StructOpt;
+use tracing::info;
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Verify", about = "Verify a miden program")]
@@ -38,19 +38,18 @@ impl VerifyCmd {
         // load proof from file
         let proof = ProofFile::read(&Some(self.proof_file.clone()), &self.proof_file)?;
 
-        println!("verifying program...");
-        let now = Instant::now();
+        info!("Verifying program");
 
         // verify proof
         verifier::verify(
             program_hash,
-            &input_data.stack_init(),
-            &outputs_data.outputs(),
+            &input_data.stack_init()?,
+            &outputs_data.outputs()?,
             proof,
         )
         .map_err(|err| format!("Program failed verification! - {}", err))?;
 
-        println!("Verification complete in {} ms", now.elapsed().as_millis());
+        println!("Verification complete");
 
         Ok(())
     }
@@ -0,0 +1,41 @@
+use super::data::BundleFile;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use tracing::info;
+use vm_core::felt_fmt;
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "Verify bundle",
+    about = "Verify a miden program using a verification bundle"
+)]
+pub struct VerifyBundleCmd {
+    /// Path to verification bundle file
+    #[structopt(short = "b", long = "bundle", parse(from_os_str))]
+    bundle_file: PathBuf,
+}
+
+impl VerifyBundleCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Verify program bundle");
+        println!("============================================================");
+
+        // load program hash, inputs, outputs, and proof from the bundle file
+        let bundle = BundleFile::read(&self.bundle_file)?;
+
+        info!(
+            "Verifying program with hash {}",
+            felt_fmt::format_digest(&bundle.program_hash())
+        );
+
+        // verify proof
+        bundle
+            .verify()
+            .map_err(|err| format!("Program failed verification! - {}", err))?;
+
+        println!("Verification complete");
+
+        Ok(())
+    }
+}
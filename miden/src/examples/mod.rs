@@ -1,5 +1,4 @@
 use miden::{Program, ProgramInputs, ProofOptions, StarkProof};
-use std::io::Write;
 use std::time::Instant;
 use structopt::StructOpt;
 
@@ -53,12 +52,6 @@ impl ExampleOptions {
     pub fn execute(&self) -> Result<(), String> {
         println!("============================================================");
 
-        // configure logging
-        env_logger::Builder::new()
-            .format(|buf, record| writeln!(buf, "{}", record.args()))
-            .filter_level(log::LevelFilter::Debug)
-            .init();
-
         let proof_options = self.get_proof_options();
 
         // instantiate and prepare the example
@@ -3,15 +3,24 @@
 // EXPORTS
 // ================================================================================================
 
+mod runner;
+pub use runner::{compile_and_run, RunError};
+
 pub use air::{FieldExtension, HashFunction, ProofOptions};
-pub use assembly::{Assembler, AssemblyError, ParsingError};
+pub use assembly::{
+    Assembler, AssemblyError, ColoredRenderer, Diagnostic, DiagnosticRenderer, JsonRenderer,
+    LibraryError, MaslLibrary, ParsingError, PlainRenderer,
+};
 pub use processor::{
-    execute, execute_iter, AsmOpInfo, ExecutionError, ExecutionTrace, VmState, VmStateIterator,
+    execute, execute_iter, execute_with_options, AsmOpInfo, DivisionByZero, ExecutionError,
+    ExecutionOptions, ExecutionTrace, VmState, VmStateIterator,
+};
+pub use prover::{prove, prove_with_seed, StarkProof};
+pub use verifier::{
+    proof_params, proofs_compatible, verify, ProofParams, VerificationBundle, VerificationError,
 };
-pub use prover::{prove, StarkProof};
-pub use verifier::{verify, VerificationError};
 pub use vm_core::{
     chiplets::hasher::Digest,
-    errors::{AdviceSetError, InputError},
-    AdviceSet, Program, ProgramInputs,
+    errors::{AdviceSetError, InputError, ProgramError},
+    AdviceSet, AdviceTapeMerge, Program, ProgramInputs, ProgramOutputs,
 };
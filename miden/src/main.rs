@@ -1,6 +1,8 @@
 use core::fmt;
 use miden::{AssemblyError, ExecutionError};
 use structopt::StructOpt;
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
 
 mod cli;
 mod examples;
@@ -13,6 +15,12 @@ mod tools;
 pub struct Cli {
     #[structopt(subcommand)]
     action: Actions,
+    /// Increase logging verbosity; use -v for debug and -vv for trace messages
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences), global = true)]
+    verbose: u8,
+    /// Log only warnings and errors
+    #[structopt(short = "q", long = "quiet", global = true)]
+    quiet: bool,
 }
 
 /// CLI actions
@@ -24,6 +32,7 @@ pub enum Actions {
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
+    VerifyBundle(cli::VerifyBundleCmd),
     #[cfg(feature = "std")]
     Repl(cli::ReplCmd),
 }
@@ -38,16 +47,38 @@ impl Cli {
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
             Actions::Verify(verify) => verify.execute(),
+            Actions::VerifyBundle(verify_bundle) => verify_bundle.execute(),
             #[cfg(feature = "std")]
             Actions::Repl(repl) => repl.execute(),
         }
     }
+
+    /// Installs a subscriber which writes tracing events and timings of closed spans to stderr;
+    /// the maximum level of the written events is driven by `--verbose` and `--quiet` flags.
+    ///
+    /// The subscriber also captures records emitted via the `log` crate (e.g., by the prover).
+    pub fn init_tracing(&self) {
+        let level = match (self.quiet, self.verbose) {
+            (true, _) => Level::WARN,
+            (false, 0) => Level::INFO,
+            (false, 1) => Level::DEBUG,
+            (false, _) => Level::TRACE,
+        };
+
+        tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_target(false)
+            .with_writer(std::io::stderr)
+            .init();
+    }
 }
 
 /// Executable entry point
 pub fn main() {
     // read command-line args
     let cli = Cli::from_args();
+    cli.init_tracing();
 
     // execute cli action
     if let Err(error) = cli.execute() {
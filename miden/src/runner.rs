@@ -0,0 +1,66 @@
+use assembly::{Assembler, AssemblyError};
+use core::fmt;
+use once_cell::race::OnceBox;
+use processor::{ExecutionError, ProgramOutputs};
+use stdlib::StdLibrary;
+use vm_core::{
+    errors::InputError,
+    utils::{collections::Vec, Box},
+    ProgramInputs,
+};
+
+/// The standard library shared by all invocations of [compile_and_run()]; instantiated on first
+/// use, and module ASTs of the library are deserialized only once across all invocations.
+static STDLIB: OnceBox<StdLibrary> = OnceBox::new();
+
+// COMPILE AND RUN
+// ================================================================================================
+
+/// Compiles the provided source code against the standard library and executes the resulting
+/// program with the specified stack and advice tape inputs, returning the outputs of the program.
+///
+/// Unlike the CLI, this function performs no file system access and prints nothing, which makes
+/// it suitable for benchmarking in environments without a file system. The execution trace is
+/// discarded once the outputs have been extracted from it, and the standard library is
+/// instantiated only once and reused by all subsequent calls.
+///
+/// # Errors
+/// Returns an error if the program fails to compile, if the inputs are not valid program inputs,
+/// or if the program fails to execute.
+pub fn compile_and_run(
+    source: &str,
+    stack: &[u64],
+    advice: &[u64],
+) -> Result<ProgramOutputs, RunError> {
+    let program = Assembler::new()
+        .with_module_provider(STDLIB.get_or_init(|| Box::new(StdLibrary::default())))
+        .compile(source)
+        .map_err(RunError::Compile)?;
+    let inputs = ProgramInputs::new(stack, advice, Vec::new()).map_err(RunError::Inputs)?;
+    let trace = processor::execute(&program, &inputs).map_err(RunError::Execution)?;
+    Ok(trace.program_outputs())
+}
+
+// RUN ERROR
+// ================================================================================================
+
+/// An error returned by [compile_and_run()]; identifies the phase in which the failure occurred.
+#[derive(Debug)]
+pub enum RunError {
+    Compile(AssemblyError),
+    Execution(ExecutionError),
+    Inputs(InputError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Compile(err) => write!(f, "failed to compile program: {err}"),
+            RunError::Execution(err) => write!(f, "failed to execute program: {err}"),
+            RunError::Inputs(err) => write!(f, "invalid program inputs: {err:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RunError {}
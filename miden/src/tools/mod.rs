@@ -29,7 +29,7 @@ impl Analyze {
             std::fs::read_to_string(&self.assembly_file).expect("Could not read masm file");
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
-        let program_info: ProgramInfo = analyze(program.as_str(), input_data.get_program_inputs())
+        let program_info: ProgramInfo = analyze(program.as_str(), input_data.get_program_inputs()?)
             .expect("Could not retrieve program info");
         println!("{}", program_info);
         Ok(())
@@ -247,7 +247,7 @@ mod tests {
         let stack_input = vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
         let program_inputs = super::ProgramInputs::new(&stack_input, &[], vec![]).unwrap();
         let program_info = super::analyze(source, program_inputs);
-        let expected_error = "Execution Error: DivideByZero(1)";
+        let expected_error = "Execution Error: DivideByZero(\"inv\", 1)";
         assert_eq!(program_info.err().unwrap().to_string(), expected_error);
     }
 
@@ -256,7 +256,7 @@ mod tests {
         let source = "proc.foo.1 loc_store.0 end mem_storew.1 dropw push.17 exec.foo end";
         let program_inputs = super::ProgramInputs::none();
         let program_info = super::analyze(source, program_inputs);
-        let expected_error = "Assembly Error: ParsingError(\"unexpected token: expected 'begin' but was 'mem_storew.1'\")";
+        let expected_error = "Assembly Error: Located(SourceLocation { line: 1, column: 28, len: 12 }, ParsingError(\"unexpected token: expected 'begin' but was 'mem_storew.1'\"))";
         assert_eq!(program_info.err().unwrap().to_string(), expected_error);
     }
 }
@@ -0,0 +1,691 @@
+#![cfg(feature = "executable")]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use vm_core::{
+    artifact::{write_header, ArtifactKind},
+    felt_fmt, Felt, StarkField,
+};
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Creates a new temporary directory with the specified name.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("miden-cli-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs Miden CLI with the specified arguments and returns everything it printed.
+fn run_cli(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_miden"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Runs Miden CLI with the specified arguments and returns everything it wrote to stderr (e.g.,
+/// logged warnings).
+fn run_cli_stderr(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_miden"))
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stderr).unwrap()
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap()
+}
+
+// CLI TESTS
+// ================================================================================================
+
+#[test]
+fn run_with_command_line_inputs() {
+    // the program is written into a directory without an input file for it
+    let dir = temp_dir("inputs");
+    let program_path = dir.join("cli_inputs.masm");
+    fs::write(&program_path, "begin adv_push.1 mul add end").unwrap();
+    assert!(!program_path.with_extension("inputs").exists());
+
+    // stack: [2, 1] (2 at the top); advice tape: [3]; thus, the output is 3 * 2 + 1
+    let program = path_str(&program_path);
+    let output = run_cli(&[
+        "run", "-a", program, "-n", "1", "--stack", "1", "0x2", "--advice", "3",
+    ]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.contains("Output: [7]"), "{output}");
+}
+
+#[test]
+fn run_with_mismatched_declared_inputs() {
+    let dir = temp_dir("declared-inputs");
+    let program_path = dir.join("declared.masm");
+    fs::write(
+        &program_path,
+        "#! @inputs(stack=2, advice=1)\nbegin adv_push.1 mul add end",
+    )
+    .unwrap();
+    let program = path_str(&program_path);
+
+    // inputs which match the declaration produce no warnings
+    let stderr = run_cli_stderr(&["run", "-a", program, "--stack", "1", "2", "--advice", "3"]);
+    assert!(!stderr.contains("program declares"), "{stderr}");
+
+    // too many stack inputs and too few advice inputs are reported
+    let stderr = run_cli_stderr(&["run", "-a", program, "--stack", "1", "2", "3"]);
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(
+        stderr.contains("program declares 2 stack inputs, but 3 were provided"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("program declares 1 advice inputs, but 0 were provided"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn run_with_input_constants() {
+    let dir = temp_dir("constants");
+    let program_path = dir.join("constants.masm");
+    fs::write(&program_path, "begin adv_push.1 mul add end").unwrap();
+
+    // `TWO` is defined via `BASE`; the output is 3 * 2 + 1
+    let inputs_path = program_path.with_extension("inputs");
+    fs::write(
+        &inputs_path,
+        r#"{
+            "constants": { "BASE": "0x2", "TWO": "BASE", "THREE": "3" },
+            "stack_init": ["1", "TWO"],
+            "advice_tape": ["THREE"]
+        }"#,
+    )
+    .unwrap();
+    let program = path_str(&program_path);
+    let output = run_cli(&["run", "-a", program, "-n", "1"]);
+    assert!(output.contains("Output: [7]"), "{output}");
+
+    // references to undefined constants are rejected
+    fs::write(
+        &inputs_path,
+        r#"{ "constants": { "TWO": "2" }, "stack_init": ["1", "TWO"], "advice_tape": ["THREE"] }"#,
+    )
+    .unwrap();
+    let output = run_cli(&["run", "-a", program, "-n", "1"]);
+    assert!(
+        output.contains("Input value references undefined constant `THREE`"),
+        "{output}"
+    );
+
+    // cyclic definitions are rejected
+    fs::write(
+        &inputs_path,
+        r#"{ "constants": { "A": "B", "B": "A" }, "stack_init": ["A"] }"#,
+    )
+    .unwrap();
+    let output = run_cli(&["run", "-a", program, "-n", "1"]);
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(
+        output.contains("Input constants have a cyclic definition: A -> B -> A"),
+        "{output}"
+    );
+}
+
+#[test]
+fn run_with_invalid_input_values() {
+    let dir = temp_dir("invalid-values");
+    let program_path = dir.join("invalid.masm");
+    fs::write(&program_path, "begin adv_push.1 mul add end").unwrap();
+    let program = path_str(&program_path);
+
+    // values in the input file are parsed via the shared field element parser, and thus, invalid
+    // values are reported with its error messages instead of causing a panic
+    let modulus = Felt::MODULUS.to_string();
+    let inputs_path = program_path.with_extension("inputs");
+    fs::write(
+        &inputs_path,
+        format!(r#"{{ "stack_init": ["1", "{modulus}"], "advice_tape": ["3"] }}"#),
+    )
+    .unwrap();
+    let output = run_cli(&["run", "-a", program, "-n", "1"]);
+    let expected = felt_fmt::parse(&modulus).unwrap_err().to_string();
+    assert!(
+        output.contains(&format!("Invalid `stack_init` input - {expected}")),
+        "{output}"
+    );
+
+    fs::write(
+        &inputs_path,
+        r#"{ "stack_init": ["1", "2"], "advice_tape": ["-3"] }"#,
+    )
+    .unwrap();
+    let output = run_cli(&["run", "-a", program, "-n", "1"]);
+    let expected = felt_fmt::parse("-3").unwrap_err().to_string();
+    assert!(output.contains(&expected), "{output}");
+
+    // the same parser is used for values specified via command-line arguments
+    let stderr = run_cli_stderr(&["run", "-a", program, "--stack", "1", "0xg"]);
+    fs::remove_dir_all(&dir).unwrap();
+    let expected = felt_fmt::parse("0xg").unwrap_err().to_string();
+    assert!(stderr.contains(&expected), "{stderr}");
+    assert!(!stderr.contains("panicked"), "{stderr}");
+}
+
+#[test]
+fn run_with_mixed_radix_input_file() {
+    let dir = temp_dir("mixed-radix");
+    let program_path = dir.join("mixed_radix.masm");
+    fs::write(&program_path, "begin adv_push.2 end").unwrap();
+    let program = path_str(&program_path);
+
+    // values in the fixture are a mix of hex and decimal strings
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/mixed_radix.inputs");
+    let mixed = run_cli(&["run", "-a", program, "-i", path_str(&fixture), "-n", "5"]);
+
+    let decimal_path = dir.join("decimal.inputs");
+    fs::write(
+        &decimal_path,
+        r#"{ "stack_init": ["1", "2", "18446744069414584320"], "advice_tape": ["10", "11"] }"#,
+    )
+    .unwrap();
+    let decimal = run_cli(&[
+        "run",
+        "-a",
+        program,
+        "-i",
+        path_str(&decimal_path),
+        "-n",
+        "5",
+    ]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    // both files result in the same program inputs, and thus, in the same outputs
+    let output_line = |output: &str| -> String {
+        output
+            .lines()
+            .find(|line| line.contains("Output: "))
+            .unwrap_or_else(|| panic!("no outputs in: {output}"))
+            .to_string()
+    };
+    assert_eq!(output_line(&mixed), output_line(&decimal));
+    assert!(
+        decimal.contains("Output: [11, 10, 18446744069414584320, 2, 1]"),
+        "{decimal}"
+    );
+}
+
+#[test]
+fn run_with_advice_map() {
+    let dir = temp_dir("advice-map");
+    let program_path = dir.join("advice_map.masm");
+    fs::write(
+        &program_path,
+        "begin push.1.2.3.4 adv.keyval adv_push.2 end",
+    )
+    .unwrap();
+    let program = path_str(&program_path);
+    let inputs_path = program_path.with_extension("inputs");
+
+    // the key is the word [1, 2, 3, 4] at the top of the stack; its values are written to the
+    // advice tape with the first value at the head of the tape
+    let key = "0100000000000000020000000000000003000000000000000400000000000000";
+    fs::write(
+        &inputs_path,
+        format!(r#"{{ "stack_init": [], "advice_map": {{ "0x{key}": ["5", "0x6"] }} }}"#),
+    )
+    .unwrap();
+    let output = run_cli(&["run", "-a", program, "-n", "6"]);
+    assert!(output.contains("Output: [6, 5, 4, 3, 2, 1]"), "{output}");
+
+    // keys which are not 4-element words are rejected
+    fs::write(
+        &inputs_path,
+        format!(
+            r#"{{ "stack_init": [], "advice_map": {{ "{}": ["5"] }} }}"#,
+            &key[2..]
+        ),
+    )
+    .unwrap();
+    let output = run_cli(&["run", "-a", program, "-n", "6"]);
+    let expected = felt_fmt::parse_digest(&key[2..]).unwrap_err().to_string();
+    assert!(
+        output.contains(&format!("Invalid `advice_map` key - {expected}")),
+        "{output}"
+    );
+
+    // values of the map are validated as well
+    fs::write(
+        &inputs_path,
+        format!(r#"{{ "stack_init": [], "advice_map": {{ "{key}": ["5", "-6"] }} }}"#),
+    )
+    .unwrap();
+    let output = run_cli(&["run", "-a", program, "-n", "6"]);
+    fs::remove_dir_all(&dir).unwrap();
+    let expected = felt_fmt::parse("-6").unwrap_err().to_string();
+    assert!(
+        output.contains(&format!(
+            "Invalid `advice_map` value for key `{key}` - {expected}"
+        )),
+        "{output}"
+    );
+}
+
+#[test]
+fn run_with_layered_inputs() {
+    let dir = temp_dir("layered");
+    let program_path = dir.join("layered.masm");
+    fs::write(&program_path, "begin adv_push.2 mul mul add end").unwrap();
+    fs::write(
+        program_path.with_extension("inputs"),
+        r#"{ "stack_init": ["5", "6"], "advice_tape": ["2"] }"#,
+    )
+    .unwrap();
+    let program = path_str(&program_path);
+
+    // the stack of the file is replaced: [2, 1]; the advice tape is appended to: [2, 3]; thus,
+    // the output is 3 * 2 * 2 + 1
+    let appended = run_cli(&[
+        "run",
+        "-a",
+        program,
+        "-n",
+        "1",
+        "--stack",
+        "1",
+        "2",
+        "--advice",
+        "3",
+        "--append-advice",
+    ]);
+
+    // without the flag, the advice tape of the file is replaced as well: [3, 4]; thus, the output
+    // is 4 * 3 * 2 + 1
+    let replaced = run_cli(&[
+        "run", "-a", program, "-n", "1", "--stack", "1", "2", "--advice", "3", "4",
+    ]);
+
+    // inputs of the file are used when no arguments are given: the output is 2 * 2 * 6 + 5
+    fs::write(
+        program_path.with_extension("inputs"),
+        r#"{ "stack_init": ["5", "6"], "advice_tape": ["2", "2"] }"#,
+    )
+    .unwrap();
+    let from_file = run_cli(&["run", "-a", program, "-n", "1"]);
+
+    // verification bundles record the stack inputs which the program was proven with
+    let bundle_path = dir.join("layered.bundle");
+    let bundle = path_str(&bundle_path);
+    run_cli(&["prove", "-a", program, "-b", bundle, "--stack", "1", "2"]);
+    let verified = run_cli(&["verify-bundle", "-b", bundle]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(appended.contains("Output: [13]"), "{appended}");
+    assert!(replaced.contains("Output: [25]"), "{replaced}");
+    assert!(from_file.contains("Output: [29]"), "{from_file}");
+    assert!(verified.contains("Verification complete"), "{verified}");
+}
+
+#[test]
+fn write_unchanged_outputs() {
+    let dir = temp_dir("unchanged");
+    let program_path = dir.join("unchanged.masm");
+    fs::write(&program_path, "begin push.1 push.2 add end").unwrap();
+    let outputs_path = dir.join("unchanged.outputs");
+    let program = path_str(&program_path);
+    let outputs = path_str(&outputs_path);
+    let args = ["run", "-a", program, "-o", outputs, "--skip-unchanged"];
+
+    // writing identical outputs again is skipped
+    let first = run_cli_stderr(&args);
+    let second = run_cli_stderr(&args);
+    assert!(!first.contains("outputs unchanged"), "{first}");
+    assert!(second.contains("outputs unchanged"), "{second}");
+
+    // without the flag, or with different outputs, the file is written
+    let without_flag = run_cli_stderr(&args[..args.len() - 1]);
+    assert!(
+        !without_flag.contains("outputs unchanged"),
+        "{without_flag}"
+    );
+
+    fs::write(&program_path, "begin push.1 push.3 add end").unwrap();
+    let third = run_cli_stderr(&args);
+    let written = fs::read_to_string(&outputs_path).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(!third.contains("outputs unchanged"), "{third}");
+    assert!(written.contains("\"4\""), "{written}");
+}
+
+#[test]
+fn write_outputs_commitment() {
+    let dir = temp_dir("commitment");
+    let program_path = dir.join("commitment.masm");
+    fs::write(&program_path, "begin push.1 push.2 add end").unwrap();
+    let outputs_path = dir.join("commitment.outputs");
+    let program = path_str(&program_path);
+    let outputs = path_str(&outputs_path);
+
+    // the commitment is written only when requested, and it is the same for the same outputs
+    run_cli(&["run", "-a", program, "-o", outputs]);
+    let plain = fs::read_to_string(&outputs_path).unwrap();
+    run_cli(&["run", "-a", program, "-o", outputs, "--commitment"]);
+    let first = fs::read_to_string(&outputs_path).unwrap();
+    run_cli(&["run", "-a", program, "-o", outputs, "--commitment"]);
+    let second = fs::read_to_string(&outputs_path).unwrap();
+    assert!(!plain.contains("\"commitment\""), "{plain}");
+    assert!(first.contains("\"commitment\""), "{first}");
+    assert_eq!(first, second);
+
+    // outputs with a matching commitment can be used as expected outputs
+    let output = run_cli(&["run", "-a", program, "-e", outputs]);
+    assert!(output.contains("match expected outputs"), "{output}");
+
+    // a commitment which does not match the stack outputs is rejected
+    fs::write(&outputs_path, first.replacen("\"3\"", "\"4\"", 1)).unwrap();
+    let output = run_cli(&["run", "-a", program, "-e", outputs]);
+    assert!(
+        output.contains("Output commitment") && output.contains("does not match"),
+        "{output}"
+    );
+
+    // the commitment changes with the outputs
+    fs::write(&program_path, "begin push.1 push.3 add end").unwrap();
+    run_cli(&["run", "-a", program, "-o", outputs, "--commitment"]);
+    let third = fs::read_to_string(&outputs_path).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    let commitment = |outputs: &str| {
+        outputs
+            .lines()
+            .find(|l| l.contains("commitment"))
+            .map(str::to_string)
+    };
+    assert_ne!(commitment(&first), commitment(&third));
+}
+
+#[test]
+fn report_assertion_error_code() {
+    let dir = temp_dir("error-code");
+    let program_path = dir.join("error_code.masm");
+    fs::write(&program_path, "begin assert.err=1 assert.err=2 end").unwrap();
+    let program = path_str(&program_path);
+
+    // stack: [0, 1] (1 at the top); thus, the second assertion fails
+    let run = run_cli(&["run", "-a", program, "--stack", "0", "1"]);
+    let prove = run_cli(&["prove", "-a", program, "--stack", "0", "1"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    for output in [run, prove] {
+        assert!(output.contains("with error code 2"), "{output}");
+        assert!(!output.contains("with error code 1"), "{output}");
+    }
+}
+
+#[test]
+fn run_with_summary() {
+    let dir = temp_dir("summary");
+    let program_path = dir.join("summary.masm");
+    fs::write(&program_path, "begin push.1 push.2 push.3 adv_push.1 end").unwrap();
+
+    // the program is executed in 8 cycles: SPAN, PAD, INCR, PUSH, PUSH, READ, NOOP, END (the
+    // NOOP pads the operation groups of the batch to a power of two); it leaves 4 new items on
+    // the stack of 16 items and reads 1 of the 2 advice values
+    let program = path_str(&program_path);
+    let output = run_cli(&["run", "-a", program, "--advice", "5", "6", "--summary"]);
+    let no_summary = run_cli(&["run", "-a", program, "--advice", "5", "6"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.contains("Execution summary:"), "{output}");
+    assert!(output.contains("cycles executed:        8"), "{output}");
+    assert!(output.contains("final stack depth:      20"), "{output}");
+    assert!(
+        output.contains("advice values consumed: 1 of 2"),
+        "{output}"
+    );
+
+    // the summary is printed only when requested
+    assert!(!no_summary.contains("Execution summary:"), "{no_summary}");
+}
+
+#[test]
+fn read_artifact_of_wrong_kind() {
+    // write a file with a header of each kind; the rest of the file does not matter since the
+    // header is checked first
+    let dir = temp_dir("artifacts");
+    let artifact_path = |kind: ArtifactKind| {
+        let mut bytes = Vec::new();
+        write_header(&mut bytes, kind);
+        let path = dir.join(format!("{kind:?}").to_lowercase());
+        fs::write(&path, bytes).unwrap();
+        path
+    };
+    let program_path = dir.join("program.masm");
+    fs::write(&program_path, "begin push.1 end").unwrap();
+    let proof_path = artifact_path(ArtifactKind::Proof);
+    let library_path = artifact_path(ArtifactKind::Library);
+    let bundle_path = artifact_path(ArtifactKind::Bundle);
+
+    // the verifier reads outputs before the proof
+    fs::write(
+        proof_path.with_extension("outputs"),
+        r#"{"stack": [], "overflow_addrs": []}"#,
+    )
+    .unwrap();
+    fs::write(
+        library_path.with_extension("outputs"),
+        r#"{"stack": [], "overflow_addrs": []}"#,
+    )
+    .unwrap();
+
+    let hash = "00".repeat(32);
+    let verify = |path: &Path| run_cli(&["verify", "-p", path_str(path), "-h", &hash]);
+    let verify_bundle = |path: &Path| run_cli(&["verify-bundle", "-b", path_str(path)]);
+    let run_with_library =
+        |path: &Path| run_cli(&["run", "-a", path_str(&program_path), "-l", path_str(path)]);
+
+    let proof_reader = verify(&library_path);
+    let bundle_reader_proof = verify_bundle(&proof_path);
+    let bundle_reader_library = verify_bundle(&library_path);
+    let library_reader_proof = run_with_library(&proof_path);
+    let library_reader_bundle = run_with_library(&bundle_path);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(
+        proof_reader.contains("expected a proof file but found a library (.masl)"),
+        "{proof_reader}"
+    );
+    assert!(
+        bundle_reader_proof.contains("expected a verification bundle but found a proof file"),
+        "{bundle_reader_proof}"
+    );
+    assert!(
+        bundle_reader_library
+            .contains("expected a verification bundle but found a library (.masl)"),
+        "{bundle_reader_library}"
+    );
+    assert!(
+        library_reader_proof.contains("expected a library (.masl) but found a proof file"),
+        "{library_reader_proof}"
+    );
+    assert!(
+        library_reader_bundle
+            .contains("expected a library (.masl) but found a verification bundle"),
+        "{library_reader_bundle}"
+    );
+}
+
+#[test]
+fn run_compiled_program() {
+    let dir = temp_dir("compiled");
+    let program_path = dir.join("compiled.masm");
+    let compiled_path = dir.join("compiled.masb");
+    fs::write(
+        &program_path,
+        "use.std::sys begin push.3 push.5 add exec.sys::truncate_stack end",
+    )
+    .unwrap();
+
+    // the compiled program has the same hash as the source program and produces the same outputs
+    let compiled = run_cli(&[
+        "compile",
+        "-a",
+        path_str(&program_path),
+        "-o",
+        path_str(&compiled_path),
+    ]);
+    let from_source = run_cli(&["run", "-a", path_str(&program_path), "-n", "1"]);
+    let from_compiled = run_cli(&["run", "-a", path_str(&compiled_path), "-n", "1"]);
+    let compiled_hash = run_cli(&["compile", "-a", path_str(&compiled_path)]);
+
+    // a file which is not a compiled program is rejected
+    let not_compiled_path = dir.join("source.masb");
+    fs::copy(&program_path, &not_compiled_path).unwrap();
+    let not_compiled = run_cli(&["run", "-a", path_str(&not_compiled_path)]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let hash_line = |output: &str| {
+        output
+            .lines()
+            .find(|line| line.starts_with("program hash is"))
+            .map(str::to_string)
+    };
+    assert!(hash_line(&compiled).is_some(), "{compiled}");
+    assert_eq!(hash_line(&compiled), hash_line(&compiled_hash));
+    assert!(from_source.contains("Output: [8]"), "{from_source}");
+    assert!(from_compiled.contains("Output: [8]"), "{from_compiled}");
+    assert!(
+        not_compiled.contains("Failed to decode compiled program"),
+        "{not_compiled}"
+    );
+}
+
+#[test]
+fn outputs_in_hex_round_trip() {
+    // the program leaves 19 items on the stack, and thus, the overflow table is not empty
+    let dir = temp_dir("hex-outputs");
+    let program_path = dir.join("hex_outputs.masm");
+    fs::write(&program_path, "begin push.1 push.2 push.3 end").unwrap();
+    let program = path_str(&program_path);
+
+    let run_with_outputs = |format: &str| {
+        let path = dir.join(format!("{format}.outputs"));
+        let output = run_cli(&[
+            "run",
+            "-a",
+            program,
+            "-o",
+            path_str(&path),
+            "--output-format",
+            format,
+        ]);
+        assert!(path.exists(), "{output}");
+        path
+    };
+    let check_outputs = |path: &Path| run_cli(&["run", "-a", program, "-e", path_str(path)]);
+    let read_json = |path: &Path| {
+        serde_json::from_str::<serde_json::Value>(&fs::read_to_string(path).unwrap()).unwrap()
+    };
+    let values = |json: &serde_json::Value, field: &str| {
+        json[field]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| value.as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // in hex format, values of both fields are written in hex
+    let hex_path = run_with_outputs("hex");
+    let hex_outputs = read_json(&hex_path);
+    let stack = values(&hex_outputs, "stack");
+    let overflow_addrs = values(&hex_outputs, "overflow_addrs");
+    assert_eq!(vec!["0x3", "0x2", "0x1"], stack[..3]);
+    assert_eq!(4, overflow_addrs.len());
+    assert!(stack
+        .iter()
+        .chain(overflow_addrs.iter())
+        .all(|value| value.starts_with("0x")));
+    let hex_check = check_outputs(&hex_path);
+
+    // in the combined format, values are written in decimal and their hex copies are added
+    let both_path = run_with_outputs("both");
+    let both_outputs = read_json(&both_path);
+    assert_eq!(vec!["3", "2", "1"], values(&both_outputs, "stack")[..3]);
+    assert_eq!(stack, values(&both_outputs, "stack_hex"));
+    assert_eq!(overflow_addrs, values(&both_outputs, "overflow_addrs_hex"));
+    let both_check = check_outputs(&both_path);
+
+    // a file which mixes radixes (stack in hex, overflow addresses in decimal) can be read
+    let decimal_path = run_with_outputs("decimal");
+    let decimal_outputs = read_json(&decimal_path);
+    let mixed_path = dir.join("mixed.outputs");
+    let mixed_outputs = serde_json::json!({
+        "stack": stack,
+        "overflow_addrs": values(&decimal_outputs, "overflow_addrs"),
+    });
+    fs::write(&mixed_path, mixed_outputs.to_string()).unwrap();
+    let mixed_check = check_outputs(&mixed_path);
+
+    // a hex copy which does not match the values is rejected
+    let mismatched_path = dir.join("mismatched.outputs");
+    let mut mismatched_outputs = both_outputs;
+    mismatched_outputs["stack_hex"][0] = "0x4".into();
+    fs::write(&mismatched_path, mismatched_outputs.to_string()).unwrap();
+    let mismatched_check = check_outputs(&mismatched_path);
+    fs::remove_dir_all(&dir).unwrap();
+
+    for check in [hex_check, both_check, mixed_check] {
+        assert!(
+            check.contains("Program outputs match expected outputs"),
+            "{check}"
+        );
+    }
+    assert!(
+        mismatched_check.contains("do not match values of `stack_hex` output"),
+        "{mismatched_check}"
+    );
+}
+
+#[test]
+fn prove_with_seed() {
+    let dir = temp_dir("seed");
+    let program_path = dir.join("seed.masm");
+    fs::write(&program_path, "begin push.1 push.2 add end").unwrap();
+    let program = path_str(&program_path);
+
+    let prove = |name: &str, args: &[&str]| {
+        let bundle_path = dir.join(format!("{name}.bundle"));
+        let mut cli_args = vec!["prove", "-a", program, "-b", path_str(&bundle_path)];
+        cli_args.extend_from_slice(args);
+        run_cli(&cli_args);
+        let verify = run_cli(&["verify-bundle", "-b", path_str(&bundle_path)]);
+        assert!(verify.contains("Verification complete"), "{verify}");
+        fs::read(&bundle_path).unwrap()
+    };
+
+    // proofs generated without a seed are identical
+    let first = prove("first", &[]);
+    let second = prove("second", &[]);
+    let seeded = prove("seeded", &["--seed", &"07".repeat(32)]);
+    let reseeded = prove("reseeded", &["--seed", &format!("0x{}", "07".repeat(32))]);
+    let invalid_seed = run_cli_stderr(&["prove", "-a", program, "--seed", "07"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(first, second);
+
+    // proofs generated with the same seed are identical, and differ from the default ones
+    assert_eq!(seeded, reseeded);
+    assert_ne!(first, seeded);
+
+    assert!(
+        invalid_seed.contains("seed must be 32 bytes long"),
+        "{invalid_seed}"
+    );
+}
@@ -6,7 +6,7 @@ fn bitwise_and() {
     let asm_op = "u32checked_and push.0 u32checked_and push.0 u32checked_and push.65535 push.137 u32checked_and";
     let pub_inputs = vec![1, 1];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -15,7 +15,7 @@ fn bitwise_or() {
     let asm_op = "u32checked_or push.0 u32checked_or not push.0 u32checked_or push.65535 push.137 u32checked_or";
     let pub_inputs = vec![1, 1];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -24,7 +24,7 @@ fn bitwise_xor() {
     let asm_op = "u32checked_xor push.0 u32checked_xor push.1 u32checked_xor push.65535 push.137 u32checked_xor";
     let pub_inputs = vec![1, 1];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -32,5 +32,5 @@ fn all_operations() {
     let source = "begin u32checked_and push.0 u32checked_or push.0 u32checked_xor end";
     let pub_inputs = vec![1, 1];
 
-    build_test!(source, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_test!(source, &pub_inputs).prove_and_verify();
 }
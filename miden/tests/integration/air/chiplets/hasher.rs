@@ -8,7 +8,7 @@ fn rpperm() {
     let asm_op = "rpperm";
     let pub_inputs = rand_vector::<u64>(8);
 
-    build_op_test!(asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(asm_op, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -16,7 +16,7 @@ fn rphash() {
     let asm_op = "rphash";
     let pub_inputs = rand_vector::<u64>(8);
 
-    build_op_test!(asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(asm_op, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -36,8 +36,7 @@ fn mtree_get() {
         tree.depth() as u64,
     ];
 
-    build_op_test!(asm_op, &stack_inputs, &[], vec![tree])
-        .prove_and_verify(stack_inputs.to_vec(), false);
+    build_op_test!(asm_op, &stack_inputs, &[], vec![tree]).prove_and_verify();
 }
 
 #[test]
@@ -45,8 +44,7 @@ fn mtree_set() {
     let asm_op = "mtree_set";
     let (stack_inputs, tree) = build_mtree_update_test_inputs();
 
-    build_op_test!(asm_op, &stack_inputs, &[], vec![tree])
-        .prove_and_verify(stack_inputs.to_vec(), false);
+    build_op_test!(asm_op, &stack_inputs, &[], vec![tree]).prove_and_verify();
 }
 
 #[test]
@@ -54,7 +52,7 @@ fn mtree_cwm() {
     let asm_op = "mtree_cwm";
     let (stack_inputs, tree) = build_mtree_update_test_inputs();
 
-    build_op_test!(asm_op, &stack_inputs, &[], vec![tree]).prove_and_verify(stack_inputs, false);
+    build_op_test!(asm_op, &stack_inputs, &[], vec![tree]).prove_and_verify();
 }
 
 /// Helper function that builds a test stack and Merkle tree for testing mtree updates.
@@ -5,7 +5,7 @@ use vm_core::utils::ToElements;
 fn mem_load() {
     let asm_op = "mem_load.0 swap";
 
-    build_op_test!(asm_op).prove_and_verify(vec![], false);
+    build_op_test!(asm_op).prove_and_verify();
 }
 
 #[test]
@@ -13,7 +13,7 @@ fn mem_store() {
     let asm_op = "mem_store.0";
     let pub_inputs = vec![1];
 
-    build_op_test!(asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(asm_op, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -42,7 +42,7 @@ fn helper_mem_store() {
 fn mem_loadw() {
     let asm_op = "mem_loadw.0";
 
-    build_op_test!(asm_op).prove_and_verify(vec![], false);
+    build_op_test!(asm_op).prove_and_verify();
 }
 
 #[test]
@@ -50,7 +50,7 @@ fn mem_storew() {
     let asm_op = "mem_storew.0";
     let pub_inputs = vec![1, 2, 3, 4];
 
-    build_op_test!(asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(asm_op, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -59,7 +59,7 @@ fn write_read() {
 
     let pub_inputs = vec![4, 3, 2, 1];
 
-    build_test!(source, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_test!(source, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -81,7 +81,7 @@ fn update() {
     let source = "begin push.0.0.0.0 mem_loadw.0 mem_storew.0 swapw end";
     let pub_inputs = vec![8, 7, 6, 5, 4, 3, 2, 1];
 
-    build_test!(source, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_test!(source, &pub_inputs).prove_and_verify();
 }
 
 #[test]
@@ -89,5 +89,5 @@ fn incr_write_addr() {
     let source = "begin mem_storew.0 mem_storew.1 end";
     let pub_inputs = vec![4, 3, 2, 1];
 
-    build_test!(source, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_test!(source, &pub_inputs).prove_and_verify();
 }
@@ -9,7 +9,7 @@ fn empty_input() {
     let asm_op = "push.1 drop";
     let pub_inputs = vec![];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
 
 /// Test an empty starting stack but enough outputs that the overflow table is non-empty at the end.
@@ -18,7 +18,7 @@ fn empty_input_overflow_output() {
     let asm_ops = "push.17 push.18";
     let pub_inputs = vec![];
 
-    build_op_test!(&asm_ops, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_ops, &pub_inputs).prove_and_verify();
 }
 
 /// Test starting stack with some inputs but not full with no overflow outputs.
@@ -27,7 +27,7 @@ fn some_inputs() {
     let asm_op = "push.5 drop";
     let pub_inputs = vec![1, 2, 3, 4];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
 
 /// Test full starting stack with no overflow outputs.
@@ -36,7 +36,7 @@ fn full_inputs() {
     let asm_op = "push.17 drop";
     let pub_inputs = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
 
 /// Test a script that finishes with enough outputs that the overflow table is non-empty at the end.
@@ -45,7 +45,7 @@ fn full_inputs_overflow_outputs() {
     let asm_ops = "push.17 push.18";
     let pub_inputs = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
 
-    build_op_test!(&asm_ops, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_ops, &pub_inputs).prove_and_verify();
 }
 
 /// Test a script initialized with enough inputs that the overflow table is non-empty at the start
@@ -58,7 +58,7 @@ fn overflow_inputs() {
         1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
     ];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
 
 /// Test a script initialized with enough inputs that the overflow table is non-empty at the start
@@ -70,5 +70,5 @@ fn overflow_inputs_overflow_outputs() {
         1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
     ];
 
-    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify();
 }
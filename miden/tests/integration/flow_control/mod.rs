@@ -1,8 +1,4 @@
-use crate::{
-    build_test,
-    helpers::{Test, TestError},
-};
-use vm_core::ProgramInputs;
+use crate::{build_test, helpers::TestError};
 
 // SIMPLE FLOW CONTROL TESTS
 // ================================================================================================
@@ -162,7 +158,7 @@ fn local_fn_call() {
     let test = build_test!(source, &inputs);
     test.expect_stack(&[2, 1]);
 
-    test.prove_and_verify(inputs, false);
+    test.prove_and_verify();
 }
 
 #[test]
@@ -184,7 +180,40 @@ fn local_fn_call_with_mem_access() {
     let test = build_test!(source, &[3, 7]);
     test.expect_stack(&[1]);
 
-    test.prove_and_verify(vec![3, 7], false);
+    test.prove_and_verify();
+}
+
+#[test]
+fn nested_fn_calls_with_locals() {
+    // each called procedure is executed in its own memory context; thus, values which bar and foo
+    // save into memory[0] do not overwrite the values saved into memory[0] by their callers, and
+    // locals of each procedure are allocated in the memory context of the procedure
+    let source = "
+        proc.bar.1
+            loc_store.0
+            push.100 mem_store.0
+            loc_load.0 push.10 mul
+            swap drop
+        end
+
+        proc.foo.1
+            loc_store.0
+            push.7 mem_store.0
+            loc_load.0 call.bar
+            loc_load.0 add
+            mem_load.0 add
+            swap drop
+        end
+
+        begin
+            push.3 mem_store.0
+            push.2 call.foo
+            mem_load.0
+        end";
+
+    // foo(2) = bar(2) + 2 + 7 = 29
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[3, 29]);
 }
 
 #[test]
@@ -200,14 +229,8 @@ fn simple_syscall() {
             syscall.foo
         end";
 
-    // TODO: update and use macro?
-    let test = Test {
-        source: program_source.to_string(),
-        kernel: Some(kernel_source.to_string()),
-        inputs: ProgramInputs::from_stack_inputs(&[1, 2]).unwrap(),
-        in_debug_mode: false,
-    };
+    let test = build_test!(program_source, &[1, 2]).with_kernel(kernel_source);
     test.expect_stack(&[3]);
 
-    test.prove_and_verify(vec![1, 2], false);
+    test.prove_and_verify();
 }
@@ -1,3 +1,9 @@
+use test_utils::build_test;
+use vm_core::{
+    artifact::{write_header, ArtifactKind, HEADER_LEN},
+    utils::Serializable,
+};
+
 mod helpers;
 
 mod air;
@@ -6,6 +12,9 @@ mod flow_control;
 mod operations;
 mod stdlib;
 
+#[cfg(feature = "tracing")]
+mod telemetry;
+
 // TESTS
 // ================================================================================================
 
@@ -14,10 +23,127 @@ fn simple_program() {
     build_test!("begin push.1 push.2 add end").expect_stack(&[3]);
 }
 
+#[test]
+fn compile_and_run_program() {
+    let outputs = miden::compile_and_run("begin push.1 push.2 add end", &[], &[]).unwrap();
+    assert_eq!(3, outputs.stack()[0]);
+
+    // failures of each phase are reported separately
+    let err = miden::compile_and_run("begin push.1 pop end", &[], &[]).unwrap_err();
+    assert!(matches!(err, miden::RunError::Compile(_)));
+    let err = miden::compile_and_run("begin assert end", &[0], &[]).unwrap_err();
+    assert!(matches!(err, miden::RunError::Execution(_)));
+}
+
 #[test]
 fn multi_output_program() {
     let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
-    test.prove_and_verify(vec![1, 2, 3], false);
+    test.prove_and_verify();
+}
+
+#[test]
+fn deterministic_proofs() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile().unwrap();
+    let options = helpers::ProofOptions::default();
+
+    let (outputs, proof) = prover::prove(&program, &test.inputs(), &options).unwrap();
+    let (_, second_proof) = prover::prove(&program, &test.inputs(), &options).unwrap();
+    assert_eq!(proof.to_bytes(), second_proof.to_bytes());
+
+    let pub_inputs = [1, 2, 3];
+    assert!(miden::verify(program.hash(), &pub_inputs, &outputs, proof.clone()).is_ok());
+    assert!(miden::verify(program.hash(), &pub_inputs, &outputs, second_proof).is_ok());
+
+    // the program hash is the default seed of the execution trace
+    let seed = program.hash().into();
+    let (_, seeded_proof) =
+        miden::prove_with_seed(&program, &test.inputs(), &options, seed).unwrap();
+    assert_eq!(proof.to_bytes(), seeded_proof.to_bytes());
+
+    // proofs generated with the same seed are identical, and proofs generated with different
+    // seeds differ, but all of them are valid
+    let (_, first_proof) =
+        miden::prove_with_seed(&program, &test.inputs(), &options, [7; 32]).unwrap();
+    let (_, second_proof) =
+        miden::prove_with_seed(&program, &test.inputs(), &options, [7; 32]).unwrap();
+    assert_eq!(first_proof.to_bytes(), second_proof.to_bytes());
+    assert_ne!(proof.to_bytes(), first_proof.to_bytes());
+    assert!(miden::verify(program.hash(), &pub_inputs, &outputs, first_proof).is_ok());
+}
+
+#[test]
+fn proof_compatibility() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile().unwrap();
+
+    let options = helpers::ProofOptions::with_96_bit_security();
+    let (_, proof) = prover::prove(&program, &test.inputs(), &options).unwrap();
+
+    // proofs of different programs generated with the same options are compatible
+    let other_program = build_test!("begin push.1 push.2 add end")
+        .compile()
+        .unwrap();
+    let (_, other_proof) = prover::prove(&other_program, &test.inputs(), &options).unwrap();
+    assert!(miden::proofs_compatible(&proof, &other_proof));
+    assert_eq!(
+        miden::proof_params(&proof),
+        miden::proof_params(&other_proof)
+    );
+
+    // proofs generated with different security levels are not compatible
+    let options = helpers::ProofOptions::with_128_bit_security();
+    let (_, secure_proof) = prover::prove(&program, &test.inputs(), &options).unwrap();
+    assert!(!miden::proofs_compatible(&proof, &secure_proof));
+    assert_eq!(16, miden::proof_params(&secure_proof).blowup_factor);
+}
+
+#[test]
+fn verification_bundle() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile().unwrap();
+    let (outputs, proof) =
+        prover::prove(&program, &test.inputs(), &helpers::ProofOptions::default()).unwrap();
+
+    // a bundle survives a serialization round trip and can be verified on its own
+    let bundle = miden::VerificationBundle::new(program.hash(), vec![1, 2, 3], outputs, proof);
+    let bytes = bundle.to_bytes();
+    let bundle = miden::VerificationBundle::read_from_bytes(&bytes).unwrap();
+    assert_eq!(program.hash(), bundle.program_hash());
+    assert_eq!(&[1, 2, 3], bundle.stack_inputs());
+    assert_eq!(&[6, 1, 0, 0], &bundle.outputs().stack()[..4]);
+    assert_eq!(bytes, bundle.to_bytes());
+    assert!(bundle.verify().is_ok());
+
+    // a bundle with tampered inputs fails verification
+    let bundle = miden::VerificationBundle::read_from_bytes(&bytes).unwrap();
+    let tampered = miden::VerificationBundle::new(
+        bundle.program_hash(),
+        vec![1, 2, 4],
+        bundle.outputs().clone(),
+        helpers::StarkProof::from_bytes(&bundle.proof().to_bytes()).unwrap(),
+    );
+    assert!(tampered.verify().is_err());
+
+    // truncated bundle cannot be deserialized
+    assert!(miden::VerificationBundle::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // a bundle is not accepted as an artifact of another kind, and vice versa
+    let err = miden::MaslLibrary::read_from_bytes(&bytes).unwrap_err();
+    assert_eq!(
+        "expected a library (.masl) but found a verification bundle",
+        err.to_string()
+    );
+    let mut library_bytes = Vec::new();
+    write_header(&mut library_bytes, ArtifactKind::Library);
+    library_bytes.extend_from_slice(&bytes[HEADER_LEN..]);
+    let err = miden::VerificationBundle::read_from_bytes(&library_bytes)
+        .err()
+        .unwrap();
+    assert_eq!(
+        "expected a verification bundle but found a library (.masl)",
+        err.to_string()
+    );
 }
 
 // MACROS TO BUILD TESTS
@@ -40,34 +166,14 @@ fn multi_output_program() {
 macro_rules! build_op_test {
     ($op_str:expr) => {{
         let source = format!("begin {} end", $op_str);
-        $crate::build_test!(&source)
+        test_utils::build_test!(&source)
     }};
     ($op_str:expr, $($tail:tt)+) => {{
         let source = format!("begin {} end", $op_str);
-        $crate::build_test!(&source, $($tail)+)
+        test_utils::build_test!(&source, $($tail)+)
     }};
 }
 
-/// Returns a Test struct in non debug mode from the provided source string, and any specified
-/// stack and advice inputs.
-///
-/// Parameters are expected in the following order:
-/// `source`, `stack_inputs` (optional), `advice_tape` (optional), `advice_sets` (optional)
-///
-/// * `source`: a well-formed source string.
-/// * `stack_inputs` (optional): the initial inputs which must be at the top of the stack before
-/// executing the `source`. Stack inputs can be provided independently without any advice inputs.
-/// * `advice_tape` (optional): the initial advice tape values. When provided, `stack_inputs` and
-/// `advice_sets` are also expected.
-/// * `advice_sets` (optional): the initial advice set values. When provided, `stack_inputs` and
-/// `advice_tape` are also expected.
-#[macro_export]
-macro_rules! build_test {
-    ($($params:tt)+) => {{
-        $crate::build_test_by_mode!(false, $($params)+)
-    }}
-}
-
 /// Returns a Test struct in debug mode from the provided source string and any specified stack
 /// and advice inputs.
 ///
@@ -84,43 +190,6 @@ macro_rules! build_test {
 #[macro_export]
 macro_rules! build_debug_test {
     ($($params:tt)+) => {{
-        $crate::build_test_by_mode!(true, $($params)+)
+        test_utils::build_test!($($params)+).with_debug_mode(true)
     }}
 }
-
-/// Returns a Test struct in the specified debug or non-debug mode using the provided source string
-/// and any specified stack and advice inputs.
-///
-/// Parameters start with a boolean flag, `in_debug_mode`, specifying whether the test is built in
-/// debug or non-debug mode. After that, they match the parameters of `build_test` and
-///`build_debug_test` macros.
-///
-/// This macro is an internal test builder, and is not intended to be called directly from tests.
-/// Instead, the build_test and build_debug_test wrappers should be used.
-#[macro_export]
-macro_rules! build_test_by_mode {
-    ($in_debug_mode:expr, $source:expr) => {{
-        $crate::helpers::Test::new($source, $in_debug_mode)
-    }};
-    ($in_debug_mode:expr, $source:expr, $stack_inputs:expr) => {{
-        let inputs = $crate::helpers::ProgramInputs::new($stack_inputs, &[], vec![]).unwrap();
-
-        $crate::helpers::Test {
-            source: String::from($source),
-            kernel: None,
-            inputs,
-            in_debug_mode: $in_debug_mode,
-        }
-    }};
-    ($in_debug_mode:expr, $source:expr, $stack_inputs:expr, $advice_tape:expr, $advice_sets:expr) => {{
-        let inputs =
-            $crate::helpers::ProgramInputs::new($stack_inputs, $advice_tape, $advice_sets).unwrap();
-
-        $crate::helpers::Test {
-            source: String::from($source),
-            kernel: None,
-            inputs,
-            in_debug_mode: $in_debug_mode,
-        }
-    }};
-}
@@ -2,8 +2,8 @@ use proptest::prelude::*;
 use rand_utils::rand_value;
 use vm_core::{Felt, FieldElement, StarkField, WORD_LEN};
 
-use crate::build_op_test;
 use crate::helpers::{prop_randw, TestError};
+use crate::{build_op_test, build_test};
 
 // FIELD OPS ASSERTIONS - MANUAL TESTS
 // ================================================================================================
@@ -46,6 +46,28 @@ fn assert_eq_fail() {
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
+#[test]
+fn assert_with_error_code() {
+    let source = "begin assert.err=1 assertz.err=2 assert_eq.err=3 end";
+
+    // the error code of the assertion which fails is reported
+    let test = build_test!(source, &[1, 1, 0, 1]);
+    test.expect_stack(&[]);
+
+    let test = build_test!(source, &[1, 1, 0, 0]);
+    test.expect_error(TestError::ExecutionError("err_code: 1"));
+
+    let test = build_test!(source, &[1, 1, 1, 1]);
+    test.expect_error(TestError::ExecutionError("err_code: 2"));
+
+    let test = build_test!(source, &[2, 1, 0, 1]);
+    test.expect_error(TestError::ExecutionError("err_code: 3"));
+
+    // assertions without an error code report code 0
+    let test = build_test!("begin assert.err=1 assert end", &[0, 1]);
+    test.expect_error(TestError::ExecutionError("err_code: 0"));
+}
+
 // FIELD OPS ARITHMETIC - MANUAL TESTS
 // ================================================================================================
 
@@ -551,6 +573,30 @@ fn gte() {
     test_felt_comparison_op("gte", 0, 1, 1);
 }
 
+#[test]
+fn lt_b() {
+    // Results in 1 if a < b for a starting stack of [a, ...] and 0 otherwise
+    test_felt_comparison_op_b("lt", 1, 0, 0);
+}
+
+#[test]
+fn lte_b() {
+    // Results in 1 if a <= b for a starting stack of [a, ...] and 0 otherwise
+    test_felt_comparison_op_b("lte", 1, 1, 0);
+}
+
+#[test]
+fn gt_b() {
+    // Results in 1 if a > b for a starting stack of [a, ...] and 0 otherwise
+    test_felt_comparison_op_b("gt", 0, 0, 1);
+}
+
+#[test]
+fn gte_b() {
+    // Results in 1 if a >= b for a starting stack of [a, ...] and 0 otherwise
+    test_felt_comparison_op_b("gte", 0, 1, 1);
+}
+
 // FIELD OPS ARITHMETIC - RANDOMIZED TESTS
 // ================================================================================================
 
@@ -862,3 +908,32 @@ fn test_felt_comparison_op(asm_op: &str, expect_if_lt: u64, expect_if_eq: u64, e
     let test = build_op_test!(asm_op, &[a_mod + 1, a]);
     test.expect_stack(&[expect_if_gt]);
 }
+
+/// This helper function tests a felt comparison operation with b provided as an immediate value
+/// against the same cases as the operation with b provided via the stack.
+fn test_felt_comparison_op_b(
+    asm_op: &str,
+    expect_if_lt: u64,
+    expect_if_eq: u64,
+    expect_if_gt: u64,
+) {
+    let smaller = (1 << 48) + 1;
+    let values = [0, 1, smaller, smaller + 1, 1 << 49, Felt::MODULUS - 1];
+    for a in values {
+        for b in values {
+            let expected = if a < b {
+                expect_if_lt
+            } else if a == b {
+                expect_if_eq
+            } else {
+                expect_if_gt
+            };
+            let test = build_op_test!(format!("{asm_op}.{b}").as_str(), &[a]);
+            test.expect_stack(&[expected]);
+        }
+    }
+
+    // the rest of the stack isn't affected
+    let test = build_op_test!(format!("{asm_op}.{smaller}").as_str(), &[7, 1]);
+    test.expect_stack(&[expect_if_lt, 7]);
+}
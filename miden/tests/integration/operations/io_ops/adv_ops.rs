@@ -32,6 +32,41 @@ fn adv_push_invalid() {
     test.expect_error(TestError::ExecutionError("AdviceTapeReadFailed"));
 }
 
+// ADVICE TAPE CONSUMPTION
+// ================================================================================================
+
+#[test]
+fn adv_tape_fully_consumed() {
+    let advice_tape = [1, 2, 3, 4];
+    let test = build_op_test!("adv_push.4", &[], &advice_tape, vec![]);
+
+    let trace = test.execute().unwrap();
+    assert_eq!(0, trace.advice_remaining());
+}
+
+#[test]
+fn adv_tape_partially_consumed() {
+    let advice_tape = [1, 2, 3, 4, 5, 6];
+    let test = build_op_test!("adv_push.4", &[], &advice_tape, vec![]);
+
+    // the two elements which were not read should be reported as remaining
+    let trace = test.execute().unwrap();
+    assert_eq!(2, trace.advice_remaining());
+}
+
+#[test]
+fn adv_tape_over_read() {
+    let advice_tape = [1, 2, 3];
+    let test = build_op_test!("adv_push.4", &[], &advice_tape, vec![]);
+
+    match test.execute() {
+        Err(err) => assert!(err
+            .to_string()
+            .starts_with("advice tape exhausted at cycle")),
+        Ok(_) => panic!("reading past the end of the advice tape should fail"),
+    }
+}
+
 // OVERWRITING VALUES ON THE STACK (LOAD)
 // ================================================================================================
 
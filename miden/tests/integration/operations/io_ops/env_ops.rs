@@ -1,8 +1,6 @@
-use crate::{build_op_test, build_test, helpers::Test};
+use crate::{build_op_test, build_test};
 use processor::FMP_MIN;
-use vm_core::{
-    code_blocks::CodeBlock, stack::STACK_TOP_SIZE, Operation, ProgramInputs, StarkField, Word,
-};
+use vm_core::{code_blocks::CodeBlock, stack::STACK_TOP_SIZE, Operation, StarkField, Word};
 
 // SDEPTH INSTRUCTION
 // ================================================================================================
@@ -142,19 +140,41 @@ fn caller() {
             call.bar
         end";
 
-    // TODO: update and use macro?
-    let test = Test {
-        source: program_source.to_string(),
-        kernel: Some(kernel_source.to_string()),
-        inputs: ProgramInputs::from_stack_inputs(&[1, 2, 3, 4, 5]).unwrap(),
-        in_debug_mode: false,
-    };
+    let test = build_test!(program_source, &[1, 2, 3, 4, 5]).with_kernel(kernel_source);
     // top 4 elements should be overwritten with the hash of `bar` procedure, but the 5th
     // element should remain untouched
     let bar_hash = build_bar_hash();
     test.expect_stack(&[bar_hash[3], bar_hash[2], bar_hash[1], bar_hash[0], 1]);
 
-    test.prove_and_verify(vec![1, 2, 3, 4, 5], false);
+    test.prove_and_verify();
+}
+
+// PUSH_MAST_ROOT INSTRUCTION
+// ================================================================================================
+
+#[test]
+fn push_mast_root() {
+    let source = "
+        proc.foo
+            push_mast_root
+            eqw assert
+            dropw
+        end
+
+        begin
+            push_mast_root
+            call.foo
+        end";
+
+    // the hash of the program is pushed onto the stack with its last element at the top; this
+    // is the case even when the instruction is executed inside a procedure (the procedure
+    // asserts that it gets the same hash as the caller, and drops it to keep the stack depth)
+    let test = build_test!(source, &[1, 2]);
+    let hash: Word = test.compile().unwrap().hash().into();
+    let hash = hash.map(|element| element.as_int());
+    test.expect_stack(&[hash[3], hash[2], hash[1], hash[0], 2, 1]);
+
+    test.prove_and_verify();
 }
 
 fn build_bar_hash() -> [u64; 4] {
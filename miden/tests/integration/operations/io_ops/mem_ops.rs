@@ -1,4 +1,5 @@
 use super::{build_op_test, build_test};
+use processor::{ExecutionError, ExecutionOptions};
 use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt, StarkField};
 
 // LOADING SINGLE ELEMENT ONTO THE STACK (MLOAD)
@@ -184,3 +185,32 @@ fn read_after_write() {
     let test = build_op_test!("mem_storew.0 dropw mem_loadw.0", &[1, 2, 3, 4, 5, 6, 7, 8]);
     test.expect_stack(&[8, 7, 6, 5]);
 }
+
+// BOUNDED MEMORY
+// ================================================================================================
+
+#[test]
+fn mem_store_beyond_max_memory_address() {
+    let options = ExecutionOptions::default().with_max_memory_address(100);
+
+    // --- accesses up to the cap are allowed -----------------------------------------------------
+    let test = build_test!("begin push.7 mem_store.100 mem_load.100 end");
+    let program = test.compile().unwrap();
+    let trace = processor::execute_with_options(&program, &test.inputs(), options).unwrap();
+    assert_eq!(7, trace.program_outputs().stack()[0]);
+
+    // --- storing beyond the cap traps -----------------------------------------------------------
+    let test = build_test!("begin push.7 mem_store.101 end");
+    let program = test.compile().unwrap();
+    match processor::execute_with_options(&program, &test.inputs(), options) {
+        Err(ExecutionError::MemoryOutOfBounds { addr, cap }) => {
+            assert_eq!(101, addr);
+            assert_eq!(100, cap);
+        }
+        Err(err) => panic!("expected memory out of bounds error, but got {err}"),
+        Ok(_) => panic!("expected memory out of bounds error, but execution succeeded"),
+    }
+
+    // --- without the cap, the same program succeeds ---------------------------------------------
+    assert!(test.execute().is_ok());
+}
@@ -192,6 +192,27 @@ fn u32checked_lt_fail() {
     test_inputs_out_of_bounds(asm_op, 2);
 }
 
+#[test]
+fn u32checked_lt_b() {
+    let asm_op = "u32checked_lt";
+
+    // should push 1 to the stack when a < b and 0 otherwise
+    test_comparison_op_b(asm_op, 1, 0, 0);
+}
+
+#[test]
+fn u32checked_lt_b_fail() {
+    let asm_op = "u32checked_lt";
+
+    // should fail when b is out of bounds and provided as a parameter
+    test_param_out_of_bounds(asm_op, U32_BOUND);
+
+    // should fail when b is a valid parameter but a is out of bounds
+    let asm_op = format!("{}.{}", asm_op, 1);
+    let test = build_op_test!(&asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 #[test]
 fn u32unchecked_lt() {
     let asm_op = "u32unchecked_lt";
@@ -219,6 +240,27 @@ fn u32checked_lte_fail() {
     test_inputs_out_of_bounds(asm_op, 2);
 }
 
+#[test]
+fn u32checked_lte_b() {
+    let asm_op = "u32checked_lte";
+
+    // should push 1 to the stack when a <= b and 0 otherwise
+    test_comparison_op_b(asm_op, 1, 1, 0);
+}
+
+#[test]
+fn u32checked_lte_b_fail() {
+    let asm_op = "u32checked_lte";
+
+    // should fail when b is out of bounds and provided as a parameter
+    test_param_out_of_bounds(asm_op, U32_BOUND);
+
+    // should fail when b is a valid parameter but a is out of bounds
+    let asm_op = format!("{}.{}", asm_op, 1);
+    let test = build_op_test!(&asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 #[test]
 fn u32unchecked_lte() {
     let asm_op = "u32unchecked_lte";
@@ -246,6 +288,27 @@ fn u32checked_gt_fail() {
     test_inputs_out_of_bounds(asm_op, 2);
 }
 
+#[test]
+fn u32checked_gt_b() {
+    let asm_op = "u32checked_gt";
+
+    // should push 1 to the stack when a > b and 0 otherwise
+    test_comparison_op_b(asm_op, 0, 0, 1);
+}
+
+#[test]
+fn u32checked_gt_b_fail() {
+    let asm_op = "u32checked_gt";
+
+    // should fail when b is out of bounds and provided as a parameter
+    test_param_out_of_bounds(asm_op, U32_BOUND);
+
+    // should fail when b is a valid parameter but a is out of bounds
+    let asm_op = format!("{}.{}", asm_op, 1);
+    let test = build_op_test!(&asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 #[test]
 fn u32unchecked_gt() {
     let asm_op = "u32unchecked_gt";
@@ -273,6 +336,27 @@ fn u32checked_gte_fail() {
     test_inputs_out_of_bounds(asm_op, 2);
 }
 
+#[test]
+fn u32checked_gte_b() {
+    let asm_op = "u32checked_gte";
+
+    // should push 1 to the stack when a >= b and 0 otherwise
+    test_comparison_op_b(asm_op, 0, 1, 1);
+}
+
+#[test]
+fn u32checked_gte_b_fail() {
+    let asm_op = "u32checked_gte";
+
+    // should fail when b is out of bounds and provided as a parameter
+    test_param_out_of_bounds(asm_op, U32_BOUND);
+
+    // should fail when b is a valid parameter but a is out of bounds
+    let asm_op = format!("{}.{}", asm_op, 1);
+    let test = build_op_test!(&asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 #[test]
 fn u32unchecked_gte() {
     let asm_op = "u32unchecked_gte";
@@ -515,6 +599,41 @@ fn test_comparison_op(asm_op: &str, expected_lt: u64, expected_eq: u64, expected
     test.expect_stack(&[expected, c]);
 }
 
+/// This helper function tests that the provided assembly comparison operation with b provided as
+/// an immediate value pushes the expected value to the stack for each of the less than, equal to,
+/// or greater than comparisons tested.
+fn test_comparison_op_b(asm_op: &str, expected_lt: u64, expected_eq: u64, expected_gt: u64) {
+    let build_asm_op = |param: u32| format!("{}.{}", asm_op, param);
+
+    // --- simple cases ---------------------------------------------------------------------------
+    let test = build_op_test!(build_asm_op(1).as_str(), &[0]);
+    test.expect_stack(&[expected_lt]);
+
+    let test = build_op_test!(build_asm_op(0).as_str(), &[0]);
+    test.expect_stack(&[expected_eq]);
+
+    let test = build_op_test!(build_asm_op(0).as_str(), &[1]);
+    test.expect_stack(&[expected_gt]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u64>() as u32;
+    let b = rand_value::<u64>() as u32;
+    let expected = match a.cmp(&b) {
+        Ordering::Less => expected_lt,
+        Ordering::Equal => expected_eq,
+        Ordering::Greater => expected_gt,
+    };
+
+    let test = build_op_test!(build_asm_op(b).as_str(), &[a as u64]);
+    test.expect_stack(&[expected]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(build_asm_op(b).as_str(), &[c, a as u64]);
+    test.expect_stack(&[expected, c]);
+}
+
 /// Tests a u32min assembly operation (u32checked_min or u32unchecked_min) against a number of
 /// cases to ensure that the operation puts the minimum of 2 input values on the stack.
 fn test_min(asm_op: &str) {
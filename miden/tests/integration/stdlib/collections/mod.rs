@@ -0,0 +1,4 @@
+use super::build_test;
+use crate::helpers::{Felt, TestError};
+
+mod sorted_array;
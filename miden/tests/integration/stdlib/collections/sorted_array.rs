@@ -0,0 +1,228 @@
+use super::{build_test, Felt, TestError};
+use crate::stdlib::{store_elements, ADDR};
+
+const MODULE: &str = "std::collections::sorted_array";
+
+// SORTEDNESS CHECKS
+// ================================================================================================
+
+#[test]
+fn assert_sorted() {
+    for values in [&[][..], &[5], &[1, 2, 2, 3, 9], &[7, 7, 7]] {
+        let operands = [values.len() as u64, ADDR];
+        let source = build_source(&store_elements(values), "assert_sorted", &operands);
+        build_test!(&source).expect_stack(&[]);
+    }
+
+    // entries beyond the end of the array are not checked
+    let source = build_source(&store_elements(&[1, 3, 2]), "assert_sorted", &[2, ADDR]);
+    build_test!(&source).expect_stack(&[]);
+}
+
+#[test]
+fn assert_sorted_fails_on_unsorted_array() {
+    for values in [&[2, 1][..], &[1, 3, 2], &[1, 2, 3, 4, 0]] {
+        let operands = [values.len() as u64, ADDR];
+        let source = build_source(&store_elements(values), "assert_sorted", &operands);
+        build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
+
+#[test]
+fn assert_sorted_word() {
+    let words = [
+        [0, 9, 9, 9],
+        [1, 0, 0, 5],
+        [1, 0, 0, 5],
+        [1, 0, 1, 0],
+        [2, 0, 0, 0],
+    ];
+    for len in 0..=words.len() {
+        let operands = [len as u64, ADDR];
+        let source = build_source(&store_words(&words[..len]), "assert_sorted_word", &operands);
+        build_test!(&source).expect_stack(&[]);
+    }
+}
+
+#[test]
+fn assert_sorted_word_fails_on_unsorted_array() {
+    // words differ only in the least significant element
+    let words = [[1, 2, 3, 5], [1, 2, 3, 4]];
+    let source = build_source(&store_words(&words), "assert_sorted_word", &[2, ADDR]);
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the last word is smaller than the previous one, even though its other elements are larger
+    let words = [[0, 0, 0, 0], [2, 0, 0, 0], [1, 9, 9, 9]];
+    let source = build_source(&store_words(&words), "assert_sorted_word", &[3, ADDR]);
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// BINARY SEARCH
+// ================================================================================================
+
+#[test]
+fn binary_search() {
+    let values = [2, 4, 4, 4, 7, 9];
+
+    // value is present; for duplicates, the index of the first occurrence is returned
+    test_binary_search(&values, 2, true, 0);
+    test_binary_search(&values, 4, true, 1);
+    test_binary_search(&values, 7, true, 4);
+    test_binary_search(&values, 9, true, 5);
+
+    // value is not present; the index at which the value could be inserted is returned
+    test_binary_search(&values, 1, false, 0);
+    test_binary_search(&values, 5, false, 4);
+    test_binary_search(&values, 10, false, 6);
+
+    // empty and single-element arrays
+    test_binary_search(&[], 3, false, 0);
+    test_binary_search(&[3], 3, true, 0);
+    test_binary_search(&[3], 2, false, 0);
+    test_binary_search(&[3], 4, false, 1);
+}
+
+#[test]
+fn binary_search_word() {
+    let words = [
+        [0, 9, 9, 9],
+        [1, 0, 0, 5],
+        [1, 0, 0, 5],
+        [1, 0, 1, 0],
+        [2, 0, 0, 0],
+    ];
+
+    test_binary_search_word(&words, [0, 9, 9, 9], true, 0);
+    test_binary_search_word(&words, [1, 0, 0, 5], true, 1);
+    test_binary_search_word(&words, [1, 0, 1, 0], true, 3);
+    test_binary_search_word(&words, [2, 0, 0, 0], true, 4);
+
+    test_binary_search_word(&words, [0, 0, 0, 0], false, 0);
+    test_binary_search_word(&words, [1, 0, 0, 6], false, 3);
+    test_binary_search_word(&words, [2, 0, 0, 1], false, 5);
+
+    test_binary_search_word(&[], [1, 2, 3, 4], false, 0);
+    test_binary_search_word(&[[1, 2, 3, 4]], [1, 2, 3, 4], true, 0);
+    test_binary_search_word(&[[1, 2, 3, 4]], [1, 2, 3, 5], false, 1);
+}
+
+// INSERTION
+// ================================================================================================
+
+#[test]
+fn insert() {
+    let values = [2, 4, 4, 7];
+
+    test_insert(&values, 8, 1, &[1, 2, 4, 4, 7]);
+    test_insert(&values, 8, 4, &[2, 4, 4, 4, 7]);
+    test_insert(&values, 8, 5, &[2, 4, 4, 5, 7]);
+    test_insert(&values, 5, 9, &[2, 4, 4, 7, 9]);
+
+    test_insert(&[], 1, 3, &[3]);
+    test_insert(&[3], 2, 1, &[1, 3]);
+    test_insert(&[3], 2, 3, &[3, 3]);
+}
+
+#[test]
+fn insert_fails_on_full_array() {
+    let values = [2, 4, 4, 7];
+    let source = build_source(&store_elements(&values), "insert", &[4, 4, ADDR, 5]);
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    let source = build_source("", "insert", &[0, 0, ADDR, 5]);
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn insert_word() {
+    let a = [0, 9, 9, 9];
+    let b = [1, 0, 0, 5];
+    let c = [2, 0, 0, 0];
+    let words = [a, b, c];
+
+    test_insert_word(&words, 4, [0, 0, 0, 1], &[[0, 0, 0, 1], a, b, c]);
+    test_insert_word(&words, 4, b, &[a, b, b, c]);
+    test_insert_word(&words, 4, [1, 0, 1, 0], &[a, b, [1, 0, 1, 0], c]);
+    test_insert_word(&words, 4, [3, 0, 0, 0], &[a, b, c, [3, 0, 0, 0]]);
+
+    test_insert_word(&[], 1, a, &[a]);
+}
+
+#[test]
+fn insert_word_fails_on_full_array() {
+    let words = [[0, 9, 9, 9], [1, 0, 0, 5], [2, 0, 0, 0]];
+    let operands = [3, 3, ADDR, 4, 3, 2, 1];
+    let source = build_source(&store_words(&words), "insert_word", &operands);
+    build_test!(&source).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn test_binary_search(values: &[u64], value: u64, is_found: bool, index: u64) {
+    let operands = [values.len() as u64, ADDR, value];
+    let source = build_source(&store_elements(values), "binary_search", &operands);
+    build_test!(&source).expect_stack(&[is_found as u64, index]);
+}
+
+fn test_binary_search_word(words: &[[u64; 4]], word: [u64; 4], is_found: bool, index: u64) {
+    let mut operands = vec![words.len() as u64, ADDR];
+    operands.extend(word.iter().rev());
+    let source = build_source(&store_words(words), "binary_search_word", &operands);
+    build_test!(&source).expect_stack(&[is_found as u64, index]);
+}
+
+fn test_insert(values: &[u64], capacity: u64, value: u64, expected: &[u64]) {
+    let operands = [capacity, values.len() as u64, ADDR, value];
+    let source = build_source(&store_elements(values), "insert", &operands);
+    let test = build_test!(&source);
+    test.expect_stack(&[expected.len() as u64]);
+
+    let memory = test.get_memory_words(ADDR, expected.len());
+    let actual = memory.iter().map(|word| word[0]).collect::<Vec<_>>();
+    let expected = expected.iter().map(|&v| Felt::new(v)).collect::<Vec<_>>();
+    assert_eq!(expected, actual);
+}
+
+fn test_insert_word(words: &[[u64; 4]], capacity: u64, word: [u64; 4], expected: &[[u64; 4]]) {
+    let mut operands = vec![capacity, words.len() as u64, ADDR];
+    operands.extend(word.iter().rev());
+    let source = build_source(&store_words(words), "insert_word", &operands);
+    let test = build_test!(&source);
+    test.expect_stack(&[expected.len() as u64]);
+
+    // words are stored in memory in reverse of their stack order
+    let memory = test.get_memory_words(ADDR, expected.len());
+    let expected = expected
+        .iter()
+        .map(|word| [word[3], word[2], word[1], word[0]].map(Felt::new))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, memory);
+}
+
+/// Builds a program which executes the specified procedure of `std::collections::sorted_array`;
+/// see [crate::stdlib::build_source()].
+fn build_source(store: &str, procedure: &str, operands: &[u64]) -> String {
+    crate::stdlib::build_source(MODULE, store, procedure, operands)
+}
+
+/// Returns instructions which store the provided words in memory, starting at [ADDR]. Words are
+/// specified in stack order (i.e., the first element of a word is on top of the stack after the
+/// word is loaded from memory).
+fn store_words(words: &[[u64; 4]]) -> String {
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            format!(
+                "push.{}.{}.{}.{} mem_storew.{} dropw",
+                w[3],
+                w[2],
+                w[1],
+                w[0],
+                ADDR + i as u64
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
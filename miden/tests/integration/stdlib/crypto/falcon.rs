@@ -1,5 +1,9 @@
-use super::build_test;
+use super::{build_test, Felt, TestError};
 use std::fmt::Write;
+use vm_core::{chiplets::hasher::apply_permutation, StarkField, ZERO};
+
+const Q: u64 = 12289;
+const N: usize = 512;
 
 #[test]
 fn test_falcon512_normalize_poly() {
@@ -2661,3 +2665,288 @@ fn test_falcon512_verify() {
     let test = build_test!(source, &[]);
     test.get_last_stack_state();
 }
+
+// VERIFICATION WITH ADVICE INPUTS
+// ================================================================================================
+
+#[test]
+fn test_falcon512_rp_verify_from_advice() {
+    let stack = build_stack(&PUBLIC_KEY, MESSAGE);
+    let advice_tape = build_advice_tape(&PUBLIC_KEY, NONCE, &signature());
+
+    let test = build_test!(VERIFY_FROM_ADVICE_SOURCE, &stack, &advice_tape, vec![]);
+    test.expect_stack(&[]);
+}
+
+#[test]
+fn test_falcon512_rp_verify_from_advice_fails_on_flipped_signature() {
+    let mut sig = signature();
+    sig[7] ^= 1;
+    let stack = build_stack(&PUBLIC_KEY, MESSAGE);
+    let advice_tape = build_advice_tape(&PUBLIC_KEY, NONCE, &sig);
+
+    let test = build_test!(VERIFY_FROM_ADVICE_SOURCE, &stack, &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn test_falcon512_rp_verify_from_advice_fails_on_flipped_public_key() {
+    let mut pk = PUBLIC_KEY;
+    pk[7] ^= 1;
+    let stack = build_stack(&PUBLIC_KEY, MESSAGE);
+    let advice_tape = build_advice_tape(&pk, NONCE, &signature());
+
+    let test = build_test!(VERIFY_FROM_ADVICE_SOURCE, &stack, &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn test_falcon512_rp_verify_from_advice_fails_on_flipped_message() {
+    let mut msg = MESSAGE;
+    msg[0] ^= 1;
+    let stack = build_stack(&PUBLIC_KEY, msg);
+    let advice_tape = build_advice_tape(&PUBLIC_KEY, NONCE, &signature());
+
+    let test = build_test!(VERIFY_FROM_ADVICE_SOURCE, &stack, &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn test_falcon512_rp_verify_from_advice_fails_on_flipped_nonce() {
+    let mut nonce = NONCE;
+    nonce[0] ^= 1;
+    let stack = build_stack(&PUBLIC_KEY, MESSAGE);
+    let advice_tape = build_advice_tape(&PUBLIC_KEY, nonce, &signature());
+
+    let test = build_test!(VERIFY_FROM_ADVICE_SOURCE, &stack, &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+/// Checks that the public key of the test vector is an NTRU public key i.e. h = g / f mod q, for
+/// short secret polynomials f and g.
+#[test]
+fn test_falcon512_rp_test_vector_is_ntru_key() {
+    let f = SECRET_F.iter().map(|&v| to_zq(v)).collect::<Vec<_>>();
+    let g = SECRET_G.iter().map(|&v| to_zq(v)).collect::<Vec<_>>();
+    assert_eq!(mul_zq(&PUBLIC_KEY, &f), g);
+
+    // Falcon-512 key generation only accepts (f, g) with norm at most 1.17 * sqrt(q)
+    let sq_norm = SECRET_F
+        .iter()
+        .chain(SECRET_G.iter())
+        .map(|&v| v * v)
+        .sum::<i64>();
+    assert!((sq_norm as f64) <= 1.17 * 1.17 * Q as f64);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+const VERIFY_FROM_ADVICE_SOURCE: &str = "
+    use.std::crypto::dsa::falcon
+
+    begin
+        exec.falcon::rp_verify_from_advice
+    end
+    ";
+
+/// Builds initial stack out of public key commitment and message, such that the last stack input
+/// ends up at the top of the stack.
+fn build_stack(pk: &[u64], msg: [u64; 4]) -> Vec<u64> {
+    let commitment = hash_poly(&poly_to_advice(pk));
+    commitment
+        .iter()
+        .map(|v| v.as_int())
+        .chain(msg)
+        .rev()
+        .collect()
+}
+
+/// Lays out public key, nonce and signature on the advice tape, in the order expected by
+/// `falcon::rp_verify_from_advice`.
+fn build_advice_tape(pk: &[u64], nonce: [u64; 4], sig: &[u64]) -> Vec<u64> {
+    let mut tape = poly_to_advice(pk);
+    tape.extend(nonce.iter().rev());
+    tape.extend(poly_to_advice(sig));
+    tape
+}
+
+/// Arranges polynomial coefficients so that after being written to memory via `adv_pipe`, they
+/// are laid out as expected by `std::math::poly512` routines.
+fn poly_to_advice(poly: &[u64]) -> Vec<u64> {
+    poly.chunks(4)
+        .flat_map(|chunk| chunk.iter().rev().copied())
+        .collect()
+}
+
+/// Mirrors public key commitment computed by `falcon::rp_verify_from_advice` via `adv_pipe`. The
+/// returned digest is in stack order.
+fn hash_poly(advice: &[u64]) -> [Felt; 4] {
+    let mut state = [ZERO; 12];
+    for chunk in advice.chunks(8) {
+        for (j, &value) in chunk.iter().enumerate() {
+            state[4 + j] += Felt::new(value);
+        }
+        apply_permutation(&mut state);
+    }
+    [state[7], state[6], state[5], state[4]]
+}
+
+/// Returns signature polynomial s2 of the test vector, with coefficients reduced modulo q.
+fn signature() -> Vec<u64> {
+    SIGNATURE.iter().map(|&v| to_zq(v)).collect()
+}
+
+fn to_zq(value: i64) -> u64 {
+    value.rem_euclid(Q as i64) as u64
+}
+
+/// Multiplies two polynomials in Z_q[x] / (x^512 + 1).
+fn mul_zq(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result = vec![0; N];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            let product = x * y % Q;
+            if i + j < N {
+                result[i + j] = (result[i + j] + product) % Q;
+            } else {
+                result[i + j - N] = (result[i + j - N] + Q - product) % Q;
+            }
+        }
+    }
+    result
+}
+
+// TEST VECTORS
+// ================================================================================================
+
+// An RP-Falcon-512 key pair, and a signature of MESSAGE under it. As no Falcon implementation
+// supports hashing to a point with Rescue Prime, these were generated offline: the key pair by
+// Falcon-512 key generation ( NTRUSolve ), and the signature by hashing MESSAGE and NONCE with
+// `rp_hash_to_point` and finding a short preimage (s1, s2) of the resulting point, using Babai's
+// nearest plane algorithm over the secret NTRU basis. Squared norm of (s1, s2) is 1339703.
+//
+// Message and nonce are given in stack order. Short polynomials are given with signed
+// coefficients.
+
+const MESSAGE: [u64; 4] = [
+    7375121054897909820,
+    8915687741774889621,
+    18221754602770580951,
+    18378880010084898416,
+];
+
+const NONCE: [u64; 4] = [
+    17239797445496036560,
+    9720834145093710140,
+    4175517183303425729,
+    11120125289014979139,
+];
+
+const PUBLIC_KEY: [u64; N] = [
+    7337, 11144, 5461, 8859, 4521, 11975, 2707, 11232, 6016, 12157, 10650, 2172, 6424, 2006, 1190,
+    2926, 4188, 8451, 11996, 1609, 9191, 11829, 2239, 6087, 1197, 3966, 7435, 10271, 903, 7980,
+    9655, 967, 9310, 4505, 7575, 6175, 3667, 8634, 1791, 7415, 5598, 4481, 4762, 10055, 7078,
+    11994, 1263, 7465, 8631, 1291, 11536, 10026, 9265, 129, 11973, 1077, 10165, 10283, 3273, 6256,
+    9342, 1545, 2448, 10322, 9593, 3460, 2497, 7151, 7957, 6722, 5833, 2909, 6984, 5074, 281, 6100,
+    6466, 606, 6352, 9962, 5112, 9295, 9437, 7212, 2502, 4124, 1446, 10296, 2256, 3398, 4731, 9386,
+    6138, 9611, 1844, 4750, 9459, 5458, 8215, 12100, 7331, 4904, 10170, 3607, 9074, 9030, 4658,
+    9733, 954, 6603, 10432, 5446, 8538, 3313, 2322, 9435, 4567, 10801, 10443, 6732, 11690, 3845,
+    12100, 2620, 8285, 2302, 5192, 3903, 12027, 9283, 823, 10118, 11052, 6456, 9089, 5809, 2485,
+    10656, 11733, 5843, 611, 4571, 10834, 2415, 10678, 8219, 7928, 11619, 11494, 6478, 11057, 347,
+    2512, 569, 10849, 6083, 5391, 3738, 4904, 6292, 6276, 2919, 5131, 3405, 2320, 7173, 5756, 5126,
+    10153, 1965, 7288, 4457, 11693, 4302, 4776, 8624, 8040, 1654, 10489, 2237, 8254, 1197, 868,
+    3867, 11284, 1850, 10721, 6046, 11649, 8129, 2261, 1144, 5131, 1574, 11857, 9514, 6701, 7966,
+    152, 4882, 3537, 2105, 3458, 11003, 6871, 2951, 9475, 3860, 2111, 2587, 731, 9091, 11533, 7163,
+    6489, 10162, 8579, 5251, 5802, 2406, 8857, 12087, 10704, 6729, 5118, 9262, 2343, 7226, 11071,
+    3647, 11196, 6439, 5906, 2925, 7482, 2578, 11937, 10184, 9013, 7616, 853, 3087, 11496, 8077,
+    4435, 3008, 10385, 9193, 6779, 8025, 11171, 7835, 1470, 1958, 1301, 3609, 7797, 4741, 6248,
+    3364, 11055, 2996, 659, 3580, 11805, 6912, 3688, 7786, 7662, 4986, 4729, 5479, 3011, 7786, 319,
+    2892, 9796, 1247, 3191, 5280, 9314, 4013, 514, 11102, 7032, 5643, 4067, 2386, 6048, 1703, 6293,
+    11038, 8219, 2241, 3291, 3620, 10708, 8474, 340, 6879, 6193, 6294, 10383, 746, 3044, 1642,
+    12160, 6019, 2779, 4840, 9180, 10806, 5558, 5738, 1269, 3801, 12224, 9838, 4041, 8422, 10974,
+    6639, 9287, 8875, 6221, 10088, 3801, 5080, 9646, 1964, 1172, 11621, 12065, 9735, 7506, 6317,
+    7342, 1579, 6427, 7762, 1458, 7846, 11153, 4030, 1983, 1416, 3248, 4164, 51, 1298, 1487, 11639,
+    451, 4942, 7121, 10417, 441, 7204, 4621, 8283, 2802, 466, 9324, 5499, 1094, 6163, 652, 3608,
+    8926, 12122, 520, 9815, 6591, 4844, 4021, 11021, 2620, 7899, 3196, 5907, 5085, 6656, 6226,
+    2850, 3654, 514, 5239, 8199, 3017, 5375, 5952, 4575, 10088, 8592, 8539, 6951, 7130, 10841,
+    10014, 66, 3561, 1097, 11016, 7125, 460, 300, 5048, 7407, 9498, 8658, 4281, 849, 9200, 7695,
+    1163, 6796, 10023, 10294, 10849, 8656, 9857, 10888, 7237, 792, 990, 12199, 734, 11743, 3069,
+    7603, 713, 4434, 11197, 5085, 3550, 1984, 1076, 432, 9251, 1798, 7342, 413, 4956, 5225, 6490,
+    2278, 8173, 6945, 4579, 8870, 7375, 5371, 2688, 325, 8956, 2354, 2653, 10186, 8094, 6495, 8534,
+    11709, 10245, 2063, 2592, 616, 1889, 4727, 352, 3167, 4145, 2780, 5146, 2339, 1063, 10956,
+    8716, 3244, 2004, 7898, 5040, 7417, 12208, 4815, 8666, 5460, 10215, 4118, 905, 10072, 11515,
+    2515, 3767, 9216, 11655, 906, 11022, 1571, 3399, 4884, 6144, 2535, 7156, 7464, 9742, 8169,
+    10446, 2799, 11755, 6707, 321, 8678,
+];
+
+const SIGNATURE: [i64; N] = [
+    29, 5, 21, -9, -22, 9, 5, 50, 7, -35, 26, -23, 28, 76, 36, 4, -40, -20, 8, -41, 34, 40, 26,
+    -36, 4, -31, 10, 4, 5, 9, 32, 15, 7, 0, 57, 44, -68, -6, -32, -8, 5, -8, -12, -39, -27, -5, 12,
+    36, -7, -31, 126, -22, -34, 9, 23, -49, -24, -76, -43, 21, -14, -8, 26, -43, -19, 23, -27, -50,
+    53, 14, -22, 19, -12, 98, -9, -29, -85, 48, -3, -36, 6, -7, 50, 5, 21, -26, 5, 26, -27, -24,
+    -1, -22, -3, -29, -28, 60, -40, 10, 11, -55, -1, -35, -22, 41, 15, -38, 1, 6, -15, 14, -38, -5,
+    -57, -34, 12, -57, -17, -16, 16, 30, 24, -5, 33, 11, 4, 11, -3, -18, 10, 12, 18, 53, 20, -40,
+    -13, -52, -28, 26, 40, -7, 33, -13, -15, 69, -6, 36, -73, 15, -21, 26, 60, -58, 1, -35, 19, 43,
+    -29, 7, 46, -9, 1, 43, -37, 38, 29, 36, 20, -11, -52, 24, -22, -49, -5, 57, 48, 12, -25, 46,
+    27, 1, 10, -8, 30, -14, 10, 28, 16, 29, 48, 3, 30, 83, -35, -2, 8, 6, 24, -22, 49, 6, 39, -7,
+    34, -50, -11, 1, -4, 22, 10, -9, 9, -40, -19, 30, -4, 41, 5, -21, 22, 3, -17, 2, 62, -58, -17,
+    -19, -9, 42, -17, -48, 27, -17, -1, -14, -50, -48, 21, -43, -60, 19, -39, 68, 1, 62, 47, -28,
+    -73, 26, 45, 3, 39, 3, 8, 16, 30, -19, 20, 37, -46, -42, -2, 27, 28, 35, 40, -26, 31, -60, -23,
+    -47, 46, 30, -4, -31, -12, -6, -62, -14, -54, 7, -38, 71, -15, 52, 48, 18, -32, -43, -10, 47,
+    12, -85, 32, 53, 33, 78, -77, 29, 16, 3, 41, 37, 40, -70, 1, 64, -25, -38, -31, 36, -39, 75,
+    59, -6, 32, 12, -3, -22, 60, 39, 3, 54, -41, -30, 57, 22, 9, -26, -26, -25, 15, -27, -41, 127,
+    27, 4, -40, -8, 9, 60, 32, 48, -33, -11, -12, 50, -16, -5, -40, 30, 11, -3, 31, -30, 7, 26,
+    -79, -32, -18, -7, -15, -3, 33, 16, 59, 6, 59, 48, 30, 23, 70, 5, 84, -14, 7, -18, -24, -72,
+    -43, -27, -14, 48, -42, -15, 5, 11, 59, -17, -5, 27, -24, 63, 55, -10, -51, -28, 27, 39, 52,
+    -42, -3, 74, -37, -7, -40, -25, -35, -31, -4, 9, 27, -11, 37, 30, -67, 18, -51, -41, 43, -48,
+    -20, 14, -34, 50, -54, 11, -10, 16, 2, 24, 16, 24, 16, -7, -46, -14, -19, 13, 5, -14, -12, 55,
+    14, 7, -55, 3, 34, -65, -13, -21, 1, 25, 31, 6, -50, -54, 0, 24, 15, -20, 44, 15, -16, 46, 35,
+    1, 18, -79, 2, -50, -26, 3, 66, 55, -37, 27, 61, 2, -8, 46, 33, 38, -21, 23, -23, 28, 91, -73,
+    5, -31, -30, -33, -18, -8, 17, 36, 32, -17, -14, -28, -3, -27, -20, 70, 33, -26, 27, -7, 36, 0,
+    -52, -9,
+];
+
+const SECRET_F: [i64; N] = [
+    2, 3, -10, -5, 0, 1, -1, -7, -2, -1, -1, 0, 3, 0, 0, 4, 2, 1, 1, -1, 2, -5, -1, -3, -2, 1, 9,
+    -6, 0, 3, 1, -4, -1, 2, -1, 1, -2, -4, 3, 3, -7, 4, -6, 1, 5, -3, -1, 1, 5, 4, -2, 4, -2, 6, 1,
+    -3, -5, 0, -3, -2, 7, 7, -9, 0, -3, 4, -4, 2, 0, -2, 4, 1, 1, -1, 7, 7, 5, -8, 8, 1, -8, 2, 1,
+    -1, -7, -10, -11, -2, -4, -3, -1, 4, 0, -1, -1, 1, 4, -6, -2, -2, 9, -3, 2, -3, 3, -2, 0, -2,
+    5, 9, -5, -4, -3, 4, 1, 3, -5, -2, 1, 9, -1, -2, 6, 2, -2, 3, 5, 1, -4, 5, 0, -1, 0, 2, -5, -1,
+    5, -3, -2, 4, 1, -3, 2, -6, -2, -2, -5, -2, 1, -4, 3, -2, 2, 5, -2, 2, 1, 5, 0, 2, -6, 3, -1,
+    -9, -3, 6, 1, 6, -8, -4, 2, 3, 3, 0, -4, 1, 0, 3, 4, 0, 0, 0, 8, -1, 1, 4, -1, -2, 0, 0, 1, -5,
+    -1, -2, 2, -3, -4, -3, -6, -1, -1, 3, 6, 0, -2, -1, -4, 1, -1, 0, -10, 4, -6, 1, -6, -5, 1, 0,
+    -1, -5, 3, -3, -6, 2, 0, -9, -3, 0, 4, -2, 5, 5, 3, -4, 2, 2, 6, -1, -2, -1, -4, 3, 1, 6, -4,
+    4, 5, 7, 1, 3, -1, -3, -4, 3, 1, 3, 1, -2, 3, -6, -5, -2, 8, -2, 1, 4, 6, 8, -3, 5, -1, -3, 7,
+    -4, 2, 0, -7, 0, -4, 2, 4, 1, 3, -1, -2, -1, 5, -7, -10, -7, 2, 0, 0, -1, -6, -3, 0, 0, 2, -5,
+    -3, -3, 0, 2, 1, 6, 2, 2, -2, 3, 3, 6, 1, 6, -1, 7, 2, -2, 2, 2, 0, 2, -5, -7, 2, -4, -3, 1,
+    -6, 1, 0, 3, 4, -3, -4, -4, -2, 0, 4, 5, -2, 4, 6, -6, -5, 5, -2, 4, -8, -5, 11, 2, 2, -5, -1,
+    -2, 0, 0, -4, 0, 2, -7, -3, -1, -1, 3, -5, -1, 5, 3, -5, 2, 3, 2, -2, -3, -1, -5, 2, -1, -7,
+    -9, 4, 3, 1, -3, -7, 4, 2, -4, 5, -3, 6, -1, 1, -1, -4, 3, 5, -2, -3, 4, 6, 7, 6, -2, -8, 4,
+    -3, 4, -1, -9, 8, -4, -2, 0, -1, 3, 2, -1, -6, 6, 1, 1, -2, -2, 2, -3, 8, -2, -1, -4, 6, 2, -3,
+    -6, 5, 8, -7, 1, -1, -9, 1, -4, 0, 4, -2, 0, -3, 0, -1, 1, 3, -2, 5, -2, -2, -7, 0, 3, -1, 6,
+    2, -5, 5, -4, 0, -2, 4, -3, 4, 2, 4, 2, 5, 7, -6, 0, 5, 0, 1, 1, 0, 4, -1, -2, 4, 3, 0, 4, 6,
+    6, 4, -4, -5, 1, -5, -1, 3, -5, -6, -3, 3, 2, 0, 0, -5, 1, -2, -2, 4, 5,
+];
+
+const SECRET_G: [i64; N] = [
+    3, 2, -3, 2, 5, -2, 0, -5, -6, 5, -5, -4, -5, 0, -5, 0, 1, 4, 4, -2, -3, -6, 1, 0, -1, -2, -5,
+    0, -1, 3, -3, 3, 0, 3, -5, -6, -4, 2, 2, -4, 2, -5, -8, 0, -3, 1, -2, 2, 5, -5, 5, -1, -3, 6,
+    -1, 2, 8, -7, 1, 9, -2, 1, -3, 1, 3, 7, -2, -1, -2, 1, 3, -2, 1, -1, -2, 4, -2, -2, -4, -7, 4,
+    2, 0, 0, 0, -4, 4, 3, -3, -4, -1, -3, -1, -1, -4, -8, 1, 10, 1, -1, -2, -2, 4, -3, 4, -5, 6, 1,
+    6, 0, 3, 2, -1, 1, 0, 0, 3, 7, 3, 2, 3, 7, 5, -4, -4, -3, 2, 4, 5, 4, -2, 8, -1, -3, 0, 3, 4,
+    -3, -4, -3, -2, 0, -2, 6, 6, 5, -3, -2, -1, 0, -2, -1, 0, -4, 3, 1, 3, -2, 1, -2, -1, 0, 3, -6,
+    4, 1, -5, 3, 2, 3, -5, 3, -5, 2, 2, 1, -3, -1, -3, 1, 1, -4, 4, 6, 4, -5, 0, 9, -1, -1, -6, 4,
+    0, -4, -2, -3, -2, 1, 5, 0, 6, -1, 4, 2, 1, 4, -2, -1, -3, 2, 1, -7, 10, -2, 7, -3, 1, 4, -3,
+    -3, 3, 0, -8, 4, 0, -4, 1, -2, 10, 4, -3, 9, -1, -3, -1, -3, -5, -1, 3, 4, 5, -1, 4, 3, 1, -3,
+    8, -3, 0, -6, -10, -1, 0, -5, -2, 1, 2, -1, -6, 7, 1, -3, 2, 0, -3, 1, 1, -7, 1, 2, -6, -5, 0,
+    -1, 4, 6, 0, 6, -5, 2, 2, -2, 3, 4, -4, 0, 7, -3, 9, 5, 3, 2, 2, -1, 3, 5, 1, -6, 9, -1, 10,
+    -1, 0, 3, -3, -2, 8, 3, -5, 3, 3, 4, -1, 4, 2, -3, 2, 3, 1, -8, 5, 0, -4, -3, -2, -2, -5, 0, 4,
+    -1, -2, 3, -2, 1, 5, 6, 2, -5, -4, 2, 2, 4, 1, 5, 3, -1, -1, 1, -2, 4, 5, 3, -4, 4, -1, -1, -2,
+    -1, 4, 11, 0, 5, -4, -1, 1, -2, -5, 7, 2, -1, -1, 3, 4, -2, 1, -5, 3, -5, 0, -4, -1, 4, 2, -2,
+    1, -3, 8, 1, -3, -3, 0, -3, 2, -3, 7, -1, 2, -2, -3, 0, 10, 5, -4, -2, 3, -7, 2, 7, 3, 3, -4,
+    -1, 1, 2, 1, -6, -3, 9, -10, -8, 5, 7, 2, 2, -9, 3, 1, 8, 2, 0, -2, -1, -7, -6, 0, -4, 5, 1,
+    -4, -5, 0, -5, 0, -5, 1, -6, 5, -6, 5, -1, -4, -3, 6, 0, -2, 3, -1, 2, -3, -2, 4, -5, 0, -5,
+    -4, 0, -6, 0, -7, 2, 1, 0, 1, -3, 0, 3, 0, 3, -7, 7, 0, 3, -2, -8, 1, 1, 3, -2, 3, 6, 8, 2, 8,
+    10, 10, -4, 0, -3, -3, -3, 1, -1, 3, -4, 6, -1, 3, -4, 1, -2, -6, -4,
+];
@@ -1,5 +1,5 @@
 use super::build_test;
-use crate::helpers::{Felt, STACK_TOP_SIZE};
+use crate::helpers::{Felt, TestError, STACK_TOP_SIZE};
 
 mod blake3;
 mod falcon;
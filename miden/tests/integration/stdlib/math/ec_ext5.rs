@@ -1,8 +1,9 @@
 use super::ext5::{bv_or, Ext5};
-use super::{build_test, Felt};
+use super::Felt;
 use ::air::FieldElement;
 use std::ops::Add;
 use test_case::test_case;
+use test_utils::build_test;
 use vm_core::StarkField;
 
 #[derive(Copy, Clone, Debug)]
@@ -1,7 +1,7 @@
-use super::build_test;
 use super::Felt;
 use air::{FieldElement, StarkField};
 use rand_utils::rand_value;
+use test_utils::build_test;
 use vm_core::QuadExtension;
 
 type ExtElement = QuadExtension<Felt>;
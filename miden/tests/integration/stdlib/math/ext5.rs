@@ -1,7 +1,8 @@
-use super::{build_test, Felt};
+use super::Felt;
 use ::air::FieldElement;
 use std::cmp::PartialEq;
 use std::ops::{Add, Div, Mul, Neg, Sub};
+use test_utils::build_test;
 use vm_core::StarkField;
 
 // Given an element v ∈ Z_q | q = 2^64 - 2^32 + 1, this routine raises
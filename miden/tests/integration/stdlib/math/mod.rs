@@ -5,6 +5,7 @@ mod ec_ext5;
 mod ext2;
 mod ext5;
 mod ntt512;
+mod poly;
 mod poly512;
 mod secp256k1;
 mod u256_mod;
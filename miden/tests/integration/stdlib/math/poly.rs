@@ -0,0 +1,181 @@
+use super::{build_test, Felt, TestError};
+use crate::stdlib::{store_elements, store_elements_at, ADDR};
+use math::{polynom, FieldElement, StarkField};
+use rand_utils::{rand_value, rand_vector};
+
+const MODULE: &str = "std::math::poly";
+
+/// Memory address at which evaluation points are stored in batched evaluation tests.
+const POINTS_ADDR: u64 = 5000;
+
+/// Memory address at which results of batched evaluation are saved.
+const OUT_ADDR: u64 = 6000;
+
+// HORNER EVALUATION
+// ================================================================================================
+
+#[test]
+fn eval() {
+    // polynomial with no coefficients evaluates to zero
+    test_eval(&[], rand_value());
+
+    // constant polynomial
+    let c = rand_value::<Felt>();
+    test_eval(&[c], Felt::ZERO);
+    test_eval(&[c], rand_value());
+
+    // random polynomials of various degrees
+    for n in [2, 3, 8, 17, 64, 255, 300] {
+        test_eval(&rand_vector::<Felt>(n), rand_value());
+    }
+}
+
+#[test]
+fn eval_batch() {
+    // single point
+    test_eval_batch(&rand_vector::<Felt>(5), &[rand_value()]);
+
+    // no points
+    test_eval_batch(&rand_vector::<Felt>(5), &[]);
+
+    // multiple points, including polynomials with no coefficients and of degree 0
+    test_eval_batch(&[], &rand_vector::<Felt>(7));
+    for n in [1, 2, 31, 200] {
+        test_eval_batch(&rand_vector::<Felt>(n), &rand_vector::<Felt>(7));
+    }
+}
+
+// BARYCENTRIC EVALUATION
+// ================================================================================================
+
+#[test]
+fn eval_barycentric() {
+    for k in 1..=8 {
+        let n = 1 << k;
+
+        // polynomial of the maximum degree over a coset of the subgroup
+        let domain = build_domain(k, Felt::GENERATOR);
+        let values = polynom::eval_many(&rand_vector::<Felt>(n), &domain);
+        test_eval_barycentric(k, Felt::GENERATOR, &values, rand_value());
+
+        // polynomial of a lower degree over the subgroup itself
+        let domain = build_domain(k, Felt::ONE);
+        let values = polynom::eval_many(&rand_vector::<Felt>(n / 2 + 1), &domain);
+        test_eval_barycentric(k, Felt::ONE, &values, rand_value());
+    }
+}
+
+#[test]
+fn eval_barycentric_fails_on_invalid_inputs() {
+    let k = 3;
+    let offset = Felt::GENERATOR;
+    let domain = build_domain(k, offset);
+    let values = polynom::eval_many(&rand_vector::<Felt>(8), &domain);
+    let weights = build_weights(k, offset);
+    let source = build_source(&values, "eval_barycentric");
+
+    // invalid weight
+    let mut advice_tape = to_ints(&weights);
+    advice_tape[5] = (weights[5] + Felt::ONE).as_int();
+    let stack = build_barycentric_stack(k, offset, rand_value());
+    let test = build_test!(&source, &stack, &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // omega is not a primitive root of unity of order 2^k
+    let mut stack = build_barycentric_stack(k, offset, rand_value());
+    stack[1] = Felt::get_root_of_unity(k - 1).as_int();
+    let test = build_test!(&source, &stack, &to_ints(&weights), vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // evaluation point is in the domain
+    let stack = build_barycentric_stack(k, offset, domain[2]);
+    let test = build_test!(&source, &stack, &to_ints(&weights), vec![]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn test_eval(coeffs: &[Felt], x: Felt) {
+    let source = build_source(coeffs, "eval");
+    let stack = [coeffs.len() as u64, ADDR, x.as_int()];
+    let expected = polynom::eval(coeffs, x);
+    build_test!(&source, &stack).expect_stack(&[expected.as_int()]);
+}
+
+fn test_eval_batch(coeffs: &[Felt], points: &[Felt]) {
+    let store = format!(
+        "{} {}",
+        store_elements(&to_ints(coeffs)),
+        store_elements_at(&to_ints(points), POINTS_ADDR)
+    );
+    let source = crate::stdlib::build_source(MODULE, &store, "eval_batch", &[]);
+    let stack = [
+        OUT_ADDR,
+        points.len() as u64,
+        POINTS_ADDR,
+        coeffs.len() as u64,
+        ADDR,
+    ];
+    let test = build_test!(&source, &stack);
+    test.expect_stack(&[]);
+
+    let memory = test.get_memory_words(OUT_ADDR, points.len());
+    let actual = memory.iter().map(|word| word[0]).collect::<Vec<_>>();
+    let expected = polynom::eval_many(coeffs, points);
+    assert_eq!(expected, actual);
+}
+
+fn test_eval_barycentric(k: u32, offset: Felt, values: &[Felt], x: Felt) {
+    let source = build_source(values, "eval_barycentric");
+    let stack = build_barycentric_stack(k, offset, x);
+    let advice_tape = to_ints(&build_weights(k, offset));
+
+    // the expected result is computed by interpolating the polynomial from its evaluations
+    let domain = build_domain(k, offset);
+    let poly = polynom::interpolate(&domain, values, false);
+    let expected = polynom::eval(&poly, x);
+
+    let test = build_test!(&source, &stack, &advice_tape, vec![]);
+    test.expect_stack(&[expected.as_int()]);
+}
+
+/// Returns stack inputs for the eval_barycentric procedure such that x ends up on top of the
+/// stack.
+fn build_barycentric_stack(k: u32, offset: Felt, x: Felt) -> Vec<u64> {
+    let omega = Felt::get_root_of_unity(k);
+    vec![ADDR, omega.as_int(), offset.as_int(), k as u64, x.as_int()]
+}
+
+/// Returns the points offset * omega^i of a domain of size 2^k.
+fn build_domain(k: u32, offset: Felt) -> Vec<Felt> {
+    let omega = Felt::get_root_of_unity(k);
+    let mut point = offset;
+    (0..1 << k)
+        .map(|_| {
+            let result = point;
+            point *= omega;
+            result
+        })
+        .collect()
+}
+
+/// Returns barycentric weights x_i / (n * offset^n) of a domain of size n = 2^k.
+fn build_weights(k: u32, offset: Felt) -> Vec<Felt> {
+    let n = 1u64 << k;
+    let scale = (Felt::new(n) * offset.exp(n)).inv();
+    build_domain(k, offset)
+        .into_iter()
+        .map(|point| point * scale)
+        .collect()
+}
+
+/// Builds a program which stores the provided values in memory, starting at [ADDR], and then
+/// executes the specified procedure of `std::math::poly`.
+fn build_source(values: &[Felt], procedure: &str) -> String {
+    crate::stdlib::build_source(MODULE, &store_elements(&to_ints(values)), procedure, &[])
+}
+
+fn to_ints(values: &[Felt]) -> Vec<u64> {
+    values.iter().map(|v| v.as_int()).collect()
+}
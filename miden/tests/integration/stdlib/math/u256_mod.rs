@@ -1,4 +1,5 @@
-use super::build_test;
+use super::{build_test, TestError};
+use assembly::ProcedureId;
 use num_bigint::BigUint;
 use rand_utils::rand_vector;
 
@@ -33,9 +34,201 @@ fn mul_unsafe() {
     build_test!(source, &operands).expect_stack(&result);
 }
 
+// DIVISION
+// ================================================================================================
+
+#[test]
+fn divmod() {
+    // random operands
+    for _ in 0..4 {
+        test_divmod(rand_u256(), rand_u256());
+    }
+
+    // operands with leading zero limbs
+    let a = rand_u256();
+    let b = rand_u256() >> 160;
+    test_divmod(a.clone(), b);
+    test_divmod(a >> 96, BigUint::from(u32::MAX) + 5_u32);
+
+    // division by 1 and by self
+    let a = rand_u256();
+    test_divmod(a.clone(), BigUint::from(1_u32));
+    test_divmod(a.clone(), a.clone());
+
+    // divisor larger than dividend
+    test_divmod(a.clone() >> 32, a);
+}
+
+#[test]
+fn div_and_mod() {
+    let a = rand_u256();
+    let b = rand_u256() >> 100;
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div
+        end";
+    let operands = [to_limbs(&a), to_limbs(&b)].concat();
+    build_test!(source, &operands).expect_stack(&to_stack(&(&a / &b)));
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::mod
+        end";
+    build_test!(source, &operands).expect_stack(&to_stack(&(&a % &b)));
+}
+
+#[test]
+fn div_by_zero() {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::div
+        end";
+    let operands = [to_limbs(&rand_u256()), [0; 8].to_vec()].concat();
+    build_test!(source, &operands).expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+#[test]
+fn divmod_from_advice() {
+    let a = rand_u256();
+    let b = rand_u256() >> 128;
+    let q = &a / &b;
+    let r = &a % &b;
+
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::divmod_from_advice
+        end";
+    let operands = [to_limbs(&a), to_limbs(&b)].concat();
+
+    // correct quotient and remainder are accepted
+    let advice_tape = [to_limbs(&q), to_limbs(&r)].concat();
+    let test = build_test!(source, &operands, &advice_tape, vec![]);
+    test.expect_stack(&[to_stack(&r), to_stack(&q)].concat());
+
+    // wrong quotient is rejected
+    let advice_tape = [to_limbs(&(&q + 1_u32)), to_limbs(&r)].concat();
+    let test = build_test!(source, &operands, &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // wrong quotient with the remainder adjusted accordingly is rejected because the remainder is
+    // not smaller than the divisor
+    let advice_tape = [to_limbs(&(&q - 1_u32)), to_limbs(&(&r + &b))].concat();
+    let test = build_test!(source, &operands, &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// MODULAR ARITHMETIC
+// ================================================================================================
+
+#[test]
+fn addmod() {
+    for m in [rand_u256(), rand_u256() >> 200, BigUint::from(1_u32)] {
+        let a = rand_u256();
+        let b = rand_u256();
+        let expected = (&a + &b) % &m;
+        build_mod_test("addmod", &a, &b, &m).expect_stack(&to_stack(&expected));
+    }
+}
+
+#[test]
+fn mulmod() {
+    for m in [rand_u256(), rand_u256() >> 200, BigUint::from(1_u32)] {
+        let a = rand_u256();
+        let b = rand_u256();
+        let expected = (&a * &b) % &m;
+        build_mod_test("mulmod", &a, &b, &m).expect_stack(&to_stack(&expected));
+    }
+}
+
+// COMPARISONS
+// ================================================================================================
+
+#[test]
+fn iszero_unsafe_by_proc_id() {
+    let named_source = "
+        use.std::math::u256
+        begin
+            exec.u256::iszero_unsafe
+        end";
+
+    let proc_id = ProcedureId::from_name("iszero_unsafe", "std::math::u256");
+    let id_source = format!("begin exec.{proc_id} end");
+
+    let named_test = build_test!(named_source, &[0, 0, 0, 0, 0, 0, 0, 0]);
+    let id_test = build_test!(&id_source, &[0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // invoking the procedure by its ID should result in exactly the same program
+    assert_eq!(
+        named_test.compile().unwrap().hash(),
+        id_test.compile().unwrap().hash()
+    );
+    id_test.expect_stack(&[1]);
+
+    let id_test = build_test!(&id_source, &[0, 0, 0, 0, 0, 0, 0, 1]);
+    id_test.expect_stack(&[0]);
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+fn test_divmod(a: BigUint, b: BigUint) {
+    let source = "
+        use.std::math::u256
+        begin
+            exec.u256::divmod
+        end";
+
+    let operands = [to_limbs(&a), to_limbs(&b)].concat();
+    let expected = [to_stack(&(&a % &b)), to_stack(&(&a / &b))].concat();
+    build_test!(source, &operands).expect_stack(&expected);
+}
+
+/// Builds a test which invokes the specified modular arithmetic procedure; since the stack inputs
+/// are limited to 16 values, the modulus is pushed onto the stack by the program itself.
+fn build_mod_test(proc_name: &str, a: &BigUint, b: &BigUint, m: &BigUint) -> crate::helpers::Test {
+    let push_modulus = to_limbs(m)
+        .iter()
+        .map(|limb| format!("push.{limb}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let source = format!(
+        "
+        use.std::math::u256
+        begin
+            {push_modulus}
+            exec.u256::{proc_name}
+        end"
+    );
+
+    let operands = [to_limbs(a), to_limbs(b)].concat();
+    build_test!(&source, &operands)
+}
+
+/// Returns 32-bit limbs of the provided value starting with the least significant limb; the value
+/// is always represented by exactly 8 limbs.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    let mut limbs = value
+        .to_u32_digits()
+        .iter()
+        .map(|&v| v as u64)
+        .collect::<Vec<_>>();
+    limbs.resize(8, 0);
+    limbs
+}
+
+/// Returns 32-bit limbs of the provided value in the order in which they are expected to be
+/// located on the stack (i.e., most significant limb first).
+fn to_stack(value: &BigUint) -> Vec<u64> {
+    let mut limbs = to_limbs(value);
+    limbs.reverse();
+    limbs
+}
+
 fn rand_u256() -> BigUint {
     let limbs = rand_vector::<u64>(8)
         .iter()
@@ -1,5 +1,5 @@
 use super::{build_test, TestError};
-use crate::helpers::U32_BOUND;
+use crate::helpers::{Test, U32_BOUND};
 use proptest::prelude::*;
 use rand_utils::rand_value;
 use std::cmp;
@@ -110,6 +110,24 @@ fn overflowing_add() {
     test.expect_stack(&[1, c1, c0]);
 }
 
+#[test]
+fn add_variants_at_boundary() {
+    let max = u64::MAX;
+    let (max1, max0) = split_u64(max);
+
+    // 2^64 - 1 + 0 does not overflow
+    let stack = [max0, max1, 0, 0];
+    test_variant("wrapping_add", &stack).expect_stack(&[max1, max0]);
+    test_variant("overflowing_add", &stack).expect_stack(&[0, max1, max0]);
+    test_variant("checked_add", &stack).expect_stack(&[max1, max0]);
+
+    // 2^64 - 1 + 1 overflows
+    let stack = [max0, max1, 1, 0];
+    test_variant("wrapping_add", &stack).expect_stack(&[0, 0]);
+    test_variant("overflowing_add", &stack).expect_stack(&[1, 0, 0]);
+    test_variant("checked_add", &stack).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
 // SUBTRACTION
 // ------------------------------------------------------------------------------------------------
 
@@ -236,6 +254,24 @@ fn overflowing_sub() {
     test.expect_stack(&[0, c1, c0]);
 }
 
+#[test]
+fn sub_variants_at_boundary() {
+    let max = u64::MAX;
+    let (max1, max0) = split_u64(max);
+
+    // 0 - 0 does not underflow
+    let stack = [0, 0, 0, 0];
+    test_variant("wrapping_sub", &stack).expect_stack(&[0, 0]);
+    test_variant("overflowing_sub", &stack).expect_stack(&[0, 0, 0]);
+    test_variant("checked_sub", &stack).expect_stack(&[0, 0]);
+
+    // 0 - 1 underflows
+    let stack = [0, 0, 1, 0];
+    test_variant("wrapping_sub", &stack).expect_stack(&[max1, max0]);
+    test_variant("overflowing_sub", &stack).expect_stack(&[1, max1, max0]);
+    test_variant("checked_sub", &stack).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
 // MULTIPLICATION
 // ------------------------------------------------------------------------------------------------
 
@@ -336,6 +372,36 @@ fn overflowing_mul() {
         exec.u64::overflowing_mul
     end";
 
+    let a: u64 = rand_value();
+    let b: u64 = rand_value();
+    let (c, flag) = a.overflowing_mul(b);
+
+    let (a1, a0) = split_u64(a);
+    let (b1, b0) = split_u64(b);
+    let (c1, c0) = split_u64(c);
+
+    let test = build_test!(source, &[a0, a1, b0, b1]);
+    test.expect_stack(&[flag as u64, c1, c0]);
+
+    // product of two u32 values never overflows
+    let a = rand_value::<u64>() as u32 as u64;
+    let b = rand_value::<u64>() as u32 as u64;
+    let c = a * b;
+
+    let (c1, c0) = split_u64(c);
+
+    let test = build_test!(source, &[a, 0, b, 0]);
+    test.expect_stack(&[0, c1, c0]);
+}
+
+#[test]
+fn widening_mul() {
+    let source = "
+    use.std::math::u64
+    begin
+        exec.u64::widening_mul
+    end";
+
     let a = u64::MAX as u128;
     let b = u64::MAX as u128;
     let c = a.wrapping_mul(b);
@@ -365,6 +431,30 @@ fn overflowing_mul() {
     test.expect_stack(&[c3, c2, c1, c0]);
 }
 
+#[test]
+fn mul_variants_at_boundary() {
+    let max = u64::MAX;
+    let (max1, max0) = split_u64(max);
+
+    // (2^64 - 1) * 1 does not overflow
+    let stack = [max0, max1, 1, 0];
+    test_variant("wrapping_mul", &stack).expect_stack(&[max1, max0]);
+    test_variant("overflowing_mul", &stack).expect_stack(&[0, max1, max0]);
+    test_variant("checked_mul", &stack).expect_stack(&[max1, max0]);
+
+    // 2^32 * 2^32 overflows
+    let stack = [0, 1, 0, 1];
+    test_variant("wrapping_mul", &stack).expect_stack(&[0, 0]);
+    test_variant("overflowing_mul", &stack).expect_stack(&[1, 0, 0]);
+    test_variant("checked_mul", &stack).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // (2^64 - 1) * 2 overflows only into the third limb of the product
+    let stack = [max0, max1, 2, 0];
+    test_variant("wrapping_mul", &stack).expect_stack(&[max1, max0 - 1]);
+    test_variant("overflowing_mul", &stack).expect_stack(&[1, max1, max0 - 1]);
+    test_variant("checked_mul", &stack).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
 // COMPARISONS
 // ------------------------------------------------------------------------------------------------
 
@@ -1335,6 +1425,19 @@ proptest! {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a test which executes the specified procedure of `std::math::u64` with the provided
+/// stack inputs.
+fn test_variant(procedure: &str, stack_inputs: &[u64]) -> Test {
+    let source = format!(
+        "
+        use.std::math::u64
+        begin
+            exec.u64::{procedure}
+        end"
+    );
+    build_test!(&source, stack_inputs)
+}
+
 /// Split the provided u64 value into 32 high and low bits.
 fn split_u64(value: u64) -> (u64, u64) {
     (value >> 32, value as u32 as u64)
@@ -1,5 +1,52 @@
 use crate::build_test;
 
+mod collections;
 mod crypto;
 mod math;
+mod module_providers;
 mod sys;
+
+// SHARED FIXTURES
+// ================================================================================================
+
+/// Memory address at which tests of procedures operating on memory store their inputs.
+const ADDR: u64 = 1000;
+
+/// Builds a program which executes the `store` instructions, pushes the operands onto the stack
+/// (such that the last operand ends up on top of the stack), and then executes the specified
+/// procedure of the specified standard library module (e.g., `std::math::poly`).
+fn build_source(module: &str, store: &str, procedure: &str, operands: &[u64]) -> String {
+    let alias = module.rsplit("::").next().unwrap_or(module);
+    let push_operands = operands
+        .iter()
+        .map(|v| format!("push.{v}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "
+        use.{module}
+
+        begin
+            {store}
+            {push_operands}
+            exec.{alias}::{procedure}
+        end"
+    )
+}
+
+/// Returns instructions which store the provided values in memory, starting at [ADDR].
+fn store_elements(values: &[u64]) -> String {
+    store_elements_at(values, ADDR)
+}
+
+/// Returns instructions which store the provided values in memory, starting at the specified
+/// address.
+fn store_elements_at(values: &[u64], addr: u64) -> String {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| format!("push.{value} mem_store.{}", addr + i as u64))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,79 @@
+use assembly::{parse_module, Assembler, AssemblyError, MaslLibrary, ModuleProviderChain, Version};
+use stdlib::StdLibrary;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a library with a single module located at the specified path.
+fn project_library(namespace: &str, path: &str, source: &str) -> MaslLibrary {
+    let module = parse_module(source).unwrap();
+    MaslLibrary::new(
+        namespace,
+        Version::new(0, 1, 0),
+        Vec::new(),
+        vec![(path.to_string(), module)],
+    )
+    .unwrap()
+}
+
+fn compile_with_stdlib(source: &str) -> Result<vm_core::Program, AssemblyError> {
+    Assembler::default()
+        .with_module_provider(StdLibrary::default())
+        .compile(source)
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn project_module_with_stdlib_fallback() {
+    let project = project_library(
+        "myns",
+        "myns::util",
+        "use.std::sys export.clear exec.sys::truncate_stack end",
+    );
+    let assembler = Assembler::default().with_module_provider(
+        ModuleProviderChain::new()
+            .with_provider(project)
+            .with_provider(StdLibrary::default())
+            .with_strict_mode(true),
+    );
+
+    // the project module is resolved by the first provider, and the stdlib module it imports is
+    // resolved by the second one
+    let program = assembler
+        .compile("use.myns::util begin exec.util::clear end")
+        .unwrap();
+    let expected = compile_with_stdlib("use.std::sys begin exec.sys::truncate_stack end").unwrap();
+    assert_eq!(expected.hash(), program.hash());
+}
+
+#[test]
+fn project_module_shadows_stdlib() {
+    let project = || project_library("std", "std::sys", "export.truncate_stack drop end");
+    let source = "use.std::sys begin exec.sys::truncate_stack end";
+
+    // the procedure of the project module is used instead of the stdlib procedure
+    let assembler = Assembler::default().with_module_provider(
+        ModuleProviderChain::new()
+            .with_provider(project())
+            .with_provider(StdLibrary::default()),
+    );
+    let program = assembler.compile(source).unwrap();
+    let expected = Assembler::default().compile("begin drop end").unwrap();
+    assert_eq!(expected.hash(), program.hash());
+    assert_ne!(compile_with_stdlib(source).unwrap().hash(), program.hash());
+
+    // in strict mode, the conflict is reported
+    let assembler = Assembler::default().with_module_provider(
+        ModuleProviderChain::new()
+            .with_provider(project())
+            .with_provider(StdLibrary::default())
+            .with_strict_mode(true),
+    );
+    let err = assembler.compile(source).unwrap_err();
+    assert!(matches!(
+        err.unlocated(),
+        AssemblyError::ConflictingModuleProviders(_, path) if path == "std::sys"
+    ));
+}
@@ -0,0 +1,45 @@
+use miden::{Assembler, ProgramInputs, ProofOptions};
+use tracing_test::traced_test;
+use vm_core::utils::DisplayHex;
+
+// TRACING TESTS
+// ================================================================================================
+
+#[test]
+#[traced_test]
+fn compile_execute_prove_verify_spans() {
+    let program = Assembler::default()
+        .compile("begin push.1 push.2 add end")
+        .unwrap();
+    let (outputs, proof) =
+        miden::prove(&program, &ProgramInputs::none(), &ProofOptions::default()).unwrap();
+    miden::verify(program.hash(), &[], &outputs, proof).unwrap();
+
+    let program_hash = format!(
+        "program_hash={}",
+        DisplayHex(&<[u8; 32]>::from(program.hash()))
+    );
+
+    // each entry point emits an event with the hash of the program within its own span
+    assert!(logs_contain("compile: "));
+    assert!(logs_contain("compiled program"));
+    assert!(logs_contain("prove:execute: "));
+    assert!(logs_contain("executed program"));
+    assert!(logs_contain("cycles="));
+    assert!(logs_contain("proved program"));
+    assert!(logs_contain("proof_size="));
+    assert!(logs_contain("verify: "));
+    assert!(logs_contain("verified program"));
+    logs_assert(|lines: &[&str]| {
+        let num_events = lines
+            .iter()
+            .filter(|line| line.contains(&program_hash))
+            .count();
+        match num_events {
+            4 => Ok(()),
+            n => Err(format!(
+                "expected 4 events with the program hash, found {n}"
+            )),
+        }
+    });
+}
@@ -47,10 +47,19 @@ impl AdviceProvider {
     // ADVICE TAPE
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the number of elements remaining on the advice tape.
+    ///
+    /// This can be used after a program has been executed to check whether the program consumed
+    /// all advice provided to it.
+    pub fn tape_len(&self) -> usize {
+        self.tape.len()
+    }
+
     /// Removes the next element from the advice tape and returns it.
     ///
     /// # Errors
-    /// Returns an error if the advice tape is empty.
+    /// Returns an error if the advice tape is empty; the error contains the clock cycle at which
+    /// the read was attempted.
     pub fn read_tape(&mut self) -> Result<Felt, ExecutionError> {
         self.tape
             .pop()
@@ -52,12 +52,19 @@ impl VmStateIterator {
         }
     }
 
+    /// Returns the number of elements which have not been read from the advice tape by the end of
+    /// the execution.
+    pub fn advice_remaining(&self) -> usize {
+        self.process.advice_remaining()
+    }
+
     /// Returns the asm op info corresponding to this vm state and whether this is the start of
     /// operation sequence corresponding to current assembly instruction.
     fn get_asmop(&self) -> (Option<AsmOpInfo>, bool) {
         let assembly_ops = self.process.decoder.debug_info().assembly_ops();
 
-        if self.clk == 0 || self.asmop_idx > assembly_ops.len() {
+        // programs compiled without debug info have no asm ops
+        if self.clk == 0 || assembly_ops.is_empty() || self.asmop_idx > assembly_ops.len() {
             return (None, false);
         }
 
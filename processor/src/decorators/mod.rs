@@ -1,4 +1,5 @@
 use super::{AdviceInjector, Decorator, ExecutionError, Felt, Process, StarkField};
+use core::cmp::Ordering;
 use vm_core::{utils::collections::Vec, WORD_LEN, ZERO};
 
 // DECORATORS
@@ -18,6 +19,7 @@ impl Process {
                         .append_asmop(self.system.clk(), assembly_op.clone());
                 }
             }
+            Decorator::ErrorCode(code) => self.err_code = *code,
         }
         Ok(())
     }
@@ -29,10 +31,12 @@ impl Process {
         match injector {
             AdviceInjector::MerkleNode => self.inject_merkle_node(),
             AdviceInjector::DivResultU64 => self.inject_div_result_u64(),
+            AdviceInjector::DivResultU256 => self.inject_div_result_u256(),
             AdviceInjector::MapValue => self.inject_map_value(),
             AdviceInjector::Memory(start_addr, num_words) => {
                 self.inject_mem_values(*start_addr, *num_words)
             }
+            AdviceInjector::ProgramHash => self.inject_program_hash(),
         }
     }
 
@@ -92,7 +96,10 @@ impl Process {
         let divisor = (divisor_hi << 32) + divisor_lo;
 
         if divisor == 0 {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+            return Err(ExecutionError::DivideByZero(
+                "div_result_u64",
+                self.system.clk(),
+            ));
         }
 
         let dividend_hi = self.stack.get(2).as_int();
@@ -113,6 +120,49 @@ impl Process {
         Ok(())
     }
 
+    /// Injects the result of division of an unsigned integer of up to 512 bits by a 256-bit
+    /// unsigned integer at the head of the advice tape. The stack is expected to be arranged as
+    /// follows (from the top):
+    /// - memory address of the dividend, which is stored as 16 32-bit limbs
+    /// - memory address of the divisor, which is stored as 8 32-bit limbs
+    ///
+    /// Each limb is expected to be located in the first element of a word, and limbs are expected
+    /// to be stored starting with the least significant limb.
+    ///
+    /// The result is injected into the advice tape as follows: first the remainder is injected,
+    /// then the quotient is injected. This guarantees that when reading values from the advice
+    /// tape, first the quotient will be read, and then the remainder. Limbs of each value are
+    /// read starting with the least significant limb. Only the 8 least significant limbs of the
+    /// quotient are injected; thus, the quotient is expected to fit into 256 bits.
+    ///
+    /// # Errors
+    /// Returns an error if the divisor is ZERO.
+    fn inject_div_result_u256(&mut self) -> Result<(), ExecutionError> {
+        let dividend_addr = self.stack.get(0).as_int();
+        let divisor_addr = self.stack.get(1).as_int();
+
+        let dividend: [u32; 16] = self.read_mem_limbs(dividend_addr);
+        let divisor: [u32; 8] = self.read_mem_limbs(divisor_addr);
+
+        if divisor.iter().all(|&limb| limb == 0) {
+            return Err(ExecutionError::DivideByZero(
+                "div_result_u256",
+                self.system.clk(),
+            ));
+        }
+
+        let (quotient, remainder) = div_u512_by_u256(&dividend, &divisor);
+
+        for &limb in remainder.iter().rev() {
+            self.advice.write_tape(Felt::from(limb));
+        }
+        for &limb in quotient[..8].iter().rev() {
+            self.advice.write_tape(Felt::from(limb));
+        }
+
+        Ok(())
+    }
+
     /// Injects a list of field elements at the front of the advice tape. The list is looked up in
     /// the key-value map maintained by the advice provider using the top 4 elements on the stack
     /// as the key.
@@ -148,6 +198,39 @@ impl Process {
 
         Ok(())
     }
+
+    /// Injects the hash of the program being executed at the head of the advice tape.
+    ///
+    /// The hash is injected with its first element at the head of the tape; thus, once 4 elements
+    /// are read from the tape, the last element of the hash is at the top of the stack.
+    fn inject_program_hash(&mut self) -> Result<(), ExecutionError> {
+        let program_hash = self.program_hash;
+        self.advice.write_tape(program_hash[3]);
+        self.advice.write_tape(program_hash[2]);
+        self.advice.write_tape(program_hash[1]);
+        self.advice.write_tape(program_hash[0]);
+
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads N 32-bit limbs from the memory of the current context starting at the specified
+    /// address. Each limb is read from the first element of a word; values which do not fit into
+    /// 32 bits are truncated.
+    fn read_mem_limbs<const N: usize>(&self, start_addr: u64) -> [u32; N] {
+        let ctx = self.system.ctx();
+        let mut result = [0; N];
+        for (i, limb) in result.iter_mut().enumerate() {
+            *limb = self
+                .chiplets
+                .get_mem_value(ctx, start_addr + i as u64)
+                .map(|word| word[0].as_int() as u32)
+                .unwrap_or(0);
+        }
+        result
+    }
 }
 
 // HELPER FUNCTIONS
@@ -159,6 +242,47 @@ fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
     (hi, lo)
 }
 
+/// Divides a 512-bit unsigned integer by a 256-bit unsigned integer and returns the quotient and
+/// the remainder. All values are represented by 32-bit limbs with the least significant limb
+/// first.
+///
+/// The divisor is assumed to be non-zero.
+fn div_u512_by_u256(dividend: &[u32; 16], divisor: &[u32; 8]) -> ([u32; 16], [u32; 8]) {
+    let mut quotient = [0u32; 16];
+
+    // the remainder is always smaller than the divisor, but shifting it left by one bit may
+    // require one more limb
+    let mut remainder = [0u32; 9];
+
+    for i in (0..512).rev() {
+        // shift the remainder left by one bit and bring down the next bit of the dividend
+        let mut carry = (dividend[i / 32] >> (i % 32)) & 1;
+        for limb in remainder.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+
+        // if the remainder is not smaller than the divisor, subtract the divisor from it
+        let is_smaller = remainder[8] == 0
+            && remainder[..8].iter().rev().cmp(divisor.iter().rev()) == Ordering::Less;
+        if !is_smaller {
+            let mut borrow = 0;
+            for (j, limb) in remainder.iter_mut().enumerate() {
+                let subtrahend = divisor.get(j).copied().unwrap_or(0) as u64 + borrow;
+                let (value, underflow) = (*limb as u64).overflowing_sub(subtrahend);
+                *limb = value as u32;
+                borrow = underflow as u64;
+            }
+            quotient[i / 32] |= 1 << (i % 32);
+        }
+    }
+
+    let mut result = [0u32; 8];
+    result.copy_from_slice(&remainder[..8]);
+    (quotient, result)
+}
+
 // TESTS
 // ================================================================================================
 
@@ -1,4 +1,5 @@
 use super::{AdviceSetError, CodeBlock, Digest, Felt, Word};
+use core::fmt;
 use winterfell::ProverError;
 
 // EXECUTION ERROR
@@ -13,14 +14,44 @@ pub enum ExecutionError {
     AdviceTapeReadFailed(u32),
     CodeBlockNotFound(Digest),
     CallerNotInSyscall,
-    DivideByZero(u32),
+    DivideByZero(&'static str, u32),
     DuplicateAdviceKey(Word),
-    FailedAssertion(u32),
+    FailedAssertion { clk: u32, err_code: u32 },
     InvalidFmpValue(Felt, Felt),
     InvalidStackDepthOnReturn(usize),
+    MemoryOutOfBounds { addr: u64, cap: u64 },
     NotBinaryValue(Felt),
     NotU32Value(Felt),
     ProverError(ProverError),
     SyscallTargetNotInKernel(Digest),
     UnexecutableCodeBlock(CodeBlock),
 }
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ExecutionError::*;
+        match self {
+            AdviceKeyNotFound(key) => write!(f, "value for key {key:?} not present in the advice map"),
+            AdviceSetLookupFailed(err) => write!(f, "advice set lookup failed: {err:?}"),
+            AdviceSetNotFound(root) => write!(f, "advice set with root {root:x?} not found"),
+            AdviceSetUpdateFailed(err) => write!(f, "advice set update failed: {err:?}"),
+            AdviceTapeReadFailed(step) => write!(f, "advice tape exhausted at cycle {step}"),
+            CodeBlockNotFound(digest) => write!(f, "code block with hash {digest:?} not found"),
+            CallerNotInSyscall => write!(f, "caller instruction used outside of syscall context"),
+            DivideByZero(op, step) => write!(f, "division by zero in {op} operation at cycle {step}"),
+            DuplicateAdviceKey(key) => write!(f, "key {key:?} is already present in the advice map"),
+            FailedAssertion { clk, err_code } => write!(f, "assertion failed at cycle {clk} with error code {err_code}"),
+            InvalidFmpValue(old, new) => write!(f, "updating FMP register from {old} to {new} failed because {new} is outside of allowed range"),
+            InvalidStackDepthOnReturn(depth) => write!(f, "when returning from a call, stack depth must be 16, but was {depth}"),
+            MemoryOutOfBounds { addr, cap } => write!(f, "memory address {addr} exceeds the maximum memory address {cap}"),
+            NotBinaryValue(value) => write!(f, "an operation expected a binary value, but received {value}"),
+            NotU32Value(value) => write!(f, "an operation expected a u32 value, but received {value}"),
+            ProverError(err) => write!(f, "proof generation failed: {err}"),
+            SyscallTargetNotInKernel(digest) => write!(f, "syscall target {digest:?} is not in the kernel"),
+            UnexecutableCodeBlock(block) => write!(f, "execution reached unexecutable code block {block:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExecutionError {}
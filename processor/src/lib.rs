@@ -7,7 +7,7 @@ extern crate alloc;
 pub use vm_core::{
     chiplets::hasher::Digest,
     errors::{AdviceSetError, InputError},
-    AdviceSet, Program, ProgramInputs, ProgramOutputs,
+    AdviceSet, AdviceTapeMerge, Program, ProgramInputs, ProgramOutputs,
 };
 use vm_core::{
     code_blocks::{
@@ -21,6 +21,9 @@ use vm_core::{
 
 use winterfell::Matrix;
 
+#[cfg(feature = "tracing")]
+use vm_core::utils::DisplayHex;
+
 mod decorators;
 mod operations;
 
@@ -50,6 +53,9 @@ use trace::TraceFragment;
 mod errors;
 pub use errors::ExecutionError;
 
+mod options;
+pub use options::{DivisionByZero, ExecutionOptions};
+
 mod utils;
 
 mod debug;
@@ -90,8 +96,30 @@ pub fn execute(
     program: &Program,
     inputs: &ProgramInputs,
 ) -> Result<ExecutionTrace, ExecutionError> {
-    let mut process = Process::new(program.kernel(), inputs.clone());
+    execute_with_options(program, inputs, ExecutionOptions::default())
+}
+
+/// Returns execution output and an execution trace resulting from executing the provided program
+/// against the provided inputs with the specified [ExecutionOptions].
+///
+/// Some options change the semantics of the VM (see [ExecutionOptions]); proofs cannot be
+/// generated for traces of executions which relied on such options.
+#[cfg_attr(feature = "tracing", tracing::instrument(name = "execute", skip_all))]
+pub fn execute_with_options(
+    program: &Program,
+    inputs: &ProgramInputs,
+    options: ExecutionOptions,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let mut process = Process::with_options(program.kernel(), inputs.clone(), options);
     let program_outputs = process.execute(program)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        program_hash = %DisplayHex(&<[u8; 32]>::from(program.hash())),
+        cycles = process.system.clk(),
+        "executed program"
+    );
+
     let trace = ExecutionTrace::new(process, program_outputs);
     assert_eq!(
         program.hash(),
@@ -126,6 +154,9 @@ pub struct Process {
     range: RangeChecker,
     chiplets: Chiplets,
     advice: AdviceProvider,
+    program_hash: Word,
+    err_code: u32,
+    options: ExecutionOptions,
 }
 
 impl Process {
@@ -133,15 +164,25 @@ impl Process {
     // --------------------------------------------------------------------------------------------
     /// Creates a new process with the provided inputs.
     pub fn new(kernel: &Kernel, inputs: ProgramInputs) -> Self {
-        Self::initialize(kernel, inputs, false)
+        Self::initialize(kernel, inputs, false, ExecutionOptions::default())
     }
 
     /// Creates a new process with provided inputs and debug options enabled.
     pub fn new_debug(kernel: &Kernel, inputs: ProgramInputs) -> Self {
-        Self::initialize(kernel, inputs, true)
+        Self::initialize(kernel, inputs, true, ExecutionOptions::default())
+    }
+
+    /// Creates a new process with the provided inputs and execution options.
+    pub fn with_options(kernel: &Kernel, inputs: ProgramInputs, options: ExecutionOptions) -> Self {
+        Self::initialize(kernel, inputs, false, options)
     }
 
-    fn initialize(kernel: &Kernel, inputs: ProgramInputs, in_debug_mode: bool) -> Self {
+    fn initialize(
+        kernel: &Kernel,
+        inputs: ProgramInputs,
+        in_debug_mode: bool,
+        options: ExecutionOptions,
+    ) -> Self {
         Self {
             system: System::new(MIN_TRACE_LEN),
             decoder: Decoder::new(in_debug_mode),
@@ -149,6 +190,9 @@ impl Process {
             range: RangeChecker::new(),
             chiplets: Chiplets::new(kernel),
             advice: AdviceProvider::new(inputs),
+            program_hash: [ZERO; 4],
+            err_code: 0,
+            options,
         }
     }
 
@@ -162,6 +206,7 @@ impl Process {
             0,
             "a program has already been executed in this process"
         );
+        self.program_hash = program.hash().into();
         self.execute_code_block(program.root(), program.cb_table())?;
 
         Ok(self.stack.get_outputs())
@@ -307,6 +352,11 @@ impl Process {
             op_offset += op_batch.ops().len();
         }
 
+        // execute decorators placed after the last operation of the span
+        while let Some(decorator) = decorators.next(op_offset) {
+            self.execute_decorator(decorator)?;
+        }
+
         self.end_span_block(block)
     }
 
@@ -404,6 +454,11 @@ impl Process {
         self.chiplets.get_mem_value(ctx, addr)
     }
 
+    /// Returns the number of elements which have not been read from the advice tape.
+    pub fn advice_remaining(&self) -> usize {
+        self.advice.tape_len()
+    }
+
     pub fn to_components(self) -> (System, Decoder, Stack, RangeChecker, Chiplets) {
         (
             self.system,
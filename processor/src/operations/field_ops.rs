@@ -1,4 +1,4 @@
-use super::{utils::assert_binary, ExecutionError, Felt, FieldElement, Process};
+use super::{utils::assert_binary, DivisionByZero, ExecutionError, Felt, FieldElement, Process};
 use vm_core::{Operation, StarkField, ZERO};
 
 // FIELD OPERATIONS
@@ -40,13 +40,19 @@ impl Process {
     /// back onto the stack.
     ///
     /// # Errors
-    /// Returns an error if the value on the top of the stack is ZERO.
+    /// Returns an error if the value on the top of the stack is ZERO, unless the process was
+    /// configured to return sentinel results for division by zero (see [DivisionByZero]). In the
+    /// latter case, the result is ZERO.
     pub(super) fn op_inv(&mut self) -> Result<(), ExecutionError> {
         let a = self.stack.get(0);
-        if a == Felt::ZERO {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
+        if a == Felt::ZERO && self.options.division_by_zero() == DivisionByZero::Trap {
+            return Err(ExecutionError::DivideByZero(
+                Operation::Inv.name(),
+                self.system.clk(),
+            ));
         }
 
+        // the inverse of ZERO is ZERO
         self.stack.set(0, a.inv());
         self.stack.copy_state(1);
         Ok(())
@@ -1,4 +1,4 @@
-use super::{ExecutionError, Felt, Operation, Process};
+use super::{ExecutionError, Felt, FieldElement, Operation, Process, StarkField};
 
 // CONSTANTS
 // ================================================================================================
@@ -38,6 +38,7 @@ impl Process {
         // get the address from the stack and read the word from current memory context
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_addr(addr)?;
         let word = self.chiplets.read_mem(ctx, addr);
 
         // reverse the order of the memory word & update the stack state
@@ -65,6 +66,7 @@ impl Process {
         // get the address from the stack and read the word from memory
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_addr(addr)?;
         let mut word = self.chiplets.read_mem(ctx, addr);
         // put the retrieved word into stack order
         word.reverse();
@@ -93,6 +95,8 @@ impl Process {
         // get the address from position 12 on the stack
         let ctx = self.system.ctx();
         let addr = self.stack.get(12);
+        self.check_mem_addr(addr)?;
+        self.check_mem_addr(addr + Felt::ONE)?;
 
         // load two words from memory
         let words = self.chiplets.read_mem_double(ctx, addr);
@@ -130,6 +134,7 @@ impl Process {
         // get the address from the stack and build the word to be saved from the stack values
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_addr(addr)?;
 
         // build the word in memory order (reverse of stack order)
         let word = [
@@ -168,6 +173,7 @@ impl Process {
         // get the address and the value from the stack
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_addr(addr)?;
         let value = self.stack.get(1);
 
         // write the value to the memory and get the previous word
@@ -200,6 +206,8 @@ impl Process {
         // get the address from position 12 on the stack
         let ctx = self.system.ctx();
         let addr = self.stack.get(12);
+        self.check_mem_addr(addr)?;
+        self.check_mem_addr(addr + Felt::ONE)?;
 
         // read two words from the advice tape
         let words = self.advice.read_tape_double()?;
@@ -258,6 +266,21 @@ impl Process {
 
         Ok(())
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if the specified memory address exceeds the maximum memory address set in
+    /// the execution options of this process.
+    fn check_mem_addr(&self, addr: Felt) -> Result<(), ExecutionError> {
+        match self.options.max_memory_address() {
+            Some(cap) if addr.as_int() > cap => Err(ExecutionError::MemoryOutOfBounds {
+                addr: addr.as_int(),
+                cap,
+            }),
+            _ => Ok(()),
+        }
+    }
 }
 
 // TESTS
@@ -9,11 +9,20 @@ use super::{
 impl Process {
     /// Pops a value off the stack and asserts that it is equal to ONE.
     ///
+    /// The error code set by the most recent [Decorator::ErrorCode] decorator (or 0 if there is
+    /// none) applies only to this assertion, and thus, it is reset once the assertion executes.
+    ///
     /// # Errors
-    /// Returns an error if the popped value is not ONE.
+    /// Returns an error carrying the error code if the popped value is not ONE.
+    ///
+    /// [Decorator::ErrorCode]: vm_core::Decorator::ErrorCode
     pub(super) fn op_assert(&mut self) -> Result<(), ExecutionError> {
+        let err_code = core::mem::take(&mut self.err_code);
         if self.stack.get(0) != Felt::ONE {
-            return Err(ExecutionError::FailedAssertion(self.system.clk()));
+            return Err(ExecutionError::FailedAssertion {
+                clk: self.system.clk(),
+                err_code,
+            });
         }
         self.stack.shift_left(1);
         Ok(())
@@ -97,8 +106,10 @@ impl Process {
 #[cfg(test)]
 mod tests {
     use super::{
-        super::Operation, super::STACK_TOP_SIZE, Felt, FieldElement, Process, FMP_MAX, FMP_MIN,
+        super::Operation, super::STACK_TOP_SIZE, ExecutionError, Felt, FieldElement, Process,
+        FMP_MAX, FMP_MIN,
     };
+    use vm_core::Decorator;
 
     const MAX_PROC_LOCALS: u64 = 2_u64.pow(31) - 1;
 
@@ -113,6 +124,26 @@ mod tests {
         assert!(process.execute_op(Operation::Assert).is_ok());
     }
 
+    #[test]
+    fn op_assert_with_error_code() {
+        let mut process = Process::new_dummy(&[]);
+
+        // the error code applies to the assertion which follows the decorator
+        process.execute_decorator(&Decorator::ErrorCode(7)).unwrap();
+        let err = process.execute_op(Operation::Assert).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::FailedAssertion { err_code: 7, .. }
+        ));
+
+        // the error code is reset after the assertion
+        let err = process.execute_op(Operation::Assert).unwrap_err();
+        assert!(matches!(
+            err,
+            ExecutionError::FailedAssertion { err_code: 0, .. }
+        ));
+    }
+
     #[test]
     fn op_fmpupdate() {
         let mut process = Process::new_dummy(&[]);
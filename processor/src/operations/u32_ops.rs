@@ -1,4 +1,4 @@
-use super::{ExecutionError, Felt, FieldElement, Operation, Process, StarkField};
+use super::{DivisionByZero, ExecutionError, Felt, FieldElement, Operation, Process, StarkField};
 use crate::utils::{split_element, split_u32_into_u16};
 
 impl Process {
@@ -133,22 +133,35 @@ impl Process {
     /// the quotient and the remainder back onto the stack.
     ///
     /// # Errors
-    /// Returns an error if the divisor is ZERO.
+    /// Returns an error if the divisor is ZERO, unless the process was configured to return
+    /// sentinel results for division by zero (see [DivisionByZero]). In the latter case, the
+    /// quotient is ZERO and the remainder is equal to the dividend.
     pub(super) fn op_u32div(&mut self) -> Result<(), ExecutionError> {
         let b = self.stack.get(0).as_int();
         let a = self.stack.get(1).as_int();
 
-        if b == 0 {
-            return Err(ExecutionError::DivideByZero(self.system.clk()));
-        }
-
-        let q = a / b;
-        let r = a - q * b;
+        let (q, r) = match a.checked_div(b).zip(a.checked_rem(b)) {
+            Some(result) => result,
+            None => match self.options.division_by_zero() {
+                DivisionByZero::Trap => {
+                    return Err(ExecutionError::DivideByZero(
+                        Operation::U32div.name(),
+                        self.system.clk(),
+                    ))
+                }
+                DivisionByZero::Sentinel => (0, a),
+            },
+        };
 
         // These range checks help enforce that q <= a.
         let lo = Felt::new(a - q);
-        // These range checks help enforce that r < b.
-        let hi = Felt::new(b - r - 1);
+        // These range checks help enforce that r < b; sentinel results do not satisfy this, and
+        // thus, ZERO is range-checked instead.
+        let hi = if b == 0 {
+            Felt::ZERO
+        } else {
+            Felt::new(b - r - 1)
+        };
         self.add_range_checks(Operation::U32div, lo, hi, false);
 
         self.stack.set(0, Felt::new(r));
@@ -233,8 +246,9 @@ impl Process {
 mod tests {
     use super::{
         super::{Felt, FieldElement, Operation},
-        split_u32_into_u16, Process,
+        split_u32_into_u16, DivisionByZero, ExecutionError, Process,
     };
+    use crate::ExecutionOptions;
     use rand_utils::rand_value;
     use vm_core::{decoder::NUM_USER_OP_HELPERS, stack::STACK_TOP_SIZE};
 
@@ -408,6 +422,34 @@ mod tests {
         assert_eq!(expected, process.stack.trace_state());
     }
 
+    #[test]
+    fn op_u32div_by_zero() {
+        // --- by default, division by zero fails with an error naming the operation and cycle --
+        let mut process = Process::new_dummy_with_decoder_helpers(&[3, 7, 0]);
+        match process.execute_op(Operation::U32div) {
+            Err(ExecutionError::DivideByZero(op, step)) => {
+                assert_eq!("u32div", op);
+                assert_eq!(1, step);
+            }
+            result => panic!("expected division by zero error, but got {result:?}"),
+        }
+
+        let err = ExecutionError::DivideByZero(Operation::U32div.name(), 1);
+        assert_eq!(
+            "division by zero in u32div operation at cycle 1",
+            err.to_string()
+        );
+
+        // --- in sentinel mode, the quotient is ZERO and the remainder is the dividend ---------
+        let mut process = Process::new_dummy_with_decoder_helpers(&[3, 7, 0]);
+        process.options =
+            ExecutionOptions::default().with_division_by_zero(DivisionByZero::Sentinel);
+
+        process.execute_op(Operation::U32div).unwrap();
+        let expected = build_expected(&[7, 0, 3]);
+        assert_eq!(expected, process.stack.trace_state());
+    }
+
     // BITWISE OPERATIONS
     // --------------------------------------------------------------------------------------------
 
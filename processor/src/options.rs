@@ -0,0 +1,86 @@
+// EXECUTION OPTIONS
+// ================================================================================================
+
+/// Options which control how programs are executed by the VM.
+///
+/// Default options (see [ExecutionOptions::default()]) result in execution traces which can be
+/// proven; options which change the semantics of the VM are documented as such.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionOptions {
+    division_by_zero: DivisionByZero,
+    max_memory_address: Option<u64>,
+    trace_seed: Option<[u8; 32]>,
+}
+
+impl ExecutionOptions {
+    /// Returns these options with the behavior of division by zero set to the specified one.
+    pub fn with_division_by_zero(mut self, division_by_zero: DivisionByZero) -> Self {
+        self.division_by_zero = division_by_zero;
+        self
+    }
+
+    /// Returns these options with memory accesses limited to addresses which do not exceed the
+    /// specified one.
+    ///
+    /// Memory operations (e.g., `mem_load`, `mem_storew`, `pipe`) which access an address above
+    /// the cap fail with [ExecutionError::MemoryOutOfBounds](super::ExecutionError::MemoryOutOfBounds).
+    /// The cap applies to all memory contexts and to procedure locals as well; note that locals
+    /// are allocated starting at address 2^30. By default, memory accesses are not limited.
+    ///
+    /// The cap does not change the semantics of the VM: executions which respect the cap result
+    /// in the same traces as executions without it.
+    pub fn with_max_memory_address(mut self, max_memory_address: u64) -> Self {
+        self.max_memory_address = Some(max_memory_address);
+        self
+    }
+
+    /// Returns these options with the random values injected into the last rows of the execution
+    /// trace drawn from a generator seeded with the specified seed. By default, the generator is
+    /// seeded with the hash of the executed program.
+    ///
+    /// The seed does not change the semantics of the VM: the injected values are not constrained,
+    /// and thus, proofs generated for traces with different seeds are equally valid. However, the
+    /// proofs are byte-identical only if the traces were generated with the same seed.
+    pub fn with_trace_seed(mut self, seed: [u8; 32]) -> Self {
+        self.trace_seed = Some(seed);
+        self
+    }
+
+    /// Returns the behavior of operations which divide by zero.
+    pub fn division_by_zero(&self) -> DivisionByZero {
+        self.division_by_zero
+    }
+
+    /// Returns the largest memory address which can be accessed, or None if memory accesses are
+    /// not limited.
+    pub fn max_memory_address(&self) -> Option<u64> {
+        self.max_memory_address
+    }
+
+    /// Returns the seed for the random values injected into the execution trace, or None if the
+    /// hash of the executed program is used as the seed.
+    pub fn trace_seed(&self) -> Option<[u8; 32]> {
+        self.trace_seed
+    }
+}
+
+// DIVISION BY ZERO
+// ================================================================================================
+
+/// Behavior of `inv` and `u32div` operations when the divisor is zero.
+///
+/// Division performed by advice injectors (e.g., `adv.u64div`) always traps.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DivisionByZero {
+    /// Execution fails with [ExecutionError::DivideByZero](super::ExecutionError::DivideByZero)
+    /// which names the operation and the cycle at which the division occurred.
+    #[default]
+    Trap,
+    /// Execution continues with sentinel results: `inv` of zero is zero, and `u32div` by zero
+    /// results in the quotient of zero and the remainder equal to the dividend.
+    ///
+    /// This changes the semantics of the VM: the constraints of the VM do not accept these
+    /// results, and thus, proofs cannot be generated for executions which divide by zero in this
+    /// mode. This mode is intended for debugging and simulation only.
+    Sentinel,
+}
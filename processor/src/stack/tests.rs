@@ -69,6 +69,38 @@ fn initialize_overflow() {
     assert_eq!(stack.overflow.all_rows(), expected_overflow_rows);
 }
 
+#[test]
+fn initialize_explicit_overflow() {
+    // seed the overflow table explicitly beneath a partially populated stack top
+    let inputs = ProgramInputs::new(&[1, 2, 3, 4], &[], vec![])
+        .unwrap()
+        .with_stack_overflow(&[100, 101, 102])
+        .unwrap();
+    let expected_overflow = [Felt::new(102), Felt::new(101), Felt::new(100)];
+    assert_eq!(inputs.stack_overflow(), expected_overflow);
+
+    let mut stack = Stack::new(&inputs, 4, false);
+    assert_eq!(stack.trace_state(), build_stack(&[4, 3, 2, 1]));
+    assert_eq!(stack.get_outputs().overflow_values(), [102, 101, 100]);
+
+    // push one more item onto the stack to move the deepest slot of the stack top into overflow
+    stack.copy_state(0);
+    stack.advance_clock();
+    stack.shift_right(0);
+    stack.advance_clock();
+
+    let outputs = stack.get_outputs();
+    assert_eq!(outputs.overflow_values(), [0, 102, 101, 100]);
+
+    // overflow values paired with addresses are ordered from the deepest item in the stack
+    let overflow_values = outputs
+        .stack_overflow()
+        .iter()
+        .map(|&(_, value)| value.as_int())
+        .collect::<Vec<_>>();
+    assert_eq!(overflow_values, [100, 101, 102, 0]);
+}
+
 // SHIFT LEFT TEST
 // ================================================================================================
 
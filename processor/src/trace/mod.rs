@@ -11,7 +11,7 @@ use vm_core::{
     ProgramOutputs, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
     STACK_TRACE_OFFSET, TRACE_WIDTH, ZERO,
 };
-use winterfell::{EvaluationFrame, Matrix, Serializable, Trace, TraceLayout};
+use winterfell::{EvaluationFrame, Matrix, Trace, TraceLayout};
 
 #[cfg(feature = "std")]
 use vm_core::StarkField;
@@ -60,6 +60,9 @@ pub struct ExecutionTrace {
     aux_trace_hints: AuxTraceHints,
     program_hash: Digest,
     program_outputs: ProgramOutputs,
+    advice_remaining: usize,
+    num_cycles: usize,
+    rng_seed: [u8; 32],
 }
 
 impl ExecutionTrace {
@@ -73,12 +76,18 @@ impl ExecutionTrace {
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
     pub(super) fn new(process: Process, program_outputs: ProgramOutputs) -> Self {
-        // use program hash to initialize random element generator; this generator will be used
-        // to inject random values at the end of the trace; using program hash here is OK because
-        // we are using random values only to stabilize constraint degrees, and not to achieve
-        // perfect zero knowledge.
+        // unless a seed was provided via execution options, use program hash to initialize random
+        // element generator; this generator will be used to inject random values at the end of
+        // the trace; using program hash here is OK because we are using random values only to
+        // stabilize constraint degrees, and not to achieve perfect zero knowledge.
         let program_hash: Digest = process.decoder.program_hash().into();
-        let rng = RandomCoin::new(&program_hash.to_bytes());
+        let rng_seed = process
+            .options
+            .trace_seed()
+            .unwrap_or_else(|| program_hash.into());
+        let rng = RandomCoin::new(&rng_seed);
+        let advice_remaining = process.advice_remaining();
+        let num_cycles = process.system.clk() as usize;
         let (main_trace, aux_trace_hints) = finalize_trace(process, rng);
 
         Self {
@@ -88,6 +97,9 @@ impl ExecutionTrace {
             aux_trace_hints,
             program_hash,
             program_outputs,
+            advice_remaining,
+            num_cycles,
+            rng_seed,
         }
     }
 
@@ -104,6 +116,20 @@ impl ExecutionTrace {
         self.program_outputs.clone()
     }
 
+    /// Returns the number of advice tape elements which were not consumed by the program.
+    ///
+    /// A non-zero value indicates that the program was provided with more advice than it read.
+    pub fn advice_remaining(&self) -> usize {
+        self.advice_remaining
+    }
+
+    /// Returns the number of VM cycles it took to execute the program.
+    ///
+    /// This is the length of the trace before it was padded to the next power of two.
+    pub fn num_cycles(&self) -> usize {
+        self.num_cycles
+    }
+
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
         let mut result = [ZERO; STACK_TOP_SIZE];
@@ -236,7 +262,7 @@ impl Trace for ExecutionTrace {
             .collect::<Vec<_>>();
 
         // inject random values into the last rows of the trace
-        let mut rng = RandomCoin::new(&self.program_hash.to_bytes());
+        let mut rng = RandomCoin::new(&self.rng_seed);
         for i in self.length() - NUM_RAND_ROWS..self.length() {
             for column in aux_columns.iter_mut() {
                 column[i] = rng.draw().expect("failed to draw a random value");
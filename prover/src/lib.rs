@@ -1,16 +1,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use air::{ProcessorAir, PublicInputs};
-use processor::ExecutionTrace;
+use processor::{ExecutionOptions, ExecutionTrace};
 use prover::Prover;
 use vm_core::{utils::collections::Vec, Felt, ProgramOutputs};
 
-#[cfg(feature = "std")]
-use log::debug;
-#[cfg(feature = "std")]
+#[cfg(feature = "tracing")]
 use prover::Trace;
-#[cfg(feature = "std")]
-use std::time::Instant;
+#[cfg(feature = "tracing")]
+use vm_core::utils::DisplayHex;
 
 // EXPORTS
 // ================================================================================================
@@ -34,23 +32,60 @@ pub use vm_core::{
 ///   inputs for the VM.
 /// * `options` defines parameters for STARK proof generation.
 ///
+/// Proof generation is deterministic: proving the same program with the same inputs and options
+/// always results in byte-identical proofs. This does not weaken soundness because all randomness
+/// used by the prover is derived from public data:
+/// - Random values injected into the last rows of the execution trace are drawn from a random
+///   coin seeded with the program hash (see [prove_with_seed()] for supplying a different seed).
+/// - Randomness for constraint composition, DEEP composition, FRI folding, and query positions is
+///   drawn from a public coin seeded with the public inputs and updated with trace and constraint
+///   commitments (Fiat-Shamir).
+/// - The proof-of-work nonce is found by searching the nonce space starting from zero.
+///
 /// # Errors
 /// Returns an error if program execution or STARK proof generation fails for any reason.
 pub fn prove(
     program: &Program,
     inputs: &ProgramInputs,
     options: &ProofOptions,
+) -> Result<(ProgramOutputs, StarkProof), ExecutionError> {
+    prove_with_options(program, inputs, options, ExecutionOptions::default())
+}
+
+/// Executes and proves the specified `program` same as [prove()], but seeds the generator of the
+/// random values injected into the last rows of the execution trace with the specified `seed`
+/// instead of the program hash.
+///
+/// Proving the same program with the same inputs, options and seed always results in
+/// byte-identical proofs. The injected values are not constrained and are not used to derive any
+/// other randomness of the prover, and thus, the choice of the seed does not affect soundness.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn prove_with_seed(
+    program: &Program,
+    inputs: &ProgramInputs,
+    options: &ProofOptions,
+    seed: [u8; 32],
+) -> Result<(ProgramOutputs, StarkProof), ExecutionError> {
+    let exec_options = ExecutionOptions::default().with_trace_seed(seed);
+    prove_with_options(program, inputs, options, exec_options)
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(name = "prove", skip_all))]
+fn prove_with_options(
+    program: &Program,
+    inputs: &ProgramInputs,
+    options: &ProofOptions,
+    exec_options: ExecutionOptions,
 ) -> Result<(ProgramOutputs, StarkProof), ExecutionError> {
     // execute the program to create an execution trace
-    #[cfg(feature = "std")]
-    let now = Instant::now();
-    let trace = processor::execute(program, inputs)?;
-    #[cfg(feature = "std")]
-    debug!(
-        "Generated execution trace of {} columns and {} steps in {} ms",
-        trace.layout().main_trace_width(),
-        trace.length(),
-        now.elapsed().as_millis()
+    let trace = processor::execute_with_options(program, inputs, exec_options)?;
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        trace_width = trace.layout().main_trace_width(),
+        trace_length = trace.length(),
+        "generated execution trace"
     );
 
     let outputs = trace.program_outputs();
@@ -63,6 +98,13 @@ pub fn prove(
     );
     let proof = prover.prove(trace).map_err(ExecutionError::ProverError)?;
 
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        program_hash = %DisplayHex(&<[u8; 32]>::from(program.hash())),
+        proof_size = proof.to_bytes().len(),
+        "proved program"
+    );
+
     Ok((outputs, proof))
 }
 
@@ -1,13 +1,14 @@
 use std::{
     collections::BTreeMap,
-    fs,
+    env, fs,
     io::{self, Write},
     path::PathBuf,
 };
-use vm_assembly::{parse_module, ModuleAst, ProcedureId};
+use vm_assembly::{parse_module, Assembler, Library, MaslLibrary, ModuleAst, ProcedureId, Version};
+use vm_core::utils::Serializable;
 
-mod md_renderer;
-use md_renderer::MarkdownRenderer;
+#[path = "src/docs.rs"]
+mod docs;
 
 // CONSTANTS
 // ================================================================================================
@@ -15,6 +16,9 @@ use md_renderer::MarkdownRenderer;
 const ASM_DIR_PATH: &str = "./asm";
 const ASM_FILE_PATH: &str = "./src/asm.rs";
 const DOC_DIR_PATH: &str = "./docs";
+const LIB_FILE_NAME: &str = "std.masl";
+const INDEX_FILE_NAME: &str = "std.idx";
+const LIB_NAMESPACE: &str = "std";
 
 // TYPE ALIASES and HELPER STRUCTS
 // ================================================================================================
@@ -35,7 +39,8 @@ struct Module {
 // ================================================================================================
 
 /// Reads the contents of the `./asm` directory, and writes these contents into a single `.rs`
-/// file under `./src/asm.rs`.
+/// file under `./src/asm.rs`. Also serializes all parsed modules into a single binary artifact
+/// which is written into `OUT_DIR` and is embedded into the library at compile time.
 ///
 /// The `asm.rs` file exports a single static array of string tuples. Each tuple consist of module
 /// namespace label and the module source code for this label.
@@ -53,11 +58,14 @@ fn main() -> io::Result<()> {
         "/// An array of modules defined in Miden standard library."
     )?;
     writeln!(output, "///")?;
-    writeln!(output, "/// Entries in the array are tuples containing module namespace and module parsed+serialized.")?;
+    writeln!(
+        output,
+        "/// Entries in the array are tuples containing module namespace and module source code."
+    )?;
     writeln!(output, "#[rustfmt::skip]")?;
     writeln!(
         output,
-        "pub const MODULES: [(&str, &[u8]); {}] = [",
+        "pub const MODULES: [(&str, &str); {}] = [",
         modules.len()
     )?;
 
@@ -68,39 +76,91 @@ fn main() -> io::Result<()> {
         .try_for_each(|Module { path, source }| {
             let module = parse_module(&source)
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e.message().as_str()))?;
-            let serialized = module.to_bytes();
 
             docs.insert(path.clone(), module);
 
-            writeln!(output, "(\"{path}\",&{serialized:?}),")
+            writeln!(output, "(\"{path}\",{source:?}),")
         })?;
 
     writeln!(output, "];")?;
 
+    // serialize the parsed modules into the precompiled library artifact
+    let out_dir =
+        PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is always set for build scripts"));
+    let (library, index) = build_library(&docs)?;
+    fs::write(out_dir.join(LIB_FILE_NAME), library.to_bytes())?;
+    fs::write(out_dir.join(INDEX_FILE_NAME), index)?;
+
     // updates the documentation of these modules
-    build_stdlib_docs(&docs, DOC_DIR_PATH);
+    build_stdlib_docs(library, DOC_DIR_PATH)?;
 
     Ok(())
 }
 
-// STDLIB DOCUMENTATION
-// ================================================================================================
+/// Builds a `.masl` library (see [MaslLibrary]) from the provided modules, and builds an index of
+/// the procedures contained in the library.
+///
+/// The index consists of an entry for each procedure: the ID of the procedure followed by the
+/// position of its module in the library (u16). The index is stored alongside the library so that
+/// procedures could be mapped to modules without deserializing module ASTs.
+fn build_library(modules: &ModuleMap) -> io::Result<(MaslLibrary, Vec<u8>)> {
+    let mut index = Vec::new();
+    for (module_idx, (path, module)) in modules.iter().enumerate() {
+        let module_idx = to_u16(module_idx)?;
+        for proc_name in module.proc_names() {
+            index.extend_from_slice(&ProcedureId::from_name(proc_name, path).0);
+            index.extend_from_slice(&module_idx.to_le_bytes());
+        }
+    }
 
-/// A renderer renders a ModuleMap into a particular doc format and index (e.g: markdown, etc)
-trait Renderer {
-    // Render writes out the document files into the output directory
-    fn render(stdlib: &ModuleMap, output_dir: &str);
+    let version = env::var("CARGO_PKG_VERSION")
+        .expect("CARGO_PKG_VERSION is always set for build scripts")
+        .parse::<Version>()
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+    let modules = modules
+        .iter()
+        .map(|(path, module)| (path.clone(), module.clone()))
+        .collect();
+    let library = MaslLibrary::new(LIB_NAMESPACE, version, Vec::new(), modules)
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+
+    Ok((library, index))
 }
 
-// Writes Miden standard library modules documentation markdown files based on the available modules and comments.
-pub fn build_stdlib_docs(module_map: &ModuleMap, output_dir: &str) {
+fn to_u16(value: usize) -> io::Result<u16> {
+    u16::try_from(value).map_err(|_| io::Error::new(io::ErrorKind::Other, "value exceeds u16"))
+}
+
+// STDLIB DOCUMENTATION
+// ================================================================================================
+
+/// Writes Markdown documentation files for all modules of the provided library into the output
+/// directory.
+///
+/// The documentation is rendered in the same way as by `StdLibrary::render_docs()`; procedures
+/// of the library are compiled to compute their MAST roots.
+fn build_stdlib_docs(library: MaslLibrary, output_dir: &str) -> io::Result<()> {
     // Remove functions folder to re-generate
-    fs::remove_dir_all(output_dir).unwrap();
-    fs::create_dir(output_dir).unwrap();
+    fs::remove_dir_all(output_dir)?;
+    fs::create_dir(output_dir)?;
 
-    // Render the stdlib struct into markdown
-    // TODO: Make the renderer choice pluggable.
-    MarkdownRenderer::render(module_map, output_dir);
+    let modules = library
+        .modules()
+        .into_iter()
+        .map(|path| (path.to_string(), library.get_module(path).cloned()))
+        .collect::<Vec<_>>();
+    let assembler = Assembler::new().with_module_provider(library);
+
+    for (path, module) in modules {
+        let module = module.map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?;
+        let procs = docs::exported_proc_roots(&assembler, &path, &module);
+        fs::write(
+            PathBuf::from(output_dir).join(docs::markdown_file_name(&path)),
+            docs::render_module(&path, &procs),
+        )?;
+    }
+
+    Ok(())
 }
 
 // HELPER FUNCTIONS
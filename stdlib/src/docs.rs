@@ -0,0 +1,93 @@
+//! Markdown documentation of standard library modules.
+//!
+//! This module is also included into the build script of the crate, which uses it to render the
+//! documentation checked into the `docs` directory; thus, it must not depend on anything else
+//! from this crate.
+
+use core::fmt::Write;
+use vm_assembly::{Assembler, ModuleAst, ProcedureAst};
+use vm_core::{
+    chiplets::hasher::Digest,
+    utils::{collections::Vec, string::String, DisplayHex},
+};
+
+// MARKDOWN RENDERER
+// ================================================================================================
+
+/// Renders documentation for a single standard library module in Markdown format.
+///
+/// The rendered document contains a header with the module path followed by a table with a row
+/// for each of the provided procedures. Each row contains procedure name, number of procedure
+/// locals, MAST root of the procedure (hex-encoded), and procedure description taken from its
+/// doc comment.
+///
+/// Procedures are rendered in the order in which they are provided.
+pub fn render_module(module_path: &str, procs: &[(&ProcedureAst, Digest)]) -> String {
+    let mut result = String::new();
+
+    writeln!(result, "\n## {module_path}").expect("writing to a string should not fail");
+    result.push_str("| Procedure | Locals | MAST root | Description |\n");
+    result.push_str("| ----------- | ----------- | ----------- | ------------- |\n");
+
+    for (proc, mast_root) in procs.iter() {
+        let description = proc
+            .docs
+            .as_ref()
+            .map(|docs| docs.replace('|', "\\|").replace('\n', "<br /><br />"))
+            .unwrap_or_default();
+
+        writeln!(
+            result,
+            "| {} | {} | 0x{} | {} |",
+            proc.name,
+            proc.num_locals,
+            DisplayHex(&<[u8; 32]>::from(*mast_root)),
+            description
+        )
+        .expect("writing to a string should not fail");
+    }
+
+    result
+}
+
+/// Returns the name of the Markdown file for the module located at the specified path.
+///
+/// The file name is built from the last two components of the path; for example, documentation
+/// for `std::math::u64` module is written into `u64_math.md` file.
+pub fn markdown_file_name(module_path: &str) -> String {
+    let mut parts = module_path.rsplit("::");
+    let name = parts.next().unwrap_or_default();
+    let section = parts.next().unwrap_or_default();
+    format!("{name}_{section}.md")
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns all procedures exported from the specified module together with their MAST roots.
+///
+/// MAST roots are computed by compiling a program which only invokes a given procedure; the root
+/// of such a program is the root of the procedure itself. The provided assembler must be able to
+/// resolve the module and all modules it depends on.
+///
+/// # Panics
+/// Panics if any of the exported procedures could not be compiled.
+pub(crate) fn exported_proc_roots<'a>(
+    assembler: &Assembler,
+    module_path: &str,
+    module: &'a ModuleAst,
+) -> Vec<(&'a ProcedureAst, Digest)> {
+    let alias = module_path.rsplit("::").next().unwrap_or(module_path);
+    module
+        .local_procs
+        .iter()
+        .filter(|proc| proc.is_export)
+        .map(|proc| {
+            let source = format!("use.{module_path} begin exec.{alias}::{} end", proc.name);
+            let program = assembler
+                .compile(source)
+                .expect("standard library procedure compilation should not fail");
+            (proc, program.hash())
+        })
+        .collect()
+}
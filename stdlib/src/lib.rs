@@ -1,34 +1,352 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use vm_assembly::{Library, LibraryError, ModuleAst, ModuleProvider, NamedModuleAst, ProcedureId};
-use vm_core::utils::{
-    collections::{BTreeMap, Vec},
-    string::{String, ToString},
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
+use once_cell::race::OnceBox;
+use vm_assembly::{
+    parse_module, Assembler, Library, LibraryError, ModuleAst, ModuleProvider, NamedModuleAst,
+    ProcedureId, Version,
+};
+use vm_core::{
+    artifact::{read_and_check_header, ArtifactKind, HEADER_LEN},
+    chiplets::hasher::Digest,
+    utils::{
+        collections::{BTreeMap, Vec},
+        string::{String, ToString},
+        Box, ByteReader, DeserializationError, DisplayHex, SliceReader,
+    },
 };
 
 pub mod asm;
 use asm::MODULES;
 
+pub mod docs;
+
 // CONSTANTS
 // ================================================================================================
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Standard library modules parsed and serialized into a `.masl` library at build time.
+const LIBRARY_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/std.masl"));
+
+/// Index mapping IDs of all standard library procedures to positions of their modules in
+/// [LIBRARY_BYTES]; built together with the library.
+const PROC_INDEX_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/std.idx"));
+
 // STANDARD LIBRARY
 // ================================================================================================
 
-/// TODO: add docs
+/// Miden standard library.
+///
+/// By default, the library is instantiated from a `.masl` library which is generated when the
+/// crate is built; the same library can also be loaded via [vm_assembly::MaslLibrary]. Module ASTs
+/// are deserialized lazily, the first time a procedure from a given module is requested.
+/// [StdLibrary::from_sources()] can be used to parse the library from its MASM sources instead.
+#[derive(Clone)]
 pub struct StdLibrary {
-    modules: Vec<(String, ModuleAst)>,
+    version: Version,
+    modules: Vec<StdModule>,
     proc_to_module: BTreeMap<ProcedureId, usize>,
 }
 
+impl StdLibrary {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StdLibrary] instantiated by parsing the MASM sources of all modules.
+    ///
+    /// This is slower than [StdLibrary::default()] and is intended to be used during development
+    /// of the standard library.
+    ///
+    /// # Panics
+    /// Panics if any of the standard library modules fails to parse, or if the version of the
+    /// crate is not a valid library version.
+    pub fn from_sources() -> Self {
+        let mut modules = Vec::with_capacity(MODULES.len());
+        let mut proc_to_module = BTreeMap::new();
+
+        for (i, (module_path, source)) in MODULES.iter().enumerate() {
+            let module_ast =
+                parse_module(source).expect("standard library module parsing should not fail");
+
+            // for each procedure in the module, compute its ID and create a map between procedure
+            // ID and its module
+            for proc_name in module_ast.proc_names() {
+                let proc_id = ProcedureId::from_name(proc_name, module_path);
+                proc_to_module.insert(proc_id, i);
+            }
+
+            // add the module together with its path to the module list
+            let ast = OnceBox::new();
+            let _ = ast.set(Box::new(module_ast));
+            modules.push(StdModule {
+                path: module_path.to_string(),
+                bytes: &[],
+                ast,
+            });
+        }
+
+        Self {
+            version: VERSION
+                .parse()
+                .expect("crate version should be a valid library version"),
+            modules,
+            proc_to_module,
+        }
+    }
+
+    /// Returns a new [StdLibrary] instantiated from the specified `.masl` library and the index
+    /// of its procedures.
+    ///
+    /// Only the module paths are read eagerly; module ASTs are sliced out of the static buffer and
+    /// deserialized when they are first accessed. Procedures are mapped to their modules via the
+    /// index, and thus, mapping them does not require deserializing module ASTs either.
+    fn from_bytes(bytes: &'static [u8], proc_index: &[u8]) -> Result<Self, DeserializationError> {
+        read_and_check_header(&mut SliceReader::new(bytes), ArtifactKind::Library)?;
+        let mut source = StaticReader::new(&bytes[HEADER_LEN..]);
+
+        // skip the namespace and read the version (major, minor, and patch components) of the
+        // library; the standard library does not have any dependencies
+        let namespace_len = source.read_u16()? as usize;
+        source.read_slice(namespace_len)?;
+        let version = Version::new(source.read_u32()?, source.read_u32()?, source.read_u32()?);
+        if source.read_u16()? != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "standard library must not have dependencies".to_string(),
+            ));
+        }
+
+        let num_modules = source.read_u16()? as usize;
+        let mut modules = Vec::with_capacity(num_modules);
+        for _ in 0..num_modules {
+            let path_len = source.read_u16()? as usize;
+            let path = core::str::from_utf8(source.read_slice(path_len)?)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            let module_len = source.read_u32()? as usize;
+
+            modules.push(StdModule {
+                path: path.to_string(),
+                bytes: source.read_slice(module_len)?,
+                ast: OnceBox::new(),
+            });
+        }
+
+        let mut proc_to_module = BTreeMap::new();
+        let mut index = SliceReader::new(proc_index);
+        while index.has_more_bytes() {
+            let proc_id = ProcedureId(index.read_u8_array()?);
+            let module_idx = index.read_u16()? as usize;
+            if module_idx >= modules.len() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid module index {module_idx} in the procedure index"
+                )));
+            }
+            proc_to_module.insert(proc_id, module_idx);
+        }
+
+        Ok(Self {
+            version,
+            modules,
+            proc_to_module,
+        })
+    }
+
+    /// Returns a new [StdLibrary] which contains only the modules located at the specified paths.
+    ///
+    /// Procedures from modules which were not selected cannot be resolved by the returned library,
+    /// and thus, programs importing such modules will fail to compile. Modules which the selected
+    /// modules depend on must be selected as well: for example, `std::math::poly512` can be
+    /// selected only together with `std::math::ntt512` and `std::math::u64`.
+    ///
+    /// Modules are taken from the library serialized at build time; the selected modules are
+    /// deserialized to check their dependencies, and modules which were not selected are never
+    /// deserialized.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A module for any of the specified paths does not exist in the standard library.
+    /// - Any of the selected modules depends on a module which was not selected.
+    pub fn with_modules(module_paths: &[&str]) -> Result<Self, LibraryError> {
+        let library = Self::default();
+
+        // make sure all requested modules exist before filtering anything out
+        if let Some(path) = module_paths
+            .iter()
+            .find(|&&path| !library.modules.iter().any(|module| module.path == path))
+        {
+            return Err(LibraryError::ModuleNotFound(path.to_string()));
+        }
+
+        // keep only the selected modules and re-map procedure IDs to the new module indexes
+        let mut module_idx_map = BTreeMap::new();
+        let mut modules = Vec::with_capacity(module_paths.len());
+        for (i, module) in library.modules.iter().enumerate() {
+            if module_paths.contains(&module.path.as_str()) {
+                module_idx_map.insert(i, modules.len());
+                modules.push(module.clone());
+            }
+        }
+
+        // make sure all modules which the selected modules depend on were selected as well
+        for module in modules.iter() {
+            for proc_id in module.ast().imported_procs() {
+                if let Some(&idx) = library.proc_to_module.get(&proc_id) {
+                    if !module_idx_map.contains_key(&idx) {
+                        return Err(LibraryError::ModuleDependencyNotFound {
+                            module: module.path.clone(),
+                            dependency: library.modules[idx].path.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let proc_to_module = library
+            .proc_to_module
+            .into_iter()
+            .filter_map(|(proc_id, idx)| module_idx_map.get(&idx).map(|&idx| (proc_id, idx)))
+            .collect();
+
+        Ok(Self {
+            version: library.version,
+            modules,
+            proc_to_module,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the semantic version of this library.
+    ///
+    /// This is the version of the crate, which is also the version of the `.masl` library
+    /// generated when the crate is built.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the module located at the specified path, or None if the module is not a part of
+    /// this library.
+    fn find_module(&self, module_path: &str) -> Option<&StdModule> {
+        self.modules
+            .iter()
+            .find(|module| module.path == module_path)
+    }
+}
+
+impl StdLibrary {
+    // PROCEDURE MANIFEST
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a list of all procedures exported from the standard library together with their
+    /// MAST roots.
+    ///
+    /// Procedures are identified by their fully-qualified names (e.g., `std::math::u64::checked_add`)
+    /// and are listed in the order of module paths, and then in the order in which they are
+    /// defined within a module.
+    ///
+    /// Since procedures invoked via `exec` are inlined into a program's MAST, a change to any of
+    /// the MAST roots in the manifest may change hashes of programs which use the standard library.
+    pub fn manifest(&self) -> Vec<(String, Digest)> {
+        let assembler = Assembler::new().with_module_provider(self.clone());
+
+        let mut result = Vec::new();
+        for module in self.modules.iter() {
+            for (proc, mast_root) in
+                docs::exported_proc_roots(&assembler, &module.path, module.ast())
+            {
+                result.push((ProcedureId::path(&proc.name, &module.path), mast_root));
+            }
+        }
+
+        result
+    }
+
+    /// Returns the manifest of this library (see [StdLibrary::manifest()]) serialized as a JSON
+    /// object with the following structure:
+    ///
+    /// ```json
+    /// {
+    ///   "version": "<library version in the major.minor.patch format>",
+    ///   "procedures": [
+    ///     { "name": "<fully-qualified procedure name>", "root": "<hex-encoded MAST root>" }
+    ///   ]
+    /// }
+    /// ```
+    pub fn manifest_json(&self) -> String {
+        let procedures = self
+            .manifest()
+            .into_iter()
+            .map(|(name, root)| {
+                let root = format!("0x{}", DisplayHex(&<[u8; 32]>::from(root)));
+                serde_json::json!({ "name": name, "root": root })
+            })
+            .collect::<Vec<_>>();
+        let version = self.version.to_string();
+        let manifest = serde_json::json!({ "version": version, "procedures": procedures });
+
+        let mut result = serde_json::to_string_pretty(&manifest)
+            .expect("manifest serialization should not fail");
+        result.push('\n');
+        result
+    }
+
+    // DOCUMENTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns Markdown documentation for the module located at the specified path.
+    ///
+    /// The documentation lists all procedures exported from the module together with the number
+    /// of their locals, their MAST roots, and their doc comments.
+    ///
+    /// # Errors
+    /// Returns an error if the module for the specified path does not exist in the standard
+    /// library.
+    pub fn render_module_docs(&self, module_path: &str) -> Result<String, LibraryError> {
+        let module = Library::get_module(self, module_path)?;
+        let assembler = Assembler::new().with_module_provider(self.clone());
+        let procs = docs::exported_proc_roots(&assembler, module_path, module);
+
+        Ok(docs::render_module(module_path, &procs))
+    }
+
+    /// Writes Markdown documentation for all modules of the standard library into the specified
+    /// directory. Documentation for each module is written into a separate file.
+    ///
+    /// # Errors
+    /// Returns an error if the directory could not be created or any of the files could not be
+    /// written.
+    #[cfg(feature = "std")]
+    pub fn render_docs<P>(&self, out_dir: P) -> std::io::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let out_dir = out_dir.as_ref();
+        std::fs::create_dir_all(out_dir)?;
+
+        for module in self.modules.iter() {
+            let docs = self
+                .render_module_docs(&module.path)
+                .expect("module should be present in the library");
+            std::fs::write(out_dir.join(docs::markdown_file_name(&module.path)), docs)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl ModuleProvider for StdLibrary {
     fn get_module(&self, proc_id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
         self.proc_to_module
             .get(proc_id)
             .map(|&module_idx| &self.modules[module_idx])
-            .map(|(path, ast)| ast.named_ref(path))
+            .map(|module| module.ast().named_ref(&module.path))
     }
 }
 
@@ -45,59 +363,373 @@ impl Library for StdLibrary {
         VERSION
     }
 
+    /// Returns paths of all modules contained in this library, in lexicographic order.
+    fn modules(&self) -> Vec<&str> {
+        self.modules
+            .iter()
+            .map(|module| module.path.as_str())
+            .collect()
+    }
+
     /// Returns the source code of the module located at the specified path.
     ///
     /// # Errors
     /// Returns an error if the modules for the specified path does not exist in the standard
     /// library.
     fn get_module(&self, module_path: &str) -> Result<&ModuleAst, LibraryError> {
-        self.modules
-            .iter()
-            .find(|(path, _)| path == module_path)
-            .map(|(_, ast)| ast)
+        self.find_module(module_path)
+            .map(|module| module.ast())
             .ok_or_else(|| LibraryError::ModuleNotFound(module_path.to_string()))
     }
+
+    /// Returns the source code of the module located at the specified path.
+    ///
+    /// Module sources are embedded into the crate regardless of how the library was instantiated;
+    /// thus, the source is available for every module contained in this library.
+    fn get_module_source(&self, module_path: &str) -> Option<&str> {
+        self.find_module(module_path)?;
+        MODULES
+            .iter()
+            .find(|(path, _)| *path == module_path)
+            .map(|(_, source)| *source)
+    }
 }
 
 impl Default for StdLibrary {
-    /// Returns a new [StdLibrary] instance instantiated with default parameters.
+    /// Returns a new [StdLibrary] instance instantiated from the library serialized at build time.
     fn default() -> Self {
-        let mut modules = Vec::with_capacity(MODULES.len());
-        let mut proc_to_module = BTreeMap::new();
+        Self::from_bytes(LIBRARY_BYTES, PROC_INDEX_BYTES)
+            .expect("static library deserialization should be infallible")
+    }
+}
 
-        for (i, (module_path, module_bytes)) in MODULES.iter().enumerate() {
-            // deserialize module AST
-            let module_ast = ModuleAst::from_bytes(module_bytes)
-                .expect("static module deserialization should be infallible");
+// STANDARD LIBRARY MODULE
+// ================================================================================================
 
-            // for each procedure in the module, compute its ID and create a map between procedure
-            // ID and its module
-            for proc_ast in module_ast.local_procs.iter() {
-                let proc_id = ProcedureId::from_name(&proc_ast.name, module_path);
-                proc_to_module.insert(proc_id, i);
-            }
+/// A module of the standard library together with its lazily deserialized AST.
+///
+/// The AST is kept in a [OnceBox] (rather than in a `sync::OnceCell`, which requires `std`) so that
+/// the library could be shared between threads in `no_std` environments as well.
+struct StdModule {
+    path: String,
+    bytes: &'static [u8],
+    ast: OnceBox<ModuleAst>,
+}
 
-            // add the module together with its path to the module list
-            modules.push((module_path.to_string(), module_ast));
+impl Clone for StdModule {
+    /// Returns a copy of this module; the AST is copied only if it has already been deserialized.
+    fn clone(&self) -> Self {
+        let ast = OnceBox::new();
+        if let Some(module) = self.ast.get() {
+            let _ = ast.set(Box::new(module.clone()));
         }
 
         Self {
-            modules,
-            proc_to_module,
+            path: self.path.clone(),
+            bytes: self.bytes,
+            ast,
         }
     }
 }
 
+impl StdModule {
+    /// Returns the AST of this module, deserializing it on first access.
+    fn ast(&self) -> &ModuleAst {
+        self.ast.get_or_init(|| {
+            let ast = ModuleAst::from_bytes(self.bytes)
+                .expect("static module deserialization should be infallible");
+            Box::new(ast)
+        })
+    }
+}
+
+// STATIC READER
+// ================================================================================================
+
+/// A reader of a static byte buffer which returns the read bytes as sub-slices of the buffer, and
+/// thus, does not allocate memory for them.
+struct StaticReader {
+    bytes: &'static [u8],
+    pos: usize,
+}
+
+impl StaticReader {
+    fn new(bytes: &'static [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read_slice(&mut self, len: usize) -> Result<&'static [u8], DeserializationError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(DeserializationError::UnexpectedEOF)?;
+        let result = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(result)
+    }
+
+    fn read_u16(&mut self) -> Result<u16, DeserializationError> {
+        let bytes = self.read_slice(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, DeserializationError> {
+        let bytes = self.read_slice(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
-    use super::Library;
+    use super::{
+        docs, Library, LibraryError, StdLibrary, String, Vec, Version, LIBRARY_BYTES, MODULES,
+    };
+    use vm_assembly::{Assembler, MaslLibrary};
+
+    /// Location of the snapshot of the procedure manifest of the standard library.
+    #[cfg(feature = "std")]
+    const MANIFEST_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/manifest.json");
 
     #[test]
     fn lib_version() {
-        let stdlib = super::StdLibrary::default();
-        assert_eq!("0.2.0", stdlib.version())
+        for stdlib in [StdLibrary::default(), StdLibrary::from_sources()] {
+            assert_eq!(Version::new(0, 2, 0), stdlib.version());
+            assert_eq!("0.2.0", Library::version(&stdlib));
+        }
+    }
+
+    #[test]
+    fn serialized_lib_matches_sources() {
+        let serialized = StdLibrary::default();
+        let parsed = StdLibrary::from_sources();
+        assert_eq!(serialized.proc_to_module, parsed.proc_to_module);
+
+        for (path, _) in MODULES.iter() {
+            let module = Library::get_module(&serialized, path).unwrap();
+            assert_eq!(module, Library::get_module(&parsed, path).unwrap());
+
+            // import every module and invoke each of its exported procedures; this also makes
+            // sure that dependencies between modules get resolved correctly
+            let alias = path.rsplit("::").next().unwrap();
+            let mut source = String::from("use.");
+            source.push_str(path);
+            source.push_str("\nbegin\n");
+            for proc_ast in module.local_procs.iter().filter(|p| p.is_export) {
+                source.push_str("exec.");
+                source.push_str(alias);
+                source.push_str("::");
+                source.push_str(&proc_ast.name);
+                source.push('\n');
+            }
+            source.push_str("end");
+
+            let expected = Assembler::new()
+                .with_module_provider(StdLibrary::from_sources())
+                .compile(&source)
+                .unwrap();
+            let actual = Assembler::new()
+                .with_module_provider(StdLibrary::default())
+                .compile(&source)
+                .unwrap();
+            assert_eq!(expected.hash(), actual.hash());
+        }
+    }
+
+    #[test]
+    fn library_artifact() {
+        // the embedded library is a regular .masl library
+        let library = MaslLibrary::read_from_bytes(LIBRARY_BYTES).unwrap();
+        assert_eq!("std", library.namespace());
+        assert_eq!(env!("CARGO_PKG_VERSION"), library.version().to_string());
+
+        let stdlib = StdLibrary::default();
+        assert_eq!(stdlib.modules(), library.modules());
+
+        // the library can be shared between threads
+        fn assert_sync<T: Send + Sync>() {}
+        assert_sync::<StdLibrary>();
+    }
+
+    #[test]
+    fn with_modules() {
+        let stdlib = StdLibrary::default();
+        assert_eq!(MODULES.len(), stdlib.modules().len());
+        for (path, _) in MODULES.iter() {
+            assert!(stdlib.modules().contains(path));
+        }
+
+        let selected = ["std::math::u64", "std::crypto::hashes::blake3"];
+        let stdlib = StdLibrary::with_modules(&selected).unwrap();
+        assert_eq!(
+            vec!["std::crypto::hashes::blake3", "std::math::u64"],
+            stdlib.modules()
+        );
+
+        // sources and procedures are available only for the selected modules
+        assert!(stdlib.get_module_source("std::math::u64").is_some());
+        assert!(stdlib.get_module_source("std::sys").is_none());
+        assert!(stdlib.procedures("std::sys").is_none());
+
+        // procedures from the selected modules can be resolved
+        let assembler = Assembler::new().with_module_provider(stdlib);
+        assert!(assembler
+            .compile("use.std::math::u64 begin exec.u64::checked_add end")
+            .is_ok());
+
+        // procedures from other modules cannot be resolved
+        assert!(assembler
+            .compile("use.std::sys begin exec.sys::truncate_stack end")
+            .is_err());
+
+        // unknown module paths are rejected
+        assert!(matches!(
+            StdLibrary::with_modules(&["std::math::u64", "std::math::u16"]),
+            Err(LibraryError::ModuleNotFound(path)) if path == "std::math::u16"
+        ));
+    }
+
+    #[test]
+    fn with_modules_dependencies() {
+        // modules cannot be selected without the modules they depend on
+        assert!(matches!(
+            StdLibrary::with_modules(&["std::math::poly512", "std::math::u64"]),
+            Err(LibraryError::ModuleDependencyNotFound { module, dependency })
+                if module == "std::math::poly512" && dependency == "std::math::ntt512"
+        ));
+
+        // when all dependencies are selected, procedures of the library can be compiled
+        let selected = ["std::math::poly512", "std::math::ntt512", "std::math::u64"];
+        let stdlib = StdLibrary::with_modules(&selected).unwrap();
+        let manifest = stdlib.manifest();
+        assert!(manifest
+            .iter()
+            .any(|(name, _)| name == "std::math::poly512::mul_zq"));
+        assert!(stdlib.render_module_docs("std::math::poly512").is_ok());
+    }
+
+    #[test]
+    fn module_listing() {
+        for stdlib in [StdLibrary::default(), StdLibrary::from_sources()] {
+            assert!(stdlib.modules().contains(&"std::sys"));
+
+            let (_, expected_source) = MODULES
+                .iter()
+                .find(|(path, _)| *path == "std::sys")
+                .unwrap();
+            assert_eq!(Some(*expected_source), stdlib.get_module_source("std::sys"));
+
+            let module = stdlib.get_module_ast("std::sys").unwrap();
+            assert_eq!(module, Library::get_module(&stdlib, "std::sys").unwrap());
+
+            let procs = stdlib.procedures("std::sys").unwrap();
+            assert_eq!(1, procs.len());
+            assert_eq!("truncate_stack", procs[0].name);
+            assert_eq!(4, procs[0].num_locals);
+            assert!(procs[0]
+                .docs
+                .as_ref()
+                .unwrap()
+                .starts_with("Removes elements deep in the stack"));
+
+            // exported procedures are listed in the order of their definition; private procedures
+            // are not listed
+            let names = stdlib
+                .procedures("std::math::u64")
+                .unwrap()
+                .iter()
+                .map(|proc| proc.name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                [
+                    "overflowing_add",
+                    "wrapping_add",
+                    "checked_add",
+                    "wrapping_sub"
+                ],
+                names[..4]
+            );
+            assert!(!names.contains(&"u32assert4"));
+
+            // unknown modules are reported as missing
+            assert!(stdlib.get_module_source("std::math::u16").is_none());
+            assert!(stdlib.get_module_ast("std::math::u16").is_none());
+            assert!(stdlib.procedures("std::math::u16").is_none());
+        }
+    }
+
+    #[test]
+    fn render_module_docs() {
+        let stdlib = StdLibrary::default();
+        let root = Assembler::new()
+            .with_module_provider(StdLibrary::default())
+            .compile("use.std::sys begin exec.sys::truncate_stack end")
+            .unwrap()
+            .hash();
+
+        let mut expected = String::from(
+            "\n## std::sys\n\
+            | Procedure | Locals | MAST root | Description |\n\
+            | ----------- | ----------- | ----------- | ------------- |\n\
+            | truncate_stack | 4 | 0x",
+        );
+        for byte in <[u8; 32]>::from(root) {
+            expected.push_str(&format!("{byte:02x}"));
+        }
+        expected.push_str(
+            " | Removes elements deep in the stack until the depth of the stack is exactly 16. \
+            The elements<br /><br />are removed in such a way that the top 16 elements of the \
+            stack remain unchanged. If the stack<br /><br />would otherwise contain more than 16 \
+            elements at the end of execution, then adding a call to this<br /><br />function at \
+            the end will reduce the size of the public inputs that are shared with the verifier.\
+            <br /><br />Input: Stack with 16 or more elements.<br /><br />Output: Stack with only \
+            the original top 16 elements. |\n",
+        );
+
+        assert_eq!(expected, stdlib.render_module_docs("std::sys").unwrap());
+        assert_eq!("sys_std.md", docs::markdown_file_name("std::sys"));
+    }
+
+    /// Compares the procedure manifest of the standard library against the snapshot checked into
+    /// the repository.
+    #[cfg(feature = "std")]
+    #[test]
+    fn manifest_snapshot() {
+        let snapshot = std::fs::read_to_string(MANIFEST_PATH).unwrap();
+        assert_eq!(
+            snapshot,
+            StdLibrary::default().manifest_json(),
+            "standard library manifest changed; run `cargo test -p miden-stdlib update_manifest \
+            -- --ignored` to update the snapshot"
+        );
+    }
+
+    /// Writes the procedure manifest of the standard library into the snapshot checked into the
+    /// repository; this test is ignored by default and is intended to be run explicitly after
+    /// changing the library.
+    #[cfg(feature = "std")]
+    #[test]
+    #[ignore]
+    fn update_manifest() {
+        std::fs::write(MANIFEST_PATH, StdLibrary::default().manifest_json()).unwrap();
+    }
+
+    #[test]
+    fn exported_procs_are_documented() {
+        let stdlib = StdLibrary::default();
+        for (path, _) in MODULES.iter() {
+            let module = Library::get_module(&stdlib, path).unwrap();
+            for proc in module.local_procs.iter().filter(|p| p.is_export) {
+                assert!(
+                    proc.docs.is_some(),
+                    "procedure {}::{} is not documented",
+                    path,
+                    proc.name
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,548 @@
+use assembly::{Assembler, ModuleProvider, NamedModuleAst, ProcedureId};
+use core::fmt;
+use processor::Process;
+use proptest::{prop_assert_eq, test_runner::TestCaseError};
+use std::rc::Rc;
+
+pub use assembly::AssemblyError;
+pub use processor::{ExecutionError, ExecutionTrace, VmState, VmStateIterator};
+pub use prover::{ProofOptions, StarkProof};
+pub use stdlib::StdLibrary;
+pub use vm_core::{
+    chiplets::hasher::Digest, code_blocks::CodeBlock, stack::STACK_TOP_SIZE, AdviceSet,
+    CodeBlockTable, Felt, FieldElement, Operation, Program, ProgramInputs, ProgramOutputs,
+    StackTopState, StarkField, Word,
+};
+
+#[cfg(test)]
+mod tests;
+
+// TEST ERROR
+// ================================================================================================
+
+/// Specifies the error expected by [Test::expect_error()].
+///
+/// Depending on the variant, the test is either compiled or executed, and the resulting error is
+/// expected to contain the provided substring in either its display or its debug representation.
+pub enum TestError<'a> {
+    AssemblyError(&'a str),
+    ExecutionError(&'a str),
+}
+
+// TEST
+// ================================================================================================
+
+/// A test of a Miden assembly program.
+///
+/// The test is compiled, executed, and proven using the same assembler, processor, and prover as
+/// used in production. A test can be instantiated via [Test::new()] or [build_test!] macro, and
+/// can be configured using a "builder" pattern. Specifically:
+/// - Miden standard library is always available to the program; additional libraries can be
+///   added via `with_library()` method.
+/// - If `with_kernel()` method is not used, the program is compiled against an empty kernel.
+/// - Stack inputs, advice tape, and advice sets are empty unless set via `with_stack_inputs()`,
+///   `with_advice_tape()`, and `with_advice_sets()` methods respectively.
+pub struct Test {
+    source: String,
+    kernel: Option<String>,
+    libraries: Vec<Rc<dyn ModuleProvider>>,
+    stack_inputs: Vec<u64>,
+    advice_tape: Vec<u64>,
+    advice_sets: Vec<AdviceSet>,
+    in_debug_mode: bool,
+}
+
+impl Test {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new test for the specified source with no inputs.
+    pub fn new<S>(source: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self {
+            source: source.as_ref().to_string(),
+            kernel: None,
+            libraries: vec![Rc::new(StdLibrary::default())],
+            stack_inputs: Vec::new(),
+            advice_tape: Vec::new(),
+            advice_sets: Vec::new(),
+            in_debug_mode: false,
+        }
+    }
+
+    /// Sets the initial stack of the test; the last value ends up on top of the stack.
+    pub fn with_stack_inputs(mut self, stack_inputs: &[u64]) -> Self {
+        self.stack_inputs = stack_inputs.to_vec();
+        self
+    }
+
+    /// Sets the initial advice tape of the test.
+    pub fn with_advice_tape(mut self, advice_tape: &[u64]) -> Self {
+        self.advice_tape = advice_tape.to_vec();
+        self
+    }
+
+    /// Sets the initial advice sets of the test.
+    pub fn with_advice_sets(mut self, advice_sets: Vec<AdviceSet>) -> Self {
+        self.advice_sets = advice_sets;
+        self
+    }
+
+    /// Sets the kernel against which the test program is compiled.
+    pub fn with_kernel(mut self, kernel_source: &str) -> Self {
+        self.kernel = Some(kernel_source.to_string());
+        self
+    }
+
+    /// Makes procedures of the specified library available to the test program.
+    ///
+    /// Libraries are searched for imported modules in the order in which they were added, after
+    /// Miden standard library.
+    pub fn with_library<L>(mut self, library: L) -> Self
+    where
+        L: ModuleProvider + 'static,
+    {
+        self.libraries.push(Rc::new(library));
+        self
+    }
+
+    /// Puts the test into the debug mode.
+    pub fn with_debug_mode(mut self, in_debug_mode: bool) -> Self {
+        self.in_debug_mode = in_debug_mode;
+        self
+    }
+
+    // TEST METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Asserts that executing the test results in the expected stack state. The expected stack
+    /// is specified starting from the top; the remaining stack slots are expected to be zeros.
+    pub fn expect_stack(&self, final_stack: &[u64]) {
+        let expected = convert_to_stack(final_stack);
+        let result = self.get_last_stack_state();
+        assert_eq!(expected, result, "unexpected final stack state");
+    }
+
+    /// Asserts that executing the test results in the expected stack state, and that the memory
+    /// of the root context contains the expected word at the specified address.
+    pub fn expect_stack_and_memory(
+        &self,
+        final_stack: &[u64],
+        mem_addr: u64,
+        expected_mem: &[u64],
+    ) {
+        let expected_mem = expected_mem
+            .iter()
+            .map(|&v| Felt::new(v))
+            .collect::<Vec<_>>();
+        let mem_state = self
+            .execute_process()
+            .get_memory_value(0, mem_addr)
+            .expect("memory address was never written to");
+        assert_eq!(expected_mem, mem_state, "unexpected memory state");
+
+        self.expect_stack(final_stack);
+    }
+
+    /// Asserts that executing the test inside a proptest results in the expected stack state.
+    /// Instead of panicking, the proptest fails if the assertion does not hold.
+    pub fn prop_expect_stack(&self, final_stack: &[u64]) -> Result<(), TestCaseError> {
+        let expected = convert_to_stack(final_stack);
+        let result = self.get_last_stack_state();
+        prop_assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    /// Asserts that compiling (for [TestError::AssemblyError]) or executing (for
+    /// [TestError::ExecutionError]) the test results in an error which contains the specified
+    /// substring.
+    ///
+    /// # Panics
+    /// Panics if no error occurs, or if the error does not contain the specified substring.
+    pub fn expect_error(&self, error: TestError) {
+        match error {
+            TestError::AssemblyError(substr) => match self.compile() {
+                Ok(_) => panic!("expected assembly error containing '{substr}', but compiled"),
+                Err(err) => assert_error_contains(&err, substr),
+            },
+            TestError::ExecutionError(substr) => match self.execute() {
+                Ok(_) => panic!("expected execution error containing '{substr}', but executed"),
+                Err(err) => assert_error_contains(&err, substr),
+            },
+        }
+    }
+
+    /// Generates a proof of execution of the test program and asserts that the proof is accepted
+    /// by the verifier. Stack inputs of the test are used as public inputs of the program.
+    ///
+    /// # Panics
+    /// Panics if compilation, execution, or verification fails.
+    pub fn prove_and_verify(&self) {
+        let program = self.compile_unwrap();
+        let (outputs, proof) = prover::prove(&program, &self.inputs(), &ProofOptions::default())
+            .expect("failed to prove test program");
+
+        let result = verifier::verify(program.hash(), &self.stack_inputs, &outputs, proof);
+        assert!(result.is_ok(), "proof verification failed: {result:?}");
+    }
+
+    /// Returns the top of the stack after executing the test.
+    ///
+    /// # Panics
+    /// Panics if compilation or execution fails.
+    pub fn get_last_stack_state(&self) -> StackTopState {
+        let trace = self.execute().expect("failed to execute test program");
+        trace.last_stack_state()
+    }
+
+    /// Executes the test and returns `len` consecutive memory words of the root context, starting
+    /// at the specified address. Words which were never written to are returned as zeros.
+    ///
+    /// # Panics
+    /// Panics if compilation or execution fails.
+    pub fn get_memory_words(&self, mem_addr: u64, len: usize) -> Vec<Word> {
+        let process = self.execute_process();
+        (mem_addr..mem_addr + len as u64)
+            .map(|addr| process.get_memory_value(0, addr).unwrap_or([Felt::ZERO; 4]))
+            .collect()
+    }
+
+    // UTILITY METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns inputs of the test.
+    ///
+    /// # Panics
+    /// Panics if the inputs are invalid (e.g., too many stack inputs were provided).
+    pub fn inputs(&self) -> ProgramInputs {
+        ProgramInputs::new(
+            &self.stack_inputs,
+            &self.advice_tape,
+            self.advice_sets.clone(),
+        )
+        .expect("invalid test inputs")
+    }
+
+    /// Compiles the test source into a program.
+    pub fn compile(&self) -> Result<Program, AssemblyError> {
+        let libraries = Libraries(self.libraries.clone());
+        let assembler = Assembler::new()
+            .with_debug_mode(self.in_debug_mode)
+            .with_module_provider(libraries);
+
+        match self.kernel.as_ref() {
+            Some(kernel) => assembler.with_kernel(kernel)?,
+            None => assembler,
+        }
+        .compile(&self.source)
+    }
+
+    /// Compiles the test source and executes the resulting program with the test inputs.
+    ///
+    /// # Panics
+    /// Panics if compilation fails.
+    pub fn execute(&self) -> Result<ExecutionTrace, ExecutionError> {
+        let program = self.compile_unwrap();
+        processor::execute(&program, &self.inputs())
+    }
+
+    /// Compiles the test source and returns an iterator over VM states at each clock cycle of
+    /// the program execution.
+    ///
+    /// # Panics
+    /// Panics if compilation fails.
+    pub fn execute_iter(&self) -> VmStateIterator {
+        let program = self.compile_unwrap();
+        processor::execute_iter(&program, &self.inputs())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn compile_unwrap(&self) -> Program {
+        match self.compile() {
+            Ok(program) => program,
+            Err(err) => panic!("failed to compile test source: {err}"),
+        }
+    }
+
+    /// Executes the test program and returns the process in its state at the end of execution.
+    fn execute_process(&self) -> Process {
+        let program = self.compile_unwrap();
+        let mut process = Process::new(program.kernel(), self.inputs());
+        process
+            .execute(&program)
+            .expect("failed to execute test program");
+        process
+    }
+}
+
+// LIBRARIES
+// ================================================================================================
+
+/// A module provider which looks up modules in a list of libraries, in order.
+struct Libraries(Vec<Rc<dyn ModuleProvider>>);
+
+impl ModuleProvider for Libraries {
+    fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.0.iter().find_map(|library| library.get_module(id))
+    }
+}
+
+// BRANCH COVERAGE
+// ================================================================================================
+
+/// Executes the program against the specified inputs and asserts that the specified branch of the
+/// SPLIT block with the specified digest was taken at least once (`true` for the `if.true`
+/// branch, `false` for the `else` branch).
+///
+/// # Panics
+/// Panics if the execution fails, if the SPLIT block was never executed, or if only the other
+/// branch of the block was taken.
+pub fn assert_branch_taken(program: &Program, inputs: &ProgramInputs, block: Digest, branch: bool) {
+    let taken = get_taken_branches(program, inputs, block);
+    assert!(
+        !taken.is_empty(),
+        "SPLIT block {block:?} was never executed"
+    );
+    assert!(
+        taken.contains(&branch),
+        "expected {branch} branch of SPLIT block {block:?} to be taken, but it was not"
+    );
+}
+
+/// Executes the program against the specified inputs and returns the branches taken at each
+/// execution of the SPLIT block with the specified digest, in the order of execution.
+///
+/// # Panics
+/// Panics if the execution fails.
+pub fn get_taken_branches(program: &Program, inputs: &ProgramInputs, block: Digest) -> Vec<bool> {
+    let states = processor::execute_iter(program, inputs)
+        .collect::<Result<Vec<_>, _>>()
+        .expect("failed to execute program");
+
+    let mut tracer = BranchTracer {
+        states,
+        clk: 0,
+        cb_table: program.cb_table(),
+        branches: Vec::new(),
+    };
+    tracer.visit(program.root());
+
+    tracer
+        .branches
+        .into_iter()
+        .filter(|(hash, _)| *hash == block)
+        .map(|(_, branch)| branch)
+        .collect()
+}
+
+/// Recovers the branches taken by SPLIT blocks by walking the program MAST alongside the
+/// operations executed by the VM.
+struct BranchTracer<'a> {
+    states: Vec<VmState>,
+    clk: usize,
+    cb_table: &'a CodeBlockTable,
+    branches: Vec<(Digest, bool)>,
+}
+
+impl BranchTracer<'_> {
+    fn visit(&mut self, block: &CodeBlock) {
+        match block {
+            CodeBlock::Join(block) => {
+                self.next_op();
+                self.visit(block.first());
+                self.visit(block.second());
+                self.next_op();
+            }
+            CodeBlock::Split(block) => {
+                let branch = self.stack_top() == Felt::ONE;
+                self.next_op();
+                self.branches.push((block.hash(), branch));
+                if branch {
+                    self.visit(block.on_true());
+                } else {
+                    self.visit(block.on_false());
+                }
+                self.next_op();
+            }
+            CodeBlock::Loop(block) => {
+                // the body is executed while the top of the stack is ONE; after the body, the
+                // condition is consumed by either REPEAT or END
+                let mut condition = self.stack_top();
+                self.next_op();
+                if condition != Felt::ONE {
+                    self.next_op();
+                }
+                while condition == Felt::ONE {
+                    self.visit(block.body());
+                    condition = self.stack_top();
+                    self.next_op();
+                }
+            }
+            CodeBlock::Call(block) => {
+                let cb_table = self.cb_table;
+                let fn_body = cb_table
+                    .get(block.fn_hash())
+                    .expect("function body not found");
+                self.next_op();
+                self.visit(fn_body);
+                self.next_op();
+            }
+            CodeBlock::Span(_) => {
+                // operations within a span (including RESPAN) are never END
+                self.next_op();
+                while self.next_op() != Operation::End {}
+            }
+            CodeBlock::Proxy(_) => unreachable!("proxy blocks cannot be executed"),
+        }
+    }
+
+    /// Returns the top of the stack before the next operation is executed.
+    fn stack_top(&self) -> Felt {
+        self.states[self.clk].stack[0]
+    }
+
+    /// Returns the next executed operation and advances the clock.
+    fn next_op(&mut self) -> Operation {
+        self.clk += 1;
+        self.states[self.clk].op.expect("missing operation")
+    }
+}
+
+// EXECUTION EQUIVALENCE
+// ================================================================================================
+
+/// Effects of a program execution which can be observed outside of the VM.
+///
+/// Two versions of a program which produce the same observable effects on the same inputs are
+/// considered equivalent, even if their execution traces differ (e.g., because one of them
+/// executes fewer operations).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObservableEffects {
+    /// The full state of the stack at the end of execution, starting from the top.
+    pub stack: Vec<u64>,
+    /// The state of the memory of the root context at the end of execution, sorted by address.
+    pub memory: Vec<(u64, [u64; 4])>,
+    /// The number of elements which the program read from the advice tape. As the tape is always
+    /// read from the front, this also identifies which advice values were consumed.
+    pub advice_consumed: usize,
+}
+
+/// Executes the program against the specified inputs and returns the observable effects of the
+/// execution.
+///
+/// The VM does not emit events to the host; the only interaction with the host during execution
+/// is consumption of advice, and thus, it is the only such effect captured here.
+pub fn get_observable_effects(
+    program: &Program,
+    inputs: &ProgramInputs,
+) -> Result<ObservableEffects, ExecutionError> {
+    // the final memory state is available only via the VM state iterator, and thus, the program
+    // is executed via the iterator only
+    let mut states = processor::execute_iter(program, inputs);
+    // advice injectors may write values onto the tape, in which case more values can remain on
+    // the tape than were initially provided
+    let advice_consumed = inputs
+        .advice_tape()
+        .len()
+        .saturating_sub(states.advice_remaining());
+
+    let mut final_state = None;
+    for state in states.by_ref() {
+        final_state = Some(state?);
+    }
+    let final_state = final_state.expect("no VM states");
+
+    let stack = final_state
+        .stack
+        .iter()
+        .map(|value| value.as_int())
+        .collect();
+    let memory = final_state
+        .memory
+        .iter()
+        .map(|&(addr, word)| (addr, word.map(|value| value.as_int())))
+        .collect();
+
+    Ok(ObservableEffects {
+        stack,
+        memory,
+        advice_consumed,
+    })
+}
+
+/// Returns true if executing both programs against the specified inputs results in the same
+/// observable effects (see [ObservableEffects]).
+///
+/// This is intended to validate optimizations of programs (e.g., constant folding): the optimized
+/// program may execute a different sequence of operations, but it must leave the stack and the
+/// memory in the same state and consume the same advice as the original program.
+///
+/// Programs execution of which fails are considered equivalent only if both of them fail with the
+/// same error.
+pub fn traces_equivalent(prog_a: &Program, prog_b: &Program, inputs: &ProgramInputs) -> bool {
+    match (
+        get_observable_effects(prog_a, inputs),
+        get_observable_effects(prog_b, inputs),
+    ) {
+        (Ok(effects_a), Ok(effects_b)) => effects_a == effects_b,
+        (Err(err_a), Err(err_b)) => err_a.to_string() == err_b.to_string(),
+        _ => false,
+    }
+}
+
+// MACROS
+// ================================================================================================
+
+/// Returns a [Test] for the specified source and (optionally) inputs.
+///
+/// Parameters are expected in the following order:
+/// `source`, `stack_inputs` (optional), `advice_tape` (optional), `advice_sets` (optional)
+///
+/// * `source`: a well-formed source string.
+/// * `stack_inputs` (optional): the initial stack; the last value ends up on top of the stack.
+/// * `advice_tape` (optional): the initial advice tape values. When provided, `stack_inputs` and
+///   `advice_sets` are also expected.
+/// * `advice_sets` (optional): the initial advice sets. When provided, `stack_inputs` and
+///   `advice_tape` are also expected.
+#[macro_export]
+macro_rules! build_test {
+    ($source:expr) => {{
+        $crate::Test::new($source)
+    }};
+    ($source:expr, $stack_inputs:expr) => {{
+        $crate::Test::new($source).with_stack_inputs($stack_inputs)
+    }};
+    ($source:expr, $stack_inputs:expr, $advice_tape:expr, $advice_sets:expr) => {{
+        $crate::Test::new($source)
+            .with_stack_inputs($stack_inputs)
+            .with_advice_tape($advice_tape)
+            .with_advice_sets($advice_sets)
+    }};
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts the provided values into a stack state, with the first value on top of the stack.
+pub fn convert_to_stack(values: &[u64]) -> StackTopState {
+    let mut result = [Felt::ZERO; STACK_TOP_SIZE];
+    for (&value, result) in values.iter().zip(result.iter_mut()) {
+        *result = Felt::new(value);
+    }
+    result
+}
+
+/// Panics if neither display nor debug representation of the error contains the substring.
+fn assert_error_contains<E: fmt::Debug + fmt::Display>(err: &E, substr: &str) {
+    let display = err.to_string();
+    let debug = format!("{err:?}");
+    assert!(
+        display.contains(substr) || debug.contains(substr),
+        "expected error containing '{substr}', but got: {display} ({debug})"
+    );
+}
@@ -0,0 +1,173 @@
+use super::{
+    assert_branch_taken, build_test, get_observable_effects, get_taken_branches, traces_equivalent,
+    CodeBlock, Digest, ProgramInputs, Test, TestError,
+};
+use assembly::{parse_module, ModuleAst, ModuleProvider, NamedModuleAst, ProcedureId};
+
+#[test]
+fn expect_stack() {
+    build_test!("begin push.1 push.2 add end").expect_stack(&[3]);
+
+    // stack inputs
+    build_test!("begin mul end", &[2, 3, 4]).expect_stack(&[12, 2]);
+
+    // advice tape
+    build_test!("begin adv_push.2 add end", &[], &[5, 6], vec![]).expect_stack(&[11]);
+
+    // stdlib is available by default
+    let source = "use.std::math::u64 begin exec.u64::checked_add end";
+    build_test!(source, &[1, 0, 2, 0]).expect_stack(&[0, 3]);
+}
+
+#[test]
+fn expect_error() {
+    let test = build_test!("begin push.1 push.2 add foo end");
+    test.expect_error(TestError::AssemblyError("foo"));
+
+    let test = build_test!("begin push.1 push.2 assert_eq end");
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+#[should_panic]
+fn expect_error_without_error() {
+    let test = build_test!("begin push.1 push.1 assert_eq end");
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn prove_and_verify() {
+    build_test!("begin mul movup.2 drop end", &[1, 2, 3]).prove_and_verify();
+}
+
+#[test]
+fn custom_library() {
+    const MODULE: &str = "dummy::math";
+    const SOURCE: &str = "export.double dup add end";
+
+    struct DummyLibrary {
+        module: ModuleAst,
+    }
+
+    impl ModuleProvider for DummyLibrary {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            let module = self.module.named_ref(MODULE);
+            module.get_procedure(id).is_some().then_some(module)
+        }
+    }
+
+    let source = "use.dummy::math begin exec.math::double end";
+    let library = DummyLibrary {
+        module: parse_module(SOURCE).unwrap(),
+    };
+    let test = Test::new(source)
+        .with_stack_inputs(&[21])
+        .with_library(library);
+    test.expect_stack(&[42]);
+
+    // without the library, the program does not compile
+    build_test!(source, &[21]).expect_error(TestError::AssemblyError("not found"));
+}
+
+#[test]
+fn branch_taken() {
+    let source = "begin if.true push.2 else push.3 end end";
+    let test = build_test!(source, &[1]);
+    let program = test.compile().unwrap();
+    let split = find_split(program.root()).unwrap();
+    assert_branch_taken(&program, &test.inputs(), split, true);
+
+    let test = build_test!(source, &[0]);
+    assert_branch_taken(&program, &test.inputs(), split, false);
+
+    // both branches are taken by a SPLIT block executed in a loop
+    let source = "begin while.true if.true push.2 drop else push.3 drop end end end";
+    let test = build_test!(source, &[0, 0, 1, 1, 1]);
+    let program = test.compile().unwrap();
+    let split = find_split(program.root()).unwrap();
+    assert_eq!(
+        vec![true, false],
+        get_taken_branches(&program, &test.inputs(), split)
+    );
+}
+
+#[test]
+#[should_panic(expected = "expected false branch")]
+fn branch_taken_wrong_branch() {
+    let test = build_test!("begin push.1 if.true push.2 else push.3 end end");
+    let program = test.compile().unwrap();
+    let split = find_split(program.root()).unwrap();
+    assert_branch_taken(&program, &test.inputs(), split, false);
+}
+
+#[test]
+fn const_folded_program_equivalent() {
+    // the original program computes a constant expression at runtime, while the const-folded
+    // program pushes the result directly
+    let original = "
+        begin
+            adv_push.1
+            push.2 push.3 add
+            push.4 mul
+            add
+            dup mem_store.7
+            mul
+        end";
+    let folded = "
+        begin
+            adv_push.1
+            push.20
+            add
+            dup mem_store.7
+            mul
+        end";
+    let original = build_test!(original).compile().unwrap();
+    let folded = build_test!(folded).compile().unwrap();
+    assert_ne!(original.hash(), folded.hash());
+
+    let inputs = ProgramInputs::new(&[3], &[5, 6], vec![]).unwrap();
+    assert!(traces_equivalent(&original, &folded, &inputs));
+
+    let effects = get_observable_effects(&folded, &inputs).unwrap();
+    assert_eq!(75, effects.stack[0]);
+    assert_eq!(vec![(7, [25, 0, 0, 0])], effects.memory);
+    assert_eq!(1, effects.advice_consumed);
+
+    // a wrongly folded constant changes the outputs
+    let wrong = build_test!("begin adv_push.1 push.21 add dup mem_store.7 mul end");
+    assert!(!traces_equivalent(
+        &original,
+        &wrong.compile().unwrap(),
+        &inputs
+    ));
+
+    // consuming more advice or writing into memory are observable, even if the stack is the same
+    let extra_advice = "begin adv_push.1 adv_push.1 drop push.20 add dup mem_store.7 mul end";
+    let extra_advice = build_test!(extra_advice).compile().unwrap();
+    assert!(!traces_equivalent(&folded, &extra_advice, &inputs));
+
+    let extra_store = "begin adv_push.1 push.20 add dup mem_store.7 dup mem_store.8 mul end";
+    let extra_store = build_test!(extra_store).compile().unwrap();
+    assert!(!traces_equivalent(&folded, &extra_store, &inputs));
+
+    // programs which fail to execute are equivalent only if they fail in the same way
+    let failing = build_test!("begin push.1 push.2 assert_eq end")
+        .compile()
+        .unwrap();
+    assert!(traces_equivalent(&failing, &failing, &inputs));
+    assert!(!traces_equivalent(&failing, &folded, &inputs));
+    let failing_later = build_test!("begin push.1 push.2 push.3 drop assert_eq end")
+        .compile()
+        .unwrap();
+    assert!(!traces_equivalent(&failing, &failing_later, &inputs));
+}
+
+/// Returns the digest of the first SPLIT block found in the specified block.
+fn find_split(block: &CodeBlock) -> Option<Digest> {
+    match block {
+        CodeBlock::Split(block) => Some(block.hash()),
+        CodeBlock::Join(block) => find_split(block.first()).or_else(|| find_split(block.second())),
+        CodeBlock::Loop(block) => find_split(block.body()),
+        _ => None,
+    }
+}
@@ -1,10 +1,20 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use air::{ProcessorAir, PublicInputs};
+use air::{FieldExtension, HashFunction, ProcessorAir, PublicInputs};
 use core::fmt;
-use vm_core::{utils::collections::Vec, ProgramOutputs};
+use vm_core::{
+    artifact::{read_and_check_header, write_header, ArtifactKind},
+    utils::{
+        collections::Vec, string::ToString, ByteReader, ByteWriter, Deserializable,
+        DeserializationError, Serializable, SliceReader,
+    },
+    Felt, ProgramOutputs, StarkField,
+};
 use winterfell::VerifierError;
 
+#[cfg(feature = "tracing")]
+use vm_core::utils::DisplayHex;
+
 // EXPORTS
 // ================================================================================================
 
@@ -32,6 +42,7 @@ pub use winterfell::StarkProof;
 ///
 /// # Errors
 /// Returns an error if the provided proof does not prove a correct execution of the program.
+#[cfg_attr(feature = "tracing", tracing::instrument(name = "verify", skip_all))]
 pub fn verify(
     program_hash: Digest,
     stack_inputs: &[u64],
@@ -50,7 +61,191 @@ pub fn verify(
 
     // build public inputs and try to verify the proof
     let pub_inputs = PublicInputs::new(program_hash, stack_input_felts, outputs.clone());
-    winterfell::verify::<ProcessorAir>(proof, pub_inputs).map_err(VerificationError::VerifierError)
+    winterfell::verify::<ProcessorAir>(proof, pub_inputs)
+        .map_err(VerificationError::VerifierError)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        program_hash = %DisplayHex(&<[u8; 32]>::from(program_hash)),
+        "verified program"
+    );
+
+    Ok(())
+}
+
+// PROOF PARAMETERS
+// ================================================================================================
+
+/// Public parameters of a STARK proof which need to match for proofs to be processed together
+/// (e.g., aggregated or verified in a batch).
+///
+/// Parameters which depend on the proven program (e.g., trace length) are not included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofParams {
+    /// Little-endian bytes of the modulus of the base field of the proof.
+    pub field_modulus: Vec<u8>,
+    /// Extension of the base field used to draw random values from.
+    pub field_extension: FieldExtension,
+    /// Hash function used to build commitments in the proof.
+    pub hash_fn: HashFunction,
+    /// Factor by which the execution trace is extended for the low-degree extension.
+    pub blowup_factor: usize,
+    /// Number of queries against the committed values.
+    pub num_queries: usize,
+    /// Number of leading zeros required in the proof-of-work nonce.
+    pub grinding_factor: u32,
+}
+
+/// Returns public parameters of the specified proof.
+pub fn proof_params(proof: &StarkProof) -> ProofParams {
+    let options = proof.options();
+    ProofParams {
+        field_modulus: proof.context.field_modulus_bytes().to_vec(),
+        field_extension: options.field_extension(),
+        hash_fn: options.hash_fn(),
+        blowup_factor: options.blowup_factor(),
+        num_queries: options.num_queries(),
+        grinding_factor: options.grinding_factor(),
+    }
+}
+
+/// Returns true if the specified proofs were generated with the same public parameters (see
+/// [ProofParams]), and thus, can be processed together.
+pub fn proofs_compatible(a: &StarkProof, b: &StarkProof) -> bool {
+    proof_params(a) == proof_params(b)
+}
+
+// VERIFICATION BUNDLE
+// ================================================================================================
+
+/// A self-contained artifact which holds everything needed to verify execution of a program.
+///
+/// The bundle consists of the hash of the executed program, the public stack inputs, the claimed
+/// program outputs, and the STARK proof of execution. Once serialized, a bundle can be
+/// distributed as a single file, and a verifier needs nothing else to check the execution.
+///
+/// A serialized bundle starts with an artifact header of the [ArtifactKind::Bundle] kind (see
+/// [vm_core::artifact]).
+pub struct VerificationBundle {
+    program_hash: Digest,
+    stack_inputs: Vec<u64>,
+    outputs: ProgramOutputs,
+    proof: StarkProof,
+}
+
+impl VerificationBundle {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [VerificationBundle] instantiated from the specified parameters.
+    ///
+    /// Stack inputs and outputs are expected to be ordered in the same way as for [verify()].
+    pub fn new(
+        program_hash: Digest,
+        stack_inputs: Vec<u64>,
+        outputs: ProgramOutputs,
+        proof: StarkProof,
+    ) -> Self {
+        Self {
+            program_hash,
+            stack_inputs,
+            outputs,
+            proof,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the program whose execution is proven by this bundle.
+    pub fn program_hash(&self) -> Digest {
+        self.program_hash
+    }
+
+    /// Returns the public stack inputs against which the program was executed.
+    pub fn stack_inputs(&self) -> &[u64] {
+        &self.stack_inputs
+    }
+
+    /// Returns the claimed outputs of the program.
+    pub fn outputs(&self) -> &ProgramOutputs {
+        &self.outputs
+    }
+
+    /// Returns the proof of the program's execution.
+    pub fn proof(&self) -> &StarkProof {
+        &self.proof
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns Ok(()) if the proof in this bundle proves a correct execution of the program with
+    /// the bundled hash against the bundled stack inputs and outputs.
+    ///
+    /// # Errors
+    /// Returns an error if the proof does not prove a correct execution of the program.
+    pub fn verify(self) -> Result<(), VerificationError> {
+        verify(
+            self.program_hash,
+            &self.stack_inputs,
+            &self.outputs,
+            self.proof,
+        )
+    }
+
+    // SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a bundle deserialized from the specified bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid serialized bundle, or if there are unread
+    /// bytes left after the bundle has been deserialized.
+    pub fn read_from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let bundle = Self::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(bundle)
+    }
+}
+
+impl Serializable for VerificationBundle {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_header(target, ArtifactKind::Bundle);
+        self.program_hash.write_into(target);
+        write_u64_vec(target, &self.stack_inputs);
+        write_u64_vec(target, self.outputs.stack());
+        write_u64_vec(target, self.outputs.overflow_addrs());
+
+        let proof_bytes = self.proof.to_bytes();
+        target.write_u32(proof_bytes.len() as u32);
+        target.write_u8_slice(&proof_bytes);
+    }
+}
+
+impl Deserializable for VerificationBundle {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_and_check_header(source, ArtifactKind::Bundle)?;
+        let program_hash = Digest::read_from(source)?;
+        let stack_inputs = read_u64_vec(source)?;
+
+        // make sure the outputs are valid field elements before building program outputs
+        let stack = read_elements(source)?;
+        let overflow_addrs = read_elements(source)?;
+        let outputs = ProgramOutputs::new(stack, overflow_addrs);
+
+        let proof_len = source.read_u32()? as usize;
+        let proof = StarkProof::from_bytes(&source.read_u8_vec(proof_len)?)?;
+
+        Ok(Self {
+            program_hash,
+            stack_inputs,
+            outputs,
+            proof,
+        })
+    }
 }
 
 // ERRORS
@@ -70,3 +265,32 @@ impl fmt::Display for VerificationError {
         write!(f, "{self:?}")
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the length of the specified vector followed by its values into the target.
+fn write_u64_vec<W: ByteWriter>(target: &mut W, values: &[u64]) {
+    target.write_u32(values.len() as u32);
+    for &value in values {
+        target.write_u64(value);
+    }
+}
+
+/// Reads a vector of values written by [write_u64_vec()] from the source.
+fn read_u64_vec<R: ByteReader>(source: &mut R) -> Result<Vec<u64>, DeserializationError> {
+    let len = source.read_u32()? as usize;
+    (0..len).map(|_| source.read_u64()).collect()
+}
+
+/// Reads a vector of values written by [write_u64_vec()] from the source, and makes sure that all
+/// values are valid field elements.
+fn read_elements<R: ByteReader>(source: &mut R) -> Result<Vec<u64>, DeserializationError> {
+    let values = read_u64_vec(source)?;
+    match values.iter().find(|&&value| value >= Felt::MODULUS) {
+        Some(value) => Err(DeserializationError::InvalidValue(
+            value.to_string() + " is not a valid field element",
+        )),
+        None => Ok(values),
+    }
+}
@@ -0,0 +1,61 @@
+//! JavaScript bindings for the functions exposed by this crate.
+//!
+//! Arguments and results which are not strings or byte arrays are passed as plain JavaScript
+//! objects with the same structure as the corresponding Rust types (e.g., [RunResult]).
+//! Malformed arguments are reported in the same way as other errors (i.e., via the `error` field
+//! of a result, or by returning `false` from `verify()`).
+
+use super::{CompileResult, Outputs, RunResult};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// Compiles the specified source code; returns a [CompileResult] object.
+#[wasm_bindgen]
+pub fn compile(source: &str) -> JsValue {
+    to_js(&super::compile(source))
+}
+
+/// Compiles and executes the specified source code; returns a [RunResult] object.
+///
+/// `stack_inputs` and `advice_tape` are expected to be arrays of decimal or `0x`-prefixed
+/// hexadecimal strings.
+#[wasm_bindgen]
+pub fn run(source: &str, stack_inputs: JsValue, advice_tape: JsValue) -> JsValue {
+    let result = from_js::<Vec<String>>(stack_inputs)
+        .and_then(|stack_inputs| Ok((stack_inputs, from_js::<Vec<String>>(advice_tape)?)))
+        .map(|(stack_inputs, advice_tape)| super::run(source, &stack_inputs, &advice_tape))
+        .unwrap_or_else(|error| RunResult {
+            error: Some(error),
+            ..Default::default()
+        });
+    to_js(&result)
+}
+
+/// Returns true if the specified proof is valid for the specified program hash, stack inputs,
+/// and outputs.
+///
+/// `stack_inputs` is expected to be an array of decimal or `0x`-prefixed hexadecimal strings, and
+/// `outputs` is expected to be an [Outputs] object (e.g., as returned by `run()`).
+#[wasm_bindgen]
+pub fn verify(hash: &str, stack_inputs: JsValue, outputs: JsValue, proof_bytes: &[u8]) -> bool {
+    match (
+        from_js::<Vec<String>>(stack_inputs),
+        from_js::<Outputs>(outputs),
+    ) {
+        (Ok(stack_inputs), Ok(outputs)) => {
+            super::verify(hash, &stack_inputs, &outputs, proof_bytes)
+        }
+        _ => false,
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn from_js<T: DeserializeOwned>(value: JsValue) -> Result<T, String> {
+    serde_wasm_bindgen::from_value(value).map_err(|err| format!("Invalid argument - {err}"))
+}
+
+fn to_js<T: Serialize>(value: &T) -> JsValue {
+    serde_wasm_bindgen::to_value(value).expect("results should be serializable")
+}
@@ -0,0 +1,181 @@
+use assembly::Assembler;
+use serde_derive::{Deserialize, Serialize};
+use stdlib::StdLibrary;
+use verifier::StarkProof;
+use vm_core::{
+    artifact::{strip_header, ArtifactKind},
+    felt_fmt, Program, ProgramInputs, ProgramOutputs, StarkField,
+};
+
+#[cfg(feature = "wasm")]
+pub mod bindings;
+
+#[cfg(test)]
+mod tests;
+
+// RESULTS
+// ================================================================================================
+
+/// Result of compiling a program via [compile()].
+///
+/// Exactly one of the fields is set.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompileResult {
+    /// Hex-encoded hash of the compiled program.
+    pub hash: Option<String>,
+    /// Description of the error which occurred during compilation.
+    pub error: Option<String>,
+}
+
+/// Result of executing a program via [run()].
+///
+/// If the execution failed, `outputs` are not set and `cycles` is zero.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunResult {
+    /// Outputs of the program.
+    pub outputs: Option<Outputs>,
+    /// Number of VM cycles it took to execute the program.
+    pub cycles: usize,
+    /// Description of the error which occurred during compilation or execution.
+    pub error: Option<String>,
+}
+
+/// Outputs of a program execution.
+///
+/// Values are encoded as decimal strings because JavaScript numbers cannot represent all 64-bit
+/// values exactly. This matches the format of output files used by Miden CLI.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Outputs {
+    pub stack: Vec<String>,
+    pub overflow_addrs: Vec<String>,
+}
+
+impl From<ProgramOutputs> for Outputs {
+    fn from(outputs: ProgramOutputs) -> Self {
+        Self {
+            stack: to_strings(outputs.stack()),
+            overflow_addrs: to_strings(outputs.overflow_addrs()),
+        }
+    }
+}
+
+impl TryFrom<&Outputs> for ProgramOutputs {
+    type Error = String;
+
+    fn try_from(outputs: &Outputs) -> Result<Self, Self::Error> {
+        Ok(ProgramOutputs::new(
+            parse_values(&outputs.stack)?,
+            parse_values(&outputs.overflow_addrs)?,
+        ))
+    }
+}
+
+// API
+// ================================================================================================
+
+/// Compiles the specified source code against Miden standard library.
+pub fn compile(source: &str) -> CompileResult {
+    match compile_program(source) {
+        Ok(program) => CompileResult {
+            hash: Some(felt_fmt::format_digest(&program.hash())),
+            error: None,
+        },
+        Err(error) => CompileResult {
+            hash: None,
+            error: Some(error),
+        },
+    }
+}
+
+/// Compiles the specified source code and executes the resulting program with the specified
+/// inputs.
+///
+/// Stack inputs and advice tape values are expected to be decimal or `0x`-prefixed hexadecimal
+/// strings of valid field elements. Stack inputs are ordered as if they would be pushed onto the
+/// stack one by one.
+pub fn run(source: &str, stack_inputs: &[String], advice_tape: &[String]) -> RunResult {
+    match execute_program(source, stack_inputs, advice_tape) {
+        Ok((outputs, cycles)) => RunResult {
+            outputs: Some(outputs),
+            cycles,
+            error: None,
+        },
+        Err(error) => RunResult {
+            error: Some(error),
+            ..Default::default()
+        },
+    }
+}
+
+/// Returns true if the specified proof attests that a program with the specified hash, executed
+/// against the specified stack inputs, produced the specified outputs.
+///
+/// The program hash is expected to be hex-encoded as returned by [compile()], and the proof is
+/// expected to be in the same format as proof files written by Miden CLI: a `StarkProof`
+/// serialized via `StarkProof::to_bytes()` and preceded by the artifact header (see
+/// [vm_core::artifact]). Returns false if any of the arguments is malformed.
+pub fn verify(hash: &str, stack_inputs: &[String], outputs: &Outputs, proof_bytes: &[u8]) -> bool {
+    verify_proof(hash, stack_inputs, outputs, proof_bytes).is_ok()
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn compile_program(source: &str) -> Result<Program, String> {
+    Assembler::new()
+        .with_module_provider(StdLibrary::default())
+        .compile(source)
+        .map_err(|err| format!("Failed to compile program - {err}"))
+}
+
+fn execute_program(
+    source: &str,
+    stack_inputs: &[String],
+    advice_tape: &[String],
+) -> Result<(Outputs, usize), String> {
+    let program = compile_program(source)?;
+    let inputs = ProgramInputs::new(
+        &parse_values(stack_inputs)?,
+        &parse_values(advice_tape)?,
+        Vec::new(),
+    )
+    .map_err(|err| format!("Invalid program inputs - {err:?}"))?;
+
+    let trace = processor::execute(&program, &inputs)
+        .map_err(|err| format!("Failed to execute program - {err}"))?;
+
+    Ok((trace.program_outputs().into(), trace.num_cycles()))
+}
+
+fn verify_proof(
+    hash: &str,
+    stack_inputs: &[String],
+    outputs: &Outputs,
+    proof_bytes: &[u8],
+) -> Result<(), String> {
+    let program_hash = felt_fmt::parse_digest(hash).map_err(|err| err.to_string())?;
+    let proof_bytes =
+        strip_header(proof_bytes, ArtifactKind::Proof).map_err(|err| err.to_string())?;
+    let proof = StarkProof::from_bytes(proof_bytes).map_err(|err| err.to_string())?;
+    let outputs = ProgramOutputs::try_from(outputs)?;
+
+    verifier::verify(program_hash, &parse_values(stack_inputs)?, &outputs, proof)
+        .map_err(|err| err.to_string())
+}
+
+/// Parses a list of decimal or `0x`-prefixed hexadecimal strings into a list of integers; every
+/// value must be a valid field element.
+fn parse_values(values: &[String]) -> Result<Vec<u64>, String> {
+    values
+        .iter()
+        .map(|value| {
+            felt_fmt::parse(value)
+                .map(|value| value.as_int())
+                .map_err(|err| format!("Invalid value '{value}' - {err}"))
+        })
+        .collect()
+}
+
+fn to_strings(values: &[u64]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
@@ -0,0 +1,122 @@
+use super::{compile, run, verify, Outputs};
+use vm_core::{
+    artifact::{with_header, ArtifactKind, HEADER_LEN},
+    felt_fmt, Felt, ProgramInputs, StarkField,
+};
+
+const SOURCE: &str = "begin add end";
+
+#[test]
+fn compile_program() {
+    let result = compile(SOURCE);
+    assert!(result.error.is_none());
+    assert_eq!(64, result.hash.unwrap().len());
+
+    let result = compile("begin add");
+    assert!(result.hash.is_none());
+    assert!(result
+        .error
+        .unwrap()
+        .starts_with("Failed to compile program"));
+}
+
+#[test]
+fn run_program() {
+    let result = run(SOURCE, &to_strings(&[1, 2]), &[]);
+    assert!(result.error.is_none());
+    assert!(result.cycles > 0);
+
+    let outputs = result.outputs.unwrap();
+    assert_eq!("3", outputs.stack[0]);
+    assert!(outputs.stack[1..].iter().all(|value| value == "0"));
+
+    // malformed inputs
+    let result = run(SOURCE, &["one".to_string()], &[]);
+    assert!(result.outputs.is_none());
+    assert!(result.error.unwrap().starts_with("Invalid value 'one'"));
+
+    // values are parsed via the shared field element parser: hex values are accepted, and values
+    // which are not field elements are rejected with its error message
+    let result = run(SOURCE, &["0x1".to_string(), "2".to_string()], &[]);
+    assert_eq!("3", result.outputs.unwrap().stack[0]);
+    let modulus = Felt::MODULUS.to_string();
+    let result = run(SOURCE, core::slice::from_ref(&modulus), &[]);
+    assert!(result.outputs.is_none());
+    let expected = felt_fmt::parse(&modulus).unwrap_err().to_string();
+    assert!(result.error.unwrap().ends_with(&expected));
+
+    // execution errors
+    let result = run("begin adv_push.1 end", &[], &[]);
+    assert!(result.outputs.is_none());
+    assert!(result
+        .error
+        .unwrap()
+        .starts_with("Failed to execute program"));
+
+    // advice tape
+    let result = run("begin adv_push.1 end", &[], &to_strings(&[7]));
+    assert_eq!("7", result.outputs.unwrap().stack[0]);
+}
+
+#[test]
+fn verify_proof() {
+    let stack_inputs = to_strings(&[1, 2]);
+    let hash = compile(SOURCE).hash.unwrap();
+    let (outputs, proof_bytes) = prove(&[1, 2]);
+
+    assert!(verify(&hash, &stack_inputs, &outputs, &proof_bytes));
+
+    // wrong inputs, outputs, or program hash
+    assert!(!verify(&hash, &to_strings(&[2, 2]), &outputs, &proof_bytes));
+    let mut wrong_outputs = outputs.clone();
+    wrong_outputs.stack[0] = "4".to_string();
+    assert!(!verify(&hash, &stack_inputs, &wrong_outputs, &proof_bytes));
+    let other_hash = compile("begin mul end").hash.unwrap();
+    assert!(!verify(&other_hash, &stack_inputs, &outputs, &proof_bytes));
+
+    // program hashes and values are parsed via the shared field element parser
+    assert!(verify(
+        &format!("0x{hash}"),
+        &stack_inputs,
+        &outputs,
+        &proof_bytes
+    ));
+    assert!(!verify(
+        &"f".repeat(64),
+        &stack_inputs,
+        &outputs,
+        &proof_bytes
+    ));
+    let mut invalid_inputs = stack_inputs.clone();
+    invalid_inputs[0] = (Felt::MODULUS + 1).to_string();
+    assert!(!verify(&hash, &invalid_inputs, &outputs, &proof_bytes));
+
+    // malformed arguments
+    assert!(!verify("0x12", &stack_inputs, &outputs, &proof_bytes));
+    assert!(!verify(&hash, &stack_inputs, &outputs, &proof_bytes[1..]));
+
+    // proofs without the artifact header are rejected
+    assert!(!verify(
+        &hash,
+        &stack_inputs,
+        &outputs,
+        &proof_bytes[HEADER_LEN..]
+    ));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn prove(stack_inputs: &[u64]) -> (Outputs, Vec<u8>) {
+    let program = super::compile_program(SOURCE).unwrap();
+    let inputs = ProgramInputs::new(stack_inputs, &[], vec![]).unwrap();
+    let (outputs, proof) = prover::prove(&program, &inputs, &Default::default()).unwrap();
+    (
+        outputs.into(),
+        with_header(ArtifactKind::Proof, &proof.to_bytes()),
+    )
+}
+
+fn to_strings(values: &[u64]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
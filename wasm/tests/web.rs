@@ -0,0 +1,80 @@
+//! Tests for JavaScript bindings. These tests can be run in a headless browser via:
+//! `wasm-pack test --headless --firefox -- --features wasm` (from the `wasm` directory).
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use miden_wasm::{bindings, CompileResult, Outputs, RunResult};
+use serde::Serialize;
+use vm_core::ProgramInputs;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const SOURCE: &str = "begin add end";
+
+#[wasm_bindgen_test]
+fn compile() {
+    let result: CompileResult = from_js(bindings::compile(SOURCE));
+    assert!(result.error.is_none());
+    assert!(result.hash.is_some());
+
+    let result: CompileResult = from_js(bindings::compile("begin add"));
+    assert!(result.hash.is_none());
+    assert!(result.error.is_some());
+}
+
+#[wasm_bindgen_test]
+fn run() {
+    let result: RunResult = from_js(bindings::run(SOURCE, to_js(&["1", "2"]), to_js(&[""; 0])));
+    assert!(result.error.is_none());
+    assert!(result.cycles > 0);
+    assert_eq!("3", result.outputs.unwrap().stack[0]);
+
+    // arguments of unexpected types are reported as errors
+    let result: RunResult = from_js(bindings::run(SOURCE, JsValue::from(1), JsValue::NULL));
+    assert!(result.outputs.is_none());
+    assert!(result.error.is_some());
+}
+
+#[wasm_bindgen_test]
+fn verify() {
+    let program = assembly::Assembler::new().compile(SOURCE).unwrap();
+    let inputs = ProgramInputs::new(&[1, 2], &[], vec![]).unwrap();
+    let (outputs, proof) = prover::prove(&program, &inputs, &Default::default()).unwrap();
+    let outputs = Outputs::from(outputs);
+    let proof_bytes = proof.to_bytes();
+
+    let result: CompileResult = from_js(bindings::compile(SOURCE));
+    let hash = result.hash.unwrap();
+
+    let stack_inputs = ["1", "2"];
+    assert!(bindings::verify(
+        &hash,
+        to_js(&stack_inputs),
+        to_js(&outputs),
+        &proof_bytes
+    ));
+    assert!(!bindings::verify(
+        &hash,
+        to_js(&["2", "2"]),
+        to_js(&outputs),
+        &proof_bytes
+    ));
+    assert!(!bindings::verify(
+        &hash,
+        to_js(&stack_inputs),
+        JsValue::NULL,
+        &proof_bytes
+    ));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn from_js<T: serde::de::DeserializeOwned>(value: JsValue) -> T {
+    serde_wasm_bindgen::from_value(value).unwrap()
+}
+
+fn to_js<T: Serialize + ?Sized>(value: &T) -> JsValue {
+    serde_wasm_bindgen::to_value(value).unwrap()
+}